@@ -0,0 +1,17 @@
+﻿using System;
+
+namespace ExampleProject {
+    public static class Program {
+        public delegate int AddDelegate(int a, int b);
+        public static int Add(int a, int b) {
+            return a + b;
+        }
+
+        public delegate void GreetDelegate(int times);
+        public static void Greet(int times) {
+            for (int i = 0; i < times; i++) {
+                Console.WriteLine("Hello from C#!");
+            }
+        }
+    }
+}
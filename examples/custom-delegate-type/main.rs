@@ -0,0 +1,30 @@
+use netcorehost::{nethost, pdcstr};
+
+fn main() {
+    let hostfxr = nethost::load_hostfxr().unwrap();
+    let context = hostfxr.initialize_for_runtime_config(pdcstr!("examples/custom-delegate-type/ExampleProject/bin/Debug/net8.0/ExampleProject.runtimeconfig.json")).unwrap();
+    let delegate_loader = context
+        .get_delegate_loader_for_assembly(pdcstr!(
+            "examples/custom-delegate-type/ExampleProject/bin/Debug/net8.0/ExampleProject.dll"
+        ))
+        .unwrap();
+
+    // the signature of the method is described by a delegate type declared in the assembly.
+    let add = delegate_loader
+        .get_function::<fn(i32, i32) -> i32>(
+            pdcstr!("ExampleProject.Program, ExampleProject"),
+            pdcstr!("Add"),
+            pdcstr!("ExampleProject.Program+AddDelegate, ExampleProject"),
+        )
+        .unwrap();
+    println!("1 + 2 = {}", add(1, 2));
+
+    let greet = delegate_loader
+        .get_function::<fn(i32)>(
+            pdcstr!("ExampleProject.Program, ExampleProject"),
+            pdcstr!("Greet"),
+            pdcstr!("ExampleProject.Program+GreetDelegate, ExampleProject"),
+        )
+        .unwrap();
+    greet(2);
+}
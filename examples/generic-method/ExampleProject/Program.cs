@@ -0,0 +1,9 @@
+﻿using System;
+
+namespace ExampleProject {
+    public static class Program {
+        public static T Max<T>(T a, T b) where T : IComparable<T> {
+            return a.CompareTo(b) >= 0 ? a : b;
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use netcorehost::{nethost, pdcstr};
+
+fn main() {
+    let hostfxr = nethost::load_hostfxr().unwrap();
+    let context = hostfxr.initialize_for_runtime_config(pdcstr!("examples/generic-method/ExampleProject/bin/Debug/net8.0/ExampleProject.runtimeconfig.json")).unwrap();
+    let delegate_loader = context
+        .get_delegate_loader_for_assembly(pdcstr!(
+            "examples/generic-method/ExampleProject/bin/Debug/net8.0/ExampleProject.dll"
+        ))
+        .unwrap();
+
+    // generic methods are instantiated by the NativeMethodBinding helper, which is compiled into the assembly.
+    let max_int = delegate_loader
+        .get_function_generic::<fn(i32, i32) -> i32>(
+            pdcstr!("NativeMethodBinding, ExampleProject"),
+            pdcstr!("ExampleProject.Program, ExampleProject"),
+            pdcstr!("Max"),
+            &[pdcstr!("System.Int32")],
+        )
+        .unwrap();
+    println!("Max(1, 2) = {}", max_int(1, 2));
+
+    let max_double = delegate_loader
+        .get_function_generic::<fn(f64, f64) -> f64>(
+            pdcstr!("NativeMethodBinding, ExampleProject"),
+            pdcstr!("ExampleProject.Program, ExampleProject"),
+            pdcstr!("Max"),
+            &[pdcstr!("System.Double")],
+        )
+        .unwrap();
+    println!("Max(1.5, 0.5) = {}", max_double(1.5, 0.5));
+}
@@ -0,0 +1,15 @@
+﻿using System;
+using System.Runtime.InteropServices;
+
+namespace ExampleProject {
+    public static class Program {
+        public static void Main() {
+            Console.WriteLine("Hello from Main!");
+        }
+
+        [UnmanagedCallersOnly]
+        public static void HelloWorld() {
+            Console.WriteLine("Hello from C#!");
+        }
+    }
+}
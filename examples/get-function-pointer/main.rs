@@ -0,0 +1,22 @@
+use netcorehost::{nethost, pdcstr};
+
+fn main() {
+    let hostfxr = nethost::load_hostfxr().unwrap();
+    let context = hostfxr
+        .initialize_for_dotnet_command_line(pdcstr!(
+            "examples/get-function-pointer/ExampleProject/bin/Debug/net8.0/ExampleProject.dll"
+        ))
+        .unwrap();
+
+    // the assembly of the app is already known to the runtime, so no assembly has to be loaded.
+    let delegate_loader = context.get_delegate_loader().unwrap();
+    let hello_world = delegate_loader
+        .get_function_with_unmanaged_callers_only::<fn()>(
+            pdcstr!("ExampleProject.Program, ExampleProject"),
+            pdcstr!("HelloWorld"),
+        )
+        .unwrap();
+    hello_world();
+
+    context.run_app().as_hosting_exit_code().unwrap();
+}
@@ -0,0 +1,28 @@
+use std::fs;
+
+use netcorehost::{nethost, pdcstr};
+
+fn main() {
+    let hostfxr = nethost::load_hostfxr().unwrap();
+    let context = hostfxr.initialize_for_runtime_config(pdcstr!("examples/load-assembly-bytes/ExampleProject/bin/Debug/net8.0/ExampleProject.runtimeconfig.json")).unwrap();
+    let delegate_loader = context.get_delegate_loader().unwrap();
+
+    // the assembly could just as well be embedded into the executable or downloaded.
+    let assembly =
+        fs::read("examples/load-assembly-bytes/ExampleProject/bin/Debug/net8.0/ExampleProject.dll")
+            .unwrap();
+    let symbols =
+        fs::read("examples/load-assembly-bytes/ExampleProject/bin/Debug/net8.0/ExampleProject.pdb")
+            .ok();
+    delegate_loader
+        .load_assembly_from_bytes(&assembly, symbols.as_deref())
+        .unwrap();
+
+    let hello_world = delegate_loader
+        .get_function_with_unmanaged_callers_only::<fn()>(
+            pdcstr!("ExampleProject.Program, ExampleProject"),
+            pdcstr!("HelloWorld"),
+        )
+        .unwrap();
+    hello_world();
+}
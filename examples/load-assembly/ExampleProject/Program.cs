@@ -0,0 +1,11 @@
+﻿using System;
+using System.Runtime.InteropServices;
+
+namespace ExampleProject {
+    public static class Program {
+        [UnmanagedCallersOnly]
+        public static void HelloWorld() {
+            Console.WriteLine("Hello from C#!");
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use std::env;
+
+use netcorehost::{nethost, pdcstr, pdcstring::PdCString};
+
+fn main() {
+    let hostfxr = nethost::load_hostfxr().unwrap();
+    let context = hostfxr.initialize_for_runtime_config(pdcstr!("examples/load-assembly/ExampleProject/bin/Debug/net8.0/ExampleProject.runtimeconfig.json")).unwrap();
+    let delegate_loader = context.get_delegate_loader().unwrap();
+
+    // the assembly is loaded into the default load context, which requires an absolute path.
+    let assembly_path = env::current_dir()
+        .unwrap()
+        .join("examples/load-assembly/ExampleProject/bin/Debug/net8.0/ExampleProject.dll");
+    delegate_loader
+        .load_assembly(PdCString::from_os_str(assembly_path).unwrap())
+        .unwrap();
+
+    // functions of loaded assemblies can be loaded without specifying the assembly path.
+    let hello_world = delegate_loader
+        .get_function_with_unmanaged_callers_only::<fn()>(
+            pdcstr!("ExampleProject.Program, ExampleProject"),
+            pdcstr!("HelloWorld"),
+        )
+        .unwrap();
+    hello_world();
+}
@@ -0,0 +1,215 @@
+use std::{
+    env::{
+        self,
+        consts::{DLL_PREFIX, DLL_SUFFIX},
+    },
+    ffi::OsString,
+    io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use thiserror::Error;
+
+use crate::{
+    error::HostingError,
+    hostfxr::{AppOrHostingResult, Hostfxr, UNSUPPORTED_HOST_VERSION_ERROR_CODE},
+    nethost,
+    pdcstring::{ContainsNul, PdCString},
+};
+
+/// The configuration of a custom apphost.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Config {
+    /// Path to the application assembly (`app.dll`), relative paths are resolved against the directory of the executable.
+    pub app_dll: PathBuf,
+    /// How the .NET installation used to run the application is located.
+    pub dotnet_root_policy: DotnetRootPolicy,
+    /// Whether the command line arguments of the executable are passed on to the application.
+    pub args_passthrough: bool,
+}
+
+impl Config {
+    /// Creates a new configuration for the given application, which uses the default [`DotnetRootPolicy`]
+    /// and passes on the command line arguments.
+    #[must_use]
+    pub fn new(app_dll: impl Into<PathBuf>) -> Self {
+        Self {
+            app_dll: app_dll.into(),
+            dotnet_root_policy: DotnetRootPolicy::default(),
+            args_passthrough: true,
+        }
+    }
+}
+
+/// Specifies how the .NET installation used by a custom apphost is located.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub enum DotnetRootPolicy {
+    /// Locates the installation like the stock apphost: an app-local hostfxr (for self-contained apps),
+    /// followed by the `DOTNET_ROOT` environment variables and the global installation.
+    #[default]
+    Default,
+    /// Only uses the hostfxr library in the directory of the executable, as in self-contained apps.
+    AppLocal,
+    /// Uses the installation at the given path.
+    DotnetRoot(PathBuf),
+}
+
+/// Runs the configured application like the stock apphost and returns its exit code.
+///
+/// Errors of the host are written to stderr together with a hint where to download .NET, like the stock apphost does.
+///
+/// # Note
+/// The exit code is truncated to 8 bits, which hides the hosting error codes on Windows.
+/// Use [`run_with_raw_exit_code`] with [`std::process::exit`] if the full exit code is needed.
+///
+/// # Example
+/// ```rust,no_run
+/// use netcorehost::apphost;
+/// use std::process::ExitCode;
+///
+/// fn main() -> ExitCode {
+///     apphost::run(&apphost::Config::new("MyApp.dll"))
+/// }
+/// ```
+#[must_use]
+pub fn run(config: &Config) -> ExitCode {
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    ExitCode::from(run_with_raw_exit_code(config) as u8)
+}
+
+/// Runs the configured application like the stock apphost and returns its full exit code.
+/// See [`run`] for details.
+#[must_use]
+pub fn run_with_raw_exit_code(config: &Config) -> i32 {
+    match try_run(config) {
+        Ok(exit_code) => exit_code,
+        Err(err) => {
+            eprintln!("{err}");
+            if err.is_missing_runtime() {
+                eprintln!(
+                    "You must install .NET to run this application.\nDownload .NET: https://aka.ms/dotnet-download"
+                );
+            }
+            err.exit_code()
+        }
+    }
+}
+
+/// Runs the configured application like [`run`], but returns errors of the host instead of printing them.
+pub fn try_run(config: &Config) -> Result<i32, ApphostError> {
+    let host_path = env::current_exe()?;
+    let host_dir = host_path
+        .parent()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "executable has no parent"))?;
+
+    let app_path = host_dir.join(&config.app_dll);
+    if !app_path.is_file() {
+        return Err(ApphostError::AppNotFound(app_path));
+    }
+    let app_path_pd = PdCString::from_os_str(&app_path)?;
+
+    let hostfxr_path = match &config.dotnet_root_policy {
+        DotnetRootPolicy::Default => {
+            PathBuf::from(nethost::get_hostfxr_path_with_assembly_path(&app_path_pd)?)
+        }
+        DotnetRootPolicy::AppLocal => {
+            let path = host_dir.join(format!("{DLL_PREFIX}hostfxr{DLL_SUFFIX}"));
+            if !path.is_file() {
+                return Err(HostingError::CoreHostLibMissingFailure.into());
+            }
+            path
+        }
+        DotnetRootPolicy::DotnetRoot(root) => PathBuf::from(
+            nethost::get_hostfxr_path_with_dotnet_root(PdCString::from_os_str(root)?)?,
+        ),
+    };
+    let dotnet_root = dotnet_root_from_hostfxr_path(&hostfxr_path, host_dir);
+    let hostfxr = Hostfxr::load_from_path(&hostfxr_path)?;
+
+    let mut args = vec![PdCString::from_os_str(&host_path)?];
+    if config.args_passthrough {
+        for arg in env::args_os().skip(1) {
+            args.push(PdCString::from_os_str(arg)?);
+        }
+    }
+    let args = args.iter().map(|arg| arg.as_ptr()).collect::<Vec<_>>();
+
+    let host_path = PdCString::from_os_str(&host_path)?;
+    let dotnet_root = PdCString::from_os_str(dotnet_root)?;
+
+    let result = unsafe {
+        hostfxr.lib.hostfxr_main_startupinfo(
+            args.len().try_into().unwrap(),
+            args.as_ptr(),
+            host_path.as_ptr(),
+            dotnet_root.as_ptr(),
+            app_path_pd.as_ptr(),
+        )
+    }
+    .unwrap_or(UNSUPPORTED_HOST_VERSION_ERROR_CODE);
+
+    crate::hostfxr::mark_runtime_started_by_app(AppOrHostingResult::from(result));
+    Ok(result)
+}
+
+/// Determines the installation root from the path of hostfxr, which is either app-local or
+/// located at `<root>/host/fxr/<version>/hostfxr`.
+fn dotnet_root_from_hostfxr_path(hostfxr_path: &Path, host_dir: &Path) -> OsString {
+    let hostfxr_dir = hostfxr_path.parent().unwrap_or(host_dir);
+    if hostfxr_dir == host_dir {
+        return host_dir.as_os_str().to_owned();
+    }
+    hostfxr_dir
+        .ancestors()
+        .nth(3)
+        .unwrap_or(hostfxr_dir)
+        .as_os_str()
+        .to_owned()
+}
+
+/// Enum for errors that can occur in a custom apphost before the application is started.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ApphostError {
+    /// An error occured inside the hosting components.
+    #[error(transparent)]
+    Hosting(#[from] HostingError),
+    /// An error occured while loading the hostfxr library.
+    #[error(transparent)]
+    DlOpen(#[from] crate::dlopen2::Error),
+    /// The application assembly does not exist.
+    #[error("The application to execute does not exist: '{}'.", .0.display())]
+    AppNotFound(PathBuf),
+    /// A path contained a nul character.
+    #[error(transparent)]
+    ContainsNul(#[from] ContainsNul),
+    /// An error occured while locating the executable.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+impl ApphostError {
+    /// Returns the exit code the stock apphost uses for this error.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn exit_code(&self) -> i32 {
+        let error = match self {
+            Self::Hosting(error) => *error,
+            Self::DlOpen(_) => HostingError::CoreHostLibLoadFailure,
+            Self::AppNotFound(_) => HostingError::AppArgNotRunnable,
+            Self::ContainsNul(_) => HostingError::InvalidArgFailure,
+            Self::Io(_) => HostingError::CoreHostCurHostFindFailure,
+        };
+        error.value() as i32
+    }
+
+    /// Returns whether the error indicates that no suitable .NET installation was found.
+    #[must_use]
+    pub fn is_missing_runtime(&self) -> bool {
+        matches!(
+            self,
+            Self::Hosting(HostingError::CoreHostLibMissingFailure) | Self::DlOpen(_)
+        )
+    }
+}
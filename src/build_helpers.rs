@@ -0,0 +1,139 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// File extensions of project files whose changes cause the project to be recompiled.
+const SOURCE_EXTENSIONS: &[&str] = &["cs", "csproj", "props", "targets", "json", "resx"];
+
+/// Directories of a project that contain build output and are therefore not watched for changes.
+const OUTPUT_DIRS: &[&str] = &["bin", "obj"];
+
+/// Compiles the given C# project using `dotnet publish` from a build script.
+///
+/// The output is placed in `$OUT_DIR/netcorehost/<project name>` and `cargo:rerun-if-changed` directives are emitted
+/// for all source files of the project, so the project is only recompiled if it changed.
+/// The assembly is expected to have the same name as the project file.
+///
+/// # Example
+/// ```rust,no_run
+/// // build.rs
+/// let project = netcorehost::build_helpers::compile_project("managed/Plugin.csproj", "net8.0").unwrap();
+/// ```
+/// The compiled assembly can then be embedded using [`include_assembly!`](crate::include_assembly):
+/// ```rust,ignore
+/// let plugin = netcorehost::include_assembly!(concat!(env!("OUT_DIR"), "/netcorehost/Plugin/Plugin.dll"));
+/// ```
+pub fn compile_project(
+    project_path: impl AsRef<Path>,
+    target_framework: &str,
+) -> io::Result<CompiledProject> {
+    let project_path = project_path.as_ref();
+    let name = project_path
+        .file_stem()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid project path: {}", project_path.display()),
+            )
+        })?
+        .to_string();
+
+    let out_dir = env::var_os("OUT_DIR").ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            "OUT_DIR is not set, compile_project has to be called from a build script",
+        )
+    })?;
+    let output_dir = PathBuf::from(out_dir).join("netcorehost").join(&name);
+
+    println!("cargo:rerun-if-changed={}", project_path.display());
+    if let Some(project_dir) = project_path.parent() {
+        let project_dir = if project_dir.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            project_dir
+        };
+        emit_rerun_if_changed(project_dir)?;
+    }
+
+    let output = crate::dotnet_cli::command()
+        .arg("publish")
+        .arg(project_path)
+        .arg("--configuration")
+        .arg("Release")
+        .arg("--framework")
+        .arg(target_framework)
+        .arg("--output")
+        .arg(&output_dir)
+        .output()?;
+
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "dotnet publish failed with {}:\n{}{}",
+            output.status,
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        )));
+    }
+
+    Ok(CompiledProject { output_dir, name })
+}
+
+fn emit_rerun_if_changed(dir: &Path) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            let is_output_dir = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| OUTPUT_DIRS.contains(&name));
+            if !is_output_dir {
+                emit_rerun_if_changed(&path)?;
+            }
+        } else if path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| SOURCE_EXTENSIONS.contains(&extension))
+        {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+    }
+    Ok(())
+}
+
+/// A C# project compiled by [`compile_project`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompiledProject {
+    output_dir: PathBuf,
+    name: String,
+}
+
+impl CompiledProject {
+    /// Returns the directory containing the published output.
+    #[must_use]
+    pub fn output_dir(&self) -> &Path {
+        &self.output_dir
+    }
+
+    /// Returns the path to the compiled assembly.
+    #[must_use]
+    pub fn assembly_path(&self) -> PathBuf {
+        self.output_dir.join(format!("{}.dll", self.name))
+    }
+
+    /// Returns the path to the symbols (`.pdb`) file of the compiled assembly.
+    #[must_use]
+    pub fn symbols_path(&self) -> PathBuf {
+        self.output_dir.join(format!("{}.pdb", self.name))
+    }
+
+    /// Returns the path to the generated `.runtimeconfig.json`.
+    #[must_use]
+    pub fn runtime_config_path(&self) -> PathBuf {
+        self.output_dir
+            .join(format!("{}.runtimeconfig.json", self.name))
+    }
+}
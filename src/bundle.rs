@@ -0,0 +1,137 @@
+use std::{
+    env,
+    ffi::{OsStr, OsString},
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+/// The environment variable overriding the directory single-file bundles extract their files to.
+pub const BUNDLE_EXTRACT_BASE_DIR_ENV_VAR: &str = "DOTNET_BUNDLE_EXTRACT_BASE_DIR";
+
+/// Returns the base directory single-file bundles extract their files to, like the host resolves it.
+///
+/// This is the value of [`DOTNET_BUNDLE_EXTRACT_BASE_DIR`](BUNDLE_EXTRACT_BASE_DIR_ENV_VAR) if it is set and
+/// otherwise `%TEMP%\.net` on Windows and `$TMPDIR/.net/<user name>` on other platforms.
+/// On non-Windows platforms the user name is taken from the `USER` environment variable.
+#[must_use]
+pub fn extraction_base_dir() -> PathBuf {
+    match env::var_os(BUNDLE_EXTRACT_BASE_DIR_ENV_VAR) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => default_extraction_base_dir(),
+    }
+}
+
+#[cfg(windows)]
+fn default_extraction_base_dir() -> PathBuf {
+    env::temp_dir().join(".net")
+}
+
+#[cfg(not(windows))]
+fn default_extraction_base_dir() -> PathBuf {
+    let dir = env::temp_dir().join(".net");
+    match env::var_os("USER") {
+        Some(user) if !user.is_empty() => dir.join(user),
+        _ => dir,
+    }
+}
+
+/// Returns the directory the files of a bundle are extracted to.
+///
+/// Bundles are extracted to `<base dir>/<app name>/<bundle id>`, where the app name is the file name of the
+/// executable without its extension and the bundle id identifies the build of the bundle.
+#[must_use]
+pub fn extraction_dir(base_dir: &Path, app_name: &str, bundle_id: impl AsRef<OsStr>) -> PathBuf {
+    base_dir.join(app_name).join(bundle_id.as_ref())
+}
+
+/// A directory containing the extracted files of a single build of a bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Extraction {
+    /// The path of the directory.
+    pub path: PathBuf,
+    /// The name of the application.
+    pub app_name: OsString,
+    /// The id of the bundle, which is the name of the directory.
+    pub bundle_id: OsString,
+    /// The time the directory was last modified.
+    pub modified: SystemTime,
+}
+
+impl Extraction {
+    /// Returns how long ago the directory was last modified, or [`Duration::ZERO`] if it lies in the future.
+    #[must_use]
+    pub fn age(&self) -> Duration {
+        SystemTime::now()
+            .duration_since(self.modified)
+            .unwrap_or(Duration::ZERO)
+    }
+}
+
+/// Lists the extraction directories of all bundles below the given base directory,
+/// see [`extraction_base_dir`].
+///
+/// Returns an empty list if the base directory does not exist.
+pub fn extractions(base_dir: &Path) -> io::Result<Vec<Extraction>> {
+    let mut extractions = Vec::new();
+    for app_dir in read_dirs(base_dir)? {
+        let app_name = app_dir.file_name();
+        extractions.extend(app_extractions(base_dir, &app_name)?);
+    }
+    Ok(extractions)
+}
+
+/// Lists the extraction directories of the application with the given name below the given base directory.
+///
+/// Returns an empty list if the application has never been extracted.
+pub fn app_extractions(
+    base_dir: &Path,
+    app_name: impl AsRef<OsStr>,
+) -> io::Result<Vec<Extraction>> {
+    let app_name = app_name.as_ref();
+    read_dirs(&base_dir.join(app_name))?
+        .into_iter()
+        .map(|entry| {
+            Ok(Extraction {
+                path: entry.path(),
+                app_name: app_name.to_owned(),
+                bundle_id: entry.file_name(),
+                modified: entry.metadata()?.modified()?,
+            })
+        })
+        .collect()
+}
+
+/// Removes the extraction directories below the given base directory that were last modified longer than `max_age`
+/// ago and returns the removed ones.
+///
+/// The host does not record which extractions are in use, so `max_age` should be long enough to not remove the
+/// files of a bundle that is still running. Directories that could not be removed are skipped.
+pub fn remove_extractions_older_than(
+    base_dir: &Path,
+    max_age: Duration,
+) -> io::Result<Vec<Extraction>> {
+    let mut removed = Vec::new();
+    for extraction in extractions(base_dir)? {
+        if extraction.age() > max_age && fs::remove_dir_all(&extraction.path).is_ok() {
+            removed.push(extraction);
+        }
+    }
+    Ok(removed)
+}
+
+fn read_dirs(path: &Path) -> io::Result<Vec<fs::DirEntry>> {
+    let entries = match fs::read_dir(path) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    let mut dirs = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            dirs.push(entry);
+        }
+    }
+    Ok(dirs)
+}
@@ -0,0 +1,179 @@
+use std::{env, path::Path};
+
+use crate::{nethost, platform::runtime_identifier};
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Environment variables affecting how the hosting components locate and configure the runtime.
+const RELEVANT_ENV_VARS: &[&str] = &[
+    "DOTNET_ROOT",
+    "DOTNET_ROOT(x86)",
+    "DOTNET_ROOT_X64",
+    "DOTNET_ROOT_X86",
+    "DOTNET_ROOT_ARM64",
+    "DOTNET_ROLL_FORWARD",
+    "DOTNET_MULTILEVEL_LOOKUP",
+    "COREHOST_TRACE",
+    "COREHOST_TRACEFILE",
+    "COREHOST_TRACE_VERBOSITY",
+];
+
+/// Crate features that are included in a [`DiagnosticsSnapshot`].
+const FEATURES: &[(&str, bool)] = &[
+    ("nethost-download", cfg!(feature = "nethost-download")),
+    ("netcore1_0", cfg!(feature = "netcore1_0")),
+    ("netcore2_0", cfg!(feature = "netcore2_0")),
+    ("netcore2_1", cfg!(feature = "netcore2_1")),
+    ("netcore3_0", cfg!(feature = "netcore3_0")),
+    ("net5_0", cfg!(feature = "net5_0")),
+    ("net6_0", cfg!(feature = "net6_0")),
+    ("net7_0", cfg!(feature = "net7_0")),
+    ("net8_0", cfg!(feature = "net8_0")),
+    ("net9_0", cfg!(feature = "net9_0")),
+    ("latest", cfg!(feature = "latest")),
+    ("strict-checks", cfg!(feature = "strict-checks")),
+    ("unstable", cfg!(feature = "unstable")),
+];
+
+/// A snapshot of the hosting environment of the current process, intended to be attached to bug and crash reports.
+///
+/// Created using [`snapshot`]. Paths are converted to strings lossily.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[non_exhaustive]
+pub struct DiagnosticsSnapshot {
+    /// The version of this crate.
+    pub crate_version: &'static str,
+    /// The enabled crate features.
+    pub features: Vec<&'static str>,
+    /// The runtime identifier of the current process (like `linux-x64`), or `None` if .NET does not support the
+    /// current platform.
+    pub runtime_identifier: Option<String>,
+    /// The path of the hostfxr library located using nethost.
+    pub hostfxr_path: Option<String>,
+    /// The error that occured while locating or loading hostfxr, if any.
+    pub hostfxr_error: Option<String>,
+    /// The root directory of the .NET installation that is used.
+    pub dotnet_root: Option<String>,
+    /// The version of the hostfxr library.
+    pub hostfxr_version: Option<String>,
+    /// The commit hash of the hostfxr library.
+    pub hostfxr_commit_hash: Option<String>,
+    /// The installed SDKs.
+    pub sdks: Vec<InstalledComponent>,
+    /// The installed shared frameworks.
+    pub frameworks: Vec<InstalledComponent>,
+    /// The values of environment variables affecting the hosting components that are set.
+    pub environment_variables: Vec<(String, String)>,
+    /// Whether a runtime has been started in the current process through this crate.
+    pub runtime_started: bool,
+    /// The error messages of the last failed hosting call on the current thread, see [`last_error_messages`].
+    ///
+    /// [`last_error_messages`]: crate::hostfxr::last_error_messages
+    pub last_error_messages: Vec<String>,
+}
+
+/// An installed SDK or shared framework.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct InstalledComponent {
+    /// The name of the component, empty for SDKs.
+    pub name: String,
+    /// The version of the component.
+    pub version: String,
+    /// The installation path of the component.
+    pub path: String,
+}
+
+impl DiagnosticsSnapshot {
+    /// Serializes this snapshot into pretty-printed JSON.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "serde")))]
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// Collects a [`DiagnosticsSnapshot`] of the hosting environment.
+///
+/// This locates and loads hostfxr and enumerates the installed SDKs and frameworks, but does not start a runtime.
+/// Errors are recorded in the snapshot instead of being returned.
+#[must_use]
+pub fn snapshot() -> DiagnosticsSnapshot {
+    let mut snapshot = DiagnosticsSnapshot {
+        crate_version: env!("CARGO_PKG_VERSION"),
+        features: FEATURES
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(name, _)| *name)
+            .collect(),
+        runtime_identifier: runtime_identifier(),
+        hostfxr_path: None,
+        hostfxr_error: None,
+        dotnet_root: None,
+        hostfxr_version: None,
+        hostfxr_commit_hash: None,
+        sdks: Vec::new(),
+        frameworks: Vec::new(),
+        environment_variables: RELEVANT_ENV_VARS
+            .iter()
+            .filter_map(|name| {
+                env::var_os(name)
+                    .map(|value| (name.to_string(), value.to_string_lossy().into_owned()))
+            })
+            .collect(),
+        runtime_started: crate::runtime_started(),
+        #[cfg(feature = "netcore3_0")]
+        last_error_messages: crate::hostfxr::last_error_messages(),
+        #[cfg(not(feature = "netcore3_0"))]
+        last_error_messages: Vec::new(),
+    };
+
+    match nethost::load_hostfxr() {
+        Ok(hostfxr) => {
+            snapshot.dotnet_root = Some(path_to_string(&hostfxr.get_dotnet_root()));
+            if let Ok(path) = nethost::get_hostfxr_path() {
+                snapshot.hostfxr_path = Some(path_to_string(Path::new(&path)));
+            }
+            #[cfg(feature = "net6_0")]
+            collect_environment_info(&hostfxr, &mut snapshot);
+        }
+        Err(err) => snapshot.hostfxr_error = Some(err.to_string()),
+    }
+
+    snapshot
+}
+
+#[cfg(feature = "net6_0")]
+fn collect_environment_info(hostfxr: &crate::hostfxr::Hostfxr, snapshot: &mut DiagnosticsSnapshot) {
+    match hostfxr.get_dotnet_environment_info() {
+        Ok(info) => {
+            snapshot.hostfxr_version = Some(info.hostfxr_version);
+            snapshot.hostfxr_commit_hash = Some(info.hostfxr_commit_hash);
+            snapshot.sdks = info
+                .sdks
+                .into_iter()
+                .map(|sdk| InstalledComponent {
+                    name: String::new(),
+                    version: sdk.version,
+                    path: path_to_string(&sdk.path),
+                })
+                .collect();
+            snapshot.frameworks = info
+                .frameworks
+                .into_iter()
+                .map(|framework| InstalledComponent {
+                    name: framework.name,
+                    version: framework.version,
+                    path: path_to_string(&framework.path),
+                })
+                .collect();
+        }
+        Err(err) => snapshot.hostfxr_error = Some(err.to_string()),
+    }
+}
+
+fn path_to_string(path: &Path) -> String {
+    path.to_string_lossy().into_owned()
+}
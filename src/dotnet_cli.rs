@@ -0,0 +1,729 @@
+use std::{
+    env::{self, consts::EXE_SUFFIX},
+    ffi::OsString,
+    fs, io,
+    path::{Path, PathBuf},
+    process::{Command, ExitStatus},
+};
+
+use thiserror::Error;
+
+use crate::platform;
+
+#[cfg(feature = "net6_0")]
+use crate::hostfxr::{EnvironmentInfo, FrameworkInfo, SdkInfo};
+
+/// Returns the file name of the dotnet executable on the current platform.
+#[must_use]
+pub fn executable_name() -> OsString {
+    OsString::from(format!("dotnet{EXE_SUFFIX}"))
+}
+
+/// Locates the dotnet executable used for CLI and SDK commands using [`DotnetLocator::for_cli`].
+#[must_use]
+pub fn locate() -> Option<PathBuf> {
+    DotnetLocator::for_cli().locate()
+}
+
+/// Environment variables opting out of the telemetry of the dotnet CLI and the SDK, see
+/// [`DotnetCli::telemetry`].
+pub const TELEMETRY_OPT_OUT_ENV_VARS: &[(&str, &str)] =
+    &[("DOTNET_CLI_TELEMETRY_OPTOUT", "1"), ("DOTNET_NOLOGO", "1")];
+
+/// Returns a [`Command`] for running the dotnet CLI, using the executable found by [`locate`].
+///
+/// Falls back to `dotnet`, which is then looked up by the operating system, if no executable could be located.
+/// Telemetry is disabled for the command, see [`DotnetCli::telemetry`].
+#[must_use]
+pub fn command() -> Command {
+    DotnetCli::new().command()
+}
+
+/// Locates the dotnet executable, looking in the same places as the hosting components and the dotnet CLI.
+///
+/// The following locations are checked in order, each of them can be disabled:
+/// 1. the directories added using [`dir`](DotnetLocator::dir)
+/// 2. the `DOTNET_ROOT_<ARCH>` (like `DOTNET_ROOT_X64`), `DOTNET_ROOT(x86)` (for 32-bit processes on Windows)
+///    and `DOTNET_ROOT` environment variables
+/// 3. the directories in `PATH`, following symbolic links like `/usr/bin/dotnet` to the installation,
+///    which are checked before the environment variables if [`prefer_path`](DotnetLocator::prefer_path) is enabled
+/// 4. the registered install location, which is stored in the registry on Windows
+///    (`HKLM\SOFTWARE\dotnet\Setup\InstalledVersions\<arch>\InstallLocation`) and in
+///    `/etc/dotnet/install_location_<arch>` or `/etc/dotnet/install_location` on other platforms
+/// 5. the default install directories of the platform, like `%ProgramFiles%\dotnet`, `/usr/local/share/dotnet`,
+///    `/usr/share/dotnet`, `/usr/lib/dotnet` and `~/.dotnet`
+///
+/// # Example
+/// ```rust
+/// # use netcorehost::dotnet_cli::DotnetLocator;
+/// let dotnet = DotnetLocator::new().search_path(false).locate();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DotnetLocator {
+    dirs: Vec<PathBuf>,
+    environment: bool,
+    search_path: bool,
+    prefer_path: bool,
+    install_location: bool,
+    default_install_dirs: bool,
+}
+
+impl Default for DotnetLocator {
+    fn default() -> Self {
+        Self {
+            dirs: Vec::new(),
+            environment: true,
+            search_path: true,
+            prefer_path: false,
+            install_location: true,
+            default_install_dirs: true,
+        }
+    }
+}
+
+impl DotnetLocator {
+    /// Creates a new locator checking all locations.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new locator for the executable used to run CLI and SDK commands like `dotnet build`, which checks
+    /// `PATH` before the `DOTNET_ROOT` environment variables, like a shell would.
+    ///
+    /// `DOTNET_ROOT` is meant for the hosting components and often points to an installation that only contains
+    /// runtimes, while the SDK is installed elsewhere.
+    #[must_use]
+    pub fn for_cli() -> Self {
+        Self::new().prefer_path(true)
+    }
+
+    /// Adds a directory that is checked before all other locations.
+    /// Directories are checked in the order they were added.
+    #[must_use]
+    pub fn dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dirs.push(dir.into());
+        self
+    }
+
+    /// Sets whether the `DOTNET_ROOT` environment variables are checked.
+    #[must_use]
+    pub fn environment(mut self, enabled: bool) -> Self {
+        self.environment = enabled;
+        self
+    }
+
+    /// Sets whether the directories in `PATH` are searched.
+    #[must_use]
+    pub fn search_path(mut self, enabled: bool) -> Self {
+        self.search_path = enabled;
+        self
+    }
+
+    /// Sets whether the directories in `PATH` are searched before the `DOTNET_ROOT` environment variables.
+    #[must_use]
+    pub fn prefer_path(mut self, enabled: bool) -> Self {
+        self.prefer_path = enabled;
+        self
+    }
+
+    /// Sets whether the registered install location is checked.
+    #[must_use]
+    pub fn install_location(mut self, enabled: bool) -> Self {
+        self.install_location = enabled;
+        self
+    }
+
+    /// Sets whether the default install directories of the platform are checked.
+    #[must_use]
+    pub fn default_install_dirs(mut self, enabled: bool) -> Self {
+        self.default_install_dirs = enabled;
+        self
+    }
+
+    /// Returns the path of the first dotnet executable found, see [`DotnetLocator`] for the order of the locations.
+    #[must_use]
+    pub fn locate(&self) -> Option<PathBuf> {
+        self.candidate_dirs()
+            .into_iter()
+            .map(|dir| dir.join(executable_name()))
+            .find(|path| path.is_file())
+    }
+
+    /// Returns the root directory of the installation of the located dotnet executable.
+    #[must_use]
+    pub fn locate_root(&self) -> Option<PathBuf> {
+        self.locate()
+            .and_then(|path| path.parent().map(Path::to_path_buf))
+    }
+
+    fn candidate_dirs(&self) -> Vec<PathBuf> {
+        let mut dirs = self.dirs.clone();
+        if self.search_path && self.prefer_path {
+            dirs.extend(path_dirs());
+        }
+        if self.environment {
+            dirs.extend(env_dirs());
+        }
+        if self.search_path && !self.prefer_path {
+            dirs.extend(path_dirs());
+        }
+        if self.install_location {
+            dirs.extend(sys::install_location());
+        }
+        if self.default_install_dirs {
+            dirs.extend(sys::default_install_dirs());
+            if let Some(home) = home_dir() {
+                dirs.push(home.join(".dotnet"));
+            }
+        }
+        dirs
+    }
+}
+
+/// Runs the dotnet CLI and parses the output of its informational commands into typed structs.
+///
+/// If the CLI cannot be run or its output cannot be parsed, the same information is retrieved through
+/// `Hostfxr::get_dotnet_environment_info` of the installation the executable belongs to, as long as the `nethost` and
+/// `net6_0` features are enabled. This can be disabled using [`native_fallback`](DotnetCli::native_fallback),
+/// for example to validate the results of the native APIs against the CLI.
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::dotnet_cli::DotnetCli;
+/// for sdk in DotnetCli::new().list_sdks().unwrap() {
+///     println!("{} [{}]", sdk.version, sdk.path.display());
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DotnetCli {
+    executable: PathBuf,
+    native_fallback: bool,
+    telemetry: bool,
+}
+
+impl Default for DotnetCli {
+    fn default() -> Self {
+        Self::with_executable(locate().unwrap_or_else(|| PathBuf::from(executable_name())))
+    }
+}
+
+impl DotnetCli {
+    /// Creates a new [`DotnetCli`] using the executable found by [`locate`], which prefers `PATH` over `DOTNET_ROOT`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`DotnetCli`] using the given dotnet executable.
+    #[must_use]
+    pub fn with_executable(executable: impl Into<PathBuf>) -> Self {
+        Self {
+            executable: executable.into(),
+            native_fallback: true,
+            telemetry: false,
+        }
+    }
+
+    /// Sets whether the native hosting APIs are used if the CLI fails, see [`DotnetCli`].
+    /// Has no effect unless the `nethost` and `net6_0` features are enabled.
+    #[must_use]
+    pub fn native_fallback(mut self, enabled: bool) -> Self {
+        self.native_fallback = enabled;
+        self
+    }
+
+    /// Sets whether commands run by this wrapper may send telemetry.
+    ///
+    /// Telemetry is disabled by default: all commands, including the ones created by
+    /// [`command`](DotnetCli::command), set the variables in [`TELEMETRY_OPT_OUT_ENV_VARS`] (`DOTNET_CLI_TELEMETRY_OPTOUT`
+    /// and `DOTNET_NOLOGO`). If enabled, the commands inherit the setting of the current process environment.
+    #[must_use]
+    pub fn telemetry(mut self, enabled: bool) -> Self {
+        self.telemetry = enabled;
+        self
+    }
+
+    /// Returns the path of the dotnet executable.
+    #[must_use]
+    pub fn executable(&self) -> &Path {
+        &self.executable
+    }
+
+    /// Returns a [`Command`] for running the dotnet executable.
+    /// Telemetry is disabled for the command, unless it was enabled using [`telemetry`](DotnetCli::telemetry).
+    #[must_use]
+    pub fn command(&self) -> Command {
+        let mut command = Command::new(&self.executable);
+        if !self.telemetry {
+            command.envs(TELEMETRY_OPT_OUT_ENV_VARS.iter().copied());
+        }
+        command
+    }
+
+    /// Lists the installed SDKs using `dotnet --list-sdks`.
+    pub fn list_sdks(&self) -> Result<Vec<InstalledSdk>, DotnetCliError> {
+        let result = self
+            .run("--list-sdks")
+            .and_then(|output| parse_list_sdks(&output));
+        self.or_native(result, |info| info.sdks)
+    }
+
+    /// Lists the installed runtimes using `dotnet --list-runtimes`.
+    pub fn list_runtimes(&self) -> Result<Vec<InstalledRuntime>, DotnetCliError> {
+        let result = self
+            .run("--list-runtimes")
+            .and_then(|output| parse_list_runtimes(&output));
+        self.or_native(result, |info| info.runtimes)
+    }
+
+    /// Retrieves information about the host and the installed SDKs and runtimes using `dotnet --info`.
+    pub fn info(&self) -> Result<DotnetInfo, DotnetCliError> {
+        let result = self.run("--info").and_then(|output| parse_info(&output));
+        self.or_native(result, |info| info)
+    }
+
+    fn run(&self, arg: &str) -> Result<String, DotnetCliError> {
+        let output = self
+            .command()
+            .arg(arg)
+            // the parser relies on the english section headers.
+            .env("DOTNET_CLI_UI_LANGUAGE", "en")
+            .env("DOTNET_NOLOGO", "1")
+            .output()?;
+        if !output.status.success() {
+            return Err(DotnetCliError::Failed {
+                status: output.status,
+                stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            });
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    fn or_native<T>(
+        &self,
+        result: Result<T, DotnetCliError>,
+        select: impl FnOnce(DotnetInfo) -> T,
+    ) -> Result<T, DotnetCliError> {
+        match result {
+            Err(err) if self.native_fallback => {
+                native_info(&self.executable).map(select).ok_or(err)
+            }
+            result => result,
+        }
+    }
+}
+
+#[cfg(all(feature = "nethost", feature = "net6_0"))]
+fn native_info(executable: &Path) -> Option<DotnetInfo> {
+    use crate::{nethost, pdcstring::PdCString};
+
+    let hostfxr = match executable
+        .parent()
+        .filter(|root| !root.as_os_str().is_empty())
+    {
+        Some(root) => nethost::load_hostfxr_with_dotnet_root(PdCString::from_os_str(root).ok()?),
+        None => nethost::load_hostfxr(),
+    }
+    .ok()?;
+    hostfxr
+        .get_dotnet_environment_info()
+        .ok()
+        .map(DotnetInfo::from)
+}
+
+#[cfg(not(all(feature = "nethost", feature = "net6_0")))]
+fn native_info(_executable: &Path) -> Option<DotnetInfo> {
+    None
+}
+
+/// An SDK listed by `dotnet --list-sdks` or `dotnet --info`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InstalledSdk {
+    /// The version of the SDK.
+    pub version: String,
+    /// The directory containing the SDK, which is the listed directory joined with the version.
+    pub path: PathBuf,
+}
+
+/// A runtime listed by `dotnet --list-runtimes` or `dotnet --info`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InstalledRuntime {
+    /// The name of the framework, like `Microsoft.NETCore.App`.
+    pub name: String,
+    /// The version of the framework.
+    pub version: String,
+    /// The listed directory, which contains all installed versions of the framework.
+    pub path: PathBuf,
+}
+
+/// The information printed by `dotnet --info`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DotnetInfo {
+    /// The version of the host.
+    pub host_version: String,
+    /// The commit hash of the host.
+    pub host_commit: String,
+    /// The architecture of the host, which is only listed by newer versions of the host.
+    pub host_architecture: Option<String>,
+    /// The version of the SDK used in the current directory, or `None` if no SDK is installed.
+    pub sdk_version: Option<String>,
+    /// The installed SDKs, ordered by version ascending.
+    pub sdks: Vec<InstalledSdk>,
+    /// The installed runtimes, ordered by name and then version ascending.
+    pub runtimes: Vec<InstalledRuntime>,
+}
+
+#[cfg(feature = "net6_0")]
+impl From<SdkInfo> for InstalledSdk {
+    fn from(sdk: SdkInfo) -> Self {
+        Self {
+            version: sdk.version,
+            path: sdk.path,
+        }
+    }
+}
+
+#[cfg(feature = "net6_0")]
+impl From<FrameworkInfo> for InstalledRuntime {
+    fn from(framework: FrameworkInfo) -> Self {
+        Self {
+            name: framework.name,
+            version: framework.version,
+            path: framework.path,
+        }
+    }
+}
+
+#[cfg(feature = "net6_0")]
+impl From<EnvironmentInfo> for DotnetInfo {
+    fn from(info: EnvironmentInfo) -> Self {
+        Self {
+            host_version: info.hostfxr_version,
+            host_commit: info.hostfxr_commit_hash,
+            host_architecture: None,
+            sdk_version: None,
+            sdks: info.sdks.into_iter().map(InstalledSdk::from).collect(),
+            runtimes: info
+                .frameworks
+                .into_iter()
+                .map(InstalledRuntime::from)
+                .collect(),
+        }
+    }
+}
+
+/// Parses the output of `dotnet --list-sdks`.
+pub fn parse_list_sdks(output: &str) -> Result<Vec<InstalledSdk>, DotnetCliError> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(parse_sdk_line)
+        .collect()
+}
+
+/// Parses the output of `dotnet --list-runtimes`.
+pub fn parse_list_runtimes(output: &str) -> Result<Vec<InstalledRuntime>, DotnetCliError> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(parse_runtime_line)
+        .collect()
+}
+
+/// Parses the output of `dotnet --info`.
+///
+/// Only the host, SDK and installation sections are parsed, the output has to be in english.
+pub fn parse_info(output: &str) -> Result<DotnetInfo, DotnetCliError> {
+    let mut sections: Vec<(&str, Vec<&str>)> = Vec::new();
+    for line in output.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if line.starts_with(char::is_whitespace) {
+            if let Some((_header, content)) = sections.last_mut() {
+                content.push(line.trim());
+            }
+        } else {
+            sections.push((line.trim().trim_end_matches(':'), Vec::new()));
+        }
+    }
+
+    let mut host_version = None;
+    let mut host_commit = None;
+    let mut host_architecture = None;
+    let mut sdk_version = None;
+    let mut sdks = Vec::new();
+    let mut runtimes = Vec::new();
+    for (header, content) in sections {
+        // older versions use headers like "Host (useful for support)" and ".NET Core SDKs installed".
+        if header.ends_with("SDKs installed") {
+            for line in content.into_iter().filter(|line| line.contains('[')) {
+                sdks.push(parse_sdk_line(line)?);
+            }
+        } else if header.ends_with("runtimes installed") {
+            for line in content.into_iter().filter(|line| line.contains('[')) {
+                runtimes.push(parse_runtime_line(line)?);
+            }
+        } else if header.starts_with("Host") {
+            for (key, value) in key_values(&content) {
+                match key {
+                    "Version" => host_version = Some(value.to_string()),
+                    "Commit" => host_commit = Some(value.to_string()),
+                    "Architecture" => host_architecture = Some(value.to_string()),
+                    _ => {}
+                }
+            }
+        } else if header.starts_with(".NET SDK") || header.starts_with(".NET Core SDK") {
+            sdk_version = key_values(&content)
+                .find(|(key, _value)| *key == "Version")
+                .map(|(_key, value)| value.to_string());
+        }
+    }
+
+    Ok(DotnetInfo {
+        host_version: host_version
+            .ok_or_else(|| DotnetCliError::InvalidOutput("missing host version".to_string()))?,
+        host_commit: host_commit
+            .ok_or_else(|| DotnetCliError::InvalidOutput("missing host commit".to_string()))?,
+        host_architecture,
+        sdk_version,
+        sdks,
+        runtimes,
+    })
+}
+
+fn key_values<'a>(content: &'a [&'a str]) -> impl Iterator<Item = (&'a str, &'a str)> + 'a {
+    content
+        .iter()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, value)| (key.trim(), value.trim()))
+}
+
+// splits a line like `8.0.100 [/usr/share/dotnet/sdk]` into the listed names and the directory.
+fn split_listing(line: &str) -> Result<(&str, &str), DotnetCliError> {
+    line.split_once(" [")
+        .and_then(|(names, dir)| Some((names.trim(), dir.strip_suffix(']')?)))
+        .ok_or_else(|| DotnetCliError::InvalidOutput(format!("unexpected line {line:?}")))
+}
+
+fn parse_sdk_line(line: &str) -> Result<InstalledSdk, DotnetCliError> {
+    let (version, dir) = split_listing(line)?;
+    if version.is_empty() || version.contains(char::is_whitespace) {
+        return Err(DotnetCliError::InvalidOutput(format!(
+            "unexpected line {line:?}"
+        )));
+    }
+    Ok(InstalledSdk {
+        version: version.to_string(),
+        path: Path::new(dir).join(version),
+    })
+}
+
+fn parse_runtime_line(line: &str) -> Result<InstalledRuntime, DotnetCliError> {
+    let (names, dir) = split_listing(line)?;
+    let (name, version) = names
+        .split_once(' ')
+        .ok_or_else(|| DotnetCliError::InvalidOutput(format!("unexpected line {line:?}")))?;
+    Ok(InstalledRuntime {
+        name: name.to_string(),
+        version: version.trim().to_string(),
+        path: PathBuf::from(dir),
+    })
+}
+
+/// Enum for errors that can occur while running the dotnet CLI using [`DotnetCli`].
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum DotnetCliError {
+    /// The dotnet executable could not be started.
+    #[error("Failed to run the dotnet executable: {0}")]
+    Io(#[from] io::Error),
+    /// The dotnet executable exited unsuccessfully.
+    #[error("The dotnet executable exited with {status}: {stderr}")]
+    Failed {
+        /// The exit status of the process.
+        status: ExitStatus,
+        /// Everything the process wrote to the standard error.
+        stderr: String,
+    },
+    /// The output of the dotnet executable could not be parsed.
+    #[error("Failed to parse the output of the dotnet executable: {0}.")]
+    InvalidOutput(String),
+}
+
+fn env_dirs() -> Vec<PathBuf> {
+    let mut names: Vec<_> = platform::architecture()
+        .map(|arch| format!("DOTNET_ROOT_{}", arch.to_ascii_uppercase()))
+        .into_iter()
+        .collect();
+    if cfg!(all(windows, target_pointer_width = "32")) {
+        names.push("DOTNET_ROOT(x86)".to_string());
+    }
+    names.push("DOTNET_ROOT".to_string());
+
+    names
+        .into_iter()
+        .filter_map(env::var_os)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .collect()
+}
+
+fn path_dirs() -> Vec<PathBuf> {
+    let Some(path) = env::var_os("PATH") else {
+        return Vec::new();
+    };
+    env::split_paths(&path)
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .map(|dir| {
+            // package managers link the executable into a shared bin directory, but the installation is elsewhere.
+            let executable = dir.join(executable_name());
+            match fs::symlink_metadata(&executable) {
+                Ok(metadata) if metadata.file_type().is_symlink() => fs::canonicalize(&executable)
+                    .ok()
+                    .and_then(|target| target.parent().map(Path::to_path_buf))
+                    .unwrap_or(dir),
+                _ => dir,
+            }
+        })
+        .collect()
+}
+
+fn home_dir() -> Option<PathBuf> {
+    let var = if cfg!(windows) { "USERPROFILE" } else { "HOME" };
+    env::var_os(var)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+#[cfg(windows)]
+mod sys {
+    use std::{
+        env,
+        ffi::{c_void, OsStr, OsString},
+        iter,
+        os::windows::ffi::{OsStrExt, OsStringExt},
+        path::PathBuf,
+        ptr,
+    };
+
+    use crate::platform;
+
+    type Hkey = isize;
+
+    const HKEY_LOCAL_MACHINE: Hkey = 0x8000_0002_u32 as i32 as Hkey;
+    const RRF_RT_REG_SZ: u32 = 0x0000_0002;
+    const RRF_SUBKEY_WOW6432KEY: u32 = 0x0002_0000;
+    const ERROR_SUCCESS: i32 = 0;
+
+    #[link(name = "advapi32")]
+    extern "system" {
+        fn RegGetValueW(
+            key: Hkey,
+            sub_key: *const u16,
+            value: *const u16,
+            flags: u32,
+            value_type: *mut u32,
+            data: *mut c_void,
+            data_size: *mut u32,
+        ) -> i32;
+    }
+
+    fn wide(s: &str) -> Vec<u16> {
+        OsStr::new(s).encode_wide().chain(iter::once(0)).collect()
+    }
+
+    pub fn install_location() -> Option<PathBuf> {
+        // the hosting components read the 32-bit view of the registry.
+        let arch = platform::architecture()?;
+        let sub_key = wide(&format!(r"SOFTWARE\dotnet\Setup\InstalledVersions\{arch}"));
+        let value = wide("InstallLocation");
+        let flags = RRF_RT_REG_SZ | RRF_SUBKEY_WOW6432KEY;
+
+        let mut size = 0;
+        let result = unsafe {
+            RegGetValueW(
+                HKEY_LOCAL_MACHINE,
+                sub_key.as_ptr(),
+                value.as_ptr(),
+                flags,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                &mut size,
+            )
+        };
+        if result != ERROR_SUCCESS || size == 0 {
+            return None;
+        }
+
+        let mut buffer = vec![0u16; size as usize / 2];
+        let result = unsafe {
+            RegGetValueW(
+                HKEY_LOCAL_MACHINE,
+                sub_key.as_ptr(),
+                value.as_ptr(),
+                flags,
+                ptr::null_mut(),
+                buffer.as_mut_ptr().cast(),
+                &mut size,
+            )
+        };
+        if result != ERROR_SUCCESS {
+            return None;
+        }
+        let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+        (len > 0).then(|| PathBuf::from(OsString::from_wide(&buffer[..len])))
+    }
+
+    pub fn default_install_dirs() -> Vec<PathBuf> {
+        let var = if cfg!(target_pointer_width = "32") {
+            "ProgramFiles(x86)"
+        } else {
+            "ProgramFiles"
+        };
+        env::var_os(var)
+            .or_else(|| env::var_os("ProgramFiles"))
+            .map(|dir| PathBuf::from(dir).join("dotnet"))
+            .into_iter()
+            .collect()
+    }
+}
+
+#[cfg(not(windows))]
+mod sys {
+    use std::{fs, path::PathBuf};
+
+    use crate::platform;
+
+    pub fn install_location() -> Option<PathBuf> {
+        platform::architecture()
+            .map(|arch| format!("/etc/dotnet/install_location_{arch}"))
+            .into_iter()
+            .chain(["/etc/dotnet/install_location".to_string()])
+            .filter_map(|file| fs::read_to_string(file).ok())
+            .filter_map(|contents| contents.lines().next().map(|line| line.trim().to_string()))
+            .find(|location| !location.is_empty())
+            .map(PathBuf::from)
+    }
+
+    pub fn default_install_dirs() -> Vec<PathBuf> {
+        if cfg!(target_os = "macos") {
+            let mut dirs = Vec::new();
+            if cfg!(target_arch = "x86_64") {
+                // x64 runtimes are installed into a subdirectory on Apple silicon.
+                dirs.push(PathBuf::from("/usr/local/share/dotnet/x64"));
+            }
+            dirs.push(PathBuf::from("/usr/local/share/dotnet"));
+            dirs
+        } else {
+            vec![
+                PathBuf::from("/usr/share/dotnet"),
+                PathBuf::from("/usr/lib/dotnet"),
+                PathBuf::from("/usr/lib64/dotnet"),
+            ]
+        }
+    }
+}
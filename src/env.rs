@@ -0,0 +1,355 @@
+use std::{
+    env,
+    ffi::{OsStr, OsString},
+    fmt::{self, Display},
+    marker::PhantomData,
+    sync::{Condvar, Mutex, MutexGuard, PoisonError},
+    thread::{self, ThreadId},
+};
+
+struct EnvLockState {
+    owner: Option<ThreadId>,
+    count: usize,
+}
+
+static ENV_LOCK_STATE: Mutex<EnvLockState> = Mutex::new(EnvLockState {
+    owner: None,
+    count: 0,
+});
+static ENV_LOCK_RELEASED: Condvar = Condvar::new();
+
+fn lock_state() -> MutexGuard<'static, EnvLockState> {
+    ENV_LOCK_STATE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A process-wide lock serializing modifications of environment variables.
+///
+/// The hosting components read their configuration (like `DOTNET_ROOT` or `COREHOST_TRACE`) from the process environment,
+/// which is shared between all threads. Code that modifies these variables, for example tests running in parallel,
+/// should hold this lock while doing so and while initializing the hosting components.
+///
+/// The lock is reentrant, so it can be acquired multiple times on the same thread.
+/// It only serializes code that uses it and does not prevent other code from modifying the environment.
+#[must_use]
+pub struct EnvLock {
+    // the lock has to be released on the thread that acquired it.
+    not_send: PhantomData<*const ()>,
+}
+
+impl EnvLock {
+    /// Acquires the lock, blocking the current thread until it is available.
+    pub fn acquire() -> Self {
+        let current = thread::current().id();
+        let mut state = lock_state();
+        while state.owner.is_some_and(|owner| owner != current) {
+            state = ENV_LOCK_RELEASED
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        state.owner = Some(current);
+        state.count += 1;
+        Self {
+            not_send: PhantomData,
+        }
+    }
+}
+
+impl Drop for EnvLock {
+    fn drop(&mut self) {
+        let mut state = lock_state();
+        state.count -= 1;
+        if state.count == 0 {
+            state.owner = None;
+            ENV_LOCK_RELEASED.notify_all();
+        }
+    }
+}
+
+/// A guard for temporary modifications of environment variables, which are reverted on drop.
+///
+/// Holds an [`EnvLock`] for its entire lifetime.
+#[must_use]
+pub struct ScopedEnv {
+    previous: Vec<(OsString, Option<OsString>)>,
+    _lock: EnvLock,
+}
+
+impl Default for ScopedEnv {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScopedEnv {
+    /// Acquires the [`EnvLock`] and creates a new scope without any modifications.
+    pub fn new() -> Self {
+        Self {
+            _lock: EnvLock::acquire(),
+            previous: Vec::new(),
+        }
+    }
+
+    /// Sets the environment variable `key` to `value` until this scope is dropped.
+    pub fn set(&mut self, key: impl AsRef<OsStr>, value: impl AsRef<OsStr>) -> &mut Self {
+        let key = key.as_ref();
+        self.remember(key);
+        env::set_var(key, value);
+        self
+    }
+
+    /// Removes the environment variable `key` until this scope is dropped.
+    pub fn remove(&mut self, key: impl AsRef<OsStr>) -> &mut Self {
+        let key = key.as_ref();
+        self.remember(key);
+        env::remove_var(key);
+        self
+    }
+
+    fn remember(&mut self, key: &OsStr) {
+        self.previous.push((key.to_owned(), env::var_os(key)));
+    }
+}
+
+impl Drop for ScopedEnv {
+    fn drop(&mut self) {
+        // restore in reverse order so that the original value wins if a variable was modified multiple times.
+        for (key, value) in self.previous.drain(..).rev() {
+            match value {
+                Some(value) => env::set_var(key, value),
+                None => env::remove_var(key),
+            }
+        }
+    }
+}
+
+/// A set of environment variables configuring the hosting components.
+///
+/// # Example
+/// ```rust
+/// # use netcorehost::env::HostEnvironment;
+/// let _env = HostEnvironment::new()
+///     .trace(true)
+///     .roll_forward("LatestMajor")
+///     .apply();
+/// // initialize the hosting components here
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HostEnvironment {
+    vars: Vec<(OsString, Option<OsString>)>,
+}
+
+impl HostEnvironment {
+    /// Creates a new empty environment configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the given environment variable.
+    #[must_use]
+    pub fn var(mut self, key: impl AsRef<OsStr>, value: impl AsRef<OsStr>) -> Self {
+        self.vars
+            .push((key.as_ref().to_owned(), Some(value.as_ref().to_owned())));
+        self
+    }
+
+    /// Removes the given environment variable.
+    #[must_use]
+    pub fn remove_var(mut self, key: impl AsRef<OsStr>) -> Self {
+        self.vars.push((key.as_ref().to_owned(), None));
+        self
+    }
+
+    /// Sets `DOTNET_ROOT`, the location of the .NET installation used by apphosts and nethost.
+    #[must_use]
+    pub fn dotnet_root(self, path: impl AsRef<OsStr>) -> Self {
+        self.var("DOTNET_ROOT", path)
+    }
+
+    /// Sets `DOTNET_ROLL_FORWARD`, the roll forward policy for framework resolution
+    /// (one of `Minor`, `Major`, `LatestPatch`, `LatestMinor`, `LatestMajor` or `Disable`).
+    #[must_use]
+    pub fn roll_forward(self, policy: impl AsRef<OsStr>) -> Self {
+        self.var("DOTNET_ROLL_FORWARD", policy)
+    }
+
+    /// Sets `COREHOST_TRACE`, which enables tracing of the hosting components.
+    #[must_use]
+    pub fn trace(self, enabled: bool) -> Self {
+        self.var("COREHOST_TRACE", if enabled { "1" } else { "0" })
+    }
+
+    /// Sets `COREHOST_TRACEFILE`, the file the trace output of the hosting components is written to.
+    #[must_use]
+    pub fn trace_file(self, path: impl AsRef<OsStr>) -> Self {
+        self.var("COREHOST_TRACEFILE", path)
+    }
+
+    /// Sets `DOTNET_CLI_TELEMETRY_OPTOUT` and `DOTNET_NOLOGO`, which prevent the dotnet CLI and the SDK from collecting
+    /// telemetry and printing the telemetry notice. This covers SDK commands run while the configuration is applied,
+    /// including ones started by the hosting components or build tools invoked from the process.
+    ///
+    /// The dotnet CLI wrappers of this crate opt out of telemetry on their own, see
+    /// [`DotnetCli::telemetry`](crate::dotnet_cli::DotnetCli::telemetry).
+    #[must_use]
+    pub fn disable_dotnet_telemetry(self) -> Self {
+        crate::dotnet_cli::TELEMETRY_OPT_OUT_ENV_VARS
+            .iter()
+            .fold(self, |environment, (key, value)| {
+                environment.var(key, value)
+            })
+    }
+
+    /// Sets `DOTNET_BUNDLE_EXTRACT_BASE_DIR`, the directory single-file bundles extract their files to,
+    /// see [`bundle::extraction_base_dir`](crate::bundle::extraction_base_dir).
+    #[must_use]
+    pub fn bundle_extract_base_dir(self, path: impl AsRef<OsStr>) -> Self {
+        self.var(crate::bundle::BUNDLE_EXTRACT_BASE_DIR_ENV_VAR, path)
+    }
+
+    /// Sets `DOTNET_MULTILEVEL_LOOKUP`, which controls whether frameworks are also searched for in the global install location,
+    /// see [`MultilevelLookup`]. [`MultilevelLookup::Auto`] removes the variable.
+    #[must_use]
+    pub fn multilevel_lookup(self, setting: MultilevelLookup) -> Self {
+        match setting.env_value() {
+            Some(value) => self.var(MULTILEVEL_LOOKUP_ENV_VAR, value),
+            None => self.remove_var(MULTILEVEL_LOOKUP_ENV_VAR),
+        }
+    }
+
+    /// Gets the multi-level lookup setting of this configuration.
+    /// Returns [`MultilevelLookup::Auto`] if it is not configured.
+    #[must_use]
+    pub fn get_multilevel_lookup(&self) -> MultilevelLookup {
+        self.vars
+            .iter()
+            .rev()
+            .find(|(key, _)| key == MULTILEVEL_LOOKUP_ENV_VAR)
+            .and_then(|(_, value)| value.as_deref())
+            .map_or(MultilevelLookup::Auto, MultilevelLookup::from_env_value)
+    }
+
+    /// Applies this configuration to the process environment until the returned guard is dropped.
+    pub fn apply(&self) -> ScopedEnv {
+        let mut scope = ScopedEnv::new();
+        for (key, value) in &self.vars {
+            match value {
+                Some(value) => scope.set(key, value),
+                None => scope.remove(key),
+            };
+        }
+        scope
+    }
+}
+
+const MULTILEVEL_LOOKUP_ENV_VAR: &str = "DOTNET_MULTILEVEL_LOOKUP";
+
+/// Setting for multi-level lookup (`DOTNET_MULTILEVEL_LOOKUP`), which makes the hosting components also search the global
+/// install location for frameworks and SDKs if a different dotnet root is used.
+///
+/// Multi-level lookup is only supported on Windows and was removed in .NET 7, hostfxr 7.0 and later ignore the setting.
+/// Use [`MultilevelLookup::check`] to detect settings which will be ignored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MultilevelLookup {
+    /// Use the default of the hosting components, which is enabled where it is supported.
+    #[default]
+    Auto,
+    /// Always search the global install location.
+    Force,
+    /// Never search the global install location.
+    Disable,
+}
+
+impl MultilevelLookup {
+    fn env_value(self) -> Option<&'static str> {
+        match self {
+            Self::Auto => None,
+            Self::Force => Some("1"),
+            Self::Disable => Some("0"),
+        }
+    }
+
+    fn from_env_value(value: &OsStr) -> Self {
+        match value.to_str().map(str::trim) {
+            Some("0") => Self::Disable,
+            Some("1") => Self::Force,
+            _ => Self::Auto,
+        }
+    }
+
+    /// Returns the setting configured in the environment of the current process.
+    #[must_use]
+    pub fn from_env() -> Self {
+        env::var_os(MULTILEVEL_LOOKUP_ENV_VAR)
+            .map_or(Self::Auto, |value| Self::from_env_value(&value))
+    }
+
+    /// Checks whether this setting is honored by the hostfxr library with the given version (like `6.0.36`) on the current platform.
+    /// Returns a warning if the setting will be ignored.
+    ///
+    /// [`MultilevelLookup::Auto`] never results in a warning.
+    #[must_use]
+    pub fn check(self, hostfxr_version: &str) -> Option<MultilevelLookupWarning> {
+        if self == Self::Auto {
+            return None;
+        }
+        if !cfg!(windows) {
+            return Some(MultilevelLookupWarning::UnsupportedPlatform);
+        }
+
+        let major = hostfxr_version
+            .split('.')
+            .next()
+            .and_then(|major| major.parse::<u32>().ok());
+        match major {
+            Some(major) if major >= 7 => Some(MultilevelLookupWarning::IgnoredByHostfxr {
+                version: hostfxr_version.to_string(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// A warning about a [`MultilevelLookup`] setting that will be ignored.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MultilevelLookupWarning {
+    /// Multi-level lookup is only supported on Windows.
+    UnsupportedPlatform,
+    /// The hostfxr library is from .NET 7 or later, which removed multi-level lookup.
+    IgnoredByHostfxr {
+        /// The version of the hostfxr library.
+        version: String,
+    },
+}
+
+impl Display for MultilevelLookupWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedPlatform => {
+                write!(f, "{MULTILEVEL_LOOKUP_ENV_VAR} is ignored on platforms other than Windows")
+            }
+            Self::IgnoredByHostfxr { version } => write!(
+                f,
+                "{MULTILEVEL_LOOKUP_ENV_VAR} is ignored by hostfxr {version}, multi-level lookup was removed in .NET 7"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "net6_0")]
+impl crate::hostfxr::Hostfxr {
+    /// Checks whether the given multi-level lookup setting is honored by this hostfxr library, see [`MultilevelLookup::check`].
+    ///
+    /// Returns an error if the version of the library could not be determined.
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net6_0")))]
+    pub fn check_multilevel_lookup(
+        &self,
+        setting: MultilevelLookup,
+    ) -> Result<Option<MultilevelLookupWarning>, crate::error::HostingError> {
+        let info = self.get_dotnet_environment_info()?;
+        Ok(setting.check(&info.hostfxr_version))
+    }
+}
@@ -0,0 +1,108 @@
+use std::fmt;
+
+use crate::{
+    error::{Error, HostingError},
+    platform::{architecture, runtime_identifier},
+};
+
+/// Advice on how an error can usually be resolved, see [`HostingError::hint`] and [`Error::hint`].
+///
+/// The [`Display`](fmt::Display) implementation renders the message followed by the link, if there is one, so it
+/// can be shown to users as is.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Hint {
+    /// A short description of how the error can usually be resolved.
+    pub message: &'static str,
+    /// A link with more information, like the download page of the runtime for the current platform.
+    pub url: Option<String>,
+}
+
+impl Hint {
+    const fn new(message: &'static str) -> Self {
+        Self { message, url: None }
+    }
+
+    fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    fn with_optional_url(mut self, url: Option<String>) -> Self {
+        self.url = url;
+        self
+    }
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.url {
+            Some(url) => write!(f, "{} ({url})", self.message),
+            None => f.write_str(self.message),
+        }
+    }
+}
+
+impl HostingError {
+    /// Returns advice on how this error can usually be resolved, if there is any.
+    ///
+    /// For a missing framework the hint links to the runtime download page for the runtime identifier of the current
+    /// process, like the stock apphost does. The link is omitted on platforms without a runtime identifier.
+    #[must_use]
+    pub fn hint(&self) -> Option<Hint> {
+        let hint = match self {
+            Self::FrameworkMissingFailure => Hint::new(
+                "Install a .NET runtime matching the framework reference in the \
+                 .runtimeconfig.json of the application.",
+            )
+            .with_optional_url(runtime_download_url()),
+            Self::CoreHostIncompatibleConfig => Hint::new(
+                "The component requires a framework that is not compatible with the runtime \
+                 already loaded in the process. Target the same framework as the primary application.",
+            ),
+            Self::CoreHostLibMissingFailure
+            | Self::CoreHostCurHostFindFailure
+            | Self::LibHostCurExeFindFailure => Hint::new(
+                "The .NET installation could not be located. Set the DOTNET_ROOT environment \
+                 variable to the installation directory or install .NET in the default location.",
+            )
+            .with_optional_url(runtime_download_url()),
+            Self::AppArgNotRunnable => Hint::new(
+                "The application path has to point to the managed .dll (or .exe) of an \
+                 application. SDK commands and native executables cannot be run this way.",
+            ),
+            Self::InvalidConfigFile => Hint::new(
+                "Check that the .runtimeconfig.json is valid JSON and belongs to a \
+                 framework-dependent application.",
+            ),
+            Self::LibHostSdkFindFailure | Self::SdkResolverResolveFailure => Hint::new(
+                "Install a .NET SDK matching the version requested in global.json or adjust the \
+                 requested version.",
+            )
+            .with_url("https://aka.ms/dotnet/download"),
+            Self::HostFeatureDisabled => Hint::new(
+                "Native hosting support is disabled for the application. Set the runtime property \
+                 System.Runtime.InteropServices.EnableConsumingManagedCodeFromNativeHosting to true.",
+            ),
+            _ => return None,
+        };
+        Some(hint)
+    }
+}
+
+impl Error {
+    /// Returns advice on how this error can usually be resolved, if there is any.
+    /// See [`HostingError::hint`].
+    #[must_use]
+    pub fn hint(&self) -> Option<Hint> {
+        self.hosting_error().and_then(|error| error.hint())
+    }
+}
+
+fn runtime_download_url() -> Option<String> {
+    let rid = runtime_identifier()?;
+    let arch = architecture()?;
+    Some(format!(
+        "https://aka.ms/dotnet-core-applaunch?missing_runtime=true&arch={arch}&rid={rid}"
+    ))
+}
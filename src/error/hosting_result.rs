@@ -1,6 +1,6 @@
-use std::convert::TryFrom;
 #[cfg(feature = "nightly")]
 use std::ops::{ControlFlow, FromResidual, Try};
+use std::{convert::TryFrom, str::FromStr};
 
 use crate::bindings;
 use derive_more::{Deref, Display, From};
@@ -141,7 +141,11 @@ impl FromResidual for HostingResult {
 /// Success codes returned by the hosting APIs from `hostfxr`, `hostpolicy` and `nethost`.
 ///
 /// Source: [https://github.com/dotnet/runtime/blob/main/docs/design/features/host-error-codes.md](https://github.com/dotnet/runtime/blob/main/docs/design/features/host-error-codes.md)
+///
+/// New status codes may be added in minor releases, so matches on this enum have to include a wildcard arm.
+/// Status codes without a dedicated variant are represented as [`HostingSuccess::Unknown`].
 #[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash, Display)]
+#[non_exhaustive]
 pub enum HostingSuccess {
     /// Operation was successful.
     #[display(fmt = "Operation was successful.")]
@@ -209,6 +213,23 @@ impl HostingSuccess {
         }
     }
 
+    /// Returns whether this status indicates that a host context was already initialized,
+    /// which means that the new context is secondary and reuses the already running runtime.
+    #[must_use]
+    pub const fn is_host_already_initialized(&self) -> bool {
+        matches!(
+            self,
+            Self::HostAlreadyInitialized | Self::DifferentRuntimeProperties
+        )
+    }
+
+    /// Returns whether this status indicates that a host context was already initialized with runtime properties
+    /// that differ from the ones requested by the new context.
+    #[must_use]
+    pub const fn is_different_runtime_properties(&self) -> bool {
+        matches!(self, Self::DifferentRuntimeProperties)
+    }
+
     /// Returns whether the status code of this success has a known meaning.
     #[must_use]
     pub const fn is_known(&self) -> bool {
@@ -220,6 +241,45 @@ impl HostingSuccess {
     pub const fn is_unknown(&self) -> bool {
         matches!(self, Self::Unknown(_))
     }
+
+    /// Returns all success codes with a known meaning.
+    #[must_use]
+    pub const fn all_variants() -> &'static [Self] {
+        &[
+            Self::Success,
+            Self::HostAlreadyInitialized,
+            Self::DifferentRuntimeProperties,
+        ]
+    }
+
+    /// Returns the name of the variant (like `"DifferentRuntimeProperties"`), or `"Unknown"` for unknown status codes.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Success => "Success",
+            Self::HostAlreadyInitialized => "HostAlreadyInitialized",
+            Self::DifferentRuntimeProperties => "DifferentRuntimeProperties",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
+}
+
+impl FromStr for HostingSuccess {
+    type Err = ParseHostingStatusError;
+
+    /// Parses the name of a variant (like `"DifferentRuntimeProperties"`) or a raw success status code in decimal or hexadecimal (`0x` prefixed) notation.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(variant) = Self::all_variants()
+            .iter()
+            .find(|variant| variant.name() == s)
+        {
+            return Ok(*variant);
+        }
+        match parse_status_code(s).map(HostingResult::from_status_code) {
+            Some(HostingResult(Ok(status))) => Ok(status),
+            _ => Err(ParseHostingStatusError(s.to_string())),
+        }
+    }
 }
 
 impl TryFrom<u32> for HostingSuccess {
@@ -239,8 +299,12 @@ impl From<HostingSuccess> for u32 {
 /// Error codes returned by the hosting APIs from `hostfxr`, `hostpolicy` and `nethost`.
 ///
 /// Source: [https://github.com/dotnet/runtime/blob/main/docs/design/features/host-error-codes.md](https://github.com/dotnet/runtime/blob/main/docs/design/features/host-error-codes.md)
+///
+/// New status codes may be added in minor releases, so matches on this enum have to include a wildcard arm.
+/// Status codes without a dedicated variant are represented as [`HostingError::Unknown`].
 #[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash, Display)]
 #[must_use]
+#[non_exhaustive]
 pub enum HostingError {
     /// One of the specified arguments for the operation is invalid.
     #[display(fmt = "One of the specified arguments for the operation is invalid.")]
@@ -359,12 +423,16 @@ pub enum HostingError {
     #[display(fmt = "Arguments to hostpolicy are invalid.")]
     InvalidConfigFile,
 
-    /// Used internally when the command line for `dotnet.exe` doesn't contain path to the application to run.
-    /// In such case the command line is considered to be a CLI/SDK command.
-    /// This error code should never be returned to external caller.
-    #[doc(hidden)]
+    /// The command line for `dotnet.exe` doesn't contain path to the application to run.
+    /// In such case the command line is considered to be a CLI/SDK command (like `dotnet test` or `dotnet run`).
+    /// The hosting layer uses this error code internally to dispatch to the SDK. It is returned by
+    /// [`Hostfxr::initialize_for_dotnet_command_line`] and related functions if the app path is not a `.dll` or `.exe`,
+    /// as SDK commands cannot be run in-process. Use [`DotnetCli::command`] to run them in a child process instead.
+    ///
+    /// [`Hostfxr::initialize_for_dotnet_command_line`]: crate::hostfxr::Hostfxr::initialize_for_dotnet_command_line
+    /// [`DotnetCli::command`]: crate::dotnet_cli::DotnetCli::command
     #[display(
-        fmt = "[Internal error] The command line for dotnet.exe doesn't contain the path to the application to run."
+        fmt = "The command line doesn't contain the path to an application to run, SDK commands like `dotnet test` cannot be run in-process."
     )]
     AppArgNotRunnable,
 
@@ -685,6 +753,113 @@ impl HostingError {
     pub const fn is_unknown(&self) -> bool {
         matches!(self, Self::Unknown(_))
     }
+
+    /// Returns all error codes with a known meaning.
+    #[must_use]
+    pub const fn all_variants() -> &'static [Self] {
+        &[
+            Self::InvalidArgFailure,
+            Self::CoreHostLibLoadFailure,
+            Self::CoreHostLibMissingFailure,
+            Self::CoreHostEntryPointFailure,
+            Self::CoreHostCurHostFindFailure,
+            Self::CoreClrResolveFailure,
+            Self::CoreClrBindFailure,
+            Self::CoreClrInitFailure,
+            Self::CoreClrExeFailure,
+            Self::ResolverInitFailure,
+            Self::ResolverResolveFailure,
+            Self::LibHostCurExeFindFailure,
+            Self::LibHostInitFailure,
+            Self::LibHostExecModeFailure,
+            Self::LibHostSdkFindFailure,
+            Self::LibHostInvalidArgs,
+            Self::InvalidConfigFile,
+            Self::AppArgNotRunnable,
+            Self::AppHostExeNotBoundFailure,
+            Self::FrameworkMissingFailure,
+            Self::HostApiFailed,
+            Self::HostApiBufferTooSmall,
+            Self::LibHostUnknownCommand,
+            Self::LibHostAppRootFindFailure,
+            Self::SdkResolverResolveFailure,
+            Self::FrameworkCompatFailure,
+            Self::FrameworkCompatRetry,
+            Self::AppHostExeNotBundle,
+            Self::BundleExtractionFailure,
+            Self::BundleExtractionIOError,
+            Self::LibHostDuplicateProperty,
+            Self::HostApiUnsupportedVersion,
+            Self::HostInvalidState,
+            Self::HostPropertyNotFound,
+            Self::CoreHostIncompatibleConfig,
+            Self::HostApiUnsupportedScenario,
+            Self::HostFeatureDisabled,
+        ]
+    }
+
+    /// Returns the name of the variant (like `"HostFeatureDisabled"`), or `"Unknown"` for unknown status codes.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::InvalidArgFailure => "InvalidArgFailure",
+            Self::CoreHostLibLoadFailure => "CoreHostLibLoadFailure",
+            Self::CoreHostLibMissingFailure => "CoreHostLibMissingFailure",
+            Self::CoreHostEntryPointFailure => "CoreHostEntryPointFailure",
+            Self::CoreHostCurHostFindFailure => "CoreHostCurHostFindFailure",
+            Self::CoreClrResolveFailure => "CoreClrResolveFailure",
+            Self::CoreClrBindFailure => "CoreClrBindFailure",
+            Self::CoreClrInitFailure => "CoreClrInitFailure",
+            Self::CoreClrExeFailure => "CoreClrExeFailure",
+            Self::ResolverInitFailure => "ResolverInitFailure",
+            Self::ResolverResolveFailure => "ResolverResolveFailure",
+            Self::LibHostCurExeFindFailure => "LibHostCurExeFindFailure",
+            Self::LibHostInitFailure => "LibHostInitFailure",
+            Self::LibHostExecModeFailure => "LibHostExecModeFailure",
+            Self::LibHostSdkFindFailure => "LibHostSdkFindFailure",
+            Self::LibHostInvalidArgs => "LibHostInvalidArgs",
+            Self::InvalidConfigFile => "InvalidConfigFile",
+            Self::AppArgNotRunnable => "AppArgNotRunnable",
+            Self::AppHostExeNotBoundFailure => "AppHostExeNotBoundFailure",
+            Self::FrameworkMissingFailure => "FrameworkMissingFailure",
+            Self::HostApiFailed => "HostApiFailed",
+            Self::HostApiBufferTooSmall => "HostApiBufferTooSmall",
+            Self::LibHostUnknownCommand => "LibHostUnknownCommand",
+            Self::LibHostAppRootFindFailure => "LibHostAppRootFindFailure",
+            Self::SdkResolverResolveFailure => "SdkResolverResolveFailure",
+            Self::FrameworkCompatFailure => "FrameworkCompatFailure",
+            Self::FrameworkCompatRetry => "FrameworkCompatRetry",
+            Self::AppHostExeNotBundle => "AppHostExeNotBundle",
+            Self::BundleExtractionFailure => "BundleExtractionFailure",
+            Self::BundleExtractionIOError => "BundleExtractionIOError",
+            Self::LibHostDuplicateProperty => "LibHostDuplicateProperty",
+            Self::HostApiUnsupportedVersion => "HostApiUnsupportedVersion",
+            Self::HostInvalidState => "HostInvalidState",
+            Self::HostPropertyNotFound => "HostPropertyNotFound",
+            Self::CoreHostIncompatibleConfig => "CoreHostIncompatibleConfig",
+            Self::HostApiUnsupportedScenario => "HostApiUnsupportedScenario",
+            Self::HostFeatureDisabled => "HostFeatureDisabled",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
+}
+
+impl FromStr for HostingError {
+    type Err = ParseHostingStatusError;
+
+    /// Parses the name of a variant (like `"HostFeatureDisabled"`) or a raw error status code in decimal or hexadecimal (`0x` prefixed) notation.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(variant) = Self::all_variants()
+            .iter()
+            .find(|variant| variant.name() == s)
+        {
+            return Ok(*variant);
+        }
+        match parse_status_code(s).map(HostingResult::from_status_code) {
+            Some(HostingResult(Err(status))) => Ok(status),
+            _ => Err(ParseHostingStatusError(s.to_string())),
+        }
+    }
 }
 
 impl TryFrom<u32> for HostingError {
@@ -700,3 +875,17 @@ impl From<HostingError> for u32 {
         code.value()
     }
 }
+
+fn parse_status_code(s: &str) -> Option<u32> {
+    match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+        None => s.parse().ok(),
+    }
+}
+
+/// Error returned when parsing an unknown name of a [`HostingSuccess`] or [`HostingError`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Display)]
+#[display(fmt = "Unknown hosting status: {_0:?}")]
+pub struct ParseHostingStatusError(String);
+
+impl std::error::Error for ParseHostingStatusError {}
@@ -3,3 +3,6 @@ pub use hosting_result::*;
 
 mod univ;
 pub use univ::*;
+
+mod hint;
+pub use hint::*;
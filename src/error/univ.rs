@@ -1,11 +1,16 @@
 use thiserror::Error;
 
+use crate::error::HostingError;
+
 /// A universal error type encompassing all possible errors from the [`netcorehost`](crate) crate.
+///
+/// New variants may be added in minor releases, use [`Error::kind`] or the accessors for matching.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum Error {
     /// An error from the native hosting components.
     #[error(transparent)]
-    Hosting(#[from] crate::error::HostingError),
+    Hosting(#[from] HostingError),
     /// An error while loading a function pointer to a managed method.
     #[error(transparent)]
     #[cfg(feature = "netcore3_0")]
@@ -18,6 +23,63 @@ pub enum Error {
     LoadHostfxr(#[from] crate::nethost::LoadHostfxrError),
 }
 
+/// The kind of an [`Error`] without any of its associated data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// See [`Error::Hosting`].
+    Hosting,
+    /// See [`Error::GetFunctionPointer`].
+    #[cfg(feature = "netcore3_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+    GetFunctionPointer,
+    /// See [`Error::LoadHostfxr`].
+    #[cfg(feature = "nethost")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "nethost")))]
+    LoadHostfxr,
+}
+
+impl Error {
+    /// Returns the kind of this error.
+    #[must_use]
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Hosting(_) => ErrorKind::Hosting,
+            #[cfg(feature = "netcore3_0")]
+            Self::GetFunctionPointer(_) => ErrorKind::GetFunctionPointer,
+            #[cfg(feature = "nethost")]
+            Self::LoadHostfxr(_) => ErrorKind::LoadHostfxr,
+        }
+    }
+
+    /// Returns the error from the hosting components that caused this error, if there is one.
+    #[must_use]
+    pub const fn hosting_error(&self) -> Option<HostingError> {
+        match self {
+            Self::Hosting(error) => Some(*error),
+            #[cfg(feature = "netcore3_0")]
+            Self::GetFunctionPointer(error) => error.hosting_error(),
+            #[cfg(feature = "nethost")]
+            Self::LoadHostfxr(error) => error.hosting_error(),
+        }
+    }
+
+    /// Returns the raw status code associated with this error, if there is one.
+    #[must_use]
+    pub const fn code(&self) -> Option<u32> {
+        match self {
+            Self::Hosting(error) => Some(error.value()),
+            #[cfg(feature = "netcore3_0")]
+            Self::GetFunctionPointer(error) => Some(error.code()),
+            #[cfg(feature = "nethost")]
+            Self::LoadHostfxr(error) => match error.hosting_error() {
+                Some(error) => Some(error.value()),
+                None => None,
+            },
+        }
+    }
+}
+
 #[cfg(feature = "nethost")]
 impl From<crate::dlopen2::Error> for Error {
     fn from(err: crate::dlopen2::Error) -> Self {
@@ -0,0 +1,222 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Debug, Display},
+    mem,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+/// An opaque identifier of a native resource registered in a [`HandleTable`], which can be passed to managed code
+/// instead of a raw pointer.
+///
+/// It has the layout of a `u64`, so it can be used in the signature of managed functions and callbacks
+/// (as `ulong` on the managed side). Identifiers are never reused by a table, so a stale identifier held by
+/// managed code never refers to a different resource.
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandleId(u64);
+
+impl HandleId {
+    /// An identifier that never refers to a resource, corresponding to `default(ulong)` in managed code.
+    pub const INVALID: Self = Self(0);
+
+    /// Creates a [`HandleId`] from the raw value received from managed code.
+    #[must_use]
+    pub const fn from_raw(raw: u64) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw value to pass to managed code.
+    #[must_use]
+    pub const fn as_raw(self) -> u64 {
+        self.0
+    }
+
+    /// Returns whether this is [`HandleId::INVALID`].
+    #[must_use]
+    pub const fn is_invalid(self) -> bool {
+        self.0 == Self::INVALID.0
+    }
+}
+
+#[cfg(feature = "netcore3_0")]
+unsafe impl crate::hostfxr::FfiSafe for HandleId {
+    fn csharp_type() -> String {
+        "ulong".to_string()
+    }
+}
+
+impl Debug for HandleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "HandleId({:#x})", self.0)
+    }
+}
+
+impl Display for HandleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
+struct TableState<T> {
+    last_id: u64,
+    entries: BTreeMap<u64, Arc<T>>,
+}
+
+/// A table mapping [`HandleId`]s to native resources that are shared with managed code.
+///
+/// Instead of handing out raw pointers, which turn into dangling pointers if managed code keeps using them after the
+/// resource was freed, a resource is registered in the table and only its [`HandleId`] is passed to managed code.
+/// Managed code passes the identifier back to native callbacks, which look it up using [`HandleTable::get`].
+/// Once the [`NativeResourceHandle`] returned by [`HandleTable::insert`] is dropped, the identifier is revoked and
+/// lookups fail instead of accessing freed memory.
+///
+/// # Example
+/// ```rust
+/// # use netcorehost::handle::{HandleId, HandleTable};
+/// struct Connection { /* ... */ }
+///
+/// static CONNECTIONS: HandleTable<Connection> = HandleTable::new();
+///
+/// // exposed to managed code as `delegate* unmanaged<ulong, int>`.
+/// extern "system" fn connection_is_open(id: HandleId) -> i32 {
+///     i32::from(CONNECTIONS.get(id).is_some())
+/// }
+///
+/// let connection = CONNECTIONS.insert(Connection { /* ... */ });
+/// // pass `connection.id()` to managed code here.
+/// assert_eq!(connection_is_open(connection.id()), 1);
+///
+/// let id = connection.id();
+/// drop(connection);
+/// assert_eq!(connection_is_open(id), 0);
+/// ```
+pub struct HandleTable<T> {
+    state: Mutex<TableState<T>>,
+}
+
+impl<T> Default for HandleTable<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for HandleTable<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HandleTable")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> HandleTable<T> {
+    /// Creates a new empty table.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: Mutex::new(TableState {
+                last_id: 0,
+                entries: BTreeMap::new(),
+            }),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, TableState<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Registers the given resource and returns the handle owning its registration.
+    /// The resource stays accessible through [`HandleTable::get`] until the handle is dropped.
+    pub fn insert(&self, value: T) -> NativeResourceHandle<'_, T> {
+        let mut state = self.lock();
+        state.last_id = state
+            .last_id
+            .checked_add(1)
+            .expect("handle table ran out of identifiers");
+        let id = state.last_id;
+        state.entries.insert(id, Arc::new(value));
+        NativeResourceHandle {
+            table: self,
+            id: HandleId(id),
+        }
+    }
+
+    /// Returns the resource with the given identifier, or [`None`] if it was revoked or never existed.
+    ///
+    /// The returned [`Arc`] keeps the resource alive even if it is revoked concurrently,
+    /// so it can safely be used for the rest of the callback.
+    #[must_use]
+    pub fn get(&self, id: HandleId) -> Option<Arc<T>> {
+        self.lock().entries.get(&id.0).cloned()
+    }
+
+    /// Returns whether the given identifier refers to a registered resource.
+    #[must_use]
+    pub fn contains(&self, id: HandleId) -> bool {
+        self.lock().entries.contains_key(&id.0)
+    }
+
+    /// Returns the number of registered resources.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Returns whether no resources are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn remove(&self, id: HandleId) -> Option<Arc<T>> {
+        self.lock().entries.remove(&id.0)
+    }
+}
+
+/// The registration of a resource in a [`HandleTable`], which revokes the [`HandleId`] of the resource when dropped.
+#[must_use]
+pub struct NativeResourceHandle<'a, T> {
+    table: &'a HandleTable<T>,
+    id: HandleId,
+}
+
+impl<T> Debug for NativeResourceHandle<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NativeResourceHandle")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> NativeResourceHandle<'_, T> {
+    /// Returns the identifier to pass to managed code.
+    #[must_use]
+    pub const fn id(&self) -> HandleId {
+        self.id
+    }
+
+    /// Returns the registered resource.
+    #[must_use]
+    pub fn get(&self) -> Arc<T> {
+        self.table
+            .get(self.id)
+            .expect("resource of a live handle was removed from its table")
+    }
+
+    /// Revokes the identifier and returns the resource.
+    ///
+    /// Returns [`Err`] with the shared resource if a callback is still using it.
+    pub fn revoke(self) -> Result<T, Arc<T>> {
+        let value = self
+            .table
+            .remove(self.id)
+            .expect("resource of a live handle was removed from its table");
+        mem::forget(self);
+        Arc::try_unwrap(value)
+    }
+}
+
+impl<T> Drop for NativeResourceHandle<'_, T> {
+    fn drop(&mut self) {
+        self.table.remove(self.id);
+    }
+}
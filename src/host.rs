@@ -0,0 +1,180 @@
+use std::fmt;
+
+use crate::{
+    error::Error,
+    hostfxr::{
+        AssemblyDelegateLoader, DelegateLoader, Hostfxr, HostfxrContext, HostfxrLoadOptions,
+        HostfxrParameters, InitializedForRuntimeConfig, RuntimeOptions,
+    },
+    nethost,
+    pdcstring::PdCString,
+};
+
+/// A builder for the common case of loading the runtime for a `.runtimeconfig.json` and calling into managed code.
+///
+/// It locates hostfxr using [`nethost`], loads it, initializes a [`HostfxrContext`] and applies runtime properties
+/// in a single step, reporting all failures as an [`Error`].
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{host::RuntimeHostBuilder, pdcstr};
+/// let host = RuntimeHostBuilder::new(pdcstr!("App.runtimeconfig.json"))
+///     .property(pdcstr!("System.GC.Server"), pdcstr!("true"))
+///     .build()
+///     .unwrap();
+/// let hello = host
+///     .delegate_loader_for_assembly(pdcstr!("App.dll"))
+///     .unwrap()
+///     .get_function_with_unmanaged_callers_only::<fn() -> i32>(
+///         pdcstr!("App.Program, App"),
+///         pdcstr!("Hello"),
+///     )
+///     .unwrap();
+/// let result = hello();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeHostBuilder {
+    runtime_config_path: PdCString,
+    load_options: HostfxrLoadOptions,
+    parameters: HostfxrParameters,
+    properties: Vec<(PdCString, PdCString)>,
+    runtime_options: RuntimeOptions,
+}
+
+impl RuntimeHostBuilder {
+    /// Creates a new builder for hosting the runtime described by the given `.runtimeconfig.json`.
+    #[must_use]
+    pub fn new(runtime_config_path: impl Into<PdCString>) -> Self {
+        Self {
+            runtime_config_path: runtime_config_path.into(),
+            load_options: HostfxrLoadOptions::default(),
+            parameters: HostfxrParameters::default(),
+            properties: Vec::new(),
+            runtime_options: RuntimeOptions::default(),
+        }
+    }
+
+    /// Sets the options used to load the hostfxr library.
+    #[must_use]
+    pub fn load_options(mut self, options: HostfxrLoadOptions) -> Self {
+        self.load_options = options;
+        self
+    }
+
+    /// Sets the path to the native host, see [`HostfxrParameters::host_path`].
+    #[must_use]
+    pub fn host_path(mut self, host_path: impl Into<PdCString>) -> Self {
+        self.parameters = self.parameters.host_path(host_path);
+        self
+    }
+
+    /// Sets the root of the .NET installation to use.
+    ///
+    /// hostfxr is located under this path instead of the default install location and the path is passed to the
+    /// hosting components, see [`HostfxrParameters::dotnet_root`].
+    #[must_use]
+    pub fn dotnet_root(mut self, dotnet_root: impl Into<PdCString>) -> Self {
+        self.parameters = self.parameters.dotnet_root(dotnet_root);
+        self
+    }
+
+    /// Sets a runtime property, overriding the value from the `.runtimeconfig.json`.
+    #[must_use]
+    pub fn property(mut self, name: impl Into<PdCString>, value: impl Into<PdCString>) -> Self {
+        self.properties.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the JIT and tiered compilation settings, see [`RuntimeOptions`].
+    ///
+    /// Only the options applied as runtime properties are used, see [`HostfxrContext::apply_runtime_options`].
+    #[must_use]
+    pub fn runtime_options(mut self, options: RuntimeOptions) -> Self {
+        self.runtime_options = options;
+        self
+    }
+
+    /// Loads hostfxr and initializes the hosting components for the configured `.runtimeconfig.json`.
+    ///
+    /// The runtime itself is only loaded once the first delegate loader is used.
+    pub fn build(self) -> Result<RuntimeHost, Error> {
+        let hostfxr_path = match self.parameters.get_dotnet_root() {
+            Some(dotnet_root) => nethost::get_hostfxr_path_with_dotnet_root(dotnet_root)?,
+            None => nethost::get_hostfxr_path()?,
+        };
+        let hostfxr = Hostfxr::load_from_path_with_options(
+            self.load_options.apply_library_name(hostfxr_path),
+            &self.load_options,
+        )?;
+
+        let mut context = hostfxr.initialize_for_runtime_config_with_params(
+            &self.runtime_config_path,
+            &self.parameters,
+        )?;
+        for (name, value) in self.runtime_options.properties() {
+            context.set_runtime_property_value(name, value)?;
+        }
+        for (name, value) in &self.properties {
+            context.set_runtime_property_value(name, value)?;
+        }
+
+        Ok(RuntimeHost { context, hostfxr })
+    }
+}
+
+/// A hostfxr library with a context initialized for a `.runtimeconfig.json`, created by [`RuntimeHostBuilder`].
+pub struct RuntimeHost {
+    context: HostfxrContext<InitializedForRuntimeConfig>,
+    hostfxr: Hostfxr,
+}
+
+impl fmt::Debug for RuntimeHost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RuntimeHost")
+            .field("context", &self.context)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RuntimeHost {
+    /// Returns the loaded hostfxr library.
+    #[must_use]
+    pub const fn hostfxr(&self) -> &Hostfxr {
+        &self.hostfxr
+    }
+
+    /// Returns the initialized context.
+    #[must_use]
+    pub const fn context(&self) -> &HostfxrContext<InitializedForRuntimeConfig> {
+        &self.context
+    }
+
+    /// Returns the initialized context for modifications, like setting further runtime properties before the
+    /// runtime is loaded.
+    pub fn context_mut(&mut self) -> &mut HostfxrContext<InitializedForRuntimeConfig> {
+        &mut self.context
+    }
+
+    /// Consumes the host and returns the hostfxr library and the context.
+    #[must_use]
+    pub fn into_parts(self) -> (Hostfxr, HostfxrContext<InitializedForRuntimeConfig>) {
+        (self.hostfxr, self.context)
+    }
+
+    /// Gets a [`DelegateLoader`] for loading function pointers from any assembly, see
+    /// [`HostfxrContext::get_delegate_loader`].
+    pub fn delegate_loader(&self) -> Result<DelegateLoader, Error> {
+        Ok(self.context.get_delegate_loader()?)
+    }
+
+    /// Gets a loader for function pointers of the assembly with the given path, see
+    /// [`HostfxrContext::get_delegate_loader_for_assembly`].
+    pub fn delegate_loader_for_assembly(
+        &self,
+        assembly_path: impl Into<PdCString>,
+    ) -> Result<AssemblyDelegateLoader, Error> {
+        Ok(self
+            .context
+            .get_delegate_loader_for_assembly(assembly_path)?)
+    }
+}
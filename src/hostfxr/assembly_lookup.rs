@@ -0,0 +1,88 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use thiserror::Error;
+
+/// Finds the assembly with the given simple name (like `ClassLibrary`) in the given directory.
+///
+/// The file name is matched case-insensitively, so that lookups behave the same as on Windows on case-sensitive
+/// file systems too. An exact match is always preferred; if there is none and multiple files only differ in
+/// casing, [`FindAssemblyError::Ambiguous`] is returned.
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{hostfxr::find_assembly_in_dir, pdcstring::PdCString};
+/// let path = find_assembly_in_dir("managed/bin", "classlibrary").unwrap();
+/// let path = PdCString::from_os_str(path).unwrap();
+/// ```
+pub fn find_assembly_in_dir(
+    dir: impl AsRef<Path>,
+    simple_name: &str,
+) -> Result<PathBuf, FindAssemblyError> {
+    let dir = dir.as_ref();
+    let file_name = if has_assembly_extension(simple_name) {
+        simple_name.to_string()
+    } else {
+        format!("{simple_name}.dll")
+    };
+
+    let exact = dir.join(&file_name);
+    if exact.is_file() {
+        return Ok(exact);
+    }
+
+    let mut candidates = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let matches = entry
+            .file_name()
+            .to_str()
+            .is_some_and(|name| name.eq_ignore_ascii_case(&file_name));
+        if matches && entry.file_type()?.is_file() {
+            candidates.push(entry.path());
+        }
+    }
+
+    match candidates.len() {
+        0 => Err(FindAssemblyError::NotFound {
+            dir: dir.to_path_buf(),
+            simple_name: simple_name.to_string(),
+        }),
+        1 => Ok(candidates.remove(0)),
+        _ => {
+            candidates.sort();
+            Err(FindAssemblyError::Ambiguous(candidates))
+        }
+    }
+}
+
+fn has_assembly_extension(name: &str) -> bool {
+    Path::new(name)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| {
+            extension.eq_ignore_ascii_case("dll") || extension.eq_ignore_ascii_case("exe")
+        })
+}
+
+/// Enum for errors that can occur while looking up an assembly in a directory.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum FindAssemblyError {
+    /// No assembly with the given name exists in the directory.
+    #[error("No assembly named {simple_name:?} found in {}.", dir.display())]
+    NotFound {
+        /// The searched directory.
+        dir: PathBuf,
+        /// The requested simple name.
+        simple_name: String,
+    },
+    /// Multiple assemblies match the given name when ignoring case, but none matches exactly.
+    #[error("Multiple assemblies match the requested name: {0:?}.")]
+    Ambiguous(Vec<PathBuf>),
+    /// An error occured while reading the directory.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
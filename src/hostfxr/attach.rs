@@ -0,0 +1,61 @@
+use std::io;
+
+use thiserror::Error;
+
+use crate::{
+    error::HostingError,
+    hostfxr::{
+        runtime_config_json::write_runtime_config, Hostfxr, HostfxrContext,
+        InitializedForRuntimeConfig,
+    },
+};
+
+impl Hostfxr {
+    /// Creates a secondary host context for the runtime that is already running in the current process.
+    ///
+    /// This is intended for native libraries that are loaded *by* a .NET application (for example through P/Invoke)
+    /// and want to call back into managed code. Instead of requiring a `.runtimeconfig.json`, a minimal one
+    /// referencing the framework version of the running runtime is generated, so that the returned context is always
+    /// compatible with the active one. Delegates can then be loaded as usual using [`HostfxrContext::get_delegate_loader`].
+    ///
+    /// The returned context is never primary and the runtime properties it reports are the ones of the active context.
+    ///
+    /// # Note
+    /// The running runtime is only visible through the hostfxr library it was started with.
+    /// `self` therefore has to refer to the same hostfxr library that was used to start the application,
+    /// otherwise [`HostingError::HostInvalidState`] is returned.
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+    pub fn attach_to_current_runtime(
+        &self,
+    ) -> Result<HostfxrContext<InitializedForRuntimeConfig>, AttachToRuntimeError> {
+        let version =
+            self.get_active_runtime_property_value(crate::pdcstr!("FX_PRODUCT_VERSION"))?;
+        let runtime_config = minimal_runtime_config(&version.to_string_lossy());
+
+        // the config file is only read during initialization, so the directory can be removed afterwards.
+        let (_scratch_dir, runtime_config_path) =
+            write_runtime_config("attach.runtimeconfig.json", &runtime_config)?;
+        Ok(self.initialize_for_runtime_config(runtime_config_path)?)
+    }
+}
+
+fn minimal_runtime_config(framework_version: &str) -> String {
+    let framework_version = framework_version.replace('\\', "\\\\").replace('"', "\\\"");
+    format!(
+        r#"{{"runtimeOptions":{{"framework":{{"name":"Microsoft.NETCore.App","version":"{framework_version}"}}}}}}"#
+    )
+}
+
+/// Enum for errors that can occur while attaching to the runtime running in the current process.
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+#[non_exhaustive]
+pub enum AttachToRuntimeError {
+    /// An error occured inside the hosting components.
+    /// [`HostingError::HostInvalidState`] indicates that no runtime is running.
+    #[error(transparent)]
+    Hosting(#[from] HostingError),
+    /// An error occured while writing the generated runtime config.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
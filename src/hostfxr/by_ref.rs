@@ -0,0 +1,160 @@
+use std::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    mem::MaybeUninit,
+};
+
+/// An `out` parameter of a managed function, for use in the signature passed to
+/// [`get_function`](crate::hostfxr::AssemblyDelegateLoader::get_function) and related methods.
+///
+/// This is a [`RefParam`] to uninitialized storage, which is borrowed from an [`Out`] using [`Out::as_param`].
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{hostfxr::{AssemblyDelegateLoader, Out, OutParam}, pdcstr};
+/// # fn test(fn_loader: AssemblyDelegateLoader) {
+/// // public delegate int TryDivideDelegate(int a, int b, out int result);
+/// let try_divide = fn_loader
+///     .get_function::<fn(i32, i32, OutParam<i32>) -> i32>(
+///         pdcstr!("Library.Math, Library"),
+///         pdcstr!("TryDivide"),
+///         pdcstr!("Library.Math+TryDivideDelegate, Library"),
+///     )
+///     .unwrap();
+///
+/// let mut result = Out::new();
+/// if try_divide(84, 2, result.as_param()) != 0 {
+///     let result = unsafe { result.assume_init() };
+///     println!("{result}");
+/// }
+/// # }
+/// ```
+pub type OutParam<'a, T> = RefParam<'a, MaybeUninit<T>>;
+
+/// A `ref` parameter of a managed function, for use in the signature passed to
+/// [`get_function`](crate::hostfxr::AssemblyDelegateLoader::get_function) and related methods.
+///
+/// It has the same layout as a pointer, which is how both `ref T` parameters of delegates and `T*` parameters of
+/// `UnmanagedCallersOnly` methods are passed. The parameter mutably borrows the value it points to, so changes made
+/// by the managed function are visible through the value once the call returns and the parameter is no longer used.
+///
+/// Signatures can contain up to two [`RefParam`]s or [`OutParam`]s, see
+/// [`DelegateSignature`](crate::hostfxr::DelegateSignature). As the parameter borrows its value, it cannot be passed
+/// to or returned from managed functions as a plain value and is not [`FfiSafe`](crate::hostfxr::FfiSafe).
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{hostfxr::{AssemblyDelegateLoader, RefParam}, pdcstr};
+/// # fn test(fn_loader: AssemblyDelegateLoader) {
+/// // [UnmanagedCallersOnly] public static unsafe void Increment(int* value) => *value += 1;
+/// let increment = fn_loader
+///     .get_function_with_unmanaged_callers_only::<fn(RefParam<i32>)>(
+///         pdcstr!("Library.Counter, Library"),
+///         pdcstr!("Increment"),
+///     )
+///     .unwrap();
+///
+/// let mut value = 41;
+/// increment(RefParam::new(&mut value));
+/// assert_eq!(value, 42);
+/// # }
+/// ```
+#[repr(transparent)]
+pub struct RefParam<'a, T> {
+    ptr: *mut T,
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> RefParam<'a, T> {
+    /// Creates a [`RefParam`] borrowing the given value.
+    #[must_use]
+    pub fn new(value: &'a mut T) -> Self {
+        Self {
+            ptr: value,
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates a [`RefParam`] from a raw pointer.
+    ///
+    /// # Safety
+    /// `ptr` has to be valid for reads and writes of `T` for the lifetime `'a`.
+    #[must_use]
+    pub const unsafe fn from_raw(ptr: *mut T) -> Self {
+        Self {
+            ptr,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the raw pointer passed to the managed function.
+    #[must_use]
+    pub const fn as_ptr(&self) -> *mut T {
+        self.ptr
+    }
+}
+
+impl<'a, T> From<&'a mut T> for RefParam<'a, T> {
+    fn from(value: &'a mut T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> Clone for RefParam<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RefParam<'_, T> {}
+
+impl<T> Debug for RefParam<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RefParam").field(&self.ptr).finish()
+    }
+}
+
+/// Storage for the value written to an [`OutParam`].
+///
+/// The storage starts out uninitialized and is borrowed by the parameter created with [`Out::as_param`].
+pub struct Out<T> {
+    value: MaybeUninit<T>,
+}
+
+impl<T> Default for Out<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Debug for Out<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Out").finish_non_exhaustive()
+    }
+}
+
+impl<T> Out<T> {
+    /// Creates new uninitialized storage.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            value: MaybeUninit::uninit(),
+        }
+    }
+
+    /// Returns the parameter to pass to the managed function.
+    pub fn as_param(&mut self) -> OutParam<'_, T> {
+        RefParam::new(&mut self.value)
+    }
+
+    /// Extracts the value written by the managed function.
+    ///
+    /// # Safety
+    /// The managed function has to have written a value, which is always the case for `out` parameters of
+    /// functions that returned normally. For `T*` parameters of `UnmanagedCallersOnly` methods this depends on the
+    /// managed implementation.
+    #[must_use]
+    pub unsafe fn assume_init(self) -> T {
+        unsafe { self.value.assume_init() }
+    }
+}
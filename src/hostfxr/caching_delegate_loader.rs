@@ -0,0 +1,240 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use crate::{
+    hostfxr::{
+        DelegateLoader, DelegateSignature, DelegateTypeSpec, FunctionPtr, GetManagedFunctionError,
+        ManagedFunction, RawFunctionPtr,
+    },
+    pdcstring::{PdCStr, PdCString},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum DelegateTypeKey {
+    Default,
+    #[cfg(feature = "net5_0")]
+    UnmanagedCallersOnly,
+    Custom(PdCString),
+}
+
+impl From<DelegateTypeSpec<'_>> for DelegateTypeKey {
+    fn from(delegate_type: DelegateTypeSpec<'_>) -> Self {
+        match delegate_type {
+            DelegateTypeSpec::Default => Self::Default,
+            #[cfg(feature = "net5_0")]
+            DelegateTypeSpec::UnmanagedCallersOnly => Self::UnmanagedCallersOnly,
+            DelegateTypeSpec::Custom(delegate_type_name) => {
+                Self::Custom(delegate_type_name.to_owned())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    // `None` for functions resolved without loading an assembly.
+    assembly_path: Option<PdCString>,
+    type_name: PdCString,
+    method_name: PdCString,
+    delegate_type: DelegateTypeKey,
+}
+
+#[derive(Clone, Copy)]
+struct CachedFunction(RawFunctionPtr);
+
+// function pointers returned by the runtime stay valid and can be called from any thread.
+unsafe impl Send for CachedFunction {}
+unsafe impl Sync for CachedFunction {}
+
+/// A [`DelegateLoader`] that remembers the resolved managed function pointers, so that resolving the same managed
+/// method again does not call into the runtime.
+///
+/// Resolving a function pointer through the hosting components involves reflection in the runtime, which is
+/// expensive compared to calling the function. Hosts that look up the same entry points repeatedly (like a plugin
+/// host dispatching every request by name) can use this loader instead of keeping the pointers around themselves.
+///
+/// Functions are cached by assembly path, type name, method name and [`DelegateTypeSpec`]. Only successful lookups
+/// are cached. Clones of a [`CachingDelegateLoader`] share the same cache.
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{hostfxr::{CachingDelegateLoader, DelegateLoader}, pdcstr};
+/// # fn test(loader: DelegateLoader) {
+/// let loader = CachingDelegateLoader::new(loader);
+/// for _ in 0..100 {
+///     // only the first iteration calls into the runtime.
+///     let add = loader
+///         .get_function_with_unmanaged_callers_only::<fn(i32, i32) -> i32>(
+///             pdcstr!("Plugin.Calculator, Plugin"),
+///             pdcstr!("Add"),
+///         )
+///         .unwrap();
+///     add(1, 2);
+/// }
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct CachingDelegateLoader {
+    loader: DelegateLoader,
+    cache: Arc<Mutex<HashMap<CacheKey, CachedFunction>>>,
+}
+
+impl CachingDelegateLoader {
+    /// Creates a new [`CachingDelegateLoader`] with an empty cache wrapping the given [`DelegateLoader`].
+    #[must_use]
+    pub fn new(loader: DelegateLoader) -> Self {
+        Self {
+            loader,
+            cache: Arc::default(),
+        }
+    }
+
+    /// Returns the wrapped [`DelegateLoader`], which can be used to resolve functions bypassing the cache.
+    #[must_use]
+    pub const fn loader(&self) -> &DelegateLoader {
+        &self.loader
+    }
+
+    /// Consumes this loader and returns the wrapped [`DelegateLoader`].
+    #[must_use]
+    pub fn into_inner(self) -> DelegateLoader {
+        self.loader
+    }
+
+    /// Returns the number of cached function pointers.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock_cache().len()
+    }
+
+    /// Returns whether no function pointers are cached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock_cache().is_empty()
+    }
+
+    /// Removes all cached function pointers, so that the next lookups call into the runtime again.
+    pub fn clear(&self) {
+        self.lock_cache().clear();
+    }
+
+    /// Like [`DelegateLoader::load_assembly_and_get_function_with_delegate_type`], but returns the cached function
+    /// if it was resolved before. The assembly is only loaded on the first lookup.
+    pub fn load_assembly_and_get_function_with_delegate_type<F: DelegateSignature>(
+        &self,
+        assembly_path: &PdCStr,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+        delegate_type: DelegateTypeSpec<'_>,
+    ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
+        let key = CacheKey {
+            assembly_path: Some(assembly_path.to_owned()),
+            type_name: type_name.to_owned(),
+            method_name: method_name.to_owned(),
+            delegate_type: delegate_type.into(),
+        };
+        self.get_or_resolve::<F>(key, || {
+            self.loader
+                .load_assembly_and_get_function_with_delegate_type::<F>(
+                    assembly_path,
+                    type_name,
+                    method_name,
+                    delegate_type,
+                )
+        })
+    }
+
+    /// Like [`DelegateLoader::load_assembly_and_get_function_with_unmanaged_callers_only`], but returns the cached
+    /// function if it was resolved before. The assembly is only loaded on the first lookup.
+    #[cfg(feature = "net5_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+    pub fn load_assembly_and_get_function_with_unmanaged_callers_only<F: DelegateSignature>(
+        &self,
+        assembly_path: &PdCStr,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+    ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
+        self.load_assembly_and_get_function_with_delegate_type::<F>(
+            assembly_path,
+            type_name,
+            method_name,
+            DelegateTypeSpec::UnmanagedCallersOnly,
+        )
+    }
+
+    /// Like [`DelegateLoader::get_function_with_delegate_type`], but returns the cached function if it was
+    /// resolved before.
+    #[cfg(feature = "net5_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+    pub fn get_function_with_delegate_type<F: DelegateSignature>(
+        &self,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+        delegate_type: DelegateTypeSpec<'_>,
+    ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
+        let key = CacheKey {
+            assembly_path: None,
+            type_name: type_name.to_owned(),
+            method_name: method_name.to_owned(),
+            delegate_type: delegate_type.into(),
+        };
+        self.get_or_resolve::<F>(key, || {
+            self.loader
+                .get_function_with_delegate_type::<F>(type_name, method_name, delegate_type)
+        })
+    }
+
+    /// Like [`DelegateLoader::get_function_with_unmanaged_callers_only`], but returns the cached function if it was
+    /// resolved before.
+    #[cfg(feature = "net5_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+    pub fn get_function_with_unmanaged_callers_only<F: DelegateSignature>(
+        &self,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+    ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
+        self.get_function_with_delegate_type::<F>(
+            type_name,
+            method_name,
+            DelegateTypeSpec::UnmanagedCallersOnly,
+        )
+    }
+
+    fn get_or_resolve<F: FunctionPtr>(
+        &self,
+        key: CacheKey,
+        resolve: impl FnOnce() -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError>,
+    ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
+        if let Some(function) = self.lock_cache().get(&key).copied() {
+            return Ok(ManagedFunction(unsafe { F::Managed::from_ptr(function.0) }));
+        }
+
+        // the lock is not held while resolving, as the runtime may call back into the host.
+        let function = resolve()?;
+        self.lock_cache()
+            .entry(key)
+            .or_insert(CachedFunction(function.as_ptr()));
+        Ok(function)
+    }
+
+    fn lock_cache(&self) -> MutexGuard<'_, HashMap<CacheKey, CachedFunction>> {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl From<DelegateLoader> for CachingDelegateLoader {
+    fn from(loader: DelegateLoader) -> Self {
+        Self::new(loader)
+    }
+}
+
+impl fmt::Debug for CachingDelegateLoader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachingDelegateLoader")
+            .field("cached_functions", &self.len())
+            .finish_non_exhaustive()
+    }
+}
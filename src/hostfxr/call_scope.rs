@@ -0,0 +1,80 @@
+use std::{io, panic, thread};
+
+use super::worker_thread_name;
+
+/// A helper for running code calling into the runtime on a dedicated thread with a configurable stack size.
+///
+/// Managed code that recurses deeply (for example compilers or serializers), especially if it re-enters native code,
+/// can exceed the stack size of the calling thread. The default stack size for threads spawned by Rust is 2 MiB,
+/// while the main thread of a .NET application usually gets 8 MiB (1.5 MiB on Windows).
+///
+/// Threads are named `netcorehost-worker-N` unless a name is set, see [`set_thread_name_hook`](super::set_thread_name_hook).
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::hostfxr::ManagedCallScope;
+/// let result = ManagedCallScope::with_stack_size(64 * 1024 * 1024)
+///     .run(|| {
+///         // call into managed code here
+///         42
+///     })
+///     .unwrap();
+/// assert_eq!(result, 42);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ManagedCallScope {
+    stack_size: Option<usize>,
+    name: Option<String>,
+}
+
+impl ManagedCallScope {
+    /// Creates a new scope which uses the default stack size for spawned threads.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new scope which spawns threads with the given stack size in bytes.
+    #[must_use]
+    pub fn with_stack_size(bytes: usize) -> Self {
+        Self::new().stack_size(bytes)
+    }
+
+    /// Sets the stack size in bytes of the thread spawned by this scope.
+    #[must_use]
+    pub fn stack_size(mut self, bytes: usize) -> Self {
+        self.stack_size = Some(bytes);
+        self
+    }
+
+    /// Sets the name of the thread spawned by this scope.
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Runs the given closure on a newly spawned thread and blocks until it has completed.
+    /// As the thread is scoped, the closure can borrow from the calling stack frame.
+    ///
+    /// If the closure panics, the panic is propagated to the caller.
+    pub fn run<F, R>(&self, f: F) -> io::Result<R>
+    where
+        F: FnOnce() -> R + Send,
+        R: Send,
+    {
+        let name = self.name.clone().unwrap_or_else(worker_thread_name);
+        let mut builder = thread::Builder::new().name(name);
+        if let Some(stack_size) = self.stack_size {
+            builder = builder.stack_size(stack_size);
+        }
+
+        thread::scope(|scope| {
+            let handle = builder.spawn_scoped(scope, f)?;
+            match handle.join() {
+                Ok(result) => Ok(result),
+                Err(payload) => panic::resume_unwind(payload),
+            }
+        })
+    }
+}
@@ -0,0 +1,227 @@
+use std::{
+    fmt,
+    ops::Deref,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::hostfxr::{FfiSafe, ManagedFunctionPtr, RawFunctionPtr};
+
+/// A Rust function that can be passed to and called from managed code, created using [`callback!`](crate::callback).
+///
+/// The function can be called from Rust like a regular function through [`Deref`].
+pub struct Callback<F: ManagedFunctionPtr> {
+    function: F,
+    signature: fn() -> String,
+}
+
+impl<F: ManagedFunctionPtr> Callback<F> {
+    #[doc(hidden)]
+    pub const fn __new(function: F, signature: fn() -> String) -> Self {
+        Self {
+            function,
+            signature,
+        }
+    }
+
+    /// Returns the `extern "system"` function pointer.
+    #[must_use]
+    pub const fn function(&self) -> F {
+        self.function
+    }
+
+    /// Returns an untyped pointer to the function, which can be passed to a managed method taking an `IntPtr`.
+    #[must_use]
+    pub fn as_ptr(&self) -> RawFunctionPtr {
+        self.function.as_ptr()
+    }
+
+    /// Returns the C# function pointer type matching the signature of the function,
+    /// e.g. `delegate* unmanaged<int, nint, void>`.
+    ///
+    /// The parameter and return types are mapped using [`FfiSafe::csharp_type`].
+    #[must_use]
+    pub fn csharp_signature(&self) -> String {
+        (self.signature)()
+    }
+}
+
+impl<F: ManagedFunctionPtr> Deref for Callback<F> {
+    type Target = F;
+
+    fn deref(&self) -> &Self::Target {
+        &self.function
+    }
+}
+
+impl<F: ManagedFunctionPtr> Clone for Callback<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: ManagedFunctionPtr> Copy for Callback<F> {}
+
+impl<F: ManagedFunctionPtr> fmt::Debug for Callback<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Callback")
+            .field("function", &self.as_ptr())
+            .field("signature", &self.csharp_signature())
+            .finish()
+    }
+}
+
+#[doc(hidden)]
+#[must_use]
+pub fn __csharp_function_pointer_type(types: &[String]) -> String {
+    format!("delegate* unmanaged<{}>", types.join(", "))
+}
+
+#[doc(hidden)]
+#[must_use]
+pub fn __csharp_type<T: FfiSafe>() -> String {
+    T::csharp_type()
+}
+
+/// Calls the given closure, returning `error_code` instead of unwinding if it panics.
+///
+/// This is meant for the body of Rust functions called by the runtime through function pointers, as unwinding across
+/// the FFI boundary into managed code is undefined behavior. The panic is reported to stderr. Functions defined using
+/// [`callback!`](crate::callback) already do this.
+///
+/// The closure is treated as unwind safe, so it is up to the caller to make sure that no broken invariants are
+/// observed after a panic.
+///
+/// # Example
+/// ```rust
+/// # use netcorehost::hostfxr::catch_panic_to_error_code;
+/// extern "system" fn parse(value: i32) -> i32 {
+///     catch_panic_to_error_code(-1, || {
+///         assert!(value >= 0, "negative value");
+///         value * 2
+///     })
+/// }
+///
+/// assert_eq!(parse(21), 42);
+/// assert_eq!(parse(-1), -1);
+/// ```
+pub fn catch_panic_to_error_code<R>(error_code: R, f: impl FnOnce() -> R) -> R {
+    catch_panic(None, f, || error_code)
+}
+
+/// Calls the given closure, aborting the process instead of unwinding if it panics.
+///
+/// This is meant for Rust functions called by the runtime through function pointers that have no way to report an
+/// error to managed code, as unwinding across the FFI boundary into managed code is undefined behavior. The panic is
+/// reported to stderr before aborting.
+pub fn abort_on_panic<R>(f: impl FnOnce() -> R) -> R {
+    catch_panic(None, f, || std::process::abort())
+}
+
+#[doc(hidden)]
+pub fn __catch_callback_panic<R>(
+    name: &str,
+    f: impl FnOnce() -> R,
+    on_panic: impl FnOnce() -> R,
+) -> R {
+    catch_panic(Some(name), f, on_panic)
+}
+
+fn catch_panic<R>(name: Option<&str>, f: impl FnOnce() -> R, on_panic: impl FnOnce() -> R) -> R {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => value,
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("Box<dyn Any>");
+            let name = name.map(|name| format!(" `{name}`")).unwrap_or_default();
+            eprintln!(
+                "netcorehost: callback{name} panicked while called from managed code: {message}"
+            );
+            on_panic()
+        }
+    }
+}
+
+#[macro_export]
+/// A macro for defining Rust functions that can be called from managed code.
+///
+/// The function is wrapped in an `extern "system"` shim, which is the calling convention of `delegate* unmanaged`
+/// function pointers and delegates marshalled by the runtime. Parameter and return types have to be
+/// [`FfiSafe`](crate::hostfxr::FfiSafe). Strings can be received as a pointer and length and read using
+/// [`utf8_from_parts`](crate::hostfxr::utf8_from_parts).
+///
+/// Unwinding into managed code is undefined behavior, so panics are caught, reported to stderr and the function
+/// returns a fallback value instead (see [`catch_panic_to_error_code`](crate::hostfxr::catch_panic_to_error_code)). The fallback value is [`Default::default`] unless it is given in an `on_panic`
+/// block after the function body.
+///
+/// The macro defines a constant [`Callback`](crate::hostfxr::Callback) with the name of the function, which provides
+/// the function pointer to pass to managed code and the matching C# function pointer type through
+/// [`csharp_signature`](crate::hostfxr::Callback::csharp_signature).
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{callback, hostfxr::{utf8_from_parts, AssemblyDelegateLoader}, pdcstr};
+/// callback! {
+///     /// Logs a message from managed code.
+///     fn log(level: i32, message: *const u8, length: i32) -> i32 {
+///         let message = unsafe { utf8_from_parts(message, length) }.unwrap();
+///         println!("[{level}] {message}");
+///         0
+///     } on_panic {
+///         -1
+///     }
+/// }
+///
+/// # fn test(fn_loader: AssemblyDelegateLoader) {
+/// assert_eq!(log.csharp_signature(), "delegate* unmanaged<int, nint, int, int>");
+///
+/// // [UnmanagedCallersOnly]
+/// // public static void SetLogger(IntPtr logger) =>
+/// //     Logger.Callback = (delegate* unmanaged<int, byte*, int, int>)logger;
+/// let set_logger = fn_loader
+///     .get_function_with_unmanaged_callers_only::<fn(*const ())>(
+///         pdcstr!("Plugin.Logger, Plugin"),
+///         pdcstr!("SetLogger"),
+///     )
+///     .unwrap();
+/// set_logger(log.as_ptr().cast());
+/// # }
+/// ```
+macro_rules! callback {
+    (@ret) => { () };
+    (@ret $ret:ty) => { $ret };
+
+    (@on_panic) => { ::core::default::Default::default() };
+    (@on_panic $on_panic:block) => { $on_panic };
+
+    (
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident($($arg:ident : $arg_ty:ty),* $(,)?) $(-> $ret:ty)? $body:block
+            $(on_panic $on_panic:block)?
+    ) => {
+        $(#[$meta])*
+        #[allow(non_upper_case_globals)]
+        $vis const $name: $crate::hostfxr::Callback<
+            extern "system" fn($($arg_ty),*) -> $crate::callback!(@ret $($ret)?),
+        > = {
+            extern "system" fn $name($($arg: $arg_ty),*) -> $crate::callback!(@ret $($ret)?) {
+                $crate::hostfxr::__catch_callback_panic(
+                    ::core::stringify!($name),
+                    move || -> $crate::callback!(@ret $($ret)?) { $body },
+                    || $crate::callback!(@on_panic $($on_panic)?),
+                )
+            }
+
+            fn signature() -> ::std::string::String {
+                $crate::hostfxr::__csharp_function_pointer_type(&[
+                    $($crate::hostfxr::__csharp_type::<$arg_ty>(),)*
+                    $crate::hostfxr::__csharp_type::<$crate::callback!(@ret $($ret)?)>(),
+                ])
+            }
+
+            $crate::hostfxr::Callback::__new($name, signature)
+        };
+    };
+}
@@ -0,0 +1,210 @@
+use std::{
+    collections::HashSet,
+    ffi::c_void,
+    fmt, mem,
+    ptr::NonNull,
+    sync::{Mutex, PoisonError},
+};
+
+use crate::hostfxr::{abort_on_panic, FfiSafe, FunctionPtr, ManagedFunctionPtr};
+
+// contexts passed to `CallbackHandle::keep_alive`, which are released by `release_callback`.
+static KEPT_ALIVE: Mutex<Option<HashSet<usize>>> = Mutex::new(None);
+
+fn with_kept_alive<R>(f: impl FnOnce(&mut HashSet<usize>) -> R) -> R {
+    let mut kept_alive = KEPT_ALIVE.lock().unwrap_or_else(PoisonError::into_inner);
+    f(kept_alive.get_or_insert_with(HashSet::default))
+}
+
+// the drop function comes first, so that a context can be released without knowing the type of the closure.
+// this also makes sure that contexts of zero sized closures have distinct addresses.
+#[repr(C)]
+struct CallbackContext<Func> {
+    drop: unsafe fn(*mut c_void),
+    closure: Func,
+}
+
+unsafe fn drop_context<Func>(context: *mut c_void) {
+    drop(unsafe { Box::from_raw(context.cast::<CallbackContext<Func>>()) });
+}
+
+unsafe fn release_context(context: NonNull<c_void>) {
+    unsafe {
+        let drop = context.cast::<unsafe fn(*mut c_void)>().read();
+        drop(context.as_ptr());
+    }
+}
+
+/// A closure that can be turned into a [`CallbackHandle`].
+///
+/// This is implemented for all `Fn` closures that are [`Send`], [`Sync`] and `'static` with up to 8 parameters whose
+/// parameter and return types are [`FfiSafe`]. The trampoline takes the context pointer as its first parameter,
+/// followed by the parameters of the closure. It is unsafe to call, as the context has to point to a live closure of
+/// the matching type.
+///
+/// # Safety
+/// The trampoline has to call the closure stored in the context it is passed.
+pub unsafe trait CallbackClosure<Args>: Send + Sync + Sized + 'static {
+    /// The type of the `unsafe extern "system"` trampoline calling the closure.
+    type Trampoline: ManagedFunctionPtr;
+
+    /// Returns the trampoline calling a closure of this type.
+    fn trampoline() -> Self::Trampoline;
+}
+
+macro_rules! impl_callback_closure {
+    ($($arg:ident : $ty:ident),*) => {
+        unsafe impl<Func, Ret, $($ty),*> CallbackClosure<($($ty,)*)> for Func
+        where
+            Func: Fn($($ty),*) -> Ret + Send + Sync + 'static,
+            Ret: FfiSafe + 'static,
+            $($ty: FfiSafe + 'static,)*
+        {
+            type Trampoline = unsafe extern "system" fn(*mut c_void $(, $ty)*) -> Ret;
+
+            fn trampoline() -> Self::Trampoline {
+                unsafe extern "system" fn trampoline<Func, Ret, $($ty),*>(context: *mut c_void $(, $arg: $ty)*) -> Ret
+                where
+                    Func: Fn($($ty),*) -> Ret,
+                {
+                    let context = unsafe { &*context.cast::<CallbackContext<Func>>() };
+                    abort_on_panic(|| (context.closure)($($arg),*))
+                }
+                trampoline::<Func, Ret, $($ty),*>
+            }
+        }
+    };
+}
+
+impl_callback_closure!();
+impl_callback_closure!(a: A);
+impl_callback_closure!(a: A, b: B);
+impl_callback_closure!(a: A, b: B, c: C);
+impl_callback_closure!(a: A, b: B, c: C, d: D);
+impl_callback_closure!(a: A, b: B, c: C, d: D, e: E);
+impl_callback_closure!(a: A, b: B, c: C, d: D, e: E, f: F);
+impl_callback_closure!(a: A, b: B, c: C, d: D, e: E, f: F, g: G);
+impl_callback_closure!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H);
+
+/// A boxed Rust closure that can be passed to managed code as a trampoline function pointer and a context pointer.
+///
+/// Managed code calls the [`trampoline`](CallbackHandle::trampoline) with the [`context`](CallbackHandle::context)
+/// as the first argument, followed by the arguments of the closure. The closure is dropped together with the handle,
+/// so the handle has to outlive all calls from managed code. If managed code decides when the closure is no longer
+/// needed (e.g. when it frees the `GCHandle` of the object using it), ownership can be handed over using
+/// [`keep_alive`](CallbackHandle::keep_alive) instead.
+///
+/// Panics in the closure abort the process, as unwinding into managed code is undefined behavior.
+///
+/// # Example
+/// ```rust,no_run
+/// # use std::ffi::c_void;
+/// # use netcorehost::{hostfxr::{release_callback, AssemblyDelegateLoader, CallbackHandle}, pdcstr};
+/// # fn test(fn_loader: AssemblyDelegateLoader) {
+/// // [UnmanagedCallersOnly]
+/// // public static void Subscribe(
+/// //     delegate* unmanaged<IntPtr, int, void> callback,
+/// //     IntPtr context,
+/// //     delegate* unmanaged<IntPtr, void> release) { ... }
+/// let subscribe = fn_loader
+///     .get_function_with_unmanaged_callers_only::<fn(
+///         unsafe extern "system" fn(*mut c_void, i32),
+///         *mut c_void,
+///         extern "system" fn(*mut c_void),
+///     )>(pdcstr!("Plugin.Events, Plugin"), pdcstr!("Subscribe"))
+///     .unwrap();
+///
+/// let prefix = String::from("event");
+/// let handle = CallbackHandle::new(move |id: i32| println!("{prefix} {id}"));
+/// let trampoline = handle.trampoline();
+/// // the closure stays alive until managed code calls `release_callback`.
+/// subscribe(trampoline, handle.keep_alive(), release_callback);
+/// # }
+/// ```
+pub struct CallbackHandle<T: ManagedFunctionPtr> {
+    context: NonNull<c_void>,
+    trampoline: T,
+}
+
+// the closure is `Send` and `Sync`.
+unsafe impl<T: ManagedFunctionPtr> Send for CallbackHandle<T> {}
+unsafe impl<T: ManagedFunctionPtr> Sync for CallbackHandle<T> {}
+
+impl<T: ManagedFunctionPtr> CallbackHandle<T> {
+    /// Boxes the given closure.
+    pub fn new<Func, Args>(closure: Func) -> Self
+    where
+        Func: CallbackClosure<Args, Trampoline = T>,
+    {
+        let context = Box::new(CallbackContext {
+            drop: drop_context::<Func>,
+            closure,
+        });
+        Self {
+            context: NonNull::from(Box::leak(context)).cast(),
+            trampoline: Func::trampoline(),
+        }
+    }
+
+    /// Returns the trampoline calling the closure, which takes the [`context`](CallbackHandle::context) as its first
+    /// argument.
+    ///
+    /// Calling the trampoline is only safe with the context of this handle while the closure is alive, i.e. until
+    /// the handle is dropped or the context passed to [`release_callback`].
+    #[must_use]
+    pub fn trampoline(&self) -> T {
+        self.trampoline
+    }
+
+    /// Returns the context pointer that has to be passed to the [`trampoline`](CallbackHandle::trampoline).
+    #[must_use]
+    pub fn context(&self) -> *mut c_void {
+        self.context.as_ptr()
+    }
+
+    /// Hands the ownership of the closure over to managed code and returns the context pointer.
+    ///
+    /// The closure stays alive until [`release_callback`] is called with the returned context, which is usually done
+    /// by managed code once it no longer needs the callback. The trampoline has to be retrieved before calling this.
+    #[must_use = "the closure is leaked if the context is not released"]
+    pub fn keep_alive(self) -> *mut c_void {
+        let context = self.context();
+        with_kept_alive(|kept_alive| kept_alive.insert(context as usize));
+        mem::forget(self);
+        context
+    }
+}
+
+impl<T: ManagedFunctionPtr> Drop for CallbackHandle<T> {
+    fn drop(&mut self) {
+        unsafe { release_context(self.context) };
+    }
+}
+
+impl<T: ManagedFunctionPtr> fmt::Debug for CallbackHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CallbackHandle")
+            .field("context", &self.context)
+            .field("trampoline", &self.trampoline.as_ptr())
+            .finish()
+    }
+}
+
+/// Releases a closure kept alive using [`CallbackHandle::keep_alive`].
+///
+/// This function can be passed to managed code as `delegate* unmanaged<IntPtr, void>`. Contexts that are not kept
+/// alive or have already been released are ignored.
+pub extern "system" fn release_callback(context: *mut c_void) {
+    if !with_kept_alive(|kept_alive| kept_alive.remove(&(context as usize))) {
+        return;
+    }
+    if let Some(context) = NonNull::new(context) {
+        abort_on_panic(|| unsafe { release_context(context) });
+    }
+}
+
+/// Returns the number of closures kept alive using [`CallbackHandle::keep_alive`] that have not been released yet.
+#[must_use]
+pub fn kept_alive_callbacks() -> usize {
+    with_kept_alive(|kept_alive| kept_alive.len())
+}
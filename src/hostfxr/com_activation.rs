@@ -0,0 +1,192 @@
+use std::{
+    ffi::c_void,
+    fmt::{self, Debug, Display},
+    mem, ptr,
+};
+
+use thiserror::Error;
+
+use crate::{
+    bindings::{char_t, hostfxr::hostfxr_delegate_type},
+    error::HostingError,
+    hostfxr::{HostfxrContext, InitializedForRuntimeConfig, SharedHostfxrLibrary},
+    pdcstring::PdCStr,
+};
+
+/// A COM globally unique identifier (like a `CLSID` or `IID`) with the memory layout of the windows `GUID` struct.
+#[repr(C)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Guid {
+    /// The first 4 bytes of the identifier.
+    pub data1: u32,
+    /// The next 2 bytes of the identifier.
+    pub data2: u16,
+    /// The next 2 bytes of the identifier.
+    pub data3: u16,
+    /// The remaining 8 bytes of the identifier.
+    pub data4: [u8; 8],
+}
+
+impl Guid {
+    /// Creates a [`Guid`] from its 128-bit integer representation,
+    /// e.g. `Guid::from_u128(0x00000001_0000_0000_c000_000000000046)` for `IClassFactory`.
+    #[must_use]
+    pub const fn from_u128(value: u128) -> Self {
+        let bytes = value.to_be_bytes();
+        Self {
+            data1: (value >> 96) as u32,
+            data2: (value >> 80) as u16,
+            data3: (value >> 64) as u16,
+            data4: [
+                bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14],
+                bytes[15],
+            ],
+        }
+    }
+
+    /// Returns the 128-bit integer representation of this identifier.
+    #[must_use]
+    pub const fn to_u128(&self) -> u128 {
+        ((self.data1 as u128) << 96)
+            | ((self.data2 as u128) << 80)
+            | ((self.data3 as u128) << 64)
+            | u64::from_be_bytes(self.data4) as u128
+    }
+}
+
+impl Display for Guid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:08x}-{:04x}-{:04x}-{:02x}{:02x}-",
+            self.data1, self.data2, self.data3, self.data4[0], self.data4[1]
+        )?;
+        for byte in &self.data4[2..] {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for Guid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{{self}}}")
+    }
+}
+
+/// The raw argument of the [`hdt_com_activation`](hostfxr_delegate_type::hdt_com_activation) delegate
+/// (`com_activation_context` in `comhost`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ComActivationContext {
+    /// The `CLSID` of the class to activate.
+    pub class_id: Guid,
+    /// The `IID` of the interface requested from the class factory, usually `IClassFactory`.
+    pub interface_id: Guid,
+    /// The path of the assembly containing the class.
+    pub assembly_path: *const char_t,
+    /// The display name of the assembly containing the class.
+    pub assembly_name: *const char_t,
+    /// The assembly qualified name of the class.
+    pub type_name: *const char_t,
+    /// Pointer to the location receiving the class factory.
+    pub class_factory_dest: *mut *mut c_void,
+}
+
+/// The raw signature of the [`hdt_com_activation`](hostfxr_delegate_type::hdt_com_activation) delegate.
+/// Returns a `HRESULT`.
+pub type ComActivationFn = unsafe extern "system" fn(context: *mut ComActivationContext) -> i32;
+
+/// A typed wrapper around the [`hdt_com_activation`](hostfxr_delegate_type::hdt_com_activation) delegate,
+/// which hands out class factories for managed COM classes.
+///
+/// This is the building block for a COM server (like `comhost`) implemented in rust:
+/// the `DllGetClassObject` export can forward to [`ComActivationDelegate::get_class_factory`].
+#[derive(Clone)]
+pub struct ComActivationDelegate {
+    activate: ComActivationFn,
+    #[allow(unused)]
+    hostfxr: SharedHostfxrLibrary,
+}
+
+impl Debug for ComActivationDelegate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ComActivationDelegate")
+            .field("activate", &(self.activate as *const ()))
+            .finish_non_exhaustive()
+    }
+}
+
+impl ComActivationDelegate {
+    /// Returns the raw function pointer of the delegate.
+    #[must_use]
+    pub fn as_raw(&self) -> ComActivationFn {
+        self.activate
+    }
+
+    /// Calls the delegate with the given raw context.
+    ///
+    /// # Safety
+    /// All pointers in `context` have to be valid for the duration of the call.
+    pub unsafe fn activate_raw(&self, context: &mut ComActivationContext) -> i32 {
+        unsafe { (self.activate)(context) }
+    }
+
+    /// Gets the class factory for the managed class `type_name` (assembly qualified) with the given `CLSID`
+    /// from the assembly at `assembly_path`, queried for the interface `interface_id`.
+    ///
+    /// The returned pointer is an owned COM interface pointer of type `interface_id`, the caller is responsible
+    /// for releasing it.
+    ///
+    /// # Note
+    /// The class has to be visible to COM, e.g. by being marked with `[ComVisible(true)]` and `[Guid(...)]`.
+    pub fn get_class_factory(
+        &self,
+        class_id: &Guid,
+        interface_id: &Guid,
+        assembly_path: &PdCStr,
+        assembly_name: &PdCStr,
+        type_name: &PdCStr,
+    ) -> Result<*mut c_void, ComActivationError> {
+        let mut class_factory = ptr::null_mut();
+        let mut context = ComActivationContext {
+            class_id: *class_id,
+            interface_id: *interface_id,
+            assembly_path: assembly_path.as_ptr(),
+            assembly_name: assembly_name.as_ptr(),
+            type_name: type_name.as_ptr(),
+            class_factory_dest: &mut class_factory,
+        };
+        let hresult = unsafe { self.activate_raw(&mut context) };
+        if hresult < 0 {
+            return Err(ComActivationError(hresult));
+        }
+        Ok(class_factory)
+    }
+}
+
+/// An error returned by the managed COM activator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Error)]
+#[error("COM activation failed with HRESULT {:#010X}.", .0)]
+pub struct ComActivationError(pub i32);
+
+impl ComActivationError {
+    /// Returns the `HRESULT` returned by the managed COM activator.
+    #[must_use]
+    pub const fn hresult(&self) -> i32 {
+        self.0
+    }
+}
+
+impl HostfxrContext<InitializedForRuntimeConfig> {
+    /// Gets the delegate for activating managed COM classes
+    /// ([`hdt_com_activation`](hostfxr_delegate_type::hdt_com_activation)).
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(all(windows, feature = "netcore3_0"))))]
+    pub fn get_com_activation_delegate(&self) -> Result<ComActivationDelegate, HostingError> {
+        let activate = self.get_runtime_delegate(hostfxr_delegate_type::hdt_com_activation)?;
+        Ok(ComActivationDelegate {
+            activate: unsafe { mem::transmute::<_, ComActivationFn>(activate) },
+            hostfxr: self.library().clone(),
+        })
+    }
+}
@@ -0,0 +1,179 @@
+use std::{
+    ffi::c_void,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    slice,
+};
+
+use thiserror::Error;
+
+use crate::{
+    hostfxr::{AssemblyDelegateLoader, GetManagedFunctionError, ManagedFunction},
+    pdcstr,
+    pdcstring::PdCStr,
+};
+
+type ConsoleCallback = dyn Fn(&ConsoleLine<'_>) + Send + Sync;
+
+struct InterceptorState {
+    source: String,
+    callback: Box<ConsoleCallback>,
+}
+
+/// The console stream a [`ConsoleLine`] was written to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConsoleStream {
+    /// `Console.Out`
+    Out,
+    /// `Console.Error`
+    Error,
+}
+
+/// A line written to the console by managed code while a [`ConsoleInterception`] is installed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ConsoleLine<'a> {
+    /// The stream the line was written to.
+    pub stream: ConsoleStream,
+    /// The source passed to [`ConsoleInterception::install`], like the name of the plugin.
+    pub source: &'a str,
+    /// The managed thread id (`Environment.CurrentManagedThreadId`) of the writing thread.
+    pub managed_thread_id: i32,
+    /// The text of the line without the line terminator.
+    /// Invalid UTF-8 is replaced with [`U+FFFD REPLACEMENT CHARACTER`](char::REPLACEMENT_CHARACTER).
+    pub text: &'a str,
+}
+
+extern "system" fn console_callback(
+    state: *const c_void,
+    stream: i32,
+    managed_thread_id: i32,
+    text: *const u8,
+    length: i32,
+) {
+    let state = unsafe { &*state.cast::<InterceptorState>() };
+    let bytes = match usize::try_from(length) {
+        Ok(length) if length > 0 && !text.is_null() => unsafe {
+            slice::from_raw_parts(text, length)
+        },
+        _ => &[],
+    };
+    let text = String::from_utf8_lossy(bytes);
+    let line = ConsoleLine {
+        stream: if stream == 2 {
+            ConsoleStream::Error
+        } else {
+            ConsoleStream::Out
+        },
+        source: &state.source,
+        managed_thread_id,
+        text: &text,
+    };
+    // unwinding into managed code is undefined behavior.
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| (state.callback)(&line)));
+}
+
+/// Redirects `Console.Out` and `Console.Error` of the runtime to a callback, line by line.
+///
+/// This can be used to turn the console output of hosted plugins into structured logs of the host.
+/// The managed side is implemented by [`MANAGED_CONSOLE_INTERCEPTOR`], which has to be compiled into an assembly
+/// loaded by the runtime. As the console is shared by the whole runtime, only one interception can be installed
+/// at a time. The previous console writers are restored when the interception is dropped, after which the callback
+/// is no longer called.
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{hostfxr::{AssemblyDelegateLoader, ConsoleInterception}, pdcstr};
+/// # fn test(fn_loader: AssemblyDelegateLoader) {
+/// let _interception = ConsoleInterception::install(
+///     &fn_loader,
+///     pdcstr!("NativeConsole, MyPlugin"),
+///     "my-plugin",
+///     |line| println!("[{}#{}] {}", line.source, line.managed_thread_id, line.text),
+/// )
+/// .unwrap();
+/// # }
+/// ```
+///
+/// [`MANAGED_CONSOLE_INTERCEPTOR`]: super::MANAGED_CONSOLE_INTERCEPTOR
+pub struct ConsoleInterception {
+    state: *mut InterceptorState,
+    uninstall: ManagedFunction<extern "system" fn()>,
+}
+
+unsafe impl Send for ConsoleInterception {}
+unsafe impl Sync for ConsoleInterception {}
+
+impl ConsoleInterception {
+    /// Installs the interception using the `NativeConsole` class with the given assembly qualified type name,
+    /// calling `callback` for every line written to the console.
+    ///
+    /// The callback is called from the managed thread that wrote the line, calls are serialized.
+    /// Panics in the callback are caught and ignored.
+    pub fn install(
+        loader: &AssemblyDelegateLoader,
+        type_name: &PdCStr,
+        source: impl Into<String>,
+        callback: impl Fn(&ConsoleLine<'_>) + Send + Sync + 'static,
+    ) -> Result<Self, ConsoleInterceptionError> {
+        let install = loader
+            .get_function_with_unmanaged_callers_only::<fn(*const c_void, *const c_void) -> i32>(
+                type_name,
+                pdcstr!("Install"),
+            )?;
+        let uninstall = loader
+            .get_function_with_unmanaged_callers_only::<fn()>(type_name, pdcstr!("Uninstall"))?;
+
+        let state = Box::into_raw(Box::new(InterceptorState {
+            source: source.into(),
+            callback: Box::new(callback),
+        }));
+        let callback: extern "system" fn(*const c_void, i32, i32, *const u8, i32) =
+            console_callback;
+        if install(callback as *const c_void, state.cast_const().cast()) != 0 {
+            drop(unsafe { Box::from_raw(state) });
+            return Err(ConsoleInterceptionError::AlreadyInstalled);
+        }
+
+        Ok(Self { state, uninstall })
+    }
+
+    /// Returns the source passed to [`install`](ConsoleInterception::install).
+    #[must_use]
+    pub fn source(&self) -> &str {
+        unsafe { &(*self.state).source }
+    }
+}
+
+impl fmt::Debug for ConsoleInterception {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConsoleInterception")
+            .field("source", &self.source())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for ConsoleInterception {
+    fn drop(&mut self) {
+        if super::is_process_exiting() {
+            // the runtime may already be shut down, the state is leaked as the callback may still be called.
+            return;
+        }
+        // the callback is not called anymore once uninstall returns.
+        (self.uninstall)();
+        drop(unsafe { Box::from_raw(self.state) });
+    }
+}
+
+/// Enum for errors that can occur while installing a [`ConsoleInterception`].
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+#[non_exhaustive]
+pub enum ConsoleInterceptionError {
+    /// The managed `Install` or `Uninstall` method could not be loaded.
+    #[error(transparent)]
+    GetFunction(#[from] GetManagedFunctionError),
+    /// Another interception is already installed.
+    #[error("Another console interception is already installed.")]
+    AlreadyInstalled,
+}
@@ -4,8 +4,11 @@ use crate::{
     },
     error::{HostingError, HostingResult, HostingSuccess},
     hostfxr::{
-        AppOrHostingResult, AssemblyDelegateLoader, DelegateLoader, Hostfxr, HostfxrLibrary,
-        RawFunctionPtr, SharedHostfxrLibrary,
+        diagnostics,
+        strict_checks::{self, LoaderOrigin},
+        AppOrHostingResult, AssemblyDelegateLoader, DelegateLoader, ErrorMessageCapture,
+        ErrorModeGuard, Hostfxr, HostfxrLibrary, ManagedCallScope, RawFunctionPtr,
+        SharedHostfxrLibrary,
     },
     pdcstring::PdCString,
 };
@@ -15,6 +18,7 @@ use crate::bindings::hostfxr::get_function_pointer_fn;
 #[cfg(feature = "net8_0")]
 use crate::{
     bindings::hostfxr::{load_assembly_bytes_fn, load_assembly_fn},
+    hostfxr::{delegate_loader, EmbeddedAssembly},
     pdcstring::PdCStr,
 };
 
@@ -22,17 +26,16 @@ use std::{
     cell::Cell,
     ffi::c_void,
     fmt::{self, Debug},
+    io,
     marker::PhantomData,
     mem::{self, ManuallyDrop, MaybeUninit},
     ptr::NonNull,
+    sync::{Arc, Mutex, PoisonError},
 };
 
-#[cfg(feature = "net8_0")]
-use std::ptr;
-
 use destruct_drop::DestructDrop;
 use enum_map::EnumMap;
-use once_cell::unsync::OnceCell;
+use once_cell::sync::OnceCell;
 
 /// A marker struct indicating that the context was initialized with a runtime config.
 /// This means that it is not possible to run the application associated with the context.
@@ -46,6 +49,41 @@ pub struct InitializedForRuntimeConfig;
 #[derive(Debug, Clone, Copy)]
 pub struct InitializedForCommandLine;
 
+/// A marker struct indicating that it is not known how the context was initialized,
+/// for example because its handle was received from another host through [`HostfxrContext::from_handle`].
+/// Such a context supports everything but running the application.
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+#[derive(Debug, Clone, Copy)]
+pub struct InitializedForUnknown;
+
+/// Whether a [`HostfxrContext`] is the primary context or a secondary context, see
+/// [`HostfxrContext::initialization_kind`].
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InitializationKind {
+    /// The context is the first one in the process and loads the runtime.
+    Primary,
+    /// The context reuses the runtime loaded by another context and is compatible with it.
+    Secondary,
+    /// The context reuses the runtime loaded by another context, but requested runtime properties that differ from
+    /// the ones of the running runtime, see [`HostfxrContext::property_conflicts`].
+    SecondaryWithDifferentProperties,
+}
+
+impl InitializationKind {
+    /// Returns whether the context is the primary context.
+    #[must_use]
+    pub const fn is_primary(&self) -> bool {
+        matches!(self, Self::Primary)
+    }
+
+    /// Returns whether the context is a secondary context.
+    #[must_use]
+    pub const fn is_secondary(&self) -> bool {
+        !self.is_primary()
+    }
+}
+
 /// Handle of a loaded [`HostfxrContext`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(transparent)]
@@ -83,7 +121,9 @@ pub struct HostfxrContext<I> {
     handle: HostfxrHandle,
     hostfxr: SharedHostfxrLibrary,
     is_primary: bool,
-    runtime_delegates: EnumMap<hostfxr_delegate_type, OnceCell<RawFunctionPtr>>,
+    initialization_success: Option<HostingSuccess>,
+    capture_error_messages: bool,
+    runtime_delegates: Arc<RuntimeDelegates>,
     context_type: PhantomData<I>,
     not_sync: PhantomData<Cell<HostfxrLibrary>>,
 }
@@ -95,12 +135,129 @@ impl<I> Debug for HostfxrContext<I> {
         f.debug_struct("HostfxrContext")
             .field("handle", &self.handle)
             .field("is_primary", &self.is_primary)
+            .field("initialization_success", &self.initialization_success)
+            .field("context_type", &diagnostics::context_type_name::<I>())
+            .field(
+                "runtime_version",
+                &diagnostics::runtime_property(self, crate::pdcstr!("FX_PRODUCT_VERSION")),
+            )
+            .field(
+                "runtime_identifier",
+                &diagnostics::runtime_property(self, crate::pdcstr!("RUNTIME_IDENTIFIER")),
+            )
             .field("runtime_delegates", &self.runtime_delegates)
-            .field("context_type", &self.context_type)
             .finish_non_exhaustive()
     }
 }
 
+/// The runtime delegates of a context, which are shared with its [`DelegateLoader`]s so that delegates only used by
+/// some loaders are resolved on their first use.
+pub(crate) struct RuntimeDelegates {
+    hostfxr: SharedHostfxrLibrary,
+    // cleared once the context is closed or released, after which only cached delegates are available.
+    // the lock is held while resolving a delegate, so the context cannot be closed concurrently.
+    handle: Mutex<Option<HostfxrHandle>>,
+    suppress_error_dialogs: bool,
+    capture_error_messages: bool,
+    // failures are cached as well, as delegate types unsupported by the runtime would otherwise be queried
+    // on every call to `get_delegate_loader`.
+    cache: EnumMap<hostfxr_delegate_type, OnceCell<Result<RawFunctionPtr, HostingError>>>,
+}
+
+// the handle is only used while holding the lock and the cached delegates are plain function pointers.
+unsafe impl Send for RuntimeDelegates {}
+unsafe impl Sync for RuntimeDelegates {}
+
+impl Debug for RuntimeDelegates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.cache, f)
+    }
+}
+
+impl RuntimeDelegates {
+    fn new(handle: HostfxrHandle, hostfxr: &Hostfxr) -> Self {
+        Self {
+            hostfxr: hostfxr.lib.clone(),
+            handle: Mutex::new(Some(handle)),
+            suppress_error_dialogs: hostfxr.suppress_error_dialogs,
+            capture_error_messages: hostfxr.capture_error_messages,
+            cache: EnumMap::default(),
+        }
+    }
+
+    /// Returns the delegate of the given type, resolving it using the context if it has not been resolved yet.
+    ///
+    /// Fails with [`HostingError::HostInvalidState`] if the delegate has not been resolved before the context was
+    /// closed.
+    pub(crate) fn get(
+        &self,
+        r#type: hostfxr_delegate_type,
+    ) -> Result<RawFunctionPtr, HostingError> {
+        if let Some(delegate) = self.cache[r#type].get() {
+            return *delegate;
+        }
+
+        let handle = self.handle.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(handle) = *handle else {
+            return self.cache[r#type]
+                .get()
+                .copied()
+                .unwrap_or(Err(HostingError::HostInvalidState));
+        };
+        *self.cache[r#type].get_or_init(|| self.resolve(handle, r#type))
+    }
+
+    fn resolve(
+        &self,
+        handle: HostfxrHandle,
+        r#type: hostfxr_delegate_type,
+    ) -> Result<RawFunctionPtr, HostingError> {
+        strict_checks::check_open(handle, "getting a runtime delegate");
+        let mut delegate = MaybeUninit::uninit();
+        // Retrieving the first delegate loads the runtime and its native dependencies.
+        let _error_mode = ErrorModeGuard::suppress_dialogs_if(self.suppress_error_dialogs);
+        let capture = ErrorMessageCapture::start_if(&self.hostfxr, self.capture_error_messages);
+        let result = unsafe {
+            self.hostfxr.hostfxr_get_runtime_delegate(
+                handle.as_raw(),
+                r#type,
+                delegate.as_mut_ptr(),
+            )
+        }
+        .unwrap();
+        ErrorMessageCapture::finish(capture, result);
+
+        HostingResult::from(result).into_result()?;
+        super::mark_runtime_started();
+
+        Ok(unsafe { delegate.assume_init() }.cast())
+    }
+
+    /// Prevents resolving further delegates, waiting for delegates currently being resolved.
+    fn release_handle(&self) {
+        *self.handle.lock().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
+    /// Returns whether the runtime has been loaded through the context.
+    fn has_loaded_runtime(&self) -> bool {
+        self.cache
+            .values()
+            .any(|delegate| matches!(delegate.get(), Some(Ok(_))))
+    }
+
+    #[cfg(feature = "net8_0")]
+    pub(crate) fn load_assembly(&self) -> Result<load_assembly_fn, HostingError> {
+        self.get(hostfxr_delegate_type::hdt_load_assembly)
+            .map(|ptr| unsafe { mem::transmute(ptr) })
+    }
+
+    #[cfg(feature = "net8_0")]
+    pub(crate) fn load_assembly_bytes(&self) -> Result<load_assembly_bytes_fn, HostingError> {
+        self.get(hostfxr_delegate_type::hdt_load_assembly_bytes)
+            .map(|ptr| unsafe { mem::transmute(ptr) })
+    }
+}
+
 impl<I> HostfxrContext<I> {
     /// Creates a new context from the given handle.
     ///
@@ -108,16 +265,25 @@ impl<I> HostfxrContext<I> {
     /// The context handle  has to be match the context type `I`.
     /// If the context was initialized using [`initialize_for_dotnet_command_line`] `I` has to be [`InitializedForCommandLine`].
     /// If the context was initialized using [`initialize_for_runtime_config`] `I` has to be [`InitializedForRuntimeConfig`].
+    /// [`InitializedForUnknown`] can be used for any context.
+    /// The handle has to be open and must not be owned by another [`HostfxrContext`], as it is closed on drop.
+    ///
+    /// This is the counterpart of [`into_handle`](HostfxrContext::into_handle), which releases the handle from a
+    /// context without closing it.
     ///
     /// [`initialize_for_dotnet_command_line`]: crate::hostfxr::Hostfxr::initialize_for_dotnet_command_line
     /// [`initialize_for_runtime_config`]: crate::hostfxr::Hostfxr::initialize_for_runtime_config
     #[must_use]
     pub unsafe fn from_handle(handle: HostfxrHandle, hostfxr: Hostfxr, is_primary: bool) -> Self {
+        strict_checks::check_open(handle, "creating a context from a handle");
+        strict_checks::check_library(handle, &hostfxr, "creating a context from a handle");
         Self {
             handle,
+            runtime_delegates: Arc::new(RuntimeDelegates::new(handle, &hostfxr)),
             hostfxr: hostfxr.lib,
             is_primary,
-            runtime_delegates: EnumMap::default(),
+            initialization_success: None,
+            capture_error_messages: hostfxr.capture_error_messages,
             context_type: PhantomData,
             not_sync: PhantomData,
         }
@@ -129,10 +295,17 @@ impl<I> HostfxrContext<I> {
         self.handle
     }
 
-    /// Gets the underlying handle to the hostfxr context and consume this context.
+    /// Gets the underlying handle to the hostfxr context and consume this context without closing it.
+    ///
+    /// The handle can be passed across an FFI boundary and adopted again using
+    /// [`from_handle`](HostfxrContext::from_handle), possibly as [`InitializedForUnknown`] if the context type is not
+    /// known at that point. The hostfxr library is never unloaded afterwards, so the handle stays valid until it is
+    /// closed.
     #[must_use]
     pub fn into_handle(self) -> HostfxrHandle {
         let this = ManuallyDrop::new(self);
+        // the handle is owned by the caller now, which may close it at any time.
+        this.runtime_delegates.release_handle();
         this.handle
     }
 
@@ -146,15 +319,51 @@ impl<I> HostfxrContext<I> {
         self.is_primary
     }
 
+    /// Gets the success status code returned when this context was initialized,
+    /// e.g. [`HostingSuccess::HostAlreadyInitialized`] if an already running runtime is reused.
+    ///
+    /// Returns [`None`] if the context was created using [`from_handle`](HostfxrContext::from_handle).
+    #[must_use]
+    pub const fn initialization_success_detail(&self) -> Option<HostingSuccess> {
+        self.initialization_success
+    }
+
+    /// Gets whether this context is the primary context or a secondary context reusing the already running runtime.
+    ///
+    /// For contexts created using [`from_handle`](HostfxrContext::from_handle), secondary contexts are always
+    /// reported as [`InitializationKind::Secondary`].
+    #[must_use]
+    pub const fn initialization_kind(&self) -> InitializationKind {
+        match (self.is_primary, self.initialization_success) {
+            (true, _) => InitializationKind::Primary,
+            (false, Some(HostingSuccess::DifferentRuntimeProperties)) => {
+                InitializationKind::SecondaryWithDifferentProperties
+            }
+            (false, _) => InitializationKind::Secondary,
+        }
+    }
+
+    pub(crate) const fn with_initialization_success(mut self, success: HostingSuccess) -> Self {
+        self.initialization_success = Some(success);
+        self
+    }
+
     #[must_use]
     pub(crate) const fn library(&self) -> &SharedHostfxrLibrary {
         &self.hostfxr
     }
 
+    /// Returns whether the runtime has been loaded through this context.
+    pub(crate) fn has_loaded_runtime(&self) -> bool {
+        self.runtime_delegates.has_loaded_runtime()
+    }
+
     /// Gets a typed delegate from the currently loaded `CoreCLR` or from a newly created one.
     /// You propably want to use [`get_delegate_loader`] or [`get_delegate_loader_for_assembly`]
     /// instead of this function if you want to load function pointers.
     ///
+    /// The result is cached per delegate type, so only the first call for each type calls into the hosting components.
+    ///
     /// # Remarks
     /// If the context was initialized using [`initialize_for_runtime_config`], then all delegate types are supported.
     /// If it was initialized using [`initialize_for_dotnet_command_line`], then only the following
@@ -172,28 +381,9 @@ impl<I> HostfxrContext<I> {
         &self,
         r#type: hostfxr_delegate_type,
     ) -> Result<RawFunctionPtr, HostingError> {
-        self.runtime_delegates[r#type]
-            .get_or_try_init(|| self.get_runtime_delegate_uncached(r#type))
-            .copied()
+        self.runtime_delegates.get(r#type)
     }
-    fn get_runtime_delegate_uncached(
-        &self,
-        r#type: hostfxr_delegate_type,
-    ) -> Result<RawFunctionPtr, HostingError> {
-        let mut delegate = MaybeUninit::uninit();
-        let result = unsafe {
-            self.hostfxr.hostfxr_get_runtime_delegate(
-                self.handle.as_raw(),
-                r#type,
-                delegate.as_mut_ptr(),
-            )
-        }
-        .unwrap();
-
-        HostingResult::from(result).into_result()?;
 
-        Ok(unsafe { delegate.assume_init() }.cast())
-    }
     fn get_load_assembly_and_get_function_pointer_delegate(
         &self,
     ) -> Result<load_assembly_and_get_function_pointer_fn, HostingError> {
@@ -211,20 +401,6 @@ impl<I> HostfxrContext<I> {
                 .map(|ptr| mem::transmute(ptr))
         }
     }
-    #[cfg(feature = "net8_0")]
-    fn get_load_assembly_delegate(&self) -> Result<load_assembly_fn, HostingError> {
-        unsafe {
-            self.get_runtime_delegate(hostfxr_delegate_type::hdt_load_assembly)
-                .map(|ptr| mem::transmute(ptr))
-        }
-    }
-    #[cfg(feature = "net8_0")]
-    fn get_load_assembly_bytes_delegate(&self) -> Result<load_assembly_bytes_fn, HostingError> {
-        unsafe {
-            self.get_runtime_delegate(hostfxr_delegate_type::hdt_load_assembly_bytes)
-                .map(|ptr| mem::transmute(ptr))
-        }
-    }
 
     /// Gets a delegate loader for loading an assembly and contained function pointers.
     pub fn get_delegate_loader(&self) -> Result<DelegateLoader, HostingError> {
@@ -233,7 +409,10 @@ impl<I> HostfxrContext<I> {
                 .get_load_assembly_and_get_function_pointer_delegate()?,
             #[cfg(feature = "net5_0")]
             get_function_pointer: self.get_get_function_pointer_delegate()?,
+            #[cfg(feature = "net8_0")]
+            runtime_delegates: self.runtime_delegates.clone(),
             hostfxr: self.hostfxr.clone(),
+            origin: LoaderOrigin::new(self.handle),
         })
     }
 
@@ -258,11 +437,8 @@ impl<I> HostfxrContext<I> {
         &self,
         assembly_path: impl AsRef<PdCStr>,
     ) -> Result<(), HostingError> {
-        let assembly_path = assembly_path.as_ref();
-        let load_assembly = self.get_load_assembly_delegate()?;
-        let result = unsafe { load_assembly(assembly_path.as_ptr(), ptr::null(), ptr::null()) };
-        HostingResult::from(result).into_result()?;
-        Ok(())
+        let load_assembly = self.runtime_delegates.load_assembly()?;
+        unsafe { delegate_loader::call_load_assembly(load_assembly, assembly_path.as_ref()) }
     }
 
     /// Loads the specified assembly in the default load context from the given buffers.
@@ -279,26 +455,39 @@ impl<I> HostfxrContext<I> {
         assembly_bytes: impl AsRef<[u8]>,
         symbols_bytes: impl AsRef<[u8]>,
     ) -> Result<(), HostingError> {
-        let symbols_bytes = symbols_bytes.as_ref();
-        let assembly_bytes = assembly_bytes.as_ref();
-        let load_assembly_bytes = self.get_load_assembly_bytes_delegate()?;
-        let result = unsafe {
-            load_assembly_bytes(
-                assembly_bytes.as_ptr(),
-                assembly_bytes.len(),
-                symbols_bytes.as_ptr(),
-                symbols_bytes.len(),
-                ptr::null_mut(),
-                ptr::null_mut(),
+        self.load_assembly_from_bytes_with_optional_symbols(
+            assembly_bytes.as_ref(),
+            Some(symbols_bytes.as_ref()),
+        )
+    }
+
+    /// Loads the given [`EmbeddedAssembly`] in the default load context.
+    /// See [`load_assembly_from_bytes`](Self::load_assembly_from_bytes) for details.
+    #[cfg(feature = "net8_0")]
+    pub fn load_embedded_assembly(&self, assembly: &EmbeddedAssembly) -> Result<(), HostingError> {
+        self.load_assembly_from_bytes_with_optional_symbols(assembly.bytes(), assembly.symbols())
+    }
+
+    #[cfg(feature = "net8_0")]
+    fn load_assembly_from_bytes_with_optional_symbols(
+        &self,
+        assembly_bytes: &[u8],
+        symbols_bytes: Option<&[u8]>,
+    ) -> Result<(), HostingError> {
+        let load_assembly_bytes = self.runtime_delegates.load_assembly_bytes()?;
+        unsafe {
+            delegate_loader::call_load_assembly_bytes(
+                load_assembly_bytes,
+                assembly_bytes,
+                symbols_bytes,
             )
-        };
-        HostingResult::from(result).into_result()?;
-        Ok(())
+        }
     }
 
     /// Closes an initialized host context.
     /// This method is automatically called on drop, but can be explicitely called to handle errors during closing.
     pub fn close(self) -> Result<HostingSuccess, HostingError> {
+        strict_checks::check_open(self.handle, "closing a context");
         let result = unsafe { self._close() };
         self.destruct_drop();
         result
@@ -306,7 +495,9 @@ impl<I> HostfxrContext<I> {
 
     /// Internal non-consuming version of [`close`](HostfxrContext::close)
     unsafe fn _close(&self) -> Result<HostingSuccess, HostingError> {
+        self.runtime_delegates.release_handle();
         let result = unsafe { self.hostfxr.hostfxr_close(self.handle.as_raw()) }.unwrap();
+        strict_checks::handle_closed(self.handle);
         HostingResult::from(result).into_result()
     }
 }
@@ -318,13 +509,33 @@ impl HostfxrContext<InitializedForCommandLine> {
     /// If the app was successfully run, the exit code of the application. Otherwise, the error code result.
     #[must_use]
     pub fn run_app(self) -> AppOrHostingResult {
+        strict_checks::check_open(self.handle, "running the app");
+        let capture = ErrorMessageCapture::start_if(&self.hostfxr, self.capture_error_messages);
         let result = unsafe { self.hostfxr.hostfxr_run_app(self.handle.as_raw()) }.unwrap();
-        AppOrHostingResult::from(result)
+        ErrorMessageCapture::finish(capture, result);
+        let result = AppOrHostingResult::from(result);
+        super::mark_runtime_started_by_app(result);
+        result
+    }
+
+    /// Like [`run_app`](HostfxrContext::run_app), but runs the application on a new thread named `netcorehost-app`
+    /// and blocks until it completes, so the thread driving the application is easy to identify in profilers and
+    /// debuggers. The name can be changed using [`set_thread_name_hook`](super::set_thread_name_hook).
+    ///
+    /// Returns an error if the thread could not be spawned.
+    pub fn run_app_on_thread(self) -> io::Result<AppOrHostingResult> {
+        ManagedCallScope::new()
+            .name(super::thread_name("netcorehost-app"))
+            .run(|| self.run_app())
     }
 }
 
 impl<I> Drop for HostfxrContext<I> {
     fn drop(&mut self) {
+        // the hosting components may already be torn down, closing the context could hang or crash.
+        if super::is_process_exiting() {
+            return;
+        }
         let _ = unsafe { self._close() };
     }
 }
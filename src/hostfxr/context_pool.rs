@@ -0,0 +1,216 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::Instant,
+};
+
+use crate::{
+    error::{HostingError, HostingSuccess},
+    hostfxr::{
+        AssemblyDelegateLoader, DelegateLoader, Hostfxr, HostfxrContext,
+        InitializedForRuntimeConfig,
+    },
+    pdcstring::{PdCStr, PdCString},
+};
+
+struct PooledContext {
+    context: HostfxrContext<InitializedForRuntimeConfig>,
+    loader: DelegateLoader,
+    last_used: Instant,
+}
+
+/// A pool of contexts initialized for a runtime config, which are kept open and reused for all operations on the
+/// same `.runtimeconfig.json`.
+///
+/// Initializing a context processes the runtime config and resolves frameworks every time, so hosts that repeatedly
+/// initialize and close secondary contexts for short-lived operations (like a server starting a session per plugin
+/// invocation) can use a pool instead. Contexts are initialized on first use and closed when they are evicted,
+/// removed using [`remove`](ContextPool::remove) or [`clear`](ContextPool::clear), or when the pool is dropped.
+///
+/// A pool can be shared between threads. The [`DelegateLoader`]s it returns must not be used after the context they
+/// were obtained from was closed.
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{hostfxr::ContextPool, nethost, pdcstr};
+/// let pool = ContextPool::new(nethost::load_hostfxr().unwrap()).capacity(8);
+/// for _ in 0..100 {
+///     // only the first iteration initializes a context.
+///     let loader = pool
+///         .delegate_loader_for_assembly(
+///             pdcstr!("Plugin.runtimeconfig.json"),
+///             pdcstr!("Plugin.dll"),
+///         )
+///         .unwrap();
+///     let run = loader
+///         .get_function_with_unmanaged_callers_only::<fn()>(pdcstr!("Plugin.Entry, Plugin"), pdcstr!("Run"))
+///         .unwrap();
+///     run();
+/// }
+/// ```
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub struct ContextPool {
+    hostfxr: Hostfxr,
+    capacity: Option<usize>,
+    contexts: Mutex<HashMap<PdCString, PooledContext>>,
+}
+
+impl ContextPool {
+    /// Creates a new empty pool initializing contexts using the given [`Hostfxr`].
+    /// The number of pooled contexts is not limited.
+    #[must_use]
+    pub fn new(hostfxr: Hostfxr) -> Self {
+        Self {
+            hostfxr,
+            capacity: None,
+            contexts: Mutex::default(),
+        }
+    }
+
+    /// Sets the maximum number of pooled contexts.
+    /// If a context has to be initialized for a full pool, the least recently used context is closed.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    #[must_use]
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "the capacity of a context pool must not be zero"
+        );
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Returns the [`Hostfxr`] used to initialize contexts.
+    #[must_use]
+    pub const fn hostfxr(&self) -> &Hostfxr {
+        &self.hostfxr
+    }
+
+    /// Returns the number of pooled contexts.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock_contexts().len()
+    }
+
+    /// Returns whether no contexts are pooled.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock_contexts().is_empty()
+    }
+
+    /// Returns whether a context for the given runtime config is pooled.
+    #[must_use]
+    pub fn contains(&self, runtime_config_path: impl AsRef<PdCStr>) -> bool {
+        self.lock_contexts()
+            .contains_key(runtime_config_path.as_ref())
+    }
+
+    /// Returns a [`DelegateLoader`] of the pooled context for the given runtime config,
+    /// initializing the context if it is not pooled yet.
+    pub fn delegate_loader(
+        &self,
+        runtime_config_path: impl AsRef<PdCStr>,
+    ) -> Result<DelegateLoader, HostingError> {
+        self.with_context(runtime_config_path, |pooled| pooled.loader.clone())
+    }
+
+    /// Returns an [`AssemblyDelegateLoader`] for the given assembly using the pooled context for the given runtime
+    /// config, see [`delegate_loader`](ContextPool::delegate_loader).
+    pub fn delegate_loader_for_assembly(
+        &self,
+        runtime_config_path: impl AsRef<PdCStr>,
+        assembly_path: impl Into<PdCString>,
+    ) -> Result<AssemblyDelegateLoader, HostingError> {
+        self.delegate_loader(runtime_config_path)
+            .map(|loader| AssemblyDelegateLoader::new(loader, assembly_path))
+    }
+
+    /// Calls the given closure with the pooled context for the given runtime config,
+    /// initializing the context if it is not pooled yet.
+    ///
+    /// The pool is locked while the closure runs, so it must not access the pool itself.
+    pub fn context<R>(
+        &self,
+        runtime_config_path: impl AsRef<PdCStr>,
+        f: impl FnOnce(&HostfxrContext<InitializedForRuntimeConfig>) -> R,
+    ) -> Result<R, HostingError> {
+        self.with_context(runtime_config_path, |pooled| f(&pooled.context))
+    }
+
+    /// Removes the context for the given runtime config from the pool and closes it.
+    /// Returns `None` if no context for the runtime config was pooled.
+    pub fn remove(
+        &self,
+        runtime_config_path: impl AsRef<PdCStr>,
+    ) -> Option<Result<HostingSuccess, HostingError>> {
+        let pooled = self.lock_contexts().remove(runtime_config_path.as_ref())?;
+        Some(pooled.context.close())
+    }
+
+    /// Closes all pooled contexts.
+    pub fn clear(&self) {
+        let contexts = std::mem::take(&mut *self.lock_contexts());
+        for (_path, pooled) in contexts {
+            let _ = pooled.context.close();
+        }
+    }
+
+    fn with_context<R>(
+        &self,
+        runtime_config_path: impl AsRef<PdCStr>,
+        f: impl FnOnce(&PooledContext) -> R,
+    ) -> Result<R, HostingError> {
+        let runtime_config_path = runtime_config_path.as_ref();
+        let mut contexts = self.lock_contexts();
+        if let Some(pooled) = contexts.get_mut(runtime_config_path) {
+            pooled.last_used = Instant::now();
+            return Ok(f(pooled));
+        }
+
+        // initializing while holding the lock prevents racing threads from creating duplicate contexts.
+        let context = self
+            .hostfxr
+            .initialize_for_runtime_config(runtime_config_path)?;
+        let loader = context.get_delegate_loader()?;
+        if self
+            .capacity
+            .is_some_and(|capacity| contexts.len() >= capacity)
+        {
+            Self::evict_least_recently_used(&mut contexts);
+        }
+        let pooled = contexts
+            .entry(runtime_config_path.to_owned())
+            .or_insert(PooledContext {
+                context,
+                loader,
+                last_used: Instant::now(),
+            });
+        Ok(f(pooled))
+    }
+
+    fn evict_least_recently_used(contexts: &mut HashMap<PdCString, PooledContext>) {
+        let least_recently_used = contexts
+            .iter()
+            .min_by_key(|(_path, pooled)| pooled.last_used)
+            .map(|(path, _pooled)| path.clone());
+        if let Some(pooled) = least_recently_used.and_then(|path| contexts.remove(&path)) {
+            let _ = pooled.context.close();
+        }
+    }
+
+    fn lock_contexts(&self) -> MutexGuard<'_, HashMap<PdCString, PooledContext>> {
+        self.contexts.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl fmt::Debug for ContextPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ContextPool")
+            .field("capacity", &self.capacity)
+            .field("pooled_contexts", &self.len())
+            .finish_non_exhaustive()
+    }
+}
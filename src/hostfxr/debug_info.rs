@@ -0,0 +1,111 @@
+use std::{
+    env::consts::{DLL_PREFIX, DLL_SUFFIX},
+    fmt::{self, Display},
+    path::{Path, PathBuf},
+    process,
+};
+
+use thiserror::Error;
+
+use crate::{error::HostingError, hostfxr::HostfxrContext};
+
+/// Information about the runtime used by a [`HostfxrContext`], which allows pointing external tools like
+/// `dotnet-dump`, `dotnet-trace` or a debugger at a runtime embedded in a Rust host.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DebugInfo {
+    runtime_dir: PathBuf,
+    runtime_version: String,
+    process_id: u32,
+}
+
+impl DebugInfo {
+    /// Returns the directory of the `Microsoft.NETCore.App` framework containing the runtime.
+    #[must_use]
+    pub fn runtime_dir(&self) -> &Path {
+        &self.runtime_dir
+    }
+
+    /// Returns the version of the `Microsoft.NETCore.App` framework.
+    #[must_use]
+    pub fn runtime_version(&self) -> &str {
+        &self.runtime_version
+    }
+
+    /// Returns the id of the current process.
+    #[must_use]
+    pub const fn process_id(&self) -> u32 {
+        self.process_id
+    }
+
+    /// Returns the path of the `coreclr` library.
+    #[must_use]
+    pub fn coreclr_path(&self) -> PathBuf {
+        self.runtime_library("coreclr")
+    }
+
+    /// Returns the path of the debugger interface library (`mscordbi`), which is used by managed debuggers.
+    #[must_use]
+    pub fn dbi_path(&self) -> PathBuf {
+        self.runtime_library("mscordbi")
+    }
+
+    /// Returns the path of the data access component (`mscordaccore`), which is used to inspect the runtime state
+    /// of a process or dump.
+    #[must_use]
+    pub fn dac_path(&self) -> PathBuf {
+        self.runtime_library("mscordaccore")
+    }
+
+    fn runtime_library(&self, name: &str) -> PathBuf {
+        self.runtime_dir
+            .join(format!("{DLL_PREFIX}{name}{DLL_SUFFIX}"))
+    }
+}
+
+impl Display for DebugInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "process id: {}", self.process_id)?;
+        writeln!(f, "runtime version: {}", self.runtime_version)?;
+        writeln!(f, "runtime directory: {}", self.runtime_dir.display())?;
+        writeln!(f, "coreclr: {}", self.coreclr_path().display())?;
+        writeln!(f, "dbi: {}", self.dbi_path().display())?;
+        write!(f, "dac: {}", self.dac_path().display())
+    }
+}
+
+impl<I> HostfxrContext<I> {
+    /// Gets information about the runtime used by this context for external diagnostic tools.
+    ///
+    /// The runtime directory is determined using the location of `System.Private.CoreLib` in the
+    /// trusted platform assemblies, so the information is available before the runtime is loaded.
+    pub fn debug_info(&self) -> Result<DebugInfo, DebugInfoError> {
+        let runtime_dir = self
+            .trusted_platform_assemblies()?
+            .into_iter()
+            .find(|path| {
+                path.file_stem()
+                    .is_some_and(|stem| stem.eq_ignore_ascii_case("System.Private.CoreLib"))
+            })
+            .and_then(|path| path.parent().map(Path::to_path_buf))
+            .ok_or(DebugInfoError::RuntimeNotFound)?;
+
+        Ok(DebugInfo {
+            runtime_dir,
+            runtime_version: self.resolved_runtime_version()?,
+            process_id: process::id(),
+        })
+    }
+}
+
+/// Enum for errors that can occur while getting the [`DebugInfo`] of a context.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+#[non_exhaustive]
+pub enum DebugInfoError {
+    /// The runtime properties could not be read.
+    #[error(transparent)]
+    Hosting(#[from] HostingError),
+    /// `System.Private.CoreLib` is not part of the trusted platform assemblies.
+    #[error("The runtime could not be located, as System.Private.CoreLib is not a trusted platform assembly.")]
+    RuntimeNotFound,
+}
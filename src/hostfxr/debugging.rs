@@ -0,0 +1,127 @@
+use std::{
+    process, thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    hostfxr::{AssemblyDelegateLoader, GetManagedFunctionError},
+    pdcstr,
+    pdcstring::PdCStr,
+};
+
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Options for debugging the managed code of a host.
+///
+/// Attaching a managed debugger to a Rust host is awkward, as the managed code often runs right after the runtime
+/// starts. With [`wait_for_managed_debugger`](DebugOptions::wait_for_managed_debugger), [`wait`](DebugOptions::wait)
+/// prints the process id and blocks until a managed debugger is attached.
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{hostfxr::{AssemblyDelegateLoader, DebugOptions}, pdcstr};
+/// # use std::time::Duration;
+/// # fn test(fn_loader: AssemblyDelegateLoader) {
+/// let attached = DebugOptions::new()
+///     .wait_for_managed_debugger(Duration::from_secs(60))
+///     .wait(&fn_loader, pdcstr!("NativeDebugger, MyApp"))
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DebugOptions {
+    wait_timeout: Option<Duration>,
+    poll_interval: Duration,
+    print_hint: bool,
+}
+
+impl Default for DebugOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DebugOptions {
+    /// Creates new options, which do not wait for a debugger.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            wait_timeout: None,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            print_hint: true,
+        }
+    }
+
+    /// Makes [`wait`](DebugOptions::wait) block until a managed debugger is attached or the timeout elapsed.
+    #[must_use]
+    pub const fn wait_for_managed_debugger(mut self, timeout: Duration) -> Self {
+        self.wait_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets how often the runtime is asked whether a debugger is attached while waiting. Defaults to 100ms.
+    #[must_use]
+    pub const fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Sets whether a hint containing the process id is written to stderr before waiting. Enabled by default.
+    #[must_use]
+    pub const fn print_hint(mut self, print_hint: bool) -> Self {
+        self.print_hint = print_hint;
+        self
+    }
+
+    /// Applies the options using the `NativeDebugger` class with the given assembly qualified type name,
+    /// see [`MANAGED_DEBUGGER_HELPERS`].
+    ///
+    /// Returns whether a managed debugger is attached, which is `false` if the timeout elapsed.
+    ///
+    /// [`MANAGED_DEBUGGER_HELPERS`]: super::MANAGED_DEBUGGER_HELPERS
+    pub fn wait(
+        &self,
+        loader: &AssemblyDelegateLoader,
+        type_name: &PdCStr,
+    ) -> Result<bool, GetManagedFunctionError> {
+        let is_attached = loader.get_function_with_unmanaged_callers_only::<fn() -> i32>(
+            type_name,
+            pdcstr!("IsAttached"),
+        )?;
+        let Some(timeout) = self.wait_timeout else {
+            return Ok(is_attached() != 0);
+        };
+        if is_attached() != 0 {
+            return Ok(true);
+        }
+
+        if self.print_hint {
+            eprintln!(
+                "Waiting {timeout:?} for a managed debugger to attach to process {}...",
+                process::id()
+            );
+        }
+        let deadline = Instant::now() + timeout;
+        loop {
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(false);
+            }
+            thread::sleep(self.poll_interval.min(deadline - now));
+            if is_attached() != 0 {
+                return Ok(true);
+            }
+        }
+    }
+}
+
+/// Returns whether a managed debugger is attached, using the `NativeDebugger` class with the given assembly qualified
+/// type name, see [`MANAGED_DEBUGGER_HELPERS`].
+///
+/// [`MANAGED_DEBUGGER_HELPERS`]: super::MANAGED_DEBUGGER_HELPERS
+pub fn is_managed_debugger_attached(
+    loader: &AssemblyDelegateLoader,
+    type_name: &PdCStr,
+) -> Result<bool, GetManagedFunctionError> {
+    DebugOptions::new().wait(loader, type_name)
+}
@@ -7,19 +7,130 @@ use crate::{
     pdcstring::{PdCStr, PdCString},
 };
 use num_enum::TryFromPrimitive;
-use std::{convert::TryFrom, mem::MaybeUninit, path::Path, ptr};
+use std::{
+    convert::TryFrom,
+    ffi::OsString,
+    mem::{self, MaybeUninit},
+    path::Path,
+    ptr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 use thiserror::Error;
 
-use super::{FunctionPtr, ManagedFunction, RawFunctionPtr, SharedHostfxrLibrary};
+use super::{
+    name_validation::{validate_method_name, validate_type_name},
+    strict_checks::LoaderOrigin,
+    DelegateSignature, FfiSafe, InvalidNameReason, ManagedFunction, RawFunctionPtr,
+    SharedHostfxrLibrary,
+};
 
+#[cfg(feature = "net8_0")]
+use super::RuntimeDelegates;
 #[cfg(feature = "net5_0")]
 use crate::bindings::hostfxr::{get_function_pointer_fn, UNMANAGED_CALLERS_ONLY_METHOD};
+#[cfg(feature = "net8_0")]
+use crate::bindings::hostfxr::{load_assembly_bytes_fn, load_assembly_fn};
 
 /// A pointer to a function with the default signature.
 pub type ManagedFunctionWithDefaultSignature = ManagedFunction<component_entry_point_fn>;
 /// A pointer to a function with an unknown signature.
 pub type ManagedFunctionWithUnknownSignature = ManagedFunction<RawFunctionPtr>;
 
+impl ManagedFunctionWithDefaultSignature {
+    /// Calls the function with the given bytes as `args` and their length as `sizeBytes`.
+    ///
+    /// # Safety
+    /// The managed method must not write to `args` or access more than `sizeBytes` bytes of it, which the
+    /// compiler cannot check for arbitrary managed code.
+    ///
+    /// # Panics
+    /// Panics if `bytes` is longer than [`i32::MAX`] bytes.
+    #[must_use]
+    pub unsafe fn call_with_bytes(&self, bytes: &[u8]) -> i32 {
+        let size = i32::try_from(bytes.len())
+            .expect("arguments are too large to be passed to managed code");
+        unsafe { (self.0)(bytes.as_ptr().cast(), size) }
+    }
+
+    /// Calls the function with a pointer to the given value as `args` and its size as `sizeBytes`.
+    ///
+    /// The managed method can read the value using `Marshal.PtrToStructure` or by casting `args` to a pointer to a
+    /// blittable struct with the same layout.
+    ///
+    /// # Safety
+    /// The managed method must not write to `args` or access more than `sizeBytes` bytes of it, which the
+    /// compiler cannot check for arbitrary managed code.
+    #[must_use]
+    pub unsafe fn call_with_struct<T: FfiSafe>(&self, args: &T) -> i32 {
+        let size = i32::try_from(mem::size_of::<T>())
+            .expect("arguments are too large to be passed to managed code");
+        unsafe { (self.0)(ptr::from_ref(args).cast(), size) }
+    }
+
+    /// Calls the function with a null pointer as `args` and zero as `sizeBytes`.
+    ///
+    /// # Safety
+    /// The managed method must not dereference `args`, which the compiler cannot check for arbitrary managed
+    /// code.
+    #[must_use]
+    pub unsafe fn call_without_args(&self) -> i32 {
+        unsafe { (self.0)(ptr::null(), 0) }
+    }
+}
+
+/// Specifies the signature of a managed method that a function pointer is loaded for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub enum DelegateTypeSpec<'a> {
+    /// The method has the default signature:
+    /// `public delegate int ComponentEntryPoint(IntPtr args, int sizeBytes);`
+    ///
+    /// This corresponds to passing `null` as the delegate type name.
+    Default,
+    /// The method is annotated with [`UnmanagedCallersOnly`](https://docs.microsoft.com/en-us/dotnet/api/system.runtime.interopservices.unmanagedcallersonlyattribute).
+    ///
+    /// This corresponds to passing `UNMANAGEDCALLERSONLY_METHOD` as the delegate type name.
+    #[cfg(feature = "net5_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+    UnmanagedCallersOnly,
+    /// The method matches the signature of the delegate type with the given assembly qualified name.
+    ///
+    /// The runtime binds the method using the signature of the delegate type, so this can be used to select
+    /// a specific overload of a method, which is not possible with the other variants.
+    /// To select an overload by its parameter types instead, see
+    /// [`AssemblyDelegateLoader::get_function_by_signature`].
+    Custom(&'a PdCStr),
+}
+
+impl DelegateTypeSpec<'_> {
+    /// Returns the raw delegate type name as it is passed to the hosting components.
+    #[must_use]
+    pub fn as_ptr(&self) -> *const char_t {
+        match self {
+            Self::Default => ptr::null(),
+            #[cfg(feature = "net5_0")]
+            Self::UnmanagedCallersOnly => UNMANAGED_CALLERS_ONLY_METHOD,
+            Self::Custom(delegate_type_name) => delegate_type_name.as_ptr(),
+        }
+    }
+
+    fn validate(&self) -> Result<(), GetManagedFunctionError> {
+        match self {
+            Self::Custom(delegate_type_name) => validate_type_name(delegate_type_name),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'a> From<&'a PdCStr> for DelegateTypeSpec<'a> {
+    fn from(delegate_type_name: &'a PdCStr) -> Self {
+        Self::Custom(delegate_type_name)
+    }
+}
+
 /// A struct for loading pointers to managed functions for a given [`HostfxrContext`].
 ///
 /// [`HostfxrContext`]: super::HostfxrContext
@@ -29,8 +140,12 @@ pub struct DelegateLoader {
         load_assembly_and_get_function_pointer_fn,
     #[cfg(feature = "net5_0")]
     pub(crate) get_function_pointer: get_function_pointer_fn,
+    // not supported by every context, so the load_assembly delegates are only resolved when they are first used.
+    #[cfg(feature = "net8_0")]
+    pub(crate) runtime_delegates: Arc<RuntimeDelegates>,
     #[allow(unused)]
     pub(crate) hostfxr: SharedHostfxrLibrary,
+    pub(crate) origin: LoaderOrigin,
 }
 
 impl Clone for DelegateLoader {
@@ -40,7 +155,10 @@ impl Clone for DelegateLoader {
                 .get_load_assembly_and_get_function_pointer,
             #[cfg(feature = "net5_0")]
             get_function_pointer: self.get_function_pointer,
+            #[cfg(feature = "net8_0")]
+            runtime_delegates: self.runtime_delegates.clone(),
             hostfxr: self.hostfxr.clone(),
+            origin: self.origin,
         }
     }
 }
@@ -53,6 +171,7 @@ impl DelegateLoader {
         method_name: *const char_t,
         delegate_type_name: *const char_t,
     ) -> Result<RawFunctionPtr, GetManagedFunctionError> {
+        self.origin.check_open();
         let mut delegate = MaybeUninit::uninit();
 
         let result = unsafe {
@@ -88,6 +207,16 @@ impl DelegateLoader {
         }
     }
 
+    fn _validate_names(
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+        delegate_type: DelegateTypeSpec<'_>,
+    ) -> Result<(), GetManagedFunctionError> {
+        validate_type_name(type_name)?;
+        validate_method_name(method_name)?;
+        delegate_type.validate()
+    }
+
     #[cfg(feature = "net5_0")]
     unsafe fn _get_function_pointer(
         &self,
@@ -95,6 +224,7 @@ impl DelegateLoader {
         method_name: *const char_t,
         delegate_type_name: *const char_t,
     ) -> Result<RawFunctionPtr, GetManagedFunctionError> {
+        self.origin.check_open();
         let mut delegate = MaybeUninit::uninit();
 
         let result = unsafe {
@@ -128,20 +258,53 @@ impl DelegateLoader {
     ///     Name of the method on the `type_name` to find. The method must be static and must match the signature of `delegate_type_name`.
     ///  * `delegate_type_name`:
     ///     Assembly qualified delegate type name for the method signature.
-    pub fn load_assembly_and_get_function<F: FunctionPtr>(
+    pub fn load_assembly_and_get_function<F: DelegateSignature>(
         &self,
         assembly_path: &PdCStr,
         type_name: &PdCStr,
         method_name: &PdCStr,
         delegate_type_name: &PdCStr,
+    ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
+        self.load_assembly_and_get_function_with_delegate_type::<F>(
+            assembly_path,
+            type_name,
+            method_name,
+            DelegateTypeSpec::Custom(delegate_type_name),
+        )
+    }
+
+    /// Calling this function will load the specified assembly in isolation (into its own `AssemblyLoadContext`)
+    /// and it will use `AssemblyDependencyResolver` on it to provide dependency resolution.
+    /// Once loaded it will find the specified type and method and return a native function pointer
+    /// to that method.
+    ///
+    /// # Arguments
+    ///  * `assembly_path`:
+    ///     Path to the assembly to load.
+    ///     In case of complex component, this should be the main assembly of the component (the one with the .deps.json next to it).
+    ///     Note that this does not have to be the assembly from which the `type_name` and `method_name` are.
+    ///  * `type_name`:
+    ///     Assembly qualified type name to find
+    ///  * `method_name`:
+    ///     Name of the method on the `type_name` to find. The method must be static and must match the signature specified by `delegate_type`.
+    ///  * `delegate_type`:
+    ///     The signature of the method, see [`DelegateTypeSpec`].
+    ///     `F` has to match this signature.
+    pub fn load_assembly_and_get_function_with_delegate_type<F: DelegateSignature>(
+        &self,
+        assembly_path: &PdCStr,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+        delegate_type: DelegateTypeSpec<'_>,
     ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
         Self::_validate_assembly_path(assembly_path)?;
+        Self::_validate_names(type_name, method_name, delegate_type)?;
         let function = unsafe {
             self._load_assembly_and_get_function_pointer(
                 assembly_path.as_ptr(),
                 type_name.as_ptr(),
                 method_name.as_ptr(),
-                delegate_type_name.as_ptr(),
+                delegate_type.as_ptr(),
             )
         }?;
         Ok(ManagedFunction(unsafe { F::Managed::from_ptr(function) }))
@@ -168,16 +331,12 @@ impl DelegateLoader {
         type_name: &PdCStr,
         method_name: &PdCStr,
     ) -> Result<ManagedFunctionWithDefaultSignature, GetManagedFunctionError> {
-        Self::_validate_assembly_path(assembly_path)?;
-        let function = unsafe {
-            self._load_assembly_and_get_function_pointer(
-                assembly_path.as_ptr(),
-                type_name.as_ptr(),
-                method_name.as_ptr(),
-                ptr::null(),
-            )
-        }?;
-        Ok(ManagedFunction(unsafe { FunctionPtr::from_ptr(function) }))
+        self.load_assembly_and_get_function_with_delegate_type::<component_entry_point_fn>(
+            assembly_path,
+            type_name,
+            method_name,
+            DelegateTypeSpec::Default,
+        )
     }
 
     /// Calling this function will load the specified assembly in isolation (into its own `AssemblyLoadContext`)
@@ -199,22 +358,18 @@ impl DelegateLoader {
     /// [`UnmanagedCallersOnly`]: https://docs.microsoft.com/en-us/dotnet/api/system.runtime.interopservices.unmanagedcallersonlyattribute
     #[cfg(feature = "net5_0")]
     #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
-    pub fn load_assembly_and_get_function_with_unmanaged_callers_only<F: FunctionPtr>(
+    pub fn load_assembly_and_get_function_with_unmanaged_callers_only<F: DelegateSignature>(
         &self,
         assembly_path: &PdCStr,
         type_name: &PdCStr,
         method_name: &PdCStr,
     ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
-        Self::_validate_assembly_path(assembly_path)?;
-        let function = unsafe {
-            self._load_assembly_and_get_function_pointer(
-                assembly_path.as_ptr(),
-                type_name.as_ptr(),
-                method_name.as_ptr(),
-                UNMANAGED_CALLERS_ONLY_METHOD,
-            )
-        }?;
-        Ok(ManagedFunction(unsafe { F::Managed::from_ptr(function) }))
+        self.load_assembly_and_get_function_with_delegate_type::<F>(
+            assembly_path,
+            type_name,
+            method_name,
+            DelegateTypeSpec::UnmanagedCallersOnly,
+        )
     }
 
     /// Calling this function will find the specified type and method and return a native function pointer to that method.
@@ -229,17 +384,44 @@ impl DelegateLoader {
     ///     Assembly qualified delegate type name for the method signature.
     #[cfg(feature = "net5_0")]
     #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
-    pub fn get_function<F: FunctionPtr>(
+    pub fn get_function<F: DelegateSignature>(
         &self,
         type_name: &PdCStr,
         method_name: &PdCStr,
         delegate_type_name: &PdCStr,
     ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
+        self.get_function_with_delegate_type::<F>(
+            type_name,
+            method_name,
+            DelegateTypeSpec::Custom(delegate_type_name),
+        )
+    }
+
+    /// Calling this function will find the specified type and method and return a native function pointer to that method.
+    /// This will **NOT** load the containing assembly.
+    ///
+    /// # Arguments
+    ///  * `type_name`:
+    ///     Assembly qualified type name to find
+    ///  * `method_name`:
+    ///     Name of the method on the `type_name` to find. The method must be static and must match the signature specified by `delegate_type`.
+    ///  * `delegate_type`:
+    ///     The signature of the method, see [`DelegateTypeSpec`].
+    ///     `F` has to match this signature.
+    #[cfg(feature = "net5_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+    pub fn get_function_with_delegate_type<F: DelegateSignature>(
+        &self,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+        delegate_type: DelegateTypeSpec<'_>,
+    ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
+        Self::_validate_names(type_name, method_name, delegate_type)?;
         let function = unsafe {
             self._get_function_pointer(
                 type_name.as_ptr(),
                 method_name.as_ptr(),
-                delegate_type_name.as_ptr(),
+                delegate_type.as_ptr(),
             )
         }?;
         Ok(ManagedFunction(unsafe { F::Managed::from_ptr(function) }))
@@ -260,10 +442,11 @@ impl DelegateLoader {
         type_name: &PdCStr,
         method_name: &PdCStr,
     ) -> Result<ManagedFunctionWithDefaultSignature, GetManagedFunctionError> {
-        let function = unsafe {
-            self._get_function_pointer(type_name.as_ptr(), method_name.as_ptr(), ptr::null())
-        }?;
-        Ok(ManagedFunction(unsafe { FunctionPtr::from_ptr(function) }))
+        self.get_function_with_delegate_type::<component_entry_point_fn>(
+            type_name,
+            method_name,
+            DelegateTypeSpec::Default,
+        )
     }
 
     /// Calling this function will find the specified type and method and return a native function pointer to that method.
@@ -279,22 +462,89 @@ impl DelegateLoader {
     /// [`UnmanagedCallersOnly`]: https://docs.microsoft.com/en-us/dotnet/api/system.runtime.interopservices.unmanagedcallersonlyattribute
     #[cfg(feature = "net5_0")]
     #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
-    pub fn get_function_with_unmanaged_callers_only<F: FunctionPtr>(
+    pub fn get_function_with_unmanaged_callers_only<F: DelegateSignature>(
         &self,
         type_name: &PdCStr,
         method_name: &PdCStr,
     ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
-        let function = unsafe {
-            self._get_function_pointer(
-                type_name.as_ptr(),
-                method_name.as_ptr(),
-                UNMANAGED_CALLERS_ONLY_METHOD,
-            )
-        }?;
-        Ok(ManagedFunction(unsafe { F::Managed::from_ptr(function) }))
+        self.get_function_with_delegate_type::<F>(
+            type_name,
+            method_name,
+            DelegateTypeSpec::UnmanagedCallersOnly,
+        )
+    }
+
+    /// Loads the specified assembly in the default load context from the given path.
+    /// It uses [`AssemblyDependencyResolver`] to register additional dependency resolution for the load context.
+    /// Function pointers to methods in the assembly can then be loaded using [`get_function`](DelegateLoader::get_function).
+    ///
+    /// The `load_assembly` delegate is retrieved from the context on first use, so this fails with
+    /// [`HostingError::HostInvalidState`] if it is first called after the context has been closed.
+    ///
+    /// [`AssemblyDependencyResolver`]: https://learn.microsoft.com/en-us/dotnet/api/system.runtime.loader.assemblydependencyresolver
+    #[cfg(feature = "net8_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net8_0")))]
+    pub fn load_assembly(&self, assembly_path: impl AsRef<PdCStr>) -> Result<(), HostingError> {
+        self.origin.check_open();
+        let load_assembly = self.runtime_delegates.load_assembly()?;
+        unsafe { call_load_assembly(load_assembly, assembly_path.as_ref()) }
+    }
+
+    /// Loads the specified assembly in the default load context from the given buffers.
+    /// It does not provide a mechanism for registering additional dependency resolution, as mechanisms like `.deps.json` and [`AssemblyDependencyResolver`] are file-based.
+    /// Dependencies can be pre-loaded (for example, via a previous call to this function) or the specified assembly can explicitly register its own resolution logic (for example, via the [`AssemblyLoadContext.Resolving`] event).
+    /// Function pointers to methods in the assembly can then be loaded using [`get_function`](DelegateLoader::get_function).
+    ///
+    /// Like [`load_assembly`](DelegateLoader::load_assembly), this fails with [`HostingError::HostInvalidState`] if
+    /// it is first called after the context has been closed.
+    ///
+    /// [`AssemblyDependencyResolver`]: https://learn.microsoft.com/en-us/dotnet/api/system.runtime.loader.assemblydependencyresolver
+    /// [`AssemblyLoadContext.Resolving`]: https://learn.microsoft.com/en-us/dotnet/api/system.runtime.loader.assemblyloadcontext.resolving
+    #[cfg(feature = "net8_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net8_0")))]
+    pub fn load_assembly_from_bytes(
+        &self,
+        assembly: &[u8],
+        symbols: Option<&[u8]>,
+    ) -> Result<(), HostingError> {
+        self.origin.check_open();
+        let load_assembly_bytes = self.runtime_delegates.load_assembly_bytes()?;
+        unsafe { call_load_assembly_bytes(load_assembly_bytes, assembly, symbols) }
     }
 }
 
+#[cfg(feature = "net8_0")]
+pub(crate) unsafe fn call_load_assembly(
+    load_assembly: load_assembly_fn,
+    assembly_path: &PdCStr,
+) -> Result<(), HostingError> {
+    let result = unsafe { load_assembly(assembly_path.as_ptr(), ptr::null(), ptr::null()) };
+    HostingResult::from(result).into_result()?;
+    Ok(())
+}
+
+#[cfg(feature = "net8_0")]
+pub(crate) unsafe fn call_load_assembly_bytes(
+    load_assembly_bytes: load_assembly_bytes_fn,
+    assembly: &[u8],
+    symbols: Option<&[u8]>,
+) -> Result<(), HostingError> {
+    let (symbols_ptr, symbols_len) =
+        symbols.map_or((ptr::null(), 0), |bytes| (bytes.as_ptr(), bytes.len()));
+    let result = unsafe {
+        load_assembly_bytes(
+            assembly.as_ptr(),
+            assembly.len(),
+            symbols_ptr,
+            symbols_len,
+            ptr::null_mut(),
+            ptr::null_mut(),
+        )
+    };
+    HostingResult::from(result).into_result()?;
+    Ok(())
+}
+
 /// A struct for loading pointers to managed functions for a given [`HostfxrContext`] which automatically loads the
 /// assembly from the given path on the first access.
 ///
@@ -304,6 +554,8 @@ impl DelegateLoader {
 pub struct AssemblyDelegateLoader {
     loader: DelegateLoader,
     assembly_path: PdCString,
+    // shared between clones, as they use the same load context.
+    loaded: Arc<AtomicBool>,
 }
 
 impl AssemblyDelegateLoader {
@@ -314,9 +566,79 @@ impl AssemblyDelegateLoader {
         Self {
             loader,
             assembly_path,
+            loaded: Arc::default(),
+        }
+    }
+
+    /// Returns the path of the assembly this loader resolves functions from.
+    #[must_use]
+    pub fn assembly_path(&self) -> &PdCStr {
+        &self.assembly_path
+    }
+
+    /// Returns the underlying [`DelegateLoader`].
+    #[must_use]
+    pub const fn loader(&self) -> &DelegateLoader {
+        &self.loader
+    }
+
+    /// Loads the assembly ahead of time, so that errors like a missing file or an invalid image are reported
+    /// here instead of by the first function lookup. Does nothing if the assembly has already been loaded.
+    ///
+    /// The hosting components cannot load a component without resolving a method, so this looks up a type
+    /// that does not exist in the assembly, which requires the assembly to be loaded first.
+    /// The type is looked up in the assembly whose simple name matches the file name of the assembly path,
+    /// so this fails with [`GetManagedFunctionError::AssemblyNotFound`] if the two differ.
+    pub fn preload(&self) -> Result<(), GetManagedFunctionError> {
+        if self.is_loaded() {
+            return Ok(());
+        }
+
+        DelegateLoader::_validate_assembly_path(&self.assembly_path)?;
+        let path = self.assembly_path.to_os_string();
+        let assembly_name = Path::new(&path)
+            .file_stem()
+            .ok_or(GetManagedFunctionError::AssemblyNotFound)?;
+        let mut type_name = OsString::from("NetcorehostPreloadProbe, ");
+        type_name.push(assembly_name);
+        let type_name = PdCString::from_os_str(type_name)
+            .map_err(|_| GetManagedFunctionError::AssemblyNotFound)?;
+
+        let result = unsafe {
+            self.loader._load_assembly_and_get_function_pointer(
+                self.assembly_path.as_ptr(),
+                type_name.as_ptr(),
+                crate::pdcstr!("Probe").as_ptr(),
+                ptr::null(),
+            )
+        };
+        match result {
+            // the assembly was loaded, but (as expected) does not contain the probe type.
+            Ok(_) | Err(GetManagedFunctionError::TypeNotFound) => {
+                self.loaded.store(true, Ordering::Release);
+                Ok(())
+            }
+            Err(err) => Err(err),
         }
     }
 
+    /// Returns whether the assembly has been loaded by [`preload`](AssemblyDelegateLoader::preload) or by
+    /// successfully resolving a function through this loader or one of its clones.
+    #[must_use]
+    pub fn is_loaded(&self) -> bool {
+        self.loaded.load(Ordering::Acquire)
+    }
+
+    fn track_load<T>(
+        &self,
+        result: Result<T, GetManagedFunctionError>,
+    ) -> Result<T, GetManagedFunctionError> {
+        if result.is_ok() {
+            self.loaded.store(true, Ordering::Release);
+        }
+        result
+    }
+
     /// If this is the first loaded function pointer, calling this function will load the specified assembly in
     /// isolation (into its own `AssemblyLoadContext`) and it will use `AssemblyDependencyResolver` on it to provide
     /// dependency resolution.
@@ -330,17 +652,47 @@ impl AssemblyDelegateLoader {
     ///     Name of the method on the `type_name` to find. The method must be static and must match the signature of `delegate_type_name`.
     ///  * `delegate_type_name`:
     ///     Assembly qualified delegate type name for the method signature.
-    pub fn get_function<F: FunctionPtr>(
+    pub fn get_function<F: DelegateSignature>(
         &self,
         type_name: &PdCStr,
         method_name: &PdCStr,
         delegate_type_name: &PdCStr,
     ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
-        self.loader.load_assembly_and_get_function::<F>(
+        self.track_load(self.loader.load_assembly_and_get_function::<F>(
             self.assembly_path.as_ref(),
             type_name,
             method_name,
             delegate_type_name,
+        ))
+    }
+
+    /// If this is the first loaded function pointer, calling this function will load the specified assembly in
+    /// isolation (into its own `AssemblyLoadContext`) and it will use `AssemblyDependencyResolver` on it to provide
+    /// dependency resolution.
+    /// Otherwise or once loaded it will find the specified type and method and return a native function pointer to that method.
+    ///
+    /// # Arguments
+    ///  * `type_name`:
+    ///     Assembly qualified type name to find
+    ///  * `method_name`:
+    ///     Name of the method on the `type_name` to find. The method must be static and must match the signature specified by `delegate_type`.
+    ///  * `delegate_type`:
+    ///     The signature of the method, see [`DelegateTypeSpec`].
+    ///     `F` has to match this signature.
+    pub fn get_function_with_delegate_type<F: DelegateSignature>(
+        &self,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+        delegate_type: DelegateTypeSpec<'_>,
+    ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
+        self.track_load(
+            self.loader
+                .load_assembly_and_get_function_with_delegate_type::<F>(
+                    self.assembly_path.as_ref(),
+                    type_name,
+                    method_name,
+                    delegate_type,
+                ),
         )
     }
 
@@ -361,12 +713,14 @@ impl AssemblyDelegateLoader {
         type_name: &PdCStr,
         method_name: &PdCStr,
     ) -> Result<ManagedFunctionWithDefaultSignature, GetManagedFunctionError> {
-        self.loader
-            .load_assembly_and_get_function_with_default_signature(
-                self.assembly_path.as_ref(),
-                type_name,
-                method_name,
-            )
+        self.track_load(
+            self.loader
+                .load_assembly_and_get_function_with_default_signature(
+                    self.assembly_path.as_ref(),
+                    type_name,
+                    method_name,
+                ),
+        )
     }
 
     /// If this is the first loaded function pointer, calling this function will load the specified assembly in
@@ -385,23 +739,28 @@ impl AssemblyDelegateLoader {
     /// [`UnmanagedCallersOnly`]: https://docs.microsoft.com/en-us/dotnet/api/system.runtime.interopservices.unmanagedcallersonlyattribute
     #[cfg(feature = "net5_0")]
     #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
-    pub fn get_function_with_unmanaged_callers_only<F: FunctionPtr>(
+    pub fn get_function_with_unmanaged_callers_only<F: DelegateSignature>(
         &self,
         type_name: &PdCStr,
         method_name: &PdCStr,
     ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
-        self.loader
-            .load_assembly_and_get_function_with_unmanaged_callers_only::<F>(
-                self.assembly_path.as_ref(),
-                type_name,
-                method_name,
-            )
+        self.track_load(
+            self.loader
+                .load_assembly_and_get_function_with_unmanaged_callers_only::<F>(
+                    self.assembly_path.as_ref(),
+                    type_name,
+                    method_name,
+                ),
+        )
     }
 }
 
 /// Enum for errors that can occur while loading a managed assembly or managed function pointers.
+///
+/// New variants may be added in minor releases, so matches on this enum have to include a wildcard arm.
 #[derive(Error, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+#[non_exhaustive]
 pub enum GetManagedFunctionError {
     /// An error occured inside the hosting components.
     #[error("Error from hosting components: {}.", .0)]
@@ -423,6 +782,25 @@ pub enum GetManagedFunctionError {
     #[error("The target method is not annotated with UnmanagedCallersOnly.")]
     MethodNotUnmanagedCallersOnly,
 
+    /// The type name (or the name of the delegate type) does not have the format `Namespace.Type, Assembly`.
+    /// This is checked before calling into the runtime, as it only reports a generic error for malformed names.
+    #[error("Invalid type name {input:?}: {reason}.")]
+    InvalidTypeName {
+        /// The rejected type name.
+        input: String,
+        /// Why the type name was rejected.
+        reason: InvalidNameReason,
+    },
+
+    /// The method name is malformed.
+    #[error("Invalid method name {input:?}: {reason}.")]
+    InvalidMethodName {
+        /// The rejected method name.
+        input: String,
+        /// Why the method name was rejected.
+        reason: InvalidNameReason,
+    },
+
     /// Some other unknown error occured.
     #[error("Unknown error code: {}", format!("{:#08X}", .0))]
     Other(u32),
@@ -448,6 +826,50 @@ impl GetManagedFunctionError {
         }
         Err(Self::Other(code))
     }
+
+    /// Returns the error from the hosting components if this is a [`GetManagedFunctionError::Hosting`] error.
+    #[must_use]
+    pub const fn hosting_error(&self) -> Option<HostingError> {
+        match self {
+            Self::Hosting(error) => Some(*error),
+            _ => None,
+        }
+    }
+
+    /// Returns the raw status code of this error.
+    ///
+    /// For [`GetManagedFunctionError::Hosting`] this is the status code of the hosting error, for
+    /// [`GetManagedFunctionError::Other`] the unknown code and for the other variants the `HRESULT` the runtime reports
+    /// for them. Invalid names are reported as `COR_E_ARGUMENT`, like the runtime does for most malformed names.
+    ///
+    /// The runtime also reports `COR_E_ARGUMENT` for methods with an incompatible signature, so
+    /// [`from_status_code`](GetManagedFunctionError::from_status_code) maps it to
+    /// [`GetManagedFunctionError::MissingMethod`]. The codes of [`GetManagedFunctionError::InvalidTypeName`] and
+    /// [`GetManagedFunctionError::InvalidMethodName`] therefore do not round-trip; the names are validated before
+    /// calling into the runtime, so these variants are never created from a status code.
+    #[must_use]
+    pub const fn code(&self) -> u32 {
+        match self {
+            Self::Hosting(error) => error.value(),
+            Self::TypeNotFound => HResult::COR_E_TYPELOAD as u32,
+            Self::MissingMethod => HResult::COR_E_MISSINGMETHOD as u32,
+            Self::AssemblyNotFound => HResult::FILE_NOT_FOUND as u32,
+            Self::MethodNotUnmanagedCallersOnly => HResult::COR_E_INVALIDOPERATION as u32,
+            Self::InvalidTypeName { .. } | Self::InvalidMethodName { .. } => {
+                HResult::COR_E_ARGUMENT as u32
+            }
+            Self::Other(code) => *code,
+        }
+    }
+
+    /// Returns whether the error indicates that the requested assembly, type or method could not be found.
+    #[must_use]
+    pub const fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            Self::TypeNotFound | Self::MissingMethod | Self::AssemblyNotFound
+        )
+    }
 }
 
 #[repr(u32)]
@@ -0,0 +1,156 @@
+use std::{
+    any,
+    collections::BTreeMap,
+    fmt::{self, Debug, Display},
+};
+
+use crate::{hostfxr::HostfxrContext, pdcstring::PdCStr};
+
+/// Name fragments of runtime properties whose values are redacted by default.
+const SENSITIVE_PROPERTY_NAME_FRAGMENTS: &[&str] = &[
+    "PASSWORD",
+    "PASSWD",
+    "SECRET",
+    "TOKEN",
+    "CREDENTIAL",
+    "APIKEY",
+    "API_KEY",
+];
+
+/// Replacement for redacted property values.
+const REDACTED: &str = "<redacted>";
+
+/// Returns whether the runtime property with the given name is considered sensitive by default.
+/// A property is considered sensitive if its name contains (ignoring ascii case) a fragment like `PASSWORD`, `SECRET` or `TOKEN`.
+#[must_use]
+pub fn is_sensitive_property_name(name: &PdCStr) -> bool {
+    // compares the code units directly, so the name does not have to be converted.
+    let name = name.as_slice();
+    SENSITIVE_PROPERTY_NAME_FRAGMENTS.iter().any(|fragment| {
+        name.windows(fragment.len()).any(|window| {
+            window
+                .iter()
+                .zip(fragment.chars())
+                .all(|(&unit, expected)| {
+                    char::from_u32(u32::from(unit))
+                        .is_some_and(|actual| actual.eq_ignore_ascii_case(&expected))
+                })
+        })
+    })
+}
+
+/// Returns the unqualified name of the context type `I`, like `InitializedForRuntimeConfig`.
+pub(crate) fn context_type_name<I>() -> &'static str {
+    let context_type = any::type_name::<I>();
+    context_type.rsplit("::").next().unwrap_or(context_type)
+}
+
+/// Returns the value of the given runtime property of the context for diagnostic output, if it is set.
+pub(crate) fn runtime_property<I>(context: &HostfxrContext<I>, name: &PdCStr) -> Option<String> {
+    context
+        .get_runtime_property_value(name)
+        .ok()
+        .map(|value| value.to_string_lossy())
+}
+
+/// Adapter for printing diagnostic information about a [`HostfxrContext`] intended for bug reports.
+/// Created using [`HostfxrContext::diagnostics`].
+///
+/// The output includes the handle, whether the context is primary, the context type and information
+/// about the selected runtime. Runtime properties are only included if enabled using [`ContextDiagnostics::with_properties`],
+/// in which case the values of sensitive properties are redacted (see [`is_sensitive_property_name`] and [`ContextDiagnostics::redact_with`]).
+pub struct ContextDiagnostics<'a, I> {
+    context: &'a HostfxrContext<I>,
+    include_properties: bool,
+    redact: Box<dyn Fn(&PdCStr) -> bool + 'a>,
+}
+
+impl<'a, I> ContextDiagnostics<'a, I> {
+    pub(crate) fn new(context: &'a HostfxrContext<I>) -> Self {
+        Self {
+            context,
+            include_properties: false,
+            redact: Box::new(is_sensitive_property_name),
+        }
+    }
+
+    /// Sets whether all runtime properties of the context should be included in the output.
+    #[must_use]
+    pub fn with_properties(mut self, include_properties: bool) -> Self {
+        self.include_properties = include_properties;
+        self
+    }
+
+    /// Sets the predicate used to decide whether the value of the property with the given name is redacted.
+    #[must_use]
+    pub fn redact_with(mut self, redact: impl Fn(&PdCStr) -> bool + 'a) -> Self {
+        self.redact = Box::new(redact);
+        self
+    }
+
+    fn property(&self, name: &PdCStr) -> Option<String> {
+        self.context
+            .get_runtime_property_value(name)
+            .ok()
+            .map(|value| self.format_value(name, value))
+    }
+
+    fn format_value(&self, name: &PdCStr, value: &PdCStr) -> String {
+        if (self.redact)(name) {
+            REDACTED.to_string()
+        } else {
+            value.to_string_lossy()
+        }
+    }
+}
+
+impl<I> Debug for ContextDiagnostics<'_, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = f.debug_struct("HostfxrContext");
+        s.field("handle", &self.context.handle().as_raw())
+            .field("is_primary", &self.context.is_primary())
+            .field("context_type", &context_type_name::<I>())
+            .field(
+                "runtime_version",
+                &self.property(crate::pdcstr!("FX_PRODUCT_VERSION")),
+            )
+            .field(
+                "runtime_identifier",
+                &self.property(crate::pdcstr!("RUNTIME_IDENTIFIER")),
+            );
+
+        if self.include_properties {
+            match self.context.runtime_properties() {
+                Ok(properties) => {
+                    let properties = properties
+                        .into_iter()
+                        .map(|(name, value)| {
+                            (name.to_string_lossy(), self.format_value(name, value))
+                        })
+                        .collect::<BTreeMap<_, _>>();
+                    s.field("properties", &properties);
+                }
+                Err(err) => {
+                    s.field("properties", &Err::<(), _>(err));
+                }
+            }
+        }
+
+        s.finish()
+    }
+}
+
+impl<I> Display for ContextDiagnostics<'_, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#?}")
+    }
+}
+
+impl<I> HostfxrContext<I> {
+    /// Returns an adapter for printing diagnostic information about this context.
+    /// See [`ContextDiagnostics`] for details.
+    #[must_use]
+    pub fn diagnostics(&self) -> ContextDiagnostics<'_, I> {
+        ContextDiagnostics::new(self)
+    }
+}
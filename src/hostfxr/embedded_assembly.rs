@@ -0,0 +1,77 @@
+use std::path::Path;
+
+#[macro_export]
+/// A macro for embedding a managed assembly (and optionally its symbols) into the binary as an [`EmbeddedAssembly`](crate::hostfxr::EmbeddedAssembly).
+///
+/// Paths are resolved relative to the file the macro is invoked in, like for [`include_bytes!`].
+/// Like for [`include_bytes!`], the paths can also be built using macros like [`concat!`] and [`env!`].
+///
+/// # Example
+/// ```rust,ignore
+/// # use netcorehost::include_assembly;
+/// let assembly = include_assembly!("../Plugin/bin/Plugin.dll", "../Plugin/bin/Plugin.pdb");
+/// context.load_embedded_assembly(&assembly).unwrap();
+/// ```
+macro_rules! include_assembly {
+    ($path:expr $(,)?) => {
+        $crate::hostfxr::EmbeddedAssembly::new($path, ::core::include_bytes!($path), None)
+    };
+    ($path:expr, $symbols_path:expr $(,)?) => {
+        $crate::hostfxr::EmbeddedAssembly::new(
+            $path,
+            ::core::include_bytes!($path),
+            Some(::core::include_bytes!($symbols_path)),
+        )
+    };
+}
+
+/// A managed assembly embedded into the binary, usually created using [`include_assembly!`](crate::include_assembly).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EmbeddedAssembly {
+    path: &'static str,
+    bytes: &'static [u8],
+    symbols: Option<&'static [u8]>,
+}
+
+impl EmbeddedAssembly {
+    /// Creates a new embedded assembly from the path it was embedded from, its contents and the contents of its symbols (`.pdb`) file.
+    #[must_use]
+    pub const fn new(
+        path: &'static str,
+        bytes: &'static [u8],
+        symbols: Option<&'static [u8]>,
+    ) -> Self {
+        Self {
+            path,
+            bytes,
+            symbols,
+        }
+    }
+
+    /// Returns the path the assembly was embedded from.
+    #[must_use]
+    pub const fn path(&self) -> &'static str {
+        self.path
+    }
+
+    /// Returns the name of the assembly, which is the file name of its path without the extension.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        Path::new(self.path)
+            .file_stem()
+            .and_then(|name| name.to_str())
+            .unwrap_or(self.path)
+    }
+
+    /// Returns the contents of the assembly.
+    #[must_use]
+    pub const fn bytes(&self) -> &'static [u8] {
+        self.bytes
+    }
+
+    /// Returns the contents of the symbols file of the assembly, if it was embedded.
+    #[must_use]
+    pub const fn symbols(&self) -> Option<&'static [u8]> {
+        self.symbols
+    }
+}
@@ -0,0 +1,53 @@
+/// Guard that temporarily changes the error mode of the current thread so that Windows does not show
+/// modal "missing DLL" dialogs while the hosting components load their native dependencies.
+/// The previous error mode is restored on drop. On other platforms this is a no-op.
+pub(crate) struct ErrorModeGuard {
+    #[cfg(windows)]
+    previous_mode: u32,
+}
+
+#[cfg(windows)]
+mod win32 {
+    pub const SEM_FAILCRITICALERRORS: u32 = 0x0001;
+    pub const SEM_NOOPENFILEERRORBOX: u32 = 0x8000;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        pub fn GetThreadErrorMode() -> u32;
+        pub fn SetThreadErrorMode(new_mode: u32, old_mode: *mut u32) -> i32;
+    }
+}
+
+impl ErrorModeGuard {
+    /// Suppresses critical error and open file error dialogs on the current thread.
+    #[must_use]
+    pub fn suppress_dialogs() -> Self {
+        #[cfg(windows)]
+        {
+            let previous_mode = unsafe { win32::GetThreadErrorMode() };
+            let new_mode =
+                previous_mode | win32::SEM_FAILCRITICALERRORS | win32::SEM_NOOPENFILEERRORBOX;
+            unsafe { win32::SetThreadErrorMode(new_mode, std::ptr::null_mut()) };
+            Self { previous_mode }
+        }
+        #[cfg(not(windows))]
+        {
+            Self {}
+        }
+    }
+
+    /// Suppresses dialogs if `suppress` is `true`, otherwise returns [`None`].
+    #[must_use]
+    pub fn suppress_dialogs_if(suppress: bool) -> Option<Self> {
+        suppress.then(Self::suppress_dialogs)
+    }
+}
+
+impl Drop for ErrorModeGuard {
+    fn drop(&mut self) {
+        #[cfg(windows)]
+        unsafe {
+            win32::SetThreadErrorMode(self.previous_mode, std::ptr::null_mut());
+        }
+    }
+}
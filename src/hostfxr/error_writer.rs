@@ -0,0 +1,148 @@
+use std::{cell::RefCell, marker::PhantomData, mem, rc::Rc};
+
+use crate::{
+    bindings::{char_t, hostfxr::hostfxr_error_writer_fn},
+    error::HostingResult,
+    hostfxr::{Hostfxr, SharedHostfxrLibrary},
+    pdcstring::PdCStr,
+};
+
+type ErrorWriter = Box<dyn FnMut(&PdCStr)>;
+
+thread_local! {
+    static ERROR_WRITERS: RefCell<Vec<ErrorWriter>> = const { RefCell::new(Vec::new()) };
+    static LAST_ERROR_MESSAGES: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+extern "C" fn error_writer_callback(message: *const char_t) {
+    let message = unsafe { PdCStr::from_str_ptr(message) };
+    ERROR_WRITERS.with(|writers| {
+        // the writer may call back into hostfxr, messages written during that call are dropped.
+        if let Ok(mut writers) = writers.try_borrow_mut() {
+            if let Some(writer) = writers.last_mut() {
+                writer(message);
+            }
+        }
+    });
+}
+
+impl Hostfxr {
+    /// Sets a callback which receives the error messages written by the hosting components on the current thread,
+    /// like details about a missing framework or an invalid `.runtimeconfig.json`.
+    /// By default, these messages are written to stderr.
+    ///
+    /// Error writers are registered per thread. The returned guard restores the previously registered writer when
+    /// it is dropped, so guards should be dropped in the reverse order of their creation.
+    ///
+    /// The writer is propagated to the runtime when a context is initialized, so messages written while
+    /// initializing or running a context are also captured.
+    ///
+    /// # Note
+    /// The writer must not panic, as panics cannot unwind through the hosting components.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use netcorehost::{nethost, pdcstr};
+    /// # use std::{cell::RefCell, rc::Rc};
+    /// let hostfxr = nethost::load_hostfxr().unwrap();
+    /// let messages = Rc::new(RefCell::new(Vec::new()));
+    /// let _guard = hostfxr.set_error_writer({
+    ///     let messages = Rc::clone(&messages);
+    ///     move |message| messages.borrow_mut().push(message.to_string_lossy())
+    /// });
+    /// let result = hostfxr.initialize_for_runtime_config(pdcstr!("Missing.runtimeconfig.json"));
+    /// assert!(result.is_err());
+    /// println!("{:?}", messages.borrow());
+    /// ```
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+    pub fn set_error_writer(&self, writer: impl FnMut(&PdCStr) + 'static) -> ErrorWriterGuard {
+        ErrorWriterGuard::push(&self.lib, Box::new(writer))
+    }
+}
+
+/// Returns the error messages written by the hosting components during the last failed call on the current thread
+/// that captured error messages, see [`HostfxrLoadOptions::capture_error_messages`].
+///
+/// The messages are cleared by every successful call that captures error messages.
+///
+/// [`HostfxrLoadOptions::capture_error_messages`]: crate::hostfxr::HostfxrLoadOptions::capture_error_messages
+#[must_use]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub fn last_error_messages() -> Vec<String> {
+    LAST_ERROR_MESSAGES.with(|messages| messages.borrow().clone())
+}
+
+/// Collects the error messages written during a single call into the hosting components.
+pub(crate) struct ErrorMessageCapture {
+    messages: Rc<RefCell<Vec<String>>>,
+    _guard: ErrorWriterGuard,
+}
+
+impl ErrorMessageCapture {
+    pub(crate) fn start_if(lib: &SharedHostfxrLibrary, enabled: bool) -> Option<Self> {
+        if !enabled {
+            return None;
+        }
+
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let guard = ErrorWriterGuard::push(lib, {
+            let messages = Rc::clone(&messages);
+            Box::new(move |message: &PdCStr| messages.borrow_mut().push(message.to_string_lossy()))
+        });
+        Some(Self {
+            messages,
+            _guard: guard,
+        })
+    }
+
+    pub(crate) fn finish(capture: Option<Self>, result: i32) {
+        let Some(capture) = capture else {
+            return;
+        };
+
+        let messages = if HostingResult::from(result).is_err() {
+            mem::take(&mut *capture.messages.borrow_mut())
+        } else {
+            Vec::new()
+        };
+        LAST_ERROR_MESSAGES.with(|last| *last.borrow_mut() = messages);
+    }
+}
+
+/// A guard restoring the previous error writer of the current thread when dropped,
+/// see [`Hostfxr::set_error_writer`].
+#[must_use]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub struct ErrorWriterGuard {
+    lib: SharedHostfxrLibrary,
+    previous: Option<hostfxr_error_writer_fn>,
+    depth: usize,
+    // error writers are registered per thread.
+    not_send: PhantomData<*const ()>,
+}
+
+impl ErrorWriterGuard {
+    fn push(lib: &SharedHostfxrLibrary, writer: ErrorWriter) -> Self {
+        let depth = ERROR_WRITERS.with(|writers| {
+            let mut writers = writers.borrow_mut();
+            writers.push(writer);
+            writers.len() - 1
+        });
+        let previous =
+            unsafe { lib.hostfxr_set_error_writer(Some(error_writer_callback)) }.unwrap();
+
+        Self {
+            lib: lib.clone(),
+            previous,
+            depth,
+            not_send: PhantomData,
+        }
+    }
+}
+
+impl Drop for ErrorWriterGuard {
+    fn drop(&mut self) {
+        unsafe { self.lib.hostfxr_set_error_writer(self.previous) };
+        ERROR_WRITERS.with(|writers| writers.borrow_mut().truncate(self.depth));
+    }
+}
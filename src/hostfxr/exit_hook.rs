@@ -0,0 +1,84 @@
+use std::{
+    ffi::c_int,
+    mem,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, MutexGuard, Once, PoisonError,
+    },
+};
+
+use crate::hostfxr::runtime_started;
+
+type ExitCallback = Box<dyn FnOnce() + Send>;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static EXITING: AtomicBool = AtomicBool::new(false);
+static REGISTER: Once = Once::new();
+static CALLBACKS: Mutex<Vec<ExitCallback>> = Mutex::new(Vec::new());
+
+extern "C" {
+    fn atexit(callback: extern "C" fn()) -> c_int;
+}
+
+/// Enables the process exit hook, which shuts down the state of this crate in order before the runtime is torn down.
+///
+/// Contexts and loaders stored in globals are never dropped or are dropped while the process is exiting,
+/// at which point `CoreCLR` and the hosting components may already be shut down and closing a context can hang or crash.
+/// With the hook enabled, the following happens when the process exits:
+/// 1. The callbacks registered using [`on_process_exit`] are run in reverse order of registration.
+///    This is the place to drop global contexts, while the runtime is still usable.
+/// 2. [`is_process_exiting`] starts returning `true` and contexts dropped afterwards are leaked instead of closed.
+///
+/// The hook is registered using `atexit` once the runtime has been loaded (or immediately if it already is),
+/// so that it runs before the exit handlers installed by the runtime. For applications run through this crate,
+/// the runtime is only known to be loaded once they returned (see [`runtime_started`]). In a dynamic library, the handlers run
+/// when the library is unloaded, which includes `DLL_PROCESS_DETACH` on Windows.
+///
+/// Without calling this function, nothing is registered and contexts are always closed on drop.
+pub fn enable_exit_hook() {
+    ENABLED.store(true, Ordering::Release);
+    if runtime_started() {
+        register_exit_hook();
+    }
+}
+
+/// Registers a callback that is run by the exit hook, see [`enable_exit_hook`].
+///
+/// Callbacks are only run if the hook is enabled. Panics in callbacks are caught and ignored.
+pub fn on_process_exit(callback: impl FnOnce() + Send + 'static) {
+    lock_callbacks().push(Box::new(callback));
+}
+
+/// Returns whether the process is exiting and the exit hook has finished running the registered callbacks.
+#[must_use]
+pub fn is_process_exiting() -> bool {
+    EXITING.load(Ordering::Acquire)
+}
+
+pub(crate) fn runtime_loaded() {
+    if ENABLED.load(Ordering::Acquire) {
+        register_exit_hook();
+    }
+}
+
+fn register_exit_hook() {
+    REGISTER.call_once(|| {
+        // registration only fails if the implementation limit of handlers is reached, in which case
+        // the process exits like it does without the hook.
+        let _ = unsafe { atexit(run_exit_hook) };
+    });
+}
+
+fn lock_callbacks() -> MutexGuard<'static, Vec<ExitCallback>> {
+    CALLBACKS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+extern "C" fn run_exit_hook() {
+    let callbacks = mem::take(&mut *lock_callbacks());
+    for callback in callbacks.into_iter().rev() {
+        // unwinding out of an exit handler aborts the process.
+        let _ = panic::catch_unwind(AssertUnwindSafe(callback));
+    }
+    EXITING.store(true, Ordering::Release);
+}
@@ -0,0 +1,140 @@
+use std::{
+    io, panic,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use thiserror::Error;
+
+use crate::{
+    hostfxr::{
+        AssemblyDelegateLoader, DelegateSignature, GetManagedFunctionError, ManagedFunction,
+        ManagedFunctionWithDefaultSignature,
+    },
+    pdcstring::PdCStr,
+};
+
+impl AssemblyDelegateLoader {
+    /// Like [`get_function`](AssemblyDelegateLoader::get_function), but gives up if the function could not be
+    /// resolved within `timeout`.
+    ///
+    /// Resolving the first function loads the assembly, which can stall for a long time if it is located on a slow
+    /// network share. The resolution is performed on a separate thread, which cannot be interrupted once it has called
+    /// into the runtime. If the timeout elapses, the thread is detached and its result discarded, so the assembly may
+    /// still be loaded in the background.
+    ///
+    /// The detached thread owns a clone of this loader, which keeps the hostfxr library and the delegates of the
+    /// context alive until the resolution returns. If it never returns, they are leaked for the rest of the process,
+    /// even if the context is closed.
+    pub fn get_function_with_timeout<F: DelegateSignature>(
+        &self,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+        delegate_type_name: &PdCStr,
+        timeout: Duration,
+    ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionTimeoutError> {
+        let delegate_type_name = delegate_type_name.to_owned();
+        self.resolve_with_timeout(
+            type_name,
+            method_name,
+            timeout,
+            move |loader, ty, method| loader.get_function::<F>(ty, method, &delegate_type_name),
+        )
+    }
+
+    /// Like [`get_function_with_default_signature`](AssemblyDelegateLoader::get_function_with_default_signature),
+    /// but gives up if the function could not be resolved within `timeout`,
+    /// see [`get_function_with_timeout`](AssemblyDelegateLoader::get_function_with_timeout).
+    pub fn get_function_with_default_signature_with_timeout(
+        &self,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+        timeout: Duration,
+    ) -> Result<ManagedFunctionWithDefaultSignature, GetManagedFunctionTimeoutError> {
+        self.resolve_with_timeout(type_name, method_name, timeout, |loader, ty, method| {
+            loader.get_function_with_default_signature(ty, method)
+        })
+    }
+
+    /// Like [`get_function_with_unmanaged_callers_only`](AssemblyDelegateLoader::get_function_with_unmanaged_callers_only),
+    /// but gives up if the function could not be resolved within `timeout`,
+    /// see [`get_function_with_timeout`](AssemblyDelegateLoader::get_function_with_timeout).
+    #[cfg(feature = "net5_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+    pub fn get_function_with_unmanaged_callers_only_with_timeout<F: DelegateSignature>(
+        &self,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+        timeout: Duration,
+    ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionTimeoutError> {
+        self.resolve_with_timeout(type_name, method_name, timeout, |loader, ty, method| {
+            loader.get_function_with_unmanaged_callers_only::<F>(ty, method)
+        })
+    }
+
+    fn resolve_with_timeout<R: Send + 'static>(
+        &self,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+        timeout: Duration,
+        resolve: impl FnOnce(&Self, &PdCStr, &PdCStr) -> Result<R, GetManagedFunctionError>
+            + Send
+            + 'static,
+    ) -> Result<R, GetManagedFunctionTimeoutError> {
+        let loader = self.clone();
+        let type_name = type_name.to_owned();
+        let method_name = method_name.to_owned();
+        let (sender, receiver) = mpsc::sync_channel(1);
+
+        let handle = thread::Builder::new()
+            .name(super::thread_name("netcorehost-get-function"))
+            .spawn(move || {
+                let result = resolve(&loader, &type_name, &method_name);
+                // the receiver is gone if the timeout elapsed.
+                let _ = sender.send(result);
+            })
+            .map_err(GetManagedFunctionTimeoutError::Spawn)?;
+
+        match receiver.recv_timeout(timeout) {
+            Ok(result) => Ok(result?),
+            Err(RecvTimeoutError::Timeout) => {
+                Err(GetManagedFunctionTimeoutError::TimedOut { timeout })
+            }
+            Err(RecvTimeoutError::Disconnected) => match handle.join() {
+                Err(payload) => panic::resume_unwind(payload),
+                Ok(()) => unreachable!("resolution thread exited without sending a result"),
+            },
+        }
+    }
+}
+
+/// Enum for errors that can occur while resolving a managed function pointer with a timeout.
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+#[non_exhaustive]
+pub enum GetManagedFunctionTimeoutError {
+    /// The function could not be resolved.
+    #[error(transparent)]
+    Failed(#[from] GetManagedFunctionError),
+    /// The function was not resolved within the given timeout.
+    /// The resolution continues in the background and may still load the assembly. Until it returns, the hostfxr
+    /// library and the delegates of the context stay loaded.
+    #[error("Resolving the managed function did not complete within {timeout:?}.")]
+    TimedOut {
+        /// The timeout that elapsed.
+        timeout: Duration,
+    },
+    /// The thread performing the resolution could not be spawned.
+    #[error("Failed to spawn the thread resolving the managed function: {0}")]
+    Spawn(#[source] io::Error),
+}
+
+impl GetManagedFunctionTimeoutError {
+    /// Returns whether the resolution was abandoned because the timeout elapsed.
+    /// Callers may want to retry in this case, as the assembly may have been loaded in the meantime.
+    #[must_use]
+    pub const fn is_timeout(&self) -> bool {
+        matches!(self, Self::TimedOut { .. })
+    }
+}
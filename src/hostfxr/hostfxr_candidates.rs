@@ -0,0 +1,199 @@
+use std::{
+    env::consts::{DLL_PREFIX, DLL_SUFFIX},
+    fs, io,
+    path::{Path, PathBuf},
+    slice,
+};
+
+use thiserror::Error;
+
+use crate::hostfxr::{runtime_version::Version, Hostfxr, HostfxrLoadOptions, RuntimeVersionReq};
+
+/// A hostfxr library found in the `host/fxr/<version>` directory of a .NET installation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub struct HostfxrCandidate {
+    version: String,
+    path: PathBuf,
+}
+
+impl HostfxrCandidate {
+    /// Returns the version of the library, which is the name of the directory containing it.
+    #[must_use]
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Returns the path to the library.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Loads the library.
+    pub fn load(&self) -> Result<Hostfxr, crate::dlopen2::Error> {
+        Hostfxr::load_from_path(&self.path)
+    }
+
+    /// Loads the library using the given options.
+    pub fn load_with_options(
+        &self,
+        options: &HostfxrLoadOptions,
+    ) -> Result<Hostfxr, crate::dlopen2::Error> {
+        Hostfxr::load_from_path_with_options(&self.path, options)
+    }
+}
+
+/// The hostfxr libraries installed side by side in a .NET installation, ordered from the lowest to the highest
+/// version.
+///
+/// [`nethost`](crate::nethost) always picks the highest version, which can be avoided by selecting one explicitly
+/// using [`load_version`](HostfxrCandidates::load_version).
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::hostfxr::HostfxrCandidates;
+/// let candidates = HostfxrCandidates::enumerate("/usr/share/dotnet").unwrap();
+/// for candidate in &candidates {
+///     println!("{} at {}", candidate.version(), candidate.path().display());
+/// }
+/// let hostfxr = candidates.load_version(&"~8.0".parse().unwrap()).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub struct HostfxrCandidates {
+    dotnet_root: PathBuf,
+    candidates: Vec<HostfxrCandidate>,
+}
+
+impl HostfxrCandidates {
+    /// Enumerates the hostfxr libraries under `host/fxr` in the given .NET installation directory.
+    ///
+    /// Directories that are not named after a version or do not contain the library are skipped.
+    /// An installation without a `host/fxr` directory has no candidates.
+    pub fn enumerate(dotnet_root: impl AsRef<Path>) -> io::Result<Self> {
+        let dotnet_root = dotnet_root.as_ref().to_path_buf();
+        let library_name = format!("{DLL_PREFIX}hostfxr{DLL_SUFFIX}");
+
+        let entries = match fs::read_dir(dotnet_root.join("host").join("fxr")) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Ok(Self {
+                    dotnet_root,
+                    candidates: Vec::new(),
+                })
+            }
+            Err(err) => return Err(err),
+        };
+
+        let mut candidates = Vec::new();
+        for entry in entries {
+            let entry = entry?;
+            let Ok(version) = entry.file_name().into_string() else {
+                continue;
+            };
+            let Some(parsed) = Version::parse(&version) else {
+                continue;
+            };
+            let path = entry.path().join(&library_name);
+            if path.is_file() {
+                candidates.push((parsed, HostfxrCandidate { version, path }));
+            }
+        }
+        candidates.sort_by(|(a, a_candidate), (b, b_candidate)| {
+            a.cmp(b)
+                .then_with(|| a_candidate.version.cmp(&b_candidate.version))
+        });
+
+        Ok(Self {
+            dotnet_root,
+            candidates: candidates
+                .into_iter()
+                .map(|(_, candidate)| candidate)
+                .collect(),
+        })
+    }
+
+    /// Returns the .NET installation directory the candidates were enumerated in.
+    #[must_use]
+    pub fn dotnet_root(&self) -> &Path {
+        &self.dotnet_root
+    }
+
+    /// Returns an iterator over the candidates, from the lowest to the highest version.
+    pub fn iter(&self) -> slice::Iter<'_, HostfxrCandidate> {
+        self.candidates.iter()
+    }
+
+    /// Returns the number of candidates.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.candidates.len()
+    }
+
+    /// Returns whether no hostfxr library was found.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.candidates.is_empty()
+    }
+
+    /// Returns the candidate with the highest version, which is the one picked by [`nethost`](crate::nethost).
+    #[must_use]
+    pub fn latest(&self) -> Option<&HostfxrCandidate> {
+        self.candidates.last()
+    }
+
+    /// Returns the candidate with the highest version satisfying the given requirement.
+    #[must_use]
+    pub fn find(&self, requirement: &RuntimeVersionReq) -> Option<&HostfxrCandidate> {
+        self.candidates
+            .iter()
+            .rev()
+            .find(|candidate| requirement.matches(&candidate.version))
+    }
+
+    /// Loads the hostfxr library with the highest version satisfying the given requirement.
+    pub fn load_version(
+        &self,
+        requirement: &RuntimeVersionReq,
+    ) -> Result<Hostfxr, LoadHostfxrVersionError> {
+        let candidate =
+            self.find(requirement)
+                .ok_or_else(|| LoadHostfxrVersionError::NoMatchingVersion {
+                    required: requirement.to_string(),
+                    available: self
+                        .candidates
+                        .iter()
+                        .map(|candidate| candidate.version.clone())
+                        .collect(),
+                })?;
+        Ok(candidate.load()?)
+    }
+}
+
+impl<'a> IntoIterator for &'a HostfxrCandidates {
+    type Item = &'a HostfxrCandidate;
+    type IntoIter = slice::Iter<'a, HostfxrCandidate>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Enum for errors that can occur while loading a hostfxr library using [`HostfxrCandidates::load_version`].
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+#[non_exhaustive]
+pub enum LoadHostfxrVersionError {
+    /// None of the installed hostfxr versions satisfies the requirement.
+    #[error("No hostfxr version satisfies the requirement {required} (installed: {available:?}).")]
+    NoMatchingVersion {
+        /// The required version.
+        required: String,
+        /// The installed versions.
+        available: Vec<String>,
+    },
+    /// An error occured while loading the hostfxr library.
+    #[error(transparent)]
+    DlOpen(#[from] crate::dlopen2::Error),
+}
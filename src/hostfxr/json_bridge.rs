@@ -0,0 +1,179 @@
+use std::{ffi::c_void, fmt, marker::PhantomData, ptr, slice};
+
+use serde::{de::DeserializeOwned, Serialize};
+use thiserror::Error;
+
+use crate::{
+    hostfxr::{
+        abort_on_panic, AssemblyDelegateLoader, FfiSafe, GetManagedFunctionError,
+        ManagedFunctionWithDefaultSignature,
+    },
+    pdcstring::PdCStr,
+};
+
+// the layout of `JsonBridge.Args` in `MANAGED_JSON_BRIDGE`.
+#[repr(C)]
+struct JsonBridgeArgs {
+    request: *const u8,
+    request_length: i32,
+    response: *mut c_void,
+    respond: extern "system" fn(*mut c_void, *const u8, i32),
+}
+
+unsafe impl FfiSafe for JsonBridgeArgs {}
+
+extern "system" fn respond(response: *mut c_void, data: *const u8, length: i32) {
+    abort_on_panic(|| {
+        let response = unsafe { &mut *response.cast::<Option<Vec<u8>>>() };
+        let bytes = match usize::try_from(length) {
+            Ok(length) if length > 0 => unsafe { slice::from_raw_parts(data, length) },
+            _ => &[],
+        };
+        *response = Some(bytes.to_vec());
+    });
+}
+
+/// A managed method with the default signature that takes a request and returns a response as JSON,
+/// created using [`AssemblyDelegateLoader::get_json_function`].
+///
+/// The managed method receives a pointer to the UTF-8 encoded request and a callback for the response in `args`,
+/// which is implemented by `JsonBridge.Invoke` in [`MANAGED_JSON_BRIDGE`].
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{hostfxr::AssemblyDelegateLoader, pdcstr};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize)]
+/// #[serde(rename_all = "camelCase")]
+/// struct SearchRequest {
+///     query: String,
+///     max_results: u32,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct SearchResponse {
+///     items: Vec<String>,
+/// }
+///
+/// # fn test(fn_loader: AssemblyDelegateLoader) {
+/// // public static int Search(IntPtr args, int sizeBytes) =>
+/// //     JsonBridge.Invoke(args, sizeBytes, (SearchRequest request) => Index.Search(request));
+/// let search = fn_loader
+///     .get_json_function::<SearchRequest, SearchResponse>(
+///         pdcstr!("Plugin.Api, Plugin"),
+///         pdcstr!("Search"),
+///     )
+///     .unwrap();
+/// let response = search
+///     .call(&SearchRequest {
+///         query: "hosting".to_string(),
+///         max_results: 10,
+///     })
+///     .unwrap();
+/// println!("{:?}", response.items);
+/// # }
+/// ```
+///
+/// [`MANAGED_JSON_BRIDGE`]: super::MANAGED_JSON_BRIDGE
+pub struct JsonFunction<Req: ?Sized, Resp> {
+    function: ManagedFunctionWithDefaultSignature,
+    marker: PhantomData<fn(&Req) -> Resp>,
+}
+
+impl<Req: Serialize + ?Sized, Resp: DeserializeOwned> JsonFunction<Req, Resp> {
+    /// Serializes the request, calls the managed method and deserializes its response.
+    ///
+    /// # Panics
+    /// Panics if the serialized request is longer than [`i32::MAX`] bytes.
+    pub fn call(&self, request: &Req) -> Result<Resp, JsonCallError> {
+        let request = serde_json::to_vec(request).map_err(JsonCallError::Serialize)?;
+        let mut response = None::<Vec<u8>>;
+        let args = JsonBridgeArgs {
+            request: request.as_ptr(),
+            request_length: i32::try_from(request.len())
+                .expect("request is too large to be passed to managed code"),
+            response: ptr::from_mut(&mut response).cast(),
+            respond,
+        };
+
+        // `JsonBridge.Invoke` only reads the arguments and writes the response through `respond`.
+        let result = unsafe { self.function.call_with_struct(&args) };
+        let response = response.ok_or(JsonCallError::NoResponse(result))?;
+        if result != 0 {
+            return Err(JsonCallError::Managed {
+                code: result,
+                message: String::from_utf8_lossy(&response).into_owned(),
+            });
+        }
+        serde_json::from_slice(&response).map_err(JsonCallError::Deserialize)
+    }
+
+    /// Returns the underlying function with the default signature.
+    #[must_use]
+    pub fn function(&self) -> ManagedFunctionWithDefaultSignature {
+        self.function
+    }
+}
+
+impl<Req: ?Sized, Resp> Clone for JsonFunction<Req, Resp> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Req: ?Sized, Resp> Copy for JsonFunction<Req, Resp> {}
+
+impl<Req: ?Sized, Resp> fmt::Debug for JsonFunction<Req, Resp> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JsonFunction")
+            .field("function", &self.function)
+            .finish()
+    }
+}
+
+impl AssemblyDelegateLoader {
+    /// Loads a managed method with the default signature that exchanges its request and response as JSON,
+    /// see [`JsonFunction`].
+    ///
+    /// The managed method has to forward its arguments to `JsonBridge.Invoke` from [`MANAGED_JSON_BRIDGE`].
+    ///
+    /// [`MANAGED_JSON_BRIDGE`]: super::MANAGED_JSON_BRIDGE
+    pub fn get_json_function<Req: Serialize + ?Sized, Resp: DeserializeOwned>(
+        &self,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+    ) -> Result<JsonFunction<Req, Resp>, GetManagedFunctionError> {
+        let function = self.get_function_with_default_signature(type_name, method_name)?;
+        Ok(JsonFunction {
+            function,
+            marker: PhantomData,
+        })
+    }
+}
+
+/// Enum for errors that can occur while calling a [`JsonFunction`].
+#[derive(Debug, Error)]
+#[cfg_attr(
+    feature = "doc-cfg",
+    doc(cfg(all(feature = "serde-bridge", feature = "net5_0")))
+)]
+#[non_exhaustive]
+pub enum JsonCallError {
+    /// The request could not be serialized.
+    #[error("The request could not be serialized.")]
+    Serialize(#[source] serde_json::Error),
+    /// The response could not be deserialized.
+    #[error("The response could not be deserialized.")]
+    Deserialize(#[source] serde_json::Error),
+    /// The managed method failed with the given result and error message.
+    #[error("The managed method failed ({code}): {message}")]
+    Managed {
+        /// The value returned by the managed method.
+        code: i32,
+        /// The error message passed back by the managed method.
+        message: String,
+    },
+    /// The managed method returned the given value without passing back a response.
+    #[error("The managed method returned {0} without passing back a response.")]
+    NoResponse(i32),
+}
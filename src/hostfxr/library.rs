@@ -1,12 +1,13 @@
 use crate::{
     dlopen2::wrapper::Container,
     error::{HostingError, HostingResult},
+    hostfxr::ErrorModeGuard,
     pdcstring::PdCString,
 };
 use derive_more::From;
 use std::{
-    env::consts::EXE_SUFFIX,
-    ffi::OsString,
+    env::consts::{DLL_PREFIX, DLL_SUFFIX, EXE_SUFFIX},
+    ffi::{OsStr, OsString},
     path::{Path, PathBuf},
     sync::Arc,
 };
@@ -23,6 +24,111 @@ pub struct Hostfxr {
     /// The underlying hostfxr library.
     pub lib: SharedHostfxrLibrary,
     pub(crate) dotnet_exe: PdCString,
+    #[cfg(any(
+        feature = "net9_0",
+        all(feature = "netcore3_0", feature = "strict-checks")
+    ))]
+    pub(crate) library_path: PathBuf,
+    pub(crate) suppress_error_dialogs: bool,
+    pub(crate) capture_error_messages: bool,
+}
+
+/// Options controlling how the hostfxr library is loaded and used.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HostfxrLoadOptions {
+    suppress_error_dialogs: bool,
+    capture_error_messages: bool,
+    library_name: Option<OsString>,
+}
+
+impl Default for HostfxrLoadOptions {
+    fn default() -> Self {
+        Self {
+            suppress_error_dialogs: false,
+            capture_error_messages: false,
+            library_name: None,
+        }
+    }
+}
+
+impl HostfxrLoadOptions {
+    /// Creates the default load options.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether Windows should be prevented from showing modal error dialogs (like the "missing DLL" dialog)
+    /// while hostfxr and the native hosting components it depends on are being loaded.
+    /// If enabled, the thread error mode is temporarily set to include `SEM_FAILCRITICALERRORS` and
+    /// `SEM_NOOPENFILEERRORBOX` and restored afterwards.
+    /// This is disabled by default, as it changes how Windows reports load failures to the user, and has no effect
+    /// on other platforms.
+    #[must_use]
+    pub fn suppress_error_dialogs(mut self, suppress: bool) -> Self {
+        self.suppress_error_dialogs = suppress;
+        self
+    }
+
+    /// Gets whether error dialogs are suppressed while loading.
+    #[must_use]
+    pub const fn suppresses_error_dialogs(&self) -> bool {
+        self.suppress_error_dialogs
+    }
+
+    /// Sets whether the error messages written by the hosting components while initializing a context or loading
+    /// the runtime are captured instead of being written to stderr or passed to the writer set using
+    /// [`Hostfxr::set_error_writer`].
+    /// The messages of the last failed call on the current thread can be retrieved using [`last_error_messages`].
+    /// This is disabled by default.
+    ///
+    /// [`last_error_messages`]: crate::hostfxr::last_error_messages
+    #[cfg(feature = "netcore3_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+    #[must_use]
+    pub fn capture_error_messages(mut self, capture: bool) -> Self {
+        self.capture_error_messages = capture;
+        self
+    }
+
+    /// Gets whether error messages are captured.
+    #[must_use]
+    pub const fn captures_error_messages(&self) -> bool {
+        self.capture_error_messages
+    }
+
+    /// Sets the file name of the hostfxr library, for runtimes that ship it under a non-standard name
+    /// (like private runtime forks).
+    ///
+    /// The name replaces the file name of the path located by [`nethost`](crate::nethost), so the library is
+    /// still expected to be in the same directory as the regular hostfxr library.
+    /// Paths passed to [`Hostfxr::load_from_path_with_options`] are used as is.
+    ///
+    /// # Note
+    /// Only the file name can be changed, the library still has to export the standard `hostfxr_*` symbols.
+    /// The symbols are resolved by the bindings of `hostfxr-sys`, which look them up by their fixed names when the
+    /// library is loaded, so there is no way to map them to other names. Builds exporting renamed symbols have to be
+    /// loaded through a shim library that re-exports them under the standard names.
+    #[must_use]
+    pub fn library_name(mut self, name: impl Into<OsString>) -> Self {
+        self.library_name = Some(name.into());
+        self
+    }
+
+    /// Gets the custom file name of the hostfxr library, if any.
+    #[must_use]
+    pub fn get_library_name(&self) -> Option<&OsStr> {
+        self.library_name.as_deref()
+    }
+
+    #[cfg(feature = "nethost")]
+    pub(crate) fn apply_library_name(&self, path: impl Into<PathBuf>) -> PathBuf {
+        let path = path.into();
+        match &self.library_name {
+            Some(name) => path.with_file_name(name),
+            None => path,
+        }
+    }
 }
 
 fn find_dotnet_bin(hostfxr_path: impl AsRef<Path>) -> PathBuf {
@@ -47,13 +153,48 @@ fn find_dotnet_bin(hostfxr_path: impl AsRef<Path>) -> PathBuf {
 impl Hostfxr {
     /// Loads the hostfxr library from the given path.
     pub fn load_from_path(path: impl AsRef<Path>) -> Result<Self, crate::dlopen2::Error> {
+        Self::load_from_path_with_options(path, &HostfxrLoadOptions::default())
+    }
+
+    /// Loads the hostfxr library from the given path using the given options.
+    pub fn load_from_path_with_options(
+        path: impl AsRef<Path>,
+        options: &HostfxrLoadOptions,
+    ) -> Result<Self, crate::dlopen2::Error> {
         let path = path.as_ref();
-        let lib = SharedHostfxrLibrary::new(unsafe { Container::load(path) }?);
+        let lib = {
+            let _error_mode = ErrorModeGuard::suppress_dialogs_if(options.suppress_error_dialogs);
+            SharedHostfxrLibrary::new(unsafe { Container::load(path) }?)
+        };
 
         // Some APIs of hostfxr.dll require a path to the dotnet executable, so we try to locate it here based on the hostfxr path.
         let dotnet_exe = PdCString::from_os_str(find_dotnet_bin(path)).unwrap();
 
-        Ok(Self { lib, dotnet_exe })
+        Ok(Self {
+            lib,
+            dotnet_exe,
+            #[cfg(any(
+                feature = "net9_0",
+                all(feature = "netcore3_0", feature = "strict-checks")
+            ))]
+            library_path: path.to_path_buf(),
+            suppress_error_dialogs: options.suppress_error_dialogs,
+            capture_error_messages: options.capture_error_messages,
+        })
+    }
+
+    /// Loads the hostfxr library of a self-contained application from the given application directory.
+    ///
+    /// Self-contained applications ship the runtime including hostfxr next to the application instead of using a shared installation.
+    pub fn load_self_contained(app_dir: impl AsRef<Path>) -> Result<Self, crate::dlopen2::Error> {
+        let app_dir = app_dir.as_ref();
+        let mut hostfxr =
+            Self::load_from_path(app_dir.join(format!("{DLL_PREFIX}hostfxr{DLL_SUFFIX}")))?;
+        // there is no dotnet executable in a self-contained layout, the app directory takes its role.
+        let mut dotnet_exe = app_dir.join("dotnet").into_os_string();
+        dotnet_exe.push(EXE_SUFFIX);
+        hostfxr.dotnet_exe = PdCString::from_os_str(dotnet_exe).unwrap();
+        Ok(hostfxr)
     }
 
     /// Locates the hostfxr library using [`nethost`](crate::nethost) and loads it.
@@ -44,8 +44,23 @@ impl Hostfxr {
         let args = [&self.dotnet_exe, app_path]
             .into_iter()
             .chain(args.iter().map(|s| s.as_ref()))
-            .map(|s| s.as_ptr())
             .collect::<Vec<_>>();
+        self.run_main(&args)
+    }
+
+    /// Runs the `dotnet` muxer in the current process with the given command line, like the native `dotnet` executable does.
+    ///
+    /// In contrast to [`run_app_with_args`](Hostfxr::run_app_with_args), `args` is passed as is and has to include the
+    /// path of the host as its first element, e.g. `["/usr/share/dotnet/dotnet", "build", "MyProject.csproj"]` or
+    /// `["/usr/share/dotnet/dotnet", "MyApp.dll", "arg"]`. This allows running SDK commands as well as applications.
+    ///
+    /// # Note
+    /// This function does not return until the command completes execution.
+    /// If an application was run successfully, this value will return the exit code of the application.
+    /// Otherwise, it will return an error code indicating the failure.
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore1_0")))]
+    pub fn run_main<A: AsRef<PdCStr>>(&self, args: &[A]) -> AppOrHostingResult {
+        let args = args.iter().map(|s| s.as_ref().as_ptr()).collect::<Vec<_>>();
 
         let result = unsafe {
             self.lib
@@ -53,6 +68,8 @@ impl Hostfxr {
         }
         .unwrap_or(UNSUPPORTED_HOST_VERSION_ERROR_CODE);
 
-        AppOrHostingResult::from(result)
+        let result = AppOrHostingResult::from(result);
+        super::mark_runtime_started_by_app(result);
+        result
     }
 }
@@ -0,0 +1,53 @@
+use std::path::PathBuf;
+
+use crate::{
+    error::HostingError,
+    hostfxr::Hostfxr,
+    pdcstring::{PdCStr, PdUChar},
+};
+
+impl Hostfxr {
+    /// Determine the directory location of the SDK, accounting for `global.json` and multi-level lookup policy,
+    /// using the original `hostfxr_resolve_sdk` export of .NET Core 2.0.
+    ///
+    /// # Arguments
+    ///  * `exe_dir` - main directory where SDKs are located in `sdk\[version]` sub-folders.
+    ///  * `working_dir` - directory where the search for `global.json` will start and proceed upwards
+    ///
+    /// Returns `None` if no SDK could be resolved. In contrast to `resolve_sdk`, pre-release versions are always
+    /// allowed and the path of the `global.json` that was used is not reported.
+    #[cfg_attr(
+        feature = "netcore2_1",
+        deprecated(note = "Use `Hostfxr::resolve_sdk` instead")
+    )]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore2_0")))]
+    pub fn resolve_sdk_legacy(
+        &self,
+        exe_dir: &PdCStr,
+        working_dir: &PdCStr,
+    ) -> Result<Option<PathBuf>, HostingError> {
+        let mut buffer = Vec::<PdUChar>::new();
+        loop {
+            // returns the required buffer size including the nul terminator and only writes the path if it fits.
+            let required_buffer_size = unsafe {
+                self.lib.hostfxr_resolve_sdk(
+                    exe_dir.as_ptr(),
+                    working_dir.as_ptr(),
+                    buffer.as_mut_ptr().cast(),
+                    buffer.len().try_into().unwrap(),
+                )
+            }
+            .map_err(|_| HostingError::HostApiUnsupportedVersion)?;
+
+            let required_buffer_size = match usize::try_from(required_buffer_size) {
+                Ok(0) | Err(_) => return Ok(None),
+                Ok(size) => size,
+            };
+            if required_buffer_size <= buffer.len() {
+                let path = PdCStr::from_slice_with_nul(&buffer[..required_buffer_size]).unwrap();
+                return Ok(Some(PathBuf::from(path.to_os_string())));
+            }
+            buffer.resize(required_buffer_size, 0);
+        }
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     },
     error::{HostingError, HostingResult},
     hostfxr::{AppOrHostingResult, Hostfxr},
-    pdcstring::{PdCStr, PdUChar},
+    pdcstring::{PdCStr, PdCString, PdUChar},
 };
 
 use coreclr_hosting_shared::char_t;
@@ -52,8 +52,35 @@ impl Hostfxr {
         let args = [&self.dotnet_exe, app_path]
             .into_iter()
             .chain(args)
-            .map(|s| s.as_ptr())
             .collect::<Vec<_>>();
+        Ok(self.run_main_with_startupinfo(host_path, dotnet_root, app_path, &args))
+    }
+
+    /// Runs the `dotnet` muxer in the current process with the given command line and startup information,
+    /// like a native apphost does.
+    ///
+    /// # Arguments
+    ///  * `host_path`
+    ///     path to the host application (the apphost or `dotnet` executable that is emulated)
+    ///  * `dotnet_root`
+    ///     path to the .NET Core installation root
+    ///  * `app_path`
+    ///     path to the application to run
+    ///  * `args`
+    ///     the complete command line, passed as is. As for a native host, the first element has to be the path of the host.
+    ///
+    /// This function does not return until the application completes execution.
+    /// If the application is successfully executed, this value will return the exit code of the application.
+    /// Otherwise, it will return an error code indicating the failure.
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore2_1")))]
+    pub fn run_main_with_startupinfo<A: AsRef<PdCStr>>(
+        &self,
+        host_path: &PdCStr,
+        dotnet_root: &PdCStr,
+        app_path: &PdCStr,
+        args: &[A],
+    ) -> AppOrHostingResult {
+        let args = args.iter().map(|s| s.as_ref().as_ptr()).collect::<Vec<_>>();
 
         let result = unsafe {
             self.lib.hostfxr_main_startupinfo(
@@ -66,7 +93,9 @@ impl Hostfxr {
         }
         .unwrap_or(UNSUPPORTED_HOST_VERSION_ERROR_CODE);
 
-        Ok(AppOrHostingResult::from(result))
+        let result = AppOrHostingResult::from(result);
+        super::mark_runtime_started_by_app(result);
+        result
     }
 
     /// Determine the directory location of the SDK, accounting for `global.json` and multi-level lookup policy.
@@ -112,6 +141,8 @@ impl Hostfxr {
     }
 
     /// Get the list of all available SDKs ordered by ascending version, based on the provided `dotnet` executable.
+    ///
+    /// The executable of the installation used by the dotnet CLI can be found using [`dotnet_cli::locate`](crate::dotnet_cli::locate).
     #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore2_1")))]
     #[must_use]
     pub fn get_available_sdks_with_dotnet_path(&self, dotnet_path: &PdCStr) -> Vec<PathBuf> {
@@ -168,16 +199,16 @@ impl Hostfxr {
         HostingResult::from(result).into_result()?;
         unsafe { buffer.set_len(required_buffer_size.try_into().unwrap()) };
 
-        let mut directories = Vec::new();
-        let last_start = 0;
-        for i in 0..buffer.len() {
-            if buffer[i] == PATH_LIST_SEPARATOR as PdUChar || buffer[i] == 0 {
-                buffer[i] = 0;
-                let directory = PdCStr::from_slice_with_nul(&buffer[last_start..=i]).unwrap();
-                directories.push(PathBuf::from(directory.to_os_string()));
-                break;
-            }
-        }
+        // the buffer contains the nul terminated list of directories, which usually ends with a separator.
+        let list = buffer.split(|c| *c == 0).next().unwrap_or_default();
+        let directories = list
+            .split(|c| *c == PATH_LIST_SEPARATOR as PdUChar)
+            .filter(|directory| !directory.is_empty())
+            .map(|directory| {
+                let directory = PdCString::from_vec(directory).unwrap();
+                PathBuf::from(directory.to_os_string())
+            })
+            .collect();
 
         Ok(directories)
     }
@@ -2,12 +2,12 @@ use crate::{
     bindings::hostfxr::{hostfxr_handle, hostfxr_initialize_parameters},
     error::{HostingError, HostingResult, HostingSuccess},
     hostfxr::{
-        Hostfxr, HostfxrContext, HostfxrHandle, InitializedForCommandLine,
-        InitializedForRuntimeConfig,
+        strict_checks, ErrorMessageCapture, ErrorModeGuard, Hostfxr, HostfxrContext, HostfxrHandle,
+        HostfxrParameters, InitializedForCommandLine, InitializedForRuntimeConfig,
     },
-    pdcstring::PdCStr,
+    pdcstring::{PdCStr, PdCString},
 };
-use std::{iter, mem::MaybeUninit, ptr};
+use std::{env::consts::EXE_SUFFIX, iter, mem::MaybeUninit, path::PathBuf, ptr};
 
 use super::UNSUPPORTED_HOST_VERSION_ERROR_CODE;
 
@@ -30,6 +30,9 @@ impl Hostfxr {
     /// This function parses the specified command-line arguments to determine the application to run. It will
     /// then find the corresponding `.runtimeconfig.json` and `.deps.json` with which to resolve frameworks and
     /// dependencies and prepare everything needed to load the runtime.
+    ///
+    /// Only applications can be run this way. If `app_path` is not a `.dll` or `.exe` it is considered to be an SDK
+    /// command (like `test` in `dotnet test`), for which [`HostingError::AppArgNotRunnable`] is returned.
     #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
     pub fn initialize_for_dotnet_command_line(
         &self,
@@ -220,17 +223,101 @@ impl Hostfxr {
         }
     }
 
+    /// Initializes the hosting components for a dotnet command line running an application
+    ///
+    /// Like all the other `initialize` functions, this function will
+    /// * Process the `.runtimeconfig.json`
+    /// * Resolve framework references and find actual frameworks
+    /// * Find the root framework (`Microsoft.NETCore.App`) and load the hostpolicy from it
+    /// * The hostpolicy will then process all relevant `.deps.json` files and produce the list of assemblies, native search paths and other artifacts needed to initialize the runtime.
+    ///
+    /// The functions will **NOT** load the `CoreCLR` runtime. They just prepare everything to the point where it can be loaded.
+    ///
+    /// # Arguments
+    ///  * `app_path`:
+    ///     The path to the target application.
+    ///  * `args`:
+    ///     The command line arguments for the managed application.
+    ///  * `parameters`:
+    ///     The host path and dotnet root to use, see [`HostfxrParameters`].
+    ///
+    /// # Remarks
+    /// This function parses the specified command-line arguments to determine the application to run. It will
+    /// then find the corresponding `.runtimeconfig.json` and `.deps.json` with which to resolve frameworks and
+    /// dependencies and prepare everything needed to load the runtime.
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+    pub fn initialize_for_dotnet_command_line_with_params(
+        &self,
+        app_path: impl AsRef<PdCStr>,
+        args: impl Iterator<Item = impl AsRef<PdCStr>>,
+        parameters: &HostfxrParameters,
+    ) -> Result<HostfxrContext<InitializedForCommandLine>, HostingError> {
+        let parameters = parameters.as_raw();
+        unsafe {
+            self.initialize_for_dotnet_command_line_with_parameters(app_path, args, &parameters)
+        }
+    }
+
+    /// Initializes the hosting components for running a self-contained application, which ships
+    /// the runtime in its own directory instead of using a shared installation.
+    ///
+    /// `self` should be the hostfxr library inside of the application directory, see [`Hostfxr::load_self_contained`].
+    /// The application directory is passed as `dotnet_root` and the path to the apphost of the application
+    /// (next to `app_path`) is passed as `host_path`, like the stock apphost does.
+    ///
+    /// The functions will **NOT** load the `CoreCLR` runtime. They just prepare everything to the point where it can be loaded.
+    ///
+    /// # Arguments
+    ///  * `app_path`:
+    ///     The path to the target application.
+    ///  * `args`:
+    ///     The command line arguments for the managed application.
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+    pub fn initialize_for_self_contained_command_line(
+        &self,
+        app_path: impl AsRef<PdCStr>,
+        args: impl Iterator<Item = impl AsRef<PdCStr>>,
+    ) -> Result<HostfxrContext<InitializedForCommandLine>, HostingError> {
+        let app_path = app_path.as_ref();
+        let app_path_buf = PathBuf::from(app_path.to_os_string());
+        let app_dir = app_path_buf
+            .parent()
+            .ok_or(HostingError::LibHostAppRootFindFailure)?;
+
+        let mut host_path = app_path_buf.with_extension("").into_os_string();
+        host_path.push(EXE_SUFFIX);
+        let host_path =
+            PdCString::from_os_str(host_path).map_err(|_| HostingError::InvalidArgFailure)?;
+        let dotnet_root =
+            PdCString::from_os_str(app_dir).map_err(|_| HostingError::InvalidArgFailure)?;
+
+        self.initialize_for_dotnet_command_line_with_params(
+            app_path,
+            args,
+            &HostfxrParameters::new()
+                .host_path(host_path)
+                .dotnet_root(dotnet_root),
+        )
+    }
+
     unsafe fn initialize_for_dotnet_command_line_with_parameters(
         &self,
         app_path: impl AsRef<PdCStr>,
         args: impl Iterator<Item = impl AsRef<PdCStr>>,
         parameters: *const hostfxr_initialize_parameters,
     ) -> Result<HostfxrContext<InitializedForCommandLine>, HostingError> {
+        let app_path = app_path.as_ref();
+        if is_sdk_command(app_path) {
+            return Err(HostingError::AppArgNotRunnable);
+        }
+
         let mut hostfxr_handle = MaybeUninit::<hostfxr_handle>::uninit();
 
-        let app_path = app_path.as_ref().as_ptr();
+        let app_path = app_path.as_ptr();
         let args = args.map(|arg| arg.as_ref().as_ptr());
         let app_path_and_args = iter::once(app_path).chain(args).collect::<Vec<_>>();
+        let _error_mode = ErrorModeGuard::suppress_dialogs_if(self.suppress_error_dialogs);
+        let capture = ErrorMessageCapture::start_if(&self.lib, self.capture_error_messages);
         let result = unsafe {
             self.lib.hostfxr_initialize_for_dotnet_command_line(
                 app_path_and_args.len().try_into().unwrap(),
@@ -240,18 +327,17 @@ impl Hostfxr {
             )
         }
         .unwrap_or(UNSUPPORTED_HOST_VERSION_ERROR_CODE);
+        ErrorMessageCapture::finish(capture, result);
 
         let success_code = HostingResult::from(result).into_result()?;
 
         let is_primary = matches!(success_code, HostingSuccess::Success);
 
-        Ok(unsafe {
-            HostfxrContext::from_handle(
-                HostfxrHandle::new_unchecked(hostfxr_handle.assume_init()),
-                self.clone(),
-                is_primary,
-            )
-        })
+        let handle = unsafe { HostfxrHandle::new_unchecked(hostfxr_handle.assume_init()) };
+        strict_checks::handle_opened(handle, self);
+
+        let context = unsafe { HostfxrContext::from_handle(handle, self.clone(), is_primary) };
+        Ok(context.with_initialization_success(success_code))
     }
 
     /// This function loads the specified `.runtimeconfig.json`, resolve all frameworks, resolve all the assets from those frameworks and
@@ -353,6 +439,38 @@ impl Hostfxr {
         }
     }
 
+    /// This function loads the specified `.runtimeconfig.json`, resolve all frameworks, resolve all the assets from those frameworks and
+    /// then prepare runtime initialization where the TPA contains only frameworks.
+    /// Note that this case does **NOT** consume any `.deps.json` from the app/component (only processes the framework's `.deps.json`).
+    ///
+    /// Like all the other `initialize` functions, this function will
+    /// * Process the `.runtimeconfig.json`
+    /// * Resolve framework references and find actual frameworks
+    /// * Find the root framework (`Microsoft.NETCore.App`) and load the hostpolicy from it
+    /// * The hostpolicy will then process all relevant `.deps.json` files and produce the list of assemblies, native search paths and other artifacts needed to initialize the runtime.
+    ///
+    /// The functions will **NOT** load the `CoreCLR` runtime. They just prepare everything to the point where it can be loaded.
+    ///
+    /// # Arguments
+    ///  * `runtime_config_path`:
+    ///     Path to the `.runtimeconfig.json` file to process.
+    ///     Unlike with [`initialize_for_dotnet_command_line`], any `.deps.json` from the app/component will not be processed by the hosting layers.
+    ///  * `parameters`:
+    ///     The host path and dotnet root to use, see [`HostfxrParameters`].
+    ///
+    /// [`initialize_for_dotnet_command_line`]: Hostfxr::initialize_for_dotnet_command_line
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+    pub fn initialize_for_runtime_config_with_params(
+        &self,
+        runtime_config_path: impl AsRef<PdCStr>,
+        parameters: &HostfxrParameters,
+    ) -> Result<HostfxrContext<InitializedForRuntimeConfig>, HostingError> {
+        let parameters = parameters.as_raw();
+        unsafe {
+            self.initialize_for_runtime_config_with_parameters(runtime_config_path, &parameters)
+        }
+    }
+
     unsafe fn initialize_for_runtime_config_with_parameters(
         &self,
         runtime_config_path: impl AsRef<PdCStr>,
@@ -360,6 +478,8 @@ impl Hostfxr {
     ) -> Result<HostfxrContext<InitializedForRuntimeConfig>, HostingError> {
         let mut hostfxr_handle = MaybeUninit::uninit();
 
+        let _error_mode = ErrorModeGuard::suppress_dialogs_if(self.suppress_error_dialogs);
+        let capture = ErrorMessageCapture::start_if(&self.lib, self.capture_error_messages);
         let result = unsafe {
             self.lib.hostfxr_initialize_for_runtime_config(
                 runtime_config_path.as_ref().as_ptr(),
@@ -368,17 +488,25 @@ impl Hostfxr {
             )
         }
         .unwrap_or(UNSUPPORTED_HOST_VERSION_ERROR_CODE);
+        ErrorMessageCapture::finish(capture, result);
 
         let success_code = HostingResult::from(result).into_result()?;
 
         let is_primary = matches!(success_code, HostingSuccess::Success);
 
-        Ok(unsafe {
-            HostfxrContext::from_handle(
-                HostfxrHandle::new_unchecked(hostfxr_handle.assume_init()),
-                self.clone(),
-                is_primary,
-            )
-        })
+        let handle = unsafe { HostfxrHandle::new_unchecked(hostfxr_handle.assume_init()) };
+        strict_checks::handle_opened(handle, self);
+
+        let context = unsafe { HostfxrContext::from_handle(handle, self.clone(), is_primary) };
+        Ok(context.with_initialization_success(success_code))
     }
 }
+
+// like the muxer, only `.dll` and `.exe` files are considered to be applications. anything else would be dispatched
+// to the SDK, which the hosting layer cannot do for an initialized context and reports as an invalid argument.
+fn is_sdk_command(app_path: &PdCStr) -> bool {
+    let app_path = PathBuf::from(app_path.to_os_string());
+    !app_path.extension().is_some_and(|extension| {
+        extension.eq_ignore_ascii_case("dll") || extension.eq_ignore_ascii_case("exe")
+    })
+}
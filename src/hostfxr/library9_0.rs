@@ -0,0 +1,168 @@
+use std::{ffi::c_void, path::PathBuf, ptr, slice};
+
+use crate::{
+    bindings::{char_t, hostfxr::hostfxr_initialize_parameters},
+    dlopen2::raw::Library,
+    error::{HostingError, HostingResult},
+    hostfxr::{Hostfxr, HostfxrParameters},
+    pdcstring::PdCStr,
+};
+
+// hostfxr-sys does not provide bindings for this API yet, so it is declared and loaded here.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct hostfxr_framework_result {
+    size: usize,
+    name: *const char_t,
+    requested_version: *const char_t,
+    resolved_version: *const char_t,
+    resolved_path: *const char_t,
+}
+
+#[repr(C)]
+#[allow(non_camel_case_types)]
+struct hostfxr_resolve_frameworks_result {
+    size: usize,
+    resolved_count: usize,
+    resolved_frameworks: *const hostfxr_framework_result,
+    unresolved_count: usize,
+    unresolved_frameworks: *const hostfxr_framework_result,
+}
+
+#[allow(non_camel_case_types)]
+type hostfxr_resolve_frameworks_result_fn =
+    extern "C" fn(result: *const hostfxr_resolve_frameworks_result, result_context: *mut c_void);
+
+#[allow(non_camel_case_types)]
+type hostfxr_resolve_frameworks_for_runtime_config_fn = unsafe extern "C" fn(
+    runtime_config_path: *const char_t,
+    parameters: *const hostfxr_initialize_parameters,
+    callback: hostfxr_resolve_frameworks_result_fn,
+    result_context: *mut c_void,
+) -> i32;
+
+/// A framework reference of a runtime config and the framework it resolved to, if any.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FrameworkResolution {
+    /// The name of the framework, like `Microsoft.NETCore.App`.
+    pub name: String,
+    /// The version requested by the runtime config.
+    pub requested_version: String,
+    /// The version of the framework the reference resolved to, [`None`] if it could not be resolved.
+    pub resolved_version: Option<String>,
+    /// The directory of the framework the reference resolved to, [`None`] if it could not be resolved.
+    pub resolved_path: Option<PathBuf>,
+}
+
+/// The result of resolving the frameworks of a runtime config using [`Hostfxr::resolve_frameworks`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ResolvedFrameworks {
+    /// The framework references that could be resolved.
+    pub resolved: Vec<FrameworkResolution>,
+    /// The framework references that could not be resolved.
+    pub unresolved: Vec<FrameworkResolution>,
+}
+
+impl ResolvedFrameworks {
+    /// Returns whether all framework references could be resolved,
+    /// i.e. whether initializing a context for the runtime config will find its frameworks.
+    #[must_use]
+    pub fn is_satisfied(&self) -> bool {
+        self.unresolved.is_empty()
+    }
+}
+
+impl Hostfxr {
+    /// Resolves the frameworks referenced by the given `.runtimeconfig.json` without initializing the hosting components,
+    /// using the same logic as [`initialize_for_runtime_config`](Hostfxr::initialize_for_runtime_config).
+    ///
+    /// This can be used to check whether a runtime config can be satisfied by the installation, before initializing it.
+    /// The optional `params` can be used to resolve frameworks from a different dotnet root.
+    ///
+    /// Returns [`HostingError::HostApiUnsupportedVersion`] if the loaded hostfxr library does not provide this API,
+    /// which was added in .NET 9.
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net9_0")))]
+    pub fn resolve_frameworks(
+        &self,
+        runtime_config_path: impl AsRef<PdCStr>,
+        params: Option<&HostfxrParameters>,
+    ) -> Result<ResolvedFrameworks, HostingError> {
+        // hostfxr is already loaded, so this only increments its reference count.
+        let lib = Library::open(&self.library_path)
+            .map_err(|_| HostingError::HostApiUnsupportedVersion)?;
+        let resolve_frameworks = unsafe {
+            lib.symbol::<hostfxr_resolve_frameworks_for_runtime_config_fn>(
+                "hostfxr_resolve_frameworks_for_runtime_config",
+            )
+        }
+        .map_err(|_| HostingError::HostApiUnsupportedVersion)?;
+
+        let params = params.map(HostfxrParameters::as_raw);
+        let params_ptr = params.as_ref().map_or(ptr::null(), ptr::from_ref);
+
+        let mut frameworks = None::<ResolvedFrameworks>;
+        let result = unsafe {
+            resolve_frameworks(
+                runtime_config_path.as_ref().as_ptr(),
+                params_ptr,
+                resolve_frameworks_callback,
+                ptr::from_mut(&mut frameworks).cast(),
+            )
+        };
+
+        // the callback is also invoked if some frameworks could not be resolved, which is reported as an error code.
+        if let Some(frameworks) = frameworks {
+            return Ok(frameworks);
+        }
+        HostingResult::from(result).into_result()?;
+        Err(HostingError::HostInvalidState)
+    }
+}
+
+extern "C" fn resolve_frameworks_callback(
+    result: *const hostfxr_resolve_frameworks_result,
+    result_context: *mut c_void,
+) {
+    let frameworks = unsafe { &mut *result_context.cast::<Option<ResolvedFrameworks>>() };
+    let result = unsafe { &*result };
+
+    let resolved = unsafe { framework_results(result.resolved_frameworks, result.resolved_count) };
+    let unresolved =
+        unsafe { framework_results(result.unresolved_frameworks, result.unresolved_count) };
+    *frameworks = Some(ResolvedFrameworks {
+        resolved,
+        unresolved,
+    });
+}
+
+unsafe fn framework_results(
+    frameworks: *const hostfxr_framework_result,
+    count: usize,
+) -> Vec<FrameworkResolution> {
+    if frameworks.is_null() {
+        return Vec::new();
+    }
+    unsafe { slice::from_raw_parts(frameworks, count) }
+        .iter()
+        .map(|framework| FrameworkResolution {
+            name: unsafe { optional_string(framework.name) }.unwrap_or_default(),
+            requested_version: unsafe { optional_string(framework.requested_version) }
+                .unwrap_or_default(),
+            resolved_version: unsafe { optional_string(framework.resolved_version) },
+            resolved_path: unsafe { optional_string_ptr(framework.resolved_path) }
+                .map(|path| path.to_os_string().into()),
+        })
+        .collect()
+}
+
+unsafe fn optional_string_ptr<'a>(s: *const char_t) -> Option<&'a PdCStr> {
+    if s.is_null() {
+        return None;
+    }
+    let s = unsafe { PdCStr::from_str_ptr(s) };
+    (!s.is_empty()).then_some(s)
+}
+
+unsafe fn optional_string(s: *const char_t) -> Option<String> {
+    unsafe { optional_string_ptr(s) }.map(PdCStr::to_string_lossy)
+}
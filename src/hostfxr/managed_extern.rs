@@ -0,0 +1,140 @@
+#[macro_export]
+/// A macro for declaring a set of managed methods as a struct with typed wrappers, which resolve the methods lazily
+/// through an [`AssemblyDelegateLoader`](crate::hostfxr::AssemblyDelegateLoader).
+///
+/// Each method is declared with its signature, followed by the name of the managed method and optionally the assembly
+/// qualified name of a delegate type. Methods without a delegate type have to be annotated with
+/// [`UnmanagedCallersOnly`], which requires the `net5_0` feature. The assembly qualified name of the type containing
+/// the methods is given after the name of the struct, the assembly path by the loader passed to `new`.
+///
+/// A method is resolved on its first call, which returns an error if it could not be resolved. Resolved methods are
+/// cached, so later calls go straight to the managed code. All methods can be resolved ahead of time using
+/// `resolve_all`. The names `new`, `loader` and `resolve_all` are used by the generated struct and cannot be used
+/// for methods.
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{managed_extern, nethost, pdcstr};
+/// managed_extern! {
+///     /// The exports of the calculator plugin.
+///     pub struct Calculator for "Plugin.Calculator, Plugin" {
+///         /// [UnmanagedCallersOnly] public static int Add(int a, int b)
+///         pub fn add(a: i32, b: i32) -> i32 = "Add";
+///         /// public static void Reset() with `public delegate void ResetDelegate();`
+///         pub fn reset() = "Reset" as "Plugin.Calculator+ResetDelegate, Plugin";
+///     }
+/// }
+///
+/// # fn test(context: netcorehost::hostfxr::HostfxrContext<netcorehost::hostfxr::InitializedForRuntimeConfig>) {
+/// let loader = context.get_delegate_loader_for_assembly(pdcstr!("Plugin.dll").to_owned()).unwrap();
+/// let calculator = Calculator::new(loader);
+/// assert_eq!(calculator.add(1, 2).unwrap(), 3);
+/// calculator.reset().unwrap();
+/// # }
+/// ```
+///
+/// [`UnmanagedCallersOnly`]: https://docs.microsoft.com/en-us/dotnet/api/system.runtime.interopservices.unmanagedcallersonlyattribute
+macro_rules! managed_extern {
+    (@ret) => { () };
+    (@ret $ret:ty) => { $ret };
+
+    (@resolve $loader:expr, $fn_type:ty, $type_name:literal, $method_name:literal) => {
+        $loader.get_function_with_unmanaged_callers_only::<$fn_type>(
+            $crate::pdcstr!($type_name),
+            $crate::pdcstr!($method_name),
+        )
+    };
+    (@resolve $loader:expr, $fn_type:ty, $type_name:literal, $method_name:literal, $delegate_type_name:literal) => {
+        $loader.get_function::<$fn_type>(
+            $crate::pdcstr!($type_name),
+            $crate::pdcstr!($method_name),
+            $crate::pdcstr!($delegate_type_name),
+        )
+    };
+
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident for $type_name:literal {
+            $(
+                $(#[$fn_meta:meta])*
+                $fn_vis:vis fn $fn_name:ident($($arg:ident : $arg_ty:ty),* $(,)?) $(-> $ret:ty)?
+                    = $method_name:literal $(as $delegate_type_name:literal)?;
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            loader: $crate::hostfxr::AssemblyDelegateLoader,
+            $(
+                $fn_name: ::std::sync::OnceLock<
+                    $crate::hostfxr::ManagedFunction<
+                        <fn($($arg_ty),*) -> $crate::managed_extern!(@ret $($ret)?)
+                            as $crate::hostfxr::FunctionPtr>::Managed,
+                    >,
+                >,
+            )*
+        }
+
+        impl $name {
+            /// Creates a new instance resolving the methods using the given loader.
+            #[must_use]
+            $vis fn new(loader: $crate::hostfxr::AssemblyDelegateLoader) -> Self {
+                Self {
+                    loader,
+                    $($fn_name: ::std::sync::OnceLock::new(),)*
+                }
+            }
+
+            /// Returns the loader used to resolve the methods.
+            #[must_use]
+            $vis const fn loader(&self) -> &$crate::hostfxr::AssemblyDelegateLoader {
+                &self.loader
+            }
+
+            /// Resolves all methods that have not been resolved yet.
+            $vis fn resolve_all(&self) -> ::core::result::Result<(), $crate::hostfxr::GetManagedFunctionError> {
+                $(
+                    if self.$fn_name.get().is_none() {
+                        let function = $crate::managed_extern!(
+                            @resolve self.loader,
+                            fn($($arg_ty),*) -> $crate::managed_extern!(@ret $($ret)?),
+                            $type_name,
+                            $method_name
+                            $(, $delegate_type_name)?
+                        )?;
+                        let _ = self.$fn_name.set(function);
+                    }
+                )*
+                ::core::result::Result::Ok(())
+            }
+
+            $(
+                $(#[$fn_meta])*
+                $fn_vis fn $fn_name(
+                    &self,
+                    $($arg: $arg_ty),*
+                ) -> ::core::result::Result<
+                    $crate::managed_extern!(@ret $($ret)?),
+                    $crate::hostfxr::GetManagedFunctionError,
+                > {
+                    let function = match self.$fn_name.get() {
+                        ::core::option::Option::Some(function) => *function,
+                        ::core::option::Option::None => {
+                            let function = $crate::managed_extern!(
+                                @resolve self.loader,
+                                fn($($arg_ty),*) -> $crate::managed_extern!(@ret $($ret)?),
+                                $type_name,
+                                $method_name
+                                $(, $delegate_type_name)?
+                            )?;
+                            // another thread may have resolved the same function in the meantime, both are valid.
+                            let _ = self.$fn_name.set(function);
+                            function
+                        }
+                    };
+                    ::core::result::Result::Ok(function($($arg),*))
+                }
+            )*
+        }
+    };
+}
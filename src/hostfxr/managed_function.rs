@@ -1,8 +1,26 @@
-use std::ops::Deref;
+use std::{fmt, ops::Deref, ptr::NonNull};
+
+use crate::hostfxr::RefParam;
 
 /// A wrapper around a managed function pointer.
+///
+/// The function can be called like a regular function through [`Deref`], without having to transmute a raw pointer.
+/// With the `nightly` feature, safe functions additionally implement the [`Fn`] traits, so they can be passed to
+/// APIs expecting closures.
+///
+/// As the runtime cannot be unloaded, the function stays valid until the process exits, even after the context used
+/// to load it has been closed. A [`ManagedFunction`] is [`Send`] and [`Sync`], it is up to the managed code whether
+/// it can be called from any thread.
 pub struct ManagedFunction<F: ManagedFunctionPtr>(pub(crate) F);
 
+impl<F: ManagedFunctionPtr> ManagedFunction<F> {
+    /// Returns the underlying function pointer.
+    #[must_use]
+    pub fn into_inner(self) -> F {
+        self.0
+    }
+}
+
 impl<F: ManagedFunctionPtr> Deref for ManagedFunction<F> {
     type Target = F;
 
@@ -11,6 +29,22 @@ impl<F: ManagedFunctionPtr> Deref for ManagedFunction<F> {
     }
 }
 
+impl<F: ManagedFunctionPtr> Clone for ManagedFunction<F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: ManagedFunctionPtr> Copy for ManagedFunction<F> {}
+
+impl<F: ManagedFunctionPtr> fmt::Debug for ManagedFunction<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ManagedFunction")
+            .field(&self.0.as_ptr())
+            .finish()
+    }
+}
+
 ffi_opaque::opaque! {
     /// A struct representing an opaque function.
     pub struct OpaqueFunction;
@@ -62,6 +96,101 @@ pub unsafe trait ManagedFunctionPtr: FunctionPtr {
     const ARITY: usize;
 }
 
+/// Marker trait for types that can be passed to and returned from managed functions.
+///
+/// This is implemented for the primitive integer and floating point types, `()` (as return type), raw pointers to
+/// sized types, [`NonNull`] and `extern "system"` function pointers (including [`Option`]s of them).
+/// It can be implemented for custom `#[repr(C)]` structs matching a blittable managed struct.
+///
+/// [`bool`] is not implemented, as the managed `bool` is not blittable and managed code can pass values other than
+/// `0` and `1`. Pass an integer (e.g. a [`u8`] for a managed `byte`) and compare it to zero instead.
+///
+/// # Safety
+/// The type has to be FFI-safe (a primitive, `#[repr(C)]` or `#[repr(transparent)]` around an FFI-safe type) and its
+/// layout has to match the type of the corresponding managed parameter or return value.
+pub unsafe trait FfiSafe {
+    /// Returns the name of the matching C# type, which is used to generate managed signatures for
+    /// [`callback!`](crate::callback).
+    ///
+    /// Pointers, references and function pointers map to `nint`. The default implementation returns the unqualified
+    /// name of the Rust type, which matches a managed struct with the same name.
+    #[must_use]
+    fn csharp_type() -> String {
+        let name = std::any::type_name::<Self>();
+        let name = name.split('<').next().unwrap_or(name);
+        name.rsplit("::").next().unwrap_or(name).to_string()
+    }
+}
+
+macro_rules! impl_ffi_safe {
+    ($($ty:ty => $csharp_type:literal),*) => {
+        $(
+            unsafe impl FfiSafe for $ty {
+                fn csharp_type() -> String {
+                    $csharp_type.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_ffi_safe!(
+    () => "void",
+    i8 => "sbyte",
+    i16 => "short",
+    i32 => "int",
+    i64 => "long",
+    isize => "nint",
+    u8 => "byte",
+    u16 => "ushort",
+    u32 => "uint",
+    u64 => "ulong",
+    usize => "nuint",
+    f32 => "float",
+    f64 => "double"
+);
+
+macro_rules! impl_ffi_safe_pointer {
+    ($($ty:ty),*) => {
+        $(
+            unsafe impl<T> FfiSafe for $ty {
+                fn csharp_type() -> String {
+                    "nint".to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_ffi_safe_pointer!(*const T, *mut T, NonNull<T>, Option<NonNull<T>>);
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A [`FunctionPtr`] type that can be used as the signature of a managed function.
+///
+/// This is implemented for `fn`, `unsafe fn`, `extern "system" fn` and `unsafe extern "system" fn` pointers with up
+/// to 12 parameters whose parameter and return types are [`FfiSafe`]. Up to two of the parameters can be
+/// [`RefParam`]s or [`OutParam`](crate::hostfxr::OutParam)s instead. Signatures the runtime cannot marshal, like
+/// ones containing [`String`], [`Vec`] or references, are rejected at compile time instead of causing undefined
+/// behavior when the function is called.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// ```rust,compile_fail
+/// # use netcorehost::{hostfxr::AssemblyDelegateLoader, pdcstr};
+/// # fn test(fn_loader: AssemblyDelegateLoader) {
+/// // `String` cannot be passed to managed code.
+/// let hello = fn_loader.get_function::<fn(String)>(
+///     pdcstr!("Test.Program, Test"),
+///     pdcstr!("Hello"),
+///     pdcstr!("Test.Program+HelloDelegate, Test"),
+/// );
+/// # }
+/// ```
+pub trait DelegateSignature: FunctionPtr + sealed::Sealed {}
+
 macro_rules! impl_fn {
     (@recurse () ($($nm:ident : $ty:ident),*)) => {
         impl_fn!(@impl_all ($($nm : $ty),*));
@@ -78,10 +207,34 @@ macro_rules! impl_fn {
     (@impl_u_and_s ($($nm:ident : $ty:ident),*) fn($($param_ty:ident),*) -> $ret:ty) => {
         impl_fn!(@impl_core ($($nm : $ty),*) (fn($($param_ty),*) -> $ret) (extern "system" fn($($param_ty),*) -> $ret));
         impl_fn!(@impl_core ($($nm : $ty),*) (unsafe fn($($param_ty),*) -> $ret) (unsafe extern "system" fn($($param_ty),*) -> $ret));
+        #[cfg(feature = "nightly")]
+        impl_fn!(@impl_call ($($nm : $ty),*) (extern "system" fn($($param_ty),*) -> $ret));
+    };
+
+    (@impl_call ($($nm:ident : $ty:ident),*) ($managed_fn_type:ty)) => {
+        impl<Ret: 'static, $($ty: FfiSafe + 'static),*> ::core::ops::FnOnce<($($ty,)*)> for crate::hostfxr::ManagedFunction<$managed_fn_type> {
+            type Output = Ret;
+
+            extern "rust-call" fn call_once(self, ($($nm,)*): ($($ty,)*)) -> Ret {
+                (self.0)($($nm),*)
+            }
+        }
+
+        impl<Ret: 'static, $($ty: FfiSafe + 'static),*> ::core::ops::FnMut<($($ty,)*)> for crate::hostfxr::ManagedFunction<$managed_fn_type> {
+            extern "rust-call" fn call_mut(&mut self, ($($nm,)*): ($($ty,)*)) -> Ret {
+                (self.0)($($nm),*)
+            }
+        }
+
+        impl<Ret: 'static, $($ty: FfiSafe + 'static),*> ::core::ops::Fn<($($ty,)*)> for crate::hostfxr::ManagedFunction<$managed_fn_type> {
+            extern "rust-call" fn call(&self, ($($nm,)*): ($($ty,)*)) -> Ret {
+                (self.0)($($nm),*)
+            }
+        }
     };
 
     (@impl_core ($($nm:ident : $ty:ident),*) ($fn_type:ty) ($managed_fn_type:ty)) => {
-        unsafe impl<Ret: 'static, $($ty: 'static),*> crate::hostfxr::FunctionPtr for $fn_type {
+        unsafe impl<Ret: 'static, $($ty: FfiSafe + 'static),*> crate::hostfxr::FunctionPtr for $fn_type {
             type Args = ($($ty,)*);
             type Output = Ret;
             type Managed = $managed_fn_type;
@@ -98,7 +251,7 @@ macro_rules! impl_fn {
             }
         }
 
-        unsafe impl<Ret: 'static, $($ty: 'static),*> crate::hostfxr::FunctionPtr for $managed_fn_type {
+        unsafe impl<Ret: 'static, $($ty: FfiSafe + 'static),*> crate::hostfxr::FunctionPtr for $managed_fn_type {
             type Args = ($($ty,)*);
             type Output = Ret;
             type Managed = $managed_fn_type;
@@ -115,12 +268,28 @@ macro_rules! impl_fn {
             }
         }
 
-        unsafe impl<Ret: 'static, $($ty: 'static),*> crate::hostfxr::ManagedFunctionPtr for $managed_fn_type {
+        unsafe impl<Ret: 'static, $($ty: FfiSafe + 'static),*> crate::hostfxr::ManagedFunctionPtr for $managed_fn_type {
             type Args = ($($ty,)*);
             type Output = Ret;
 
             const ARITY: ::core::primitive::usize = impl_fn!(@count ($($ty)*));
         }
+
+        impl<Ret: FfiSafe + 'static, $($ty: FfiSafe + 'static),*> sealed::Sealed for $fn_type {}
+        impl<Ret: FfiSafe + 'static, $($ty: FfiSafe + 'static),*> DelegateSignature for $fn_type {}
+        impl<Ret: FfiSafe + 'static, $($ty: FfiSafe + 'static),*> sealed::Sealed for $managed_fn_type {}
+        impl<Ret: FfiSafe + 'static, $($ty: FfiSafe + 'static),*> DelegateSignature for $managed_fn_type {}
+
+        unsafe impl<Ret, $($ty),*> FfiSafe for $managed_fn_type {
+            fn csharp_type() -> String {
+                "nint".to_string()
+            }
+        }
+        unsafe impl<Ret, $($ty),*> FfiSafe for Option<$managed_fn_type> {
+            fn csharp_type() -> String {
+                "nint".to_string()
+            }
+        }
     };
 
     (@count ()) => {
@@ -139,3 +308,102 @@ impl_fn! {
     __arg_0:  A, __arg_1:  B, __arg_2:  C, __arg_3:  D, __arg_4:  E, __arg_5:  F, __arg_6:  G,
     __arg_7:  H, __arg_8:  I, __arg_9:  J, __arg_10: K, __arg_11: L
 }
+
+// Signatures containing by-ref parameters are higher-ranked over the lifetimes of the borrows (`fn(RefParam<i32>)` is
+// `for<'a> fn(RefParam<'a, i32>)`), which the impls above do not cover. This generates impls for every signature
+// with one or two by-ref parameters. As by-ref parameters are not `FfiSafe`, they do not overlap with each other or
+// the impls above.
+macro_rules! impl_by_ref_fn {
+    (@recurse [$($done:tt)*] []) => {};
+    (@recurse [$($done:tt)*] [$hd_ty:ident $hd_lt:lifetime $($tl:tt)*]) => {
+        impl_by_ref_fn!(@params [] [] [] ($($done)* $hd_ty $hd_lt));
+        impl_by_ref_fn!(@recurse [$($done)* $hd_ty $hd_lt] [$($tl)*]);
+    };
+
+    // chooses whether each parameter is passed by value or by reference.
+    (@params [$($params:tt)*] [$($lts:lifetime)*] [$($bounds:tt)*] ()) => {
+        impl_by_ref_fn!(@impl_all [$($lts)*] [$($bounds)*] $($params)*);
+    };
+    (@params [$($params:tt)*] [$($lts:lifetime)*] [$($bounds:tt)*] ($ty:ident $lt:lifetime $($tl:tt)*)) => {
+        impl_by_ref_fn!(@params [$($params)* (val $ty $lt)] [$($lts)*] [$($bounds)* $ty: FfiSafe + 'static,] ($($tl)*));
+        impl_by_ref_fn!(@by_ref [$($params)* (ref $ty $lt)] [$($lts)*] $lt [$($bounds)* $ty: 'static,] ($($tl)*));
+    };
+    (@by_ref [$($params:tt)*] [$($lts:lifetime)?] $lt:lifetime [$($bounds:tt)*] ($($tl:tt)*)) => {
+        impl_by_ref_fn!(@params [$($params)*] [$($lts)* $lt] [$($bounds)*] ($($tl)*));
+    };
+    (@by_ref [$($params:tt)*] [$($lts:lifetime)*] $lt:lifetime [$($bounds:tt)*] ($($tl:tt)*)) => {};
+
+    (@param val $ty:ident $lt:lifetime) => { $ty };
+    (@param ref $ty:ident $lt:lifetime) => { RefParam<$lt, $ty> };
+    (@static_param val $ty:ident) => { $ty };
+    (@static_param ref $ty:ident) => { RefParam<'static, $ty> };
+
+    (@impl_all [] [$($bounds:tt)*] $(($kind:ident $ty:ident $lt:lifetime))*) => {};
+    (@impl_all [$($lts:lifetime)+] [$($bounds:tt)*] $(($kind:ident $ty:ident $lt:lifetime))*) => {
+        impl_by_ref_fn!(@impl_core ($($ty),*) [$($bounds)*]
+            (($(impl_by_ref_fn!(@static_param $kind $ty),)*))
+            (for<$($lts),*> fn($(impl_by_ref_fn!(@param $kind $ty $lt)),*) -> Ret)
+            (for<$($lts),*> extern "system" fn($(impl_by_ref_fn!(@param $kind $ty $lt)),*) -> Ret));
+        impl_by_ref_fn!(@impl_core ($($ty),*) [$($bounds)*]
+            (($(impl_by_ref_fn!(@static_param $kind $ty),)*))
+            (for<$($lts),*> unsafe fn($(impl_by_ref_fn!(@param $kind $ty $lt)),*) -> Ret)
+            (for<$($lts),*> unsafe extern "system" fn($(impl_by_ref_fn!(@param $kind $ty $lt)),*) -> Ret));
+    };
+
+    (@impl_core ($($ty:ident),*) [$($bounds:tt)*] ($args:ty) ($fn_type:ty) ($managed_fn_type:ty)) => {
+        unsafe impl<Ret: 'static, $($ty),*> crate::hostfxr::FunctionPtr for $fn_type where $($bounds)* {
+            // the lifetimes of the by-ref parameters are chosen by the caller.
+            type Args = $args;
+            type Output = Ret;
+            type Managed = $managed_fn_type;
+
+            const ARITY: ::core::primitive::usize = impl_fn!(@count ($($ty)*));
+
+            unsafe fn from_ptr(ptr: crate::hostfxr::RawFunctionPtr) -> Self {
+                ::core::assert!(!ptr.is_null());
+                unsafe { ::core::mem::transmute(ptr) }
+            }
+
+            fn as_ptr(&self) -> crate::hostfxr::RawFunctionPtr {
+                *self as crate::hostfxr::RawFunctionPtr
+            }
+        }
+
+        unsafe impl<Ret: 'static, $($ty),*> crate::hostfxr::FunctionPtr for $managed_fn_type where $($bounds)* {
+            type Args = $args;
+            type Output = Ret;
+            type Managed = $managed_fn_type;
+
+            const ARITY: ::core::primitive::usize = impl_fn!(@count ($($ty)*));
+
+            unsafe fn from_ptr(ptr: crate::hostfxr::RawFunctionPtr) -> Self {
+                ::core::assert!(!ptr.is_null());
+                unsafe { ::core::mem::transmute(ptr) }
+            }
+
+            fn as_ptr(&self) -> crate::hostfxr::RawFunctionPtr {
+                *self as crate::hostfxr::RawFunctionPtr
+            }
+        }
+
+        unsafe impl<Ret: 'static, $($ty),*> crate::hostfxr::ManagedFunctionPtr for $managed_fn_type where $($bounds)* {
+            type Args = $args;
+            type Output = Ret;
+
+            const ARITY: ::core::primitive::usize = impl_fn!(@count ($($ty)*));
+        }
+
+        impl<Ret: FfiSafe + 'static, $($ty),*> sealed::Sealed for $fn_type where $($bounds)* {}
+        impl<Ret: FfiSafe + 'static, $($ty),*> DelegateSignature for $fn_type where $($bounds)* {}
+        impl<Ret: FfiSafe + 'static, $($ty),*> sealed::Sealed for $managed_fn_type where $($bounds)* {}
+        impl<Ret: FfiSafe + 'static, $($ty),*> DelegateSignature for $managed_fn_type where $($bounds)* {}
+    };
+
+    ($($ty:ident $lt:lifetime),*) => {
+        impl_by_ref_fn!(@recurse [] [$($ty $lt)*]);
+    };
+}
+
+impl_by_ref_fn! {
+    A 'a, B 'b, C 'c, D 'd, E 'e, F 'f, G 'g, H 'h, I 'i, J 'j, K 'k, L 'l
+}
@@ -0,0 +1,385 @@
+//! C# sources of the managed helpers used by the hosting APIs, meant to be added to the managed project.
+
+/// C# helpers implementing the managed side of the string passing convention of [`StringExchangeBuffer`].
+///
+/// The source defines an `internal static class NativeStrings` with
+/// * `string FromUtf8(IntPtr ptr, int length)`, which reads a string passed using [`utf8_parts`], and
+/// * `int ToUtf8Buffer(string value, IntPtr buffer, int capacity)`, which writes a result for [`StringExchangeBuffer::receive`]
+///   and returns the value the managed method should return.
+///
+/// It only uses safe code and can be added to a managed project as is.
+///
+/// [`StringExchangeBuffer`]: super::StringExchangeBuffer
+/// [`utf8_parts`]: super::utf8_parts
+/// [`StringExchangeBuffer::receive`]: super::StringExchangeBuffer::receive
+pub const MANAGED_STRING_HELPERS: &str = r#"using System;
+using System.Runtime.InteropServices;
+using System.Text;
+
+internal static class NativeStrings {
+    public static string FromUtf8(IntPtr ptr, int length) {
+        return length == 0 ? string.Empty : Marshal.PtrToStringUTF8(ptr, length);
+    }
+
+    public static int ToUtf8Buffer(string value, IntPtr buffer, int capacity) {
+        byte[] bytes = Encoding.UTF8.GetBytes(value);
+        if (bytes.Length <= capacity) {
+            Marshal.Copy(bytes, 0, buffer, bytes.Length);
+        }
+        return bytes.Length;
+    }
+}
+"#;
+
+/// C# helpers implementing the managed side of [`ConsoleInterception`].
+///
+/// The source defines a `public static class NativeConsole` with the `Install` and `Uninstall` methods used by
+/// [`ConsoleInterception::install`]. It requires `AllowUnsafeBlocks` and can be added to a managed project as is.
+///
+/// [`ConsoleInterception`]: super::ConsoleInterception
+/// [`ConsoleInterception::install`]: super::ConsoleInterception::install
+#[cfg(feature = "net5_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+pub const MANAGED_CONSOLE_INTERCEPTOR: &str = r#"using System;
+using System.IO;
+using System.Runtime.InteropServices;
+using System.Text;
+using System.Threading;
+
+public static unsafe class NativeConsole {
+    private static readonly object Gate = new object();
+    private static TextWriter originalOut;
+    private static TextWriter originalError;
+    private static CallbackWriter outWriter;
+    private static CallbackWriter errorWriter;
+
+    [UnmanagedCallersOnly]
+    public static int Install(IntPtr callback, IntPtr state) {
+        lock (Gate) {
+            if (outWriter != null) {
+                return -1;
+            }
+            originalOut = Console.Out;
+            originalError = Console.Error;
+            outWriter = new CallbackWriter(callback, state, 1);
+            errorWriter = new CallbackWriter(callback, state, 2);
+            Console.SetOut(outWriter);
+            Console.SetError(errorWriter);
+            return 0;
+        }
+    }
+
+    [UnmanagedCallersOnly]
+    public static void Uninstall() {
+        lock (Gate) {
+            if (outWriter == null) {
+                return;
+            }
+            Console.SetOut(originalOut);
+            Console.SetError(originalError);
+            outWriter.Flush();
+            errorWriter.Flush();
+            outWriter = null;
+            errorWriter = null;
+        }
+    }
+
+    private sealed class CallbackWriter : TextWriter {
+        private readonly IntPtr callback;
+        private readonly IntPtr state;
+        private readonly int stream;
+        private readonly ThreadLocal<StringBuilder> lines = new ThreadLocal<StringBuilder>(() => new StringBuilder());
+
+        public CallbackWriter(IntPtr callback, IntPtr state, int stream) {
+            this.callback = callback;
+            this.state = state;
+            this.stream = stream;
+        }
+
+        public override Encoding Encoding => Encoding.UTF8;
+
+        public override void Write(char value) {
+            if (value == '\n') {
+                Emit();
+            } else if (value != '\r') {
+                lines.Value.Append(value);
+            }
+        }
+
+        public override void Flush() {
+            if (lines.Value.Length > 0) {
+                Emit();
+            }
+        }
+
+        private void Emit() {
+            StringBuilder line = lines.Value;
+            byte[] bytes = Encoding.UTF8.GetBytes(line.ToString());
+            line.Clear();
+            lock (Gate) {
+                if (outWriter != this && errorWriter != this) {
+                    return;
+                }
+                fixed (byte* ptr = bytes) {
+                    ((delegate* unmanaged<IntPtr, int, int, byte*, int, void>)callback)(
+                        state, stream, Environment.CurrentManagedThreadId, ptr, bytes.Length);
+                }
+            }
+        }
+    }
+}
+"#;
+
+/// C# helpers implementing the managed side of [`DebugOptions`].
+///
+/// The source defines a `public static class NativeDebugger` with an `IsAttached` method returning whether a managed
+/// debugger is attached (`System.Diagnostics.Debugger.IsAttached`). It can be added to a managed project as is.
+///
+/// [`DebugOptions`]: super::DebugOptions
+#[cfg(feature = "net5_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+pub const MANAGED_DEBUGGER_HELPERS: &str = r#"using System.Diagnostics;
+using System.Runtime.InteropServices;
+
+public static class NativeDebugger {
+    [UnmanagedCallersOnly]
+    public static int IsAttached() {
+        return Debugger.IsAttached ? 1 : 0;
+    }
+}
+"#;
+
+/// C# helpers implementing the managed side of [`AssemblyDelegateLoader::warmup_and_prepare`].
+///
+/// The source defines a `public static class NativeWarmup` with a `PrepareMethod` method, which compiles all static
+/// methods with the given name of the type with the given assembly qualified name using
+/// `RuntimeHelpers.PrepareMethod` and returns the `HRESULT` of the exception if that fails.
+/// It can be added to a managed project as is.
+///
+/// [`AssemblyDelegateLoader::warmup_and_prepare`]: super::AssemblyDelegateLoader::warmup_and_prepare
+#[cfg(feature = "net5_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+pub const MANAGED_WARMUP_HELPERS: &str = r#"using System;
+using System.Reflection;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+
+public static class NativeWarmup {
+    [UnmanagedCallersOnly]
+    public static int PrepareMethod(IntPtr typeName, IntPtr methodName) {
+        try {
+            Type type = Type.GetType(Marshal.PtrToStringAuto(typeName), throwOnError: true);
+            string name = Marshal.PtrToStringAuto(methodName);
+            BindingFlags flags = BindingFlags.Public | BindingFlags.NonPublic | BindingFlags.Static;
+            bool found = false;
+            foreach (MethodInfo method in type.GetMethods(flags)) {
+                if (method.Name == name && !method.ContainsGenericParameters) {
+                    RuntimeHelpers.PrepareMethod(method.MethodHandle);
+                    found = true;
+                }
+            }
+            return found ? 0 : new MissingMethodException(type.FullName, name).HResult;
+        } catch (Exception e) {
+            return e.HResult;
+        }
+    }
+}
+"#;
+
+/// C# helpers implementing the managed side of [`AssemblyDelegateLoader::ensure_initialized`] and
+/// [`AssemblyDelegateLoader::ensure_module_initialized`].
+///
+/// The source defines a `public static class NativeInitializer` with `RunClassConstructor` and `RunModuleConstructor`
+/// methods, which run the static constructor of the type with the given assembly qualified name or the module
+/// initializer of its module and return the `HRESULT` of the exception if that fails.
+/// It can be added to a managed project as is.
+///
+/// [`AssemblyDelegateLoader::ensure_initialized`]: super::AssemblyDelegateLoader::ensure_initialized
+/// [`AssemblyDelegateLoader::ensure_module_initialized`]: super::AssemblyDelegateLoader::ensure_module_initialized
+#[cfg(feature = "net5_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+pub const MANAGED_INITIALIZER_HELPERS: &str = r#"using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+
+public static class NativeInitializer {
+    [UnmanagedCallersOnly]
+    public static int RunClassConstructor(IntPtr typeName) {
+        try {
+            Type type = Type.GetType(Marshal.PtrToStringAuto(typeName), throwOnError: true);
+            RuntimeHelpers.RunClassConstructor(type.TypeHandle);
+            return 0;
+        } catch (Exception e) {
+            return e.HResult;
+        }
+    }
+
+    [UnmanagedCallersOnly]
+    public static int RunModuleConstructor(IntPtr typeName) {
+        try {
+            Type type = Type.GetType(Marshal.PtrToStringAuto(typeName), throwOnError: true);
+            RuntimeHelpers.RunModuleConstructor(type.Module.ModuleHandle);
+            return 0;
+        } catch (Exception e) {
+            return e.HResult;
+        }
+    }
+}
+"#;
+
+/// C# helpers implementing the managed side of [`AssemblyDelegateLoader::get_function_generic`] and
+/// [`AssemblyDelegateLoader::get_function_by_signature`].
+///
+/// The source defines a `public static class NativeMethodBinding` with a `BindGenericMethod` method, which
+/// instantiates a generic static method with the given type arguments using `MethodInfo.MakeGenericMethod`, and a
+/// `BindMethod` method, which selects the overload of a static method with the given parameter types.
+/// Both return a function pointer for the method, which is created for a delegate type generated to match the
+/// signature of the method and kept alive for the lifetime of the runtime.
+/// It can be added to a managed project as is.
+///
+/// [`AssemblyDelegateLoader::get_function_generic`]: super::AssemblyDelegateLoader::get_function_generic
+/// [`AssemblyDelegateLoader::get_function_by_signature`]: super::AssemblyDelegateLoader::get_function_by_signature
+#[cfg(feature = "net5_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+pub const MANAGED_METHOD_BINDING_HELPERS: &str = r#"using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Reflection;
+using System.Reflection.Emit;
+using System.Runtime.InteropServices;
+
+public static class NativeMethodBinding {
+    private static readonly ModuleBuilder DelegateTypes = AssemblyBuilder
+        .DefineDynamicAssembly(new AssemblyName("NativeMethodBinding.DelegateTypes"), AssemblyBuilderAccess.Run)
+        .DefineDynamicModule("NativeMethodBinding.DelegateTypes");
+    // the delegates have to be kept alive as long as their function pointers may be called.
+    private static readonly List<Delegate> Delegates = new List<Delegate>();
+
+    [UnmanagedCallersOnly]
+    public static int BindGenericMethod(IntPtr typeName, IntPtr methodName, IntPtr typeArguments, int typeArgumentCount, IntPtr result) {
+        try {
+            Type type = ResolveType(typeName);
+            string name = Marshal.PtrToStringAuto(methodName);
+            Type[] arguments = ResolveTypes(typeArguments, typeArgumentCount);
+            MethodInfo method = type
+                .GetMethods(BindingFlags.Public | BindingFlags.NonPublic | BindingFlags.Static)
+                .SingleOrDefault(m => m.Name == name && m.IsGenericMethodDefinition && m.GetGenericArguments().Length == arguments.Length)
+                ?? throw new MissingMethodException(type.FullName, name);
+            Marshal.WriteIntPtr(result, Bind(method.MakeGenericMethod(arguments)));
+            return 0;
+        } catch (Exception e) {
+            return e.HResult;
+        }
+    }
+
+    [UnmanagedCallersOnly]
+    public static int BindMethod(IntPtr typeName, IntPtr methodName, IntPtr parameterTypes, int parameterTypeCount, IntPtr result) {
+        try {
+            Type type = ResolveType(typeName);
+            string name = Marshal.PtrToStringAuto(methodName);
+            Type[] parameters = ResolveTypes(parameterTypes, parameterTypeCount);
+            MethodInfo method = type.GetMethod(name, BindingFlags.Public | BindingFlags.NonPublic | BindingFlags.Static, null, parameters, null)
+                ?? throw new MissingMethodException(type.FullName, name);
+            Marshal.WriteIntPtr(result, Bind(method));
+            return 0;
+        } catch (Exception e) {
+            return e.HResult;
+        }
+    }
+
+    private static Type ResolveType(IntPtr name) {
+        return Type.GetType(Marshal.PtrToStringAuto(name), throwOnError: true);
+    }
+
+    private static Type[] ResolveTypes(IntPtr names, int count) {
+        Type[] types = new Type[count];
+        for (int i = 0; i < count; i++) {
+            types[i] = ResolveType(Marshal.ReadIntPtr(names, i * IntPtr.Size));
+        }
+        return types;
+    }
+
+    private static IntPtr Bind(MethodInfo method) {
+        Type[] parameterTypes = method.GetParameters().Select(p => p.ParameterType).ToArray();
+        Delegate function;
+        lock (Delegates) {
+            Type delegateType = CreateDelegateType("NativeDelegate" + Delegates.Count, method.ReturnType, parameterTypes);
+            function = Delegate.CreateDelegate(delegateType, method);
+            Delegates.Add(function);
+        }
+        return Marshal.GetFunctionPointerForDelegate(function);
+    }
+
+    private static Type CreateDelegateType(string name, Type returnType, Type[] parameterTypes) {
+        TypeBuilder builder = DelegateTypes.DefineType(name, TypeAttributes.Public | TypeAttributes.Sealed, typeof(MulticastDelegate));
+        builder
+            .DefineConstructor(
+                MethodAttributes.Public | MethodAttributes.HideBySig | MethodAttributes.SpecialName | MethodAttributes.RTSpecialName,
+                CallingConventions.Standard,
+                new[] { typeof(object), typeof(IntPtr) })
+            .SetImplementationFlags(MethodImplAttributes.Runtime | MethodImplAttributes.Managed);
+        builder
+            .DefineMethod(
+                "Invoke",
+                MethodAttributes.Public | MethodAttributes.HideBySig | MethodAttributes.NewSlot | MethodAttributes.Virtual,
+                returnType,
+                parameterTypes)
+            .SetImplementationFlags(MethodImplAttributes.Runtime | MethodImplAttributes.Managed);
+        return builder.CreateType();
+    }
+}
+"#;
+
+/// C# helper implementing the managed side of a [`JsonFunction`].
+///
+/// The source defines an `internal static unsafe class JsonBridge` with
+/// `int Invoke<TRequest, TResponse>(IntPtr args, int sizeBytes, Func<TRequest, TResponse> handler)`, which reads the
+/// request, calls the handler and passes the response back. Exceptions thrown by the handler are passed back as their
+/// message and reported as [`JsonCallError::Managed`].
+///
+/// JSON is (de)serialized using `System.Text.Json` with the web defaults, so property names are expected in camel
+/// case (e.g. using `#[serde(rename_all = "camelCase")]` on the Rust side). The project has to set
+/// `<AllowUnsafeBlocks>true</AllowUnsafeBlocks>`.
+///
+/// [`JsonFunction`]: super::JsonFunction
+/// [`JsonCallError::Managed`]: super::JsonCallError::Managed
+#[cfg(all(feature = "serde-bridge", feature = "net5_0"))]
+#[cfg_attr(
+    feature = "doc-cfg",
+    doc(cfg(all(feature = "serde-bridge", feature = "net5_0")))
+)]
+pub const MANAGED_JSON_BRIDGE: &str = r#"using System;
+using System.Runtime.InteropServices;
+using System.Text;
+using System.Text.Json;
+
+internal static unsafe class JsonBridge {
+    private static readonly JsonSerializerOptions Options = new JsonSerializerOptions(JsonSerializerDefaults.Web);
+
+    [StructLayout(LayoutKind.Sequential)]
+    private struct Args {
+        public byte* Request;
+        public int RequestLength;
+        public IntPtr Response;
+        public delegate* unmanaged<IntPtr, byte*, int, void> Respond;
+    }
+
+    public static int Invoke<TRequest, TResponse>(IntPtr args, int sizeBytes, Func<TRequest, TResponse> handler) {
+        Args* bridge = (Args*)args;
+        byte[] response;
+        int result;
+        try {
+            var request = JsonSerializer.Deserialize<TRequest>(new ReadOnlySpan<byte>(bridge->Request, bridge->RequestLength), Options);
+            response = JsonSerializer.SerializeToUtf8Bytes(handler(request), Options);
+            result = 0;
+        } catch (Exception e) {
+            response = Encoding.UTF8.GetBytes(e.Message);
+            result = -1;
+        }
+        fixed (byte* ptr = response) {
+            bridge->Respond(bridge->Response, ptr, response.Length);
+        }
+        return result;
+    }
+}
+"#;
@@ -0,0 +1,142 @@
+use std::ptr;
+
+use crate::{
+    bindings::char_t,
+    hostfxr::{
+        AssemblyDelegateLoader, DelegateSignature, FunctionPtr, GetManagedFunctionError,
+        ManagedFunction, RawFunctionPtr,
+    },
+    pdcstr,
+    pdcstring::PdCStr,
+};
+
+use super::name_validation::{validate_method_name, validate_type_name};
+
+type BindMethodFn =
+    fn(*const char_t, *const char_t, *const *const char_t, i32, *mut RawFunctionPtr) -> i32;
+
+impl AssemblyDelegateLoader {
+    /// Loads a function pointer for a generic static method instantiated with the given type arguments
+    /// (like `Type.Method<int>`), which the hosting components cannot load on their own.
+    ///
+    /// The method is instantiated by the `NativeMethodBinding` class with the given assembly qualified type name using
+    /// `MethodInfo.MakeGenericMethod`, see [`MANAGED_METHOD_BINDING_HELPERS`]. `type_arguments` are the assembly
+    /// qualified names of the type arguments, the assembly can be omitted for types of the core library
+    /// (like `System.Int32`). The generic method is selected by its name and number of type parameters and `F` has to
+    /// match the signature of its instantiation.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use netcorehost::{hostfxr::AssemblyDelegateLoader, pdcstr};
+    /// # fn test(loader: AssemblyDelegateLoader) {
+    /// let max = loader
+    ///     .get_function_generic::<fn(i32, i32) -> i32>(
+    ///         pdcstr!("NativeMethodBinding, MyApp"),
+    ///         pdcstr!("MyApp.Math, MyApp"),
+    ///         pdcstr!("Max"),
+    ///         &[pdcstr!("System.Int32")],
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(max(1, 2), 2);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if more than [`i32::MAX`] type arguments are given.
+    ///
+    /// [`MANAGED_METHOD_BINDING_HELPERS`]: super::MANAGED_METHOD_BINDING_HELPERS
+    pub fn get_function_generic<F: DelegateSignature>(
+        &self,
+        helper_type_name: &PdCStr,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+        type_arguments: &[&PdCStr],
+    ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
+        validate_type_name(type_name)?;
+        validate_method_name(method_name)?;
+        self.bind_method::<F>(
+            helper_type_name,
+            pdcstr!("BindGenericMethod"),
+            type_name,
+            method_name,
+            type_arguments,
+        )
+    }
+
+    /// Loads a function pointer for the overload of a static method with the given parameter types, without
+    /// declaring a delegate type for it (see [`DelegateTypeSpec::Custom`]).
+    ///
+    /// The overload is selected by the `NativeMethodBinding` class with the given assembly qualified type name using
+    /// `Type.GetMethod`, see [`MANAGED_METHOD_BINDING_HELPERS`]. `parameter_types` are the assembly qualified names of
+    /// the parameter types, the assembly can be omitted for types of the core library (like `System.Int32`).
+    /// `F` has to match the signature of the selected overload.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use netcorehost::{hostfxr::AssemblyDelegateLoader, pdcstr};
+    /// # fn test(loader: AssemblyDelegateLoader) {
+    /// let scale = loader
+    ///     .get_function_by_signature::<fn(f64) -> f64>(
+    ///         pdcstr!("NativeMethodBinding, MyApp"),
+    ///         pdcstr!("MyApp.Math, MyApp"),
+    ///         pdcstr!("Scale"),
+    ///         &[pdcstr!("System.Double")],
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(scale(3.0), 1.5);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if more than [`i32::MAX`] parameter types are given.
+    ///
+    /// [`DelegateTypeSpec::Custom`]: super::DelegateTypeSpec::Custom
+    /// [`MANAGED_METHOD_BINDING_HELPERS`]: super::MANAGED_METHOD_BINDING_HELPERS
+    pub fn get_function_by_signature<F: DelegateSignature>(
+        &self,
+        helper_type_name: &PdCStr,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+        parameter_types: &[&PdCStr],
+    ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
+        validate_type_name(type_name)?;
+        validate_method_name(method_name)?;
+        self.bind_method::<F>(
+            helper_type_name,
+            pdcstr!("BindMethod"),
+            type_name,
+            method_name,
+            parameter_types,
+        )
+    }
+
+    fn bind_method<F: DelegateSignature>(
+        &self,
+        helper_type_name: &PdCStr,
+        helper_method_name: &PdCStr,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+        type_names: &[&PdCStr],
+    ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
+        let bind_method = self.get_function_with_unmanaged_callers_only::<BindMethodFn>(
+            helper_type_name,
+            helper_method_name,
+        )?;
+
+        let type_names = type_names
+            .iter()
+            .map(|type_name| type_name.as_ptr())
+            .collect::<Vec<_>>();
+        let type_name_count = i32::try_from(type_names.len()).expect("too many type names");
+        let mut function = ptr::null();
+        let result = bind_method(
+            type_name.as_ptr(),
+            method_name.as_ptr(),
+            type_names.as_ptr(),
+            type_name_count,
+            ptr::from_mut(&mut function),
+        );
+        GetManagedFunctionError::from_status_code(result)?;
+        Ok(ManagedFunction(unsafe { F::Managed::from_ptr(function) }))
+    }
+}
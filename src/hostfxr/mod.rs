@@ -1,6 +1,31 @@
 mod library;
 pub use library::*;
 
+mod error_mode;
+pub(crate) use error_mode::ErrorModeGuard;
+
+mod call_scope;
+pub use call_scope::*;
+
+mod thread_naming;
+pub use thread_naming::{clear_thread_name_hook, set_thread_name_hook};
+pub(crate) use thread_naming::{thread_name, worker_thread_name};
+
+mod runtime_state;
+pub use runtime_state::*;
+
+mod exit_hook;
+pub use exit_hook::*;
+
+mod embedded_assembly;
+pub use embedded_assembly::*;
+
+mod assembly_lookup;
+pub use assembly_lookup::*;
+
+#[cfg(feature = "unstable")]
+pub(crate) mod resolution_trace;
+
 #[cfg(feature = "netcore1_0")]
 mod library1_0;
 #[cfg(feature = "netcore1_0")]
@@ -8,6 +33,9 @@ mod library1_0;
 #[allow(unused)]
 pub use library1_0::*;
 
+#[cfg(feature = "netcore2_0")]
+mod library2_0;
+
 #[cfg(feature = "netcore2_1")]
 mod library2_1;
 #[cfg(feature = "netcore2_1")]
@@ -27,18 +55,63 @@ mod library6_0;
 #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net6_0")))]
 pub use library6_0::*;
 
+#[cfg(feature = "net9_0")]
+mod library9_0;
+#[cfg(feature = "net9_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net9_0")))]
+pub use library9_0::*;
+
+#[cfg(feature = "netcore3_0")]
+mod parameters;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use parameters::*;
+
 #[cfg(feature = "netcore3_0")]
 mod context;
 #[cfg(feature = "netcore3_0")]
 #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
 pub use context::*;
 
+#[cfg(feature = "netcore3_0")]
+mod runtime_delegate;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use runtime_delegate::*;
+
+#[cfg(feature = "netcore3_0")]
+mod strict_checks;
+
 #[cfg(feature = "netcore3_0")]
 mod delegate_loader;
 #[cfg(feature = "netcore3_0")]
 #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
 pub use delegate_loader::*;
 
+#[cfg(feature = "netcore3_0")]
+mod caching_delegate_loader;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use caching_delegate_loader::*;
+
+#[cfg(feature = "netcore3_0")]
+mod function_timeout;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use function_timeout::*;
+
+#[cfg(feature = "netcore3_0")]
+mod name_validation;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use name_validation::InvalidNameReason;
+
+#[cfg(feature = "netcore3_0")]
+mod type_name;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use type_name::*;
+
 #[cfg(feature = "netcore3_0")]
 mod runtime_property;
 #[cfg(feature = "netcore3_0")]
@@ -46,8 +119,169 @@ mod runtime_property;
 #[allow(unused)]
 pub use runtime_property::*;
 
+#[cfg(feature = "netcore3_0")]
+mod error_writer;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use error_writer::*;
+
+#[cfg(all(windows, feature = "netcore3_0"))]
+mod com_activation;
+#[cfg(all(windows, feature = "netcore3_0"))]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(all(windows, feature = "netcore3_0"))))]
+pub use com_activation::*;
+
+#[cfg(feature = "netcore3_0")]
+mod runtime_version;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use runtime_version::*;
+
+#[cfg(feature = "netcore3_0")]
+mod hostfxr_candidates;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use hostfxr_candidates::*;
+
+#[cfg(feature = "netcore3_0")]
+mod runtime_options;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use runtime_options::*;
+
+#[cfg(feature = "netcore3_0")]
+/// Names of well-known runtime properties set by the hosting components and typed accessors for them.
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub mod runtime_properties;
+
+#[cfg(feature = "netcore3_0")]
+mod trusted_platform_assemblies;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use trusted_platform_assemblies::*;
+
 #[cfg(feature = "netcore3_0")]
 mod managed_function;
 #[cfg(feature = "netcore3_0")]
 #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
 pub use managed_function::*;
+
+#[cfg(feature = "netcore3_0")]
+mod by_ref;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use by_ref::*;
+
+#[cfg(feature = "netcore3_0")]
+mod string_exchange;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use string_exchange::*;
+
+#[cfg(feature = "netcore3_0")]
+mod managed_helpers;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use managed_helpers::*;
+
+#[cfg(feature = "net5_0")]
+mod unmanaged_export;
+#[cfg(feature = "net5_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+pub use unmanaged_export::*;
+
+#[cfg(feature = "netcore3_0")]
+mod diagnostics;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use diagnostics::*;
+
+#[cfg(feature = "netcore3_0")]
+mod warmup;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use warmup::*;
+
+#[cfg(feature = "net5_0")]
+mod type_initialization;
+
+#[cfg(feature = "net5_0")]
+mod method_binding;
+
+#[cfg(feature = "netcore3_0")]
+mod multi_assembly;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use multi_assembly::*;
+
+#[cfg(feature = "netcore3_0")]
+mod runtime_config;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use runtime_config::*;
+
+#[cfg(feature = "netcore3_0")]
+mod runtime_config_json;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use runtime_config_json::*;
+
+#[cfg(feature = "netcore3_0")]
+mod attach;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use attach::*;
+
+#[cfg(feature = "netcore3_0")]
+mod output_capture;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use output_capture::*;
+
+#[cfg(feature = "net5_0")]
+mod console_interception;
+#[cfg(feature = "net5_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+pub use console_interception::*;
+
+#[cfg(feature = "net5_0")]
+mod debugging;
+#[cfg(feature = "net5_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+pub use debugging::*;
+
+#[cfg(feature = "netcore3_0")]
+mod debug_info;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use debug_info::*;
+
+#[cfg(feature = "netcore3_0")]
+mod context_pool;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use context_pool::*;
+
+#[cfg(feature = "netcore3_0")]
+mod managed_extern;
+
+#[cfg(feature = "netcore3_0")]
+mod callback;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use callback::*;
+
+#[cfg(feature = "netcore3_0")]
+mod callback_handle;
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use callback_handle::*;
+
+#[cfg(all(feature = "serde-bridge", feature = "net5_0"))]
+mod json_bridge;
+#[cfg(all(feature = "serde-bridge", feature = "net5_0"))]
+#[cfg_attr(
+    feature = "doc-cfg",
+    doc(cfg(all(feature = "serde-bridge", feature = "net5_0")))
+)]
+pub use json_bridge::*;
@@ -0,0 +1,259 @@
+use std::{cell::RefCell, collections::HashMap, fmt, rc::Rc};
+
+use crate::{
+    bindings::hostfxr::component_entry_point_fn,
+    error::HostingError,
+    hostfxr::{
+        warmup::warmup_with, DelegateLoader, DelegateSignature, DelegateTypeSpec, FunctionPtr,
+        GetManagedFunctionError, HostfxrContext, ManagedFunction,
+        ManagedFunctionWithDefaultSignature, RawFunctionPtr, WarmupMethod, WarmupReport,
+    },
+    pdcstring::{PdCStr, PdCString},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum DelegateTypeKey {
+    Default,
+    #[cfg(feature = "net5_0")]
+    UnmanagedCallersOnly,
+    Custom(PdCString),
+}
+
+impl From<DelegateTypeSpec<'_>> for DelegateTypeKey {
+    fn from(delegate_type: DelegateTypeSpec<'_>) -> Self {
+        match delegate_type {
+            DelegateTypeSpec::Default => Self::Default,
+            #[cfg(feature = "net5_0")]
+            DelegateTypeSpec::UnmanagedCallersOnly => Self::UnmanagedCallersOnly,
+            DelegateTypeSpec::Custom(delegate_type_name) => {
+                Self::Custom(delegate_type_name.to_owned())
+            }
+        }
+    }
+}
+
+type FunctionKey = (PdCString, PdCString, DelegateTypeKey);
+type FunctionCache = Rc<RefCell<HashMap<FunctionKey, RawFunctionPtr>>>;
+
+/// A struct for loading pointers to managed functions from many assemblies using a single [`DelegateLoader`].
+///
+/// In contrast to holding one [`AssemblyDelegateLoader`](crate::hostfxr::AssemblyDelegateLoader) per assembly,
+/// the runtime delegates are only stored once and resolved function pointers are cached per assembly,
+/// so repeated lookups of the same method do not call into the runtime again.
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{hostfxr::{HostfxrContext, InitializedForRuntimeConfig}, pdcstr};
+/// # fn test(context: HostfxrContext<InitializedForRuntimeConfig>) {
+/// let loader = context.get_multi_assembly_delegate_loader().unwrap();
+/// let run = loader
+///     .for_assembly(pdcstr!("plugins/A.dll"))
+///     .get_function_with_unmanaged_callers_only::<fn() -> i32>(
+///         pdcstr!("A.Plugin, A"),
+///         pdcstr!("Run"),
+///     )
+///     .unwrap();
+/// # }
+/// ```
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub struct MultiAssemblyDelegateLoader {
+    loader: DelegateLoader,
+    assemblies: RefCell<HashMap<PdCString, FunctionCache>>,
+}
+
+impl fmt::Debug for MultiAssemblyDelegateLoader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultiAssemblyDelegateLoader")
+            .field("assemblies", &self.assemblies.borrow().keys())
+            .finish_non_exhaustive()
+    }
+}
+
+impl MultiAssemblyDelegateLoader {
+    /// Creates a new [`MultiAssemblyDelegateLoader`] wrapping the given [`DelegateLoader`].
+    #[must_use]
+    pub fn new(loader: DelegateLoader) -> Self {
+        Self {
+            loader,
+            assemblies: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the underlying [`DelegateLoader`].
+    #[must_use]
+    pub const fn delegate_loader(&self) -> &DelegateLoader {
+        &self.loader
+    }
+
+    /// Returns a loader for functions of the assembly at the given path.
+    /// The assembly is loaded in isolation on the first access, see [`DelegateLoader::load_assembly_and_get_function`].
+    pub fn for_assembly(&self, assembly_path: impl AsRef<PdCStr>) -> AssemblyFunctionLoader<'_> {
+        let assembly_path = assembly_path.as_ref();
+        let functions = self
+            .assemblies
+            .borrow_mut()
+            .entry(assembly_path.to_owned())
+            .or_default()
+            .clone();
+        AssemblyFunctionLoader {
+            loader: &self.loader,
+            assembly_path: assembly_path.to_owned(),
+            functions,
+        }
+    }
+
+    /// Returns the paths of all assemblies that were accessed through this loader.
+    #[must_use]
+    pub fn assemblies(&self) -> Vec<PdCString> {
+        self.assemblies.borrow().keys().cloned().collect()
+    }
+
+    /// Resolves the given methods of each assembly ahead of time, see [`AssemblyDelegateLoader::warmup`](crate::hostfxr::AssemblyDelegateLoader::warmup).
+    /// Successfully resolved methods are cached.
+    ///
+    /// Returns a report for each assembly in the order they were given.
+    pub fn preload<'a, P, M>(
+        &self,
+        assemblies: impl IntoIterator<Item = (P, M)>,
+    ) -> Vec<(PdCString, WarmupReport)>
+    where
+        P: AsRef<PdCStr>,
+        M: IntoIterator,
+        M::Item: Into<WarmupMethod<'a>>,
+    {
+        assemblies
+            .into_iter()
+            .map(|(assembly_path, methods)| {
+                let report = self.for_assembly(&assembly_path).warmup(methods);
+                (assembly_path.as_ref().to_owned(), report)
+            })
+            .collect()
+    }
+}
+
+/// A loader for functions of a single assembly of a [`MultiAssemblyDelegateLoader`],
+/// returned by [`MultiAssemblyDelegateLoader::for_assembly`].
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub struct AssemblyFunctionLoader<'a> {
+    loader: &'a DelegateLoader,
+    assembly_path: PdCString,
+    functions: FunctionCache,
+}
+
+impl fmt::Debug for AssemblyFunctionLoader<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AssemblyFunctionLoader")
+            .field("assembly_path", &self.assembly_path)
+            .field("cached_functions", &self.functions.borrow().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl AssemblyFunctionLoader<'_> {
+    /// Returns the path of the assembly functions are loaded from.
+    #[must_use]
+    pub fn assembly_path(&self) -> &PdCStr {
+        &self.assembly_path
+    }
+
+    /// Returns the pointer to the given method, using the cached pointer if it was resolved before.
+    ///
+    /// See [`AssemblyDelegateLoader::get_function_with_delegate_type`](crate::hostfxr::AssemblyDelegateLoader::get_function_with_delegate_type)
+    /// for details. `F` has to match the signature specified by `delegate_type`.
+    pub fn get_function_with_delegate_type<F: DelegateSignature>(
+        &self,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+        delegate_type: DelegateTypeSpec<'_>,
+    ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
+        let key = (
+            type_name.to_owned(),
+            method_name.to_owned(),
+            DelegateTypeKey::from(delegate_type),
+        );
+        if let Some(&function) = self.functions.borrow().get(&key) {
+            return Ok(ManagedFunction(unsafe { F::Managed::from_ptr(function) }));
+        }
+
+        let function = self
+            .loader
+            .load_assembly_and_get_function_with_delegate_type::<F>(
+                &self.assembly_path,
+                type_name,
+                method_name,
+                delegate_type,
+            )?;
+        self.functions
+            .borrow_mut()
+            .insert(key, FunctionPtr::as_ptr(&*function));
+        Ok(function)
+    }
+
+    /// Returns the pointer to the given method with the signature of the delegate type with the given assembly qualified name.
+    /// See [`get_function_with_delegate_type`](AssemblyFunctionLoader::get_function_with_delegate_type).
+    pub fn get_function<F: DelegateSignature>(
+        &self,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+        delegate_type_name: &PdCStr,
+    ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
+        self.get_function_with_delegate_type::<F>(
+            type_name,
+            method_name,
+            DelegateTypeSpec::Custom(delegate_type_name),
+        )
+    }
+
+    /// Returns the pointer to the given method with the default signature.
+    /// See [`get_function_with_delegate_type`](AssemblyFunctionLoader::get_function_with_delegate_type).
+    pub fn get_function_with_default_signature(
+        &self,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+    ) -> Result<ManagedFunctionWithDefaultSignature, GetManagedFunctionError> {
+        self.get_function_with_delegate_type::<component_entry_point_fn>(
+            type_name,
+            method_name,
+            DelegateTypeSpec::Default,
+        )
+    }
+
+    /// Returns the pointer to the given method annotated with `UnmanagedCallersOnly`.
+    /// See [`get_function_with_delegate_type`](AssemblyFunctionLoader::get_function_with_delegate_type).
+    #[cfg(feature = "net5_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+    pub fn get_function_with_unmanaged_callers_only<F: DelegateSignature>(
+        &self,
+        type_name: &PdCStr,
+        method_name: &PdCStr,
+    ) -> Result<ManagedFunction<F::Managed>, GetManagedFunctionError> {
+        self.get_function_with_delegate_type::<F>(
+            type_name,
+            method_name,
+            DelegateTypeSpec::UnmanagedCallersOnly,
+        )
+    }
+
+    /// Resolves the given methods ahead of time and caches them,
+    /// see [`AssemblyDelegateLoader::warmup`](crate::hostfxr::AssemblyDelegateLoader::warmup).
+    pub fn warmup<'m>(
+        &self,
+        methods: impl IntoIterator<Item = impl Into<WarmupMethod<'m>>>,
+    ) -> WarmupReport {
+        warmup_with(methods, |type_name, method_name, delegate_type| {
+            // the function is never called, so the signature does not matter.
+            self.get_function_with_delegate_type::<fn()>(type_name, method_name, delegate_type)
+                .map(|_| ())
+        })
+    }
+}
+
+impl<I> HostfxrContext<I> {
+    /// Gets a [`MultiAssemblyDelegateLoader`] for loading function pointers from many assemblies.
+    pub fn get_multi_assembly_delegate_loader(
+        &self,
+    ) -> Result<MultiAssemblyDelegateLoader, HostingError> {
+        self.get_delegate_loader()
+            .map(MultiAssemblyDelegateLoader::new)
+    }
+}
@@ -0,0 +1,100 @@
+use thiserror::Error;
+
+use crate::{hostfxr::GetManagedFunctionError, pdcstring::PdCStr};
+
+/// The reason why a type or method name passed to a [`DelegateLoader`](crate::hostfxr::DelegateLoader) was rejected.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+#[non_exhaustive]
+pub enum InvalidNameReason {
+    /// The name is empty.
+    #[error("the name is empty")]
+    Empty,
+    /// The name starts or ends with whitespace.
+    #[error("the name has leading or trailing whitespace")]
+    SurroundingWhitespace,
+    /// The type name is not assembly qualified.
+    #[error(
+        "the type name is not assembly qualified, expected the format \"Namespace.Type, Assembly\""
+    )]
+    MissingAssemblyName,
+    /// A generic type or method was specified using the C# syntax.
+    #[error("generic types have to be specified using their arity, like \"List`1[[System.Int32, System.Private.CoreLib]]\" instead of \"List<int>\"")]
+    CSharpGenericSyntax,
+    /// The method name contains whitespace.
+    #[error("method names cannot contain whitespace")]
+    ContainsWhitespace,
+    /// The method name contains a parameter list.
+    #[error("method names must not include a parameter list")]
+    ContainsParameterList,
+}
+
+/// Validates that `type_name` has the format `Namespace.Type, Assembly`.
+pub(crate) fn validate_type_name(type_name: &PdCStr) -> Result<(), GetManagedFunctionError> {
+    let input = type_name.to_string_lossy();
+    check_type_name(&input)
+        .map_err(|reason| GetManagedFunctionError::InvalidTypeName { input, reason })
+}
+
+/// Validates that `method_name` is a plain method name.
+pub(crate) fn validate_method_name(method_name: &PdCStr) -> Result<(), GetManagedFunctionError> {
+    let input = method_name.to_string_lossy();
+    check_method_name(&input)
+        .map_err(|reason| GetManagedFunctionError::InvalidMethodName { input, reason })
+}
+
+fn check_type_name(name: &str) -> Result<(), InvalidNameReason> {
+    check_common(name)?;
+
+    // find the comma separating the type from the assembly name, ignoring the ones inside of generic arguments.
+    let mut depth = 0usize;
+    let mut escaped = false;
+    let mut assembly_name = None;
+    for (index, c) in name.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                assembly_name = Some(&name[index + 1..]);
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    match assembly_name {
+        Some(assembly_name) if !assembly_name.trim().is_empty() => Ok(()),
+        _ => Err(InvalidNameReason::MissingAssemblyName),
+    }
+}
+
+fn check_method_name(name: &str) -> Result<(), InvalidNameReason> {
+    check_common(name)?;
+    if name.contains('(') {
+        return Err(InvalidNameReason::ContainsParameterList);
+    }
+    if name.contains(char::is_whitespace) {
+        return Err(InvalidNameReason::ContainsWhitespace);
+    }
+    Ok(())
+}
+
+fn check_common(name: &str) -> Result<(), InvalidNameReason> {
+    if name.is_empty() {
+        return Err(InvalidNameReason::Empty);
+    }
+    if name.trim() != name {
+        return Err(InvalidNameReason::SurroundingWhitespace);
+    }
+    // compiler generated names like "<>c" or "<Main>$" also contain angle brackets, but never directly after an identifier.
+    let uses_generic_syntax = name
+        .as_bytes()
+        .windows(2)
+        .any(|pair| pair[1] == b'<' && (pair[0].is_ascii_alphanumeric() || pair[0] == b'_'));
+    if uses_generic_syntax {
+        return Err(InvalidNameReason::CSharpGenericSyntax);
+    }
+    Ok(())
+}
@@ -0,0 +1,353 @@
+use std::{
+    fs::File,
+    io::{self, Read, Write},
+    mem, process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Receiver, Sender},
+    },
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::hostfxr::{AppOrHostingResult, HostfxrContext, InitializedForCommandLine};
+
+/// The result of [`HostfxrContext::run_app_captured`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub struct CapturedOutput {
+    /// The exit code of the application or the error of the hosting components.
+    pub result: AppOrHostingResult,
+    /// Everything written to the standard output while the application was running.
+    pub stdout: Vec<u8>,
+    /// Everything written to the standard error while the application was running.
+    pub stderr: Vec<u8>,
+}
+
+impl HostfxrContext<InitializedForCommandLine> {
+    /// Like [`run_app`](HostfxrContext::run_app), but captures everything the application writes to the standard output
+    /// and standard error instead of passing it through.
+    ///
+    /// The process-wide standard streams are redirected into pipes for the duration of the call,
+    /// so output of other threads of the current process is captured as well.
+    /// On Windows, only output written through the standard handles (which includes `System.Console`) is captured,
+    /// output written through file descriptors of the C runtime is not.
+    ///
+    /// `System.Console` keeps using the pipes after the call, as it caches the streams it opened. The pipes are
+    /// therefore kept open and drained for the lifetime of the process, and anything written to them afterwards (for
+    /// example by background threads of the application) is forwarded to the restored standard streams.
+    ///
+    /// Returns an error if the streams could not be redirected, in which case the application is not run.
+    pub fn run_app_captured(self) -> io::Result<CapturedOutput> {
+        flush_std_streams();
+
+        let stdout = StreamCapture::start(sys::Stream::Stdout)?;
+        let stderr = StreamCapture::start(sys::Stream::Stderr)?;
+        let result = self.run_app();
+        sys::flush_c_runtime();
+        flush_std_streams();
+
+        Ok(CapturedOutput {
+            result,
+            stdout: stdout.finish()?,
+            stderr: stderr.finish()?,
+        })
+    }
+}
+
+fn flush_std_streams() {
+    let _ = io::stdout().flush();
+    let _ = io::stderr().flush();
+}
+
+struct StreamCapture {
+    redirect: Option<sys::Redirect>,
+    writer: File,
+    marker: Vec<u8>,
+    output: Receiver<io::Result<Vec<u8>>>,
+}
+
+impl StreamCapture {
+    fn start(stream: sys::Stream) -> io::Result<Self> {
+        static CAPTURES: AtomicUsize = AtomicUsize::new(0);
+
+        // written after the run to find the end of the captured output, as the pipe is never closed.
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos());
+        let marker = format!(
+            "\0netcorehost-capture-{}-{}-{nanos}\0",
+            process::id(),
+            CAPTURES.fetch_add(1, Ordering::Relaxed)
+        )
+        .into_bytes();
+
+        let (redirect, pipe, writer) = sys::Redirect::start(stream)?;
+        let (sender, output) = mpsc::channel();
+        let reader_marker = marker.clone();
+        // the pipe has to be drained while the app is running, as writes block once its buffer is full.
+        // the thread is not joined, as it keeps forwarding the output written after the capture.
+        thread::Builder::new()
+            .name(super::thread_name("netcorehost-output-capture"))
+            .spawn(move || drain(pipe, stream, &reader_marker, &sender))?;
+        Ok(Self {
+            redirect: Some(redirect),
+            writer,
+            marker,
+            output,
+        })
+    }
+
+    fn finish(mut self) -> io::Result<Vec<u8>> {
+        drop(self.redirect.take());
+        self.writer.write_all(&self.marker)?;
+        self.output.recv().unwrap_or_else(|_| {
+            Err(io::Error::new(
+                io::ErrorKind::BrokenPipe,
+                "the output capture ended unexpectedly",
+            ))
+        })
+    }
+}
+
+impl Drop for StreamCapture {
+    fn drop(&mut self) {
+        if self.redirect.take().is_some() {
+            // ends the capture, so that the output is forwarded from now on.
+            let _ = self.writer.write_all(&self.marker);
+        }
+    }
+}
+
+fn drain(mut pipe: File, stream: sys::Stream, marker: &[u8], sender: &Sender<io::Result<Vec<u8>>>) {
+    let mut output = Vec::new();
+    let mut buffer = [0; 4096];
+    let mut capturing = true;
+    loop {
+        let read = match pipe.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => {
+                if capturing {
+                    let _ = sender.send(Err(err));
+                }
+                return;
+            }
+        };
+        if !capturing {
+            forward(stream, &buffer[..read]);
+            continue;
+        }
+
+        // the marker may span several reads, so the search starts before the new data.
+        let searched = output.len().saturating_sub(marker.len() - 1);
+        output.extend_from_slice(&buffer[..read]);
+        if let Some(position) = output[searched..]
+            .windows(marker.len())
+            .position(|window| window == marker)
+        {
+            let rest = output
+                .split_off(searched + position)
+                .split_off(marker.len());
+            let _ = sender.send(Ok(mem::take(&mut output)));
+            capturing = false;
+            forward(stream, &rest);
+        }
+    }
+    if capturing {
+        let _ = sender.send(Ok(output));
+    }
+}
+
+fn forward(stream: sys::Stream, output: &[u8]) {
+    let _ = match stream {
+        sys::Stream::Stdout => io::stdout().write_all(output),
+        sys::Stream::Stderr => io::stderr().write_all(output),
+    };
+}
+
+#[cfg(unix)]
+mod sys {
+    use std::{
+        ffi::{c_int, c_void},
+        fs::File,
+        io,
+        os::unix::io::FromRawFd,
+    };
+
+    extern "C" {
+        fn pipe(fds: *mut c_int) -> c_int;
+        fn dup(fd: c_int) -> c_int;
+        fn dup2(old_fd: c_int, new_fd: c_int) -> c_int;
+        fn close(fd: c_int) -> c_int;
+        fn fflush(stream: *mut c_void) -> c_int;
+    }
+
+    #[derive(Clone, Copy)]
+    pub enum Stream {
+        Stdout,
+        Stderr,
+    }
+
+    /// Redirects a standard stream into a pipe and restores it on drop.
+    pub struct Redirect {
+        fd: c_int,
+        saved_fd: c_int,
+    }
+
+    impl Redirect {
+        /// Returns the redirect and the read and write ends of the pipe.
+        pub fn start(stream: Stream) -> io::Result<(Self, File, File)> {
+            let fd = match stream {
+                Stream::Stdout => 1,
+                Stream::Stderr => 2,
+            };
+
+            let mut fds = [0; 2];
+            if unsafe { pipe(fds.as_mut_ptr()) } != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let [read_fd, write_fd] = fds;
+            let reader = unsafe { File::from_raw_fd(read_fd) };
+            let writer = unsafe { File::from_raw_fd(write_fd) };
+
+            let saved_fd = unsafe { dup(fd) };
+            if saved_fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            if unsafe { dup2(write_fd, fd) } < 0 {
+                let err = io::Error::last_os_error();
+                unsafe { close(saved_fd) };
+                return Err(err);
+            }
+
+            Ok((Self { fd, saved_fd }, reader, writer))
+        }
+    }
+
+    impl Drop for Redirect {
+        fn drop(&mut self) {
+            unsafe {
+                dup2(self.saved_fd, self.fd);
+                close(self.saved_fd);
+            }
+        }
+    }
+
+    pub fn flush_c_runtime() {
+        unsafe { fflush(std::ptr::null_mut()) };
+    }
+}
+
+#[cfg(windows)]
+mod sys {
+    use std::{
+        ffi::c_void,
+        fs::File,
+        io,
+        os::windows::io::{FromRawHandle, IntoRawHandle},
+        ptr,
+    };
+
+    type Handle = *mut c_void;
+
+    const STD_OUTPUT_HANDLE: u32 = -11i32 as u32;
+    const STD_ERROR_HANDLE: u32 = -12i32 as u32;
+    const DUPLICATE_SAME_ACCESS: u32 = 0x2;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn CreatePipe(
+            read_pipe: *mut Handle,
+            write_pipe: *mut Handle,
+            pipe_attributes: *const c_void,
+            size: u32,
+        ) -> i32;
+        fn GetStdHandle(std_handle: u32) -> Handle;
+        fn SetStdHandle(std_handle: u32, handle: Handle) -> i32;
+        fn GetCurrentProcess() -> Handle;
+        fn DuplicateHandle(
+            source_process: Handle,
+            source_handle: Handle,
+            target_process: Handle,
+            target_handle: *mut Handle,
+            desired_access: u32,
+            inherit_handle: i32,
+            options: u32,
+        ) -> i32;
+    }
+
+    #[derive(Clone, Copy)]
+    pub enum Stream {
+        Stdout,
+        Stderr,
+    }
+
+    /// Redirects a standard handle into a pipe and restores it on drop.
+    ///
+    /// The write end of the pipe set as the standard handle is never closed, as `System.Console` caches the handle.
+    pub struct Redirect {
+        std_handle: u32,
+        saved_handle: Handle,
+    }
+
+    impl Redirect {
+        /// Returns the redirect and the read end and a copy of the write end of the pipe.
+        pub fn start(stream: Stream) -> io::Result<(Self, File, File)> {
+            let std_handle = match stream {
+                Stream::Stdout => STD_OUTPUT_HANDLE,
+                Stream::Stderr => STD_ERROR_HANDLE,
+            };
+
+            let mut read_handle = ptr::null_mut();
+            let mut write_handle = ptr::null_mut();
+            if unsafe { CreatePipe(&mut read_handle, &mut write_handle, ptr::null(), 0) } == 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let reader = unsafe { File::from_raw_handle(read_handle) };
+            // owns the write end until it has been set as the standard handle.
+            let write_end = unsafe { File::from_raw_handle(write_handle) };
+
+            let mut writer_handle = ptr::null_mut();
+            let duplicated = unsafe {
+                DuplicateHandle(
+                    GetCurrentProcess(),
+                    write_handle,
+                    GetCurrentProcess(),
+                    &mut writer_handle,
+                    0,
+                    0,
+                    DUPLICATE_SAME_ACCESS,
+                )
+            };
+            if duplicated == 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let writer = unsafe { File::from_raw_handle(writer_handle) };
+
+            let saved_handle = unsafe { GetStdHandle(std_handle) };
+            if unsafe { SetStdHandle(std_handle, write_handle) } == 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let _ = write_end.into_raw_handle();
+
+            Ok((
+                Self {
+                    std_handle,
+                    saved_handle,
+                },
+                reader,
+                writer,
+            ))
+        }
+    }
+
+    impl Drop for Redirect {
+        fn drop(&mut self) {
+            unsafe { SetStdHandle(self.std_handle, self.saved_handle) };
+        }
+    }
+
+    pub fn flush_c_runtime() {}
+}
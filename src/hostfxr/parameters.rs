@@ -0,0 +1,85 @@
+use std::{mem, ptr};
+
+use crate::{
+    bindings::hostfxr::hostfxr_initialize_parameters,
+    pdcstring::{PdCStr, PdCString},
+};
+
+/// Additional parameters for initializing the hosting components, used by
+/// [`Hostfxr::initialize_for_runtime_config_with_params`] and [`Hostfxr::initialize_for_dotnet_command_line_with_params`].
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{hostfxr::{Hostfxr, HostfxrParameters}, pdcstr};
+/// let hostfxr = Hostfxr::load_from_path("/opt/private-dotnet/host/fxr/8.0.0/libhostfxr.so").unwrap();
+/// let context = hostfxr
+///     .initialize_for_runtime_config_with_params(
+///         pdcstr!("App.runtimeconfig.json"),
+///         &HostfxrParameters::new().dotnet_root(pdcstr!("/opt/private-dotnet")),
+///     )
+///     .unwrap();
+/// ```
+///
+/// [`Hostfxr::initialize_for_runtime_config_with_params`]: crate::hostfxr::Hostfxr::initialize_for_runtime_config_with_params
+/// [`Hostfxr::initialize_for_dotnet_command_line_with_params`]: crate::hostfxr::Hostfxr::initialize_for_dotnet_command_line_with_params
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub struct HostfxrParameters {
+    host_path: Option<PdCString>,
+    dotnet_root: Option<PdCString>,
+}
+
+impl HostfxrParameters {
+    /// Creates new parameters without a host path or dotnet root.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the path to the native host (typically the `.exe`).
+    ///
+    /// This value is not used for anything by the hosting components.
+    /// It's just passed to the `CoreCLR` as the path to the executable.
+    /// It can point to a file which is not executable itself, if such file doesn't exist (for example in COM activation scenarios this points to the `comhost.dll`).
+    /// This is used by PAL to initialize internal command line structures, process name and so on.
+    #[must_use]
+    pub fn host_path(mut self, host_path: impl Into<PdCString>) -> Self {
+        self.host_path = Some(host_path.into());
+        self
+    }
+
+    /// Sets the path to the root of the .NET Core installation in use.
+    ///
+    /// This typically points to the install location from which the hostfxr has been loaded.
+    /// For example on Windows this would typically point to `C:\Program Files\dotnet`.
+    /// The path is used to search for shared frameworks and potentially SDKs.
+    #[must_use]
+    pub fn dotnet_root(mut self, dotnet_root: impl Into<PdCString>) -> Self {
+        self.dotnet_root = Some(dotnet_root.into());
+        self
+    }
+
+    /// Gets the path to the native host, if set.
+    #[must_use]
+    pub fn get_host_path(&self) -> Option<&PdCStr> {
+        self.host_path.as_deref()
+    }
+
+    /// Gets the path to the root of the .NET Core installation, if set.
+    #[must_use]
+    pub fn get_dotnet_root(&self) -> Option<&PdCStr> {
+        self.dotnet_root.as_deref()
+    }
+
+    /// Returns the raw parameters, which borrow the paths of `self`.
+    pub(crate) fn as_raw(&self) -> hostfxr_initialize_parameters {
+        hostfxr_initialize_parameters {
+            size: mem::size_of::<hostfxr_initialize_parameters>(),
+            host_path: self.host_path.as_ref().map_or(ptr::null(), |p| p.as_ptr()),
+            dotnet_root: self
+                .dotnet_root
+                .as_ref()
+                .map_or(ptr::null(), |p| p.as_ptr()),
+        }
+    }
+}
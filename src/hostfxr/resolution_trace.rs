@@ -0,0 +1,298 @@
+use std::{
+    fs::{self, File},
+    io::{self, Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use crate::{env::ScopedEnv, scratch::ScratchDir};
+
+#[cfg(feature = "netcore3_0")]
+use crate::{
+    error::HostingError,
+    hostfxr::{Hostfxr, HostfxrContext, InitializedForRuntimeConfig},
+    pdcstring::PdCStr,
+};
+#[cfg(feature = "netcore3_0")]
+use thiserror::Error;
+
+/// Returns the trace file used for all captures in this process.
+///
+/// The hosting components open the trace file the first time tracing is enabled and keep appending to it
+/// for the lifetime of the process, so every capture has to use the same file.
+fn trace_file() -> io::Result<&'static Path> {
+    static TRACE_FILE: OnceLock<PathBuf> = OnceLock::new();
+    if let Some(path) = TRACE_FILE.get() {
+        return Ok(path);
+    }
+    // the directory has to outlive the process, it is removed by the orphan sweeping of later processes.
+    let path = ScratchDir::new()?.keep().join("corehost-trace.txt");
+    File::create(&path)?;
+    Ok(TRACE_FILE.get_or_init(|| path))
+}
+
+/// Captures the trace output of the hosting components (`COREHOST_TRACE`) while it is alive.
+///
+/// As the trace settings are read from the environment, this holds a [`ScopedEnv`] for its entire lifetime.
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{unstable::{ResolutionReport, TraceCapture}, nethost, pdcstr};
+/// let hostfxr = nethost::load_hostfxr().unwrap();
+/// let capture = TraceCapture::start().unwrap();
+/// let result = hostfxr.initialize_for_runtime_config(pdcstr!("app.runtimeconfig.json"));
+/// let report = ResolutionReport::parse(&capture.finish().unwrap());
+/// println!("{:#?}", report.probed_paths());
+/// ```
+pub struct TraceCapture {
+    path: &'static Path,
+    start: u64,
+    _env: ScopedEnv,
+}
+
+impl TraceCapture {
+    /// Enables tracing of the hosting components and starts capturing the output.
+    pub fn start() -> io::Result<Self> {
+        let path = trace_file()?;
+        let start = fs::metadata(path)?.len();
+
+        let mut env = ScopedEnv::new();
+        env.set("COREHOST_TRACE", "1")
+            .set("COREHOST_TRACEFILE", path)
+            .set("COREHOST_TRACE_VERBOSITY", "4");
+
+        Ok(Self {
+            path,
+            start,
+            _env: env,
+        })
+    }
+
+    /// Stops capturing and returns the trace output written since the capture was started.
+    ///
+    /// # Note
+    /// Once enabled, the hosting components keep tracing until the process exits,
+    /// only the environment is restored.
+    pub fn finish(self) -> io::Result<String> {
+        let mut file = File::open(self.path)?;
+        file.seek(SeekFrom::Start(self.start))?;
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
+
+/// A framework version considered while resolving a framework or hostfxr.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FrameworkCandidate {
+    /// The version of the candidate.
+    pub version: String,
+    /// Whether the candidate was chosen, only considered or rejected.
+    pub status: CandidateStatus,
+}
+
+/// The outcome for a [`FrameworkCandidate`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CandidateStatus {
+    /// The candidate was found and considered.
+    Considered,
+    /// The candidate was chosen.
+    Chosen,
+    /// The candidate was rejected for the given reason.
+    Rejected(String),
+}
+
+/// A structured summary of the trace output of the hosting components, see [`TraceCapture`].
+///
+/// # Note
+/// The trace output is meant for humans and its format is not stable between .NET versions.
+/// The report is extracted on a best-effort basis, the full trace is available through [`lines`](Self::lines).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ResolutionReport {
+    lines: Vec<String>,
+    probed_paths: Vec<PathBuf>,
+    candidates: Vec<FrameworkCandidate>,
+    errors: Vec<String>,
+}
+
+impl ResolutionReport {
+    /// Parses the given trace output.
+    #[must_use]
+    pub fn parse(trace: &str) -> Self {
+        let mut report = Self::default();
+        for line in trace.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            report.parse_line(line);
+            report.lines.push(line.to_string());
+        }
+        report
+    }
+
+    fn parse_line(&mut self, line: &str) {
+        let value = bracketed_value(line);
+
+        if let Some(value) = value {
+            if starts_with_any(
+                line,
+                &["Probing", "Probed", "Searching", "Reading", "Looking for"],
+            ) {
+                self.probed_paths.push(PathBuf::from(value));
+            }
+
+            let status = if starts_with_any(line, &["Chose", "Detected latest", "Resolved fxr"]) {
+                Some(CandidateStatus::Chosen)
+            } else if line.starts_with("Ignoring") {
+                let reason = line[line.find(']').map_or(line.len(), |end| end + 1)..].trim();
+                Some(CandidateStatus::Rejected(reason.to_string()))
+            } else if starts_with_any(line, &["Found version", "Considering"]) {
+                Some(CandidateStatus::Considered)
+            } else {
+                None
+            };
+            if let Some(status) = status {
+                self.candidates.push(FrameworkCandidate {
+                    version: version_from_value(value).to_string(),
+                    status,
+                });
+            }
+        }
+
+        let lowercase = line.to_ascii_lowercase();
+        if [
+            "error",
+            "failed",
+            "does not exist",
+            "not found",
+            "could not",
+            "cannot",
+        ]
+        .iter()
+        .any(|pattern| lowercase.contains(pattern))
+        {
+            self.errors.push(line.to_string());
+        }
+    }
+
+    /// Returns all non-empty lines of the trace.
+    #[must_use]
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Returns the directories and files that were probed, in the order they were probed.
+    #[must_use]
+    pub fn probed_paths(&self) -> &[PathBuf] {
+        &self.probed_paths
+    }
+
+    /// Returns the framework and hostfxr versions that were considered.
+    #[must_use]
+    pub fn candidates(&self) -> &[FrameworkCandidate] {
+        &self.candidates
+    }
+
+    /// Returns the candidates that were rejected together with the reason.
+    pub fn rejected_candidates(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.candidates
+            .iter()
+            .filter_map(|candidate| match &candidate.status {
+                CandidateStatus::Rejected(reason) => {
+                    Some((candidate.version.as_str(), reason.as_str()))
+                }
+                _ => None,
+            })
+    }
+
+    /// Returns the lines reporting errors.
+    #[must_use]
+    pub fn errors(&self) -> &[String] {
+        &self.errors
+    }
+}
+
+/// Some lines report chosen versions by their directory (like `/usr/share/dotnet/host/fxr/9.0.0/libhostfxr.so`),
+/// in which case the version is the last path component starting with a digit.
+fn version_from_value(value: &str) -> &str {
+    if !value.contains(['/', '\\']) {
+        return value;
+    }
+    value
+        .rsplit(['/', '\\'])
+        .find(|component| component.starts_with(|c: char| c.is_ascii_digit()))
+        .unwrap_or(value)
+}
+
+fn starts_with_any(line: &str, prefixes: &[&str]) -> bool {
+    prefixes.iter().any(|prefix| line.starts_with(prefix))
+}
+
+/// Returns the first value enclosed in brackets (like `[/usr/share/dotnet]`), which is how the hosting components
+/// format paths and versions in their trace output.
+fn bracketed_value(line: &str) -> Option<&str> {
+    let start = line.find('[')? + 1;
+    let end = start + line[start..].find(']')?;
+    Some(&line[start..end])
+}
+
+#[cfg(feature = "netcore3_0")]
+impl Hostfxr {
+    /// Initializes the hosting components like [`initialize_for_runtime_config`](Hostfxr::initialize_for_runtime_config),
+    /// but captures the trace output and attaches a [`ResolutionReport`] to errors.
+    #[cfg_attr(
+        feature = "doc-cfg",
+        doc(cfg(all(feature = "netcore3_0", feature = "unstable")))
+    )]
+    pub fn initialize_for_runtime_config_traced(
+        &self,
+        runtime_config_path: impl AsRef<PdCStr>,
+    ) -> Result<HostfxrContext<InitializedForRuntimeConfig>, TracedHostingError> {
+        let capture = TraceCapture::start().ok();
+        let result = self.initialize_for_runtime_config(runtime_config_path);
+        let trace = capture.and_then(|capture| capture.finish().ok());
+        result.map_err(|error| TracedHostingError {
+            error,
+            report: trace
+                .as_deref()
+                .map(ResolutionReport::parse)
+                .unwrap_or_default(),
+        })
+    }
+}
+
+/// A [`HostingError`] together with the [`ResolutionReport`] of the failed operation.
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+#[derive(Debug, Error)]
+#[error("{error}")]
+pub struct TracedHostingError {
+    #[source]
+    error: HostingError,
+    report: ResolutionReport,
+}
+
+#[cfg(feature = "netcore3_0")]
+impl TracedHostingError {
+    /// Returns the underlying hosting error.
+    #[must_use]
+    pub const fn error(&self) -> HostingError {
+        self.error
+    }
+
+    /// Returns the report of the trace output captured during the failed operation.
+    /// The report is empty if the trace could not be captured.
+    #[must_use]
+    pub const fn report(&self) -> &ResolutionReport {
+        &self.report
+    }
+}
+
+#[cfg(feature = "netcore3_0")]
+impl From<TracedHostingError> for HostingError {
+    fn from(error: TracedHostingError) -> Self {
+        error.error
+    }
+}
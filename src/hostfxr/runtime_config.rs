@@ -0,0 +1,328 @@
+use std::{
+    fmt::{self, Display, Write},
+    fs, io,
+    path::Path,
+    str::FromStr,
+};
+
+use thiserror::Error;
+
+use crate::hostfxr::{
+    Hostfxr, HostfxrContext, InitializeForRuntimeConfigJsonError, InitializedForRuntimeConfig,
+};
+
+/// A model of a `.runtimeconfig.json`, which describes the frameworks and settings used to run a component.
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{hostfxr::{RollForward, RuntimeConfig}, nethost};
+/// let config = RuntimeConfig::new()
+///     .tfm("net8.0")
+///     .framework("Microsoft.NETCore.App", "8.0.0")
+///     .roll_forward(RollForward::LatestMajor)
+///     .property("System.GC.Server", true);
+///
+/// let hostfxr = nethost::load_hostfxr().unwrap();
+/// let context = hostfxr.initialize_for_runtime_config_value(&config).unwrap();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub struct RuntimeConfig {
+    tfm: Option<String>,
+    frameworks: Vec<FrameworkReference>,
+    roll_forward: Option<RollForward>,
+    properties: Vec<(String, ConfigPropertyValue)>,
+}
+
+impl RuntimeConfig {
+    /// Creates a new empty configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the target framework moniker (like `net8.0`).
+    #[must_use]
+    pub fn tfm(mut self, tfm: impl Into<String>) -> Self {
+        self.tfm = Some(tfm.into());
+        self
+    }
+
+    /// Adds a reference to the shared framework with the given name and minimum version.
+    #[must_use]
+    pub fn framework(mut self, name: impl Into<String>, version: impl Into<String>) -> Self {
+        self.frameworks.push(FrameworkReference {
+            name: name.into(),
+            version: version.into(),
+        });
+        self
+    }
+
+    /// Sets the policy used to select a framework version if the referenced one is not installed.
+    #[must_use]
+    pub const fn roll_forward(mut self, roll_forward: RollForward) -> Self {
+        self.roll_forward = Some(roll_forward);
+        self
+    }
+
+    /// Sets a configuration property, which becomes a runtime property (like `System.GC.Server`).
+    /// Setting a property again replaces the previous value.
+    #[must_use]
+    pub fn property(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<ConfigPropertyValue>,
+    ) -> Self {
+        let name = name.into();
+        let value = value.into();
+        match self.properties.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, existing)) => *existing = value,
+            None => self.properties.push((name, value)),
+        }
+        self
+    }
+
+    /// Gets the target framework moniker, if set.
+    #[must_use]
+    pub fn get_tfm(&self) -> Option<&str> {
+        self.tfm.as_deref()
+    }
+
+    /// Gets the referenced frameworks.
+    #[must_use]
+    pub fn get_frameworks(&self) -> &[FrameworkReference] {
+        &self.frameworks
+    }
+
+    /// Gets the roll forward policy, if set.
+    #[must_use]
+    pub const fn get_roll_forward(&self) -> Option<RollForward> {
+        self.roll_forward
+    }
+
+    /// Gets the value of the configuration property with the given name, if set.
+    #[must_use]
+    pub fn get_property(&self, name: &str) -> Option<&ConfigPropertyValue> {
+        self.properties
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Serializes this configuration to the JSON format of a `.runtimeconfig.json`.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut options = Vec::new();
+        if let Some(tfm) = &self.tfm {
+            options.push(format!(r#""tfm":{}"#, json_string(tfm)));
+        }
+        if let Some(roll_forward) = self.roll_forward {
+            options.push(format!(
+                r#""rollForward":{}"#,
+                json_string(roll_forward.as_str())
+            ));
+        }
+        match self.frameworks.as_slice() {
+            [] => {}
+            [framework] => options.push(format!(r#""framework":{}"#, framework.to_json())),
+            frameworks => {
+                let frameworks = frameworks
+                    .iter()
+                    .map(FrameworkReference::to_json)
+                    .collect::<Vec<_>>();
+                options.push(format!(r#""frameworks":[{}]"#, frameworks.join(",")));
+            }
+        }
+        if !self.properties.is_empty() {
+            let properties = self
+                .properties
+                .iter()
+                .map(|(name, value)| format!("{}:{}", json_string(name), value.to_json()))
+                .collect::<Vec<_>>();
+            options.push(format!(
+                r#""configProperties":{{{}}}"#,
+                properties.join(",")
+            ));
+        }
+        format!(r#"{{"runtimeOptions":{{{}}}}}"#, options.join(","))
+    }
+
+    /// Writes this configuration to the given path.
+    pub fn write_to(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_json())
+    }
+}
+
+impl Display for RuntimeConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_json())
+    }
+}
+
+/// A reference to a shared framework in a [`RuntimeConfig`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub struct FrameworkReference {
+    /// The name of the framework (like `Microsoft.NETCore.App`).
+    pub name: String,
+    /// The minimum version of the framework (like `8.0.0`).
+    pub version: String,
+}
+
+impl FrameworkReference {
+    fn to_json(&self) -> String {
+        format!(
+            r#"{{"name":{},"version":{}}}"#,
+            json_string(&self.name),
+            json_string(&self.version)
+        )
+    }
+}
+
+/// The policy for selecting a framework version if the exact version referenced by a [`RuntimeConfig`] is not installed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub enum RollForward {
+    /// Roll forward to the highest patch version, but not to a higher minor or major version.
+    LatestPatch,
+    /// Roll forward to the lowest higher minor version if the requested minor version is missing.
+    /// This is the default.
+    Minor,
+    /// Roll forward to the lowest higher major version if the requested major version is missing.
+    Major,
+    /// Roll forward to the highest minor version of the requested major version.
+    LatestMinor,
+    /// Roll forward to the highest installed major version.
+    LatestMajor,
+    /// Do not roll forward, only the exact version is used.
+    Disable,
+}
+
+impl RollForward {
+    /// Returns the name of the policy as used in a `.runtimeconfig.json` and `DOTNET_ROLL_FORWARD`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::LatestPatch => "LatestPatch",
+            Self::Minor => "Minor",
+            Self::Major => "Major",
+            Self::LatestMinor => "LatestMinor",
+            Self::LatestMajor => "LatestMajor",
+            Self::Disable => "Disable",
+        }
+    }
+}
+
+impl Display for RollForward {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RollForward {
+    type Err = ParseRollForwardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Self::LatestPatch,
+            Self::Minor,
+            Self::Major,
+            Self::LatestMinor,
+            Self::LatestMajor,
+            Self::Disable,
+        ]
+        .into_iter()
+        .find(|policy| policy.as_str().eq_ignore_ascii_case(s))
+        .ok_or_else(|| ParseRollForwardError(s.to_string()))
+    }
+}
+
+/// An error returned when parsing an unknown [`RollForward`] policy.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Error)]
+#[error("Unknown roll forward policy '{0}'.")]
+pub struct ParseRollForwardError(String);
+
+/// The value of a configuration property in a [`RuntimeConfig`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub enum ConfigPropertyValue {
+    /// A boolean value.
+    Bool(bool),
+    /// An integer value.
+    Integer(i64),
+    /// A string value.
+    String(String),
+}
+
+impl ConfigPropertyValue {
+    fn to_json(&self) -> String {
+        match self {
+            Self::Bool(value) => value.to_string(),
+            Self::Integer(value) => value.to_string(),
+            Self::String(value) => json_string(value),
+        }
+    }
+}
+
+impl From<bool> for ConfigPropertyValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i64> for ConfigPropertyValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<i32> for ConfigPropertyValue {
+    fn from(value: i32) -> Self {
+        Self::Integer(value.into())
+    }
+}
+
+impl From<String> for ConfigPropertyValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for ConfigPropertyValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", u32::from(c));
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+impl Hostfxr {
+    /// Initializes the hosting components using the given [`RuntimeConfig`],
+    /// see [`initialize_for_runtime_config_json`](Hostfxr::initialize_for_runtime_config_json).
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+    pub fn initialize_for_runtime_config_value(
+        &self,
+        runtime_config: &RuntimeConfig,
+    ) -> Result<HostfxrContext<InitializedForRuntimeConfig>, InitializeForRuntimeConfigJsonError>
+    {
+        self.initialize_for_runtime_config_json(&runtime_config.to_json())
+    }
+}
@@ -0,0 +1,99 @@
+use std::{fs, io};
+
+use thiserror::Error;
+
+use crate::{
+    error::HostingError,
+    hostfxr::{Hostfxr, HostfxrContext, HostfxrParameters, InitializedForRuntimeConfig},
+    pdcstring::PdCString,
+    scratch::ScratchDir,
+};
+
+impl Hostfxr {
+    /// Initializes the hosting components using the given contents of a `.runtimeconfig.json`,
+    /// see [`initialize_for_runtime_config`](Hostfxr::initialize_for_runtime_config).
+    ///
+    /// The hosting components can only read the configuration from a file, so it is written to a new
+    /// [`ScratchDir`] that is only accessible by the current user and removed again once the context is initialized.
+    ///
+    /// # Note
+    /// The hosting components use the directory containing the configuration as the application base directory
+    /// (`APP_CONTEXT_BASE_DIRECTORY`), which will no longer exist. Override the runtime property before the
+    /// runtime is loaded if managed code relies on it.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use netcorehost::nethost;
+    /// let hostfxr = nethost::load_hostfxr().unwrap();
+    /// let context = hostfxr
+    ///     .initialize_for_runtime_config_json(
+    ///         r#"{
+    ///             "runtimeOptions": {
+    ///                 "tfm": "net8.0",
+    ///                 "framework": { "name": "Microsoft.NETCore.App", "version": "8.0.0" }
+    ///             }
+    ///         }"#,
+    ///     )
+    ///     .unwrap();
+    /// ```
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+    pub fn initialize_for_runtime_config_json(
+        &self,
+        runtime_config: &str,
+    ) -> Result<HostfxrContext<InitializedForRuntimeConfig>, InitializeForRuntimeConfigJsonError>
+    {
+        self.initialize_for_runtime_config_json_with_params(
+            runtime_config,
+            &HostfxrParameters::new(),
+        )
+    }
+
+    /// Initializes the hosting components using the given contents of a `.runtimeconfig.json` and parameters,
+    /// see [`initialize_for_runtime_config_json`](Hostfxr::initialize_for_runtime_config_json)
+    /// and [`initialize_for_runtime_config_with_params`](Hostfxr::initialize_for_runtime_config_with_params).
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+    pub fn initialize_for_runtime_config_json_with_params(
+        &self,
+        runtime_config: &str,
+        parameters: &HostfxrParameters,
+    ) -> Result<HostfxrContext<InitializedForRuntimeConfig>, InitializeForRuntimeConfigJsonError>
+    {
+        // the config file is only read during initialization, so the directory can be removed afterwards.
+        let (_scratch_dir, runtime_config_path) =
+            write_runtime_config("app.runtimeconfig.json", runtime_config)?;
+        Ok(self.initialize_for_runtime_config_with_params(runtime_config_path, parameters)?)
+    }
+}
+
+/// Writes the given runtime config to a new scratch directory and returns the directory together with the path of the file.
+pub(crate) fn write_runtime_config(
+    file_name: &str,
+    runtime_config: &str,
+) -> io::Result<(ScratchDir, PdCString)> {
+    let scratch_dir = ScratchDir::new()?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(scratch_dir.path(), fs::Permissions::from_mode(0o700))?;
+    }
+
+    let runtime_config_path = scratch_dir.join(file_name);
+    fs::write(&runtime_config_path, runtime_config)?;
+
+    let runtime_config_path = PdCString::from_os_str(&runtime_config_path)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+    Ok((scratch_dir, runtime_config_path))
+}
+
+/// Enum for errors that can occur while initializing the hosting components from the contents of a `.runtimeconfig.json`.
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+#[non_exhaustive]
+pub enum InitializeForRuntimeConfigJsonError {
+    /// An error occured inside the hosting components, for example because the configuration is invalid.
+    #[error(transparent)]
+    Hosting(#[from] HostingError),
+    /// An error occured while writing the runtime config to a temporary file.
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
@@ -0,0 +1,247 @@
+use std::mem;
+
+#[cfg(windows)]
+use std::ffi::c_void;
+
+use crate::{
+    bindings::hostfxr::{hostfxr_delegate_type, load_assembly_and_get_function_pointer_fn},
+    error::HostingError,
+    hostfxr::{HostfxrContext, RawFunctionPtr},
+};
+
+#[cfg(feature = "net5_0")]
+use crate::bindings::hostfxr::get_function_pointer_fn;
+#[cfg(feature = "net8_0")]
+use crate::bindings::hostfxr::{load_assembly_bytes_fn, load_assembly_fn};
+#[cfg(windows)]
+use crate::{bindings::char_t, hostfxr::ComActivationFn};
+
+/// The raw signature of the [`hdt_load_in_memory_assembly`](hostfxr_delegate_type::hdt_load_in_memory_assembly)
+/// delegate, which loads a mixed-mode (C++/CLI) assembly that is already mapped into memory.
+/// Returns a `HRESULT`.
+#[cfg(windows)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(windows)))]
+pub type LoadInMemoryAssemblyFn =
+    unsafe extern "system" fn(module_handle: *mut c_void, assembly_path: *const char_t) -> i32;
+
+/// The type of a delegate that can be retrieved from a [`HostfxrContext`] using
+/// [`get_typed_runtime_delegate`](HostfxrContext::get_typed_runtime_delegate).
+///
+/// New variants may be added in minor releases, so matches on this enum have to include a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+#[non_exhaustive]
+pub enum DelegateType {
+    /// Activation of managed COM classes, see [`RuntimeDelegate::ComActivation`].
+    #[cfg(windows)]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(windows)))]
+    ComActivation,
+    /// Loading of mixed-mode (C++/CLI) assemblies, see [`RuntimeDelegate::LoadInMemoryAssembly`].
+    #[cfg(windows)]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(windows)))]
+    LoadInMemoryAssembly,
+    /// Activation of managed `WinRT` classes. Only supported by .NET Core 3.x.
+    #[cfg(windows)]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(windows)))]
+    WinrtActivation,
+    /// Registration of managed COM classes, see [`RuntimeDelegate::ComRegister`].
+    #[cfg(windows)]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(windows)))]
+    ComRegister,
+    /// Unregistration of managed COM classes, see [`RuntimeDelegate::ComUnregister`].
+    #[cfg(windows)]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(windows)))]
+    ComUnregister,
+    /// Loading of an assembly in isolation and resolving a function pointer,
+    /// see [`RuntimeDelegate::LoadAssemblyAndGetFunctionPointer`].
+    LoadAssemblyAndGetFunctionPointer,
+    /// Resolving a function pointer from an already loaded assembly, see [`RuntimeDelegate::GetFunctionPointer`].
+    #[cfg(feature = "net5_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+    GetFunctionPointer,
+    /// Loading of an assembly into the default load context, see [`RuntimeDelegate::LoadAssembly`].
+    #[cfg(feature = "net8_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net8_0")))]
+    LoadAssembly,
+    /// Loading of an assembly from memory into the default load context, see [`RuntimeDelegate::LoadAssemblyBytes`].
+    #[cfg(feature = "net8_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net8_0")))]
+    LoadAssemblyBytes,
+}
+
+impl From<DelegateType> for hostfxr_delegate_type {
+    fn from(delegate_type: DelegateType) -> Self {
+        match delegate_type {
+            #[cfg(windows)]
+            DelegateType::ComActivation => Self::hdt_com_activation,
+            #[cfg(windows)]
+            DelegateType::LoadInMemoryAssembly => Self::hdt_load_in_memory_assembly,
+            #[cfg(windows)]
+            DelegateType::WinrtActivation => Self::hdt_winrt_activation,
+            #[cfg(windows)]
+            DelegateType::ComRegister => Self::hdt_com_register,
+            #[cfg(windows)]
+            DelegateType::ComUnregister => Self::hdt_com_unregister,
+            DelegateType::LoadAssemblyAndGetFunctionPointer => {
+                Self::hdt_load_assembly_and_get_function_pointer
+            }
+            #[cfg(feature = "net5_0")]
+            DelegateType::GetFunctionPointer => Self::hdt_get_function_pointer,
+            #[cfg(feature = "net8_0")]
+            DelegateType::LoadAssembly => Self::hdt_load_assembly,
+            #[cfg(feature = "net8_0")]
+            DelegateType::LoadAssemblyBytes => Self::hdt_load_assembly_bytes,
+        }
+    }
+}
+
+/// A delegate retrieved from a [`HostfxrContext`] using
+/// [`get_typed_runtime_delegate`](HostfxrContext::get_typed_runtime_delegate), typed according to its [`DelegateType`].
+///
+/// New variants may be added in minor releases, so matches on this enum have to include a wildcard arm.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+#[non_exhaustive]
+pub enum RuntimeDelegate {
+    /// See [`DelegateType::ComActivation`] and [`ComActivationDelegate`](crate::hostfxr::ComActivationDelegate).
+    #[cfg(windows)]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(windows)))]
+    ComActivation(ComActivationFn),
+    /// See [`DelegateType::LoadInMemoryAssembly`].
+    #[cfg(windows)]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(windows)))]
+    LoadInMemoryAssembly(LoadInMemoryAssemblyFn),
+    /// See [`DelegateType::WinrtActivation`]. The signature of this delegate is not documented.
+    #[cfg(windows)]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(windows)))]
+    WinrtActivation(RawFunctionPtr),
+    /// See [`DelegateType::ComRegister`].
+    #[cfg(windows)]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(windows)))]
+    ComRegister(ComActivationFn),
+    /// See [`DelegateType::ComUnregister`].
+    #[cfg(windows)]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(windows)))]
+    ComUnregister(ComActivationFn),
+    /// See [`DelegateType::LoadAssemblyAndGetFunctionPointer`] and [`DelegateLoader`](crate::hostfxr::DelegateLoader).
+    LoadAssemblyAndGetFunctionPointer(load_assembly_and_get_function_pointer_fn),
+    /// See [`DelegateType::GetFunctionPointer`] and [`DelegateLoader`](crate::hostfxr::DelegateLoader).
+    #[cfg(feature = "net5_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+    GetFunctionPointer(get_function_pointer_fn),
+    /// See [`DelegateType::LoadAssembly`] and [`DelegateLoader::load_assembly`](crate::hostfxr::DelegateLoader::load_assembly).
+    #[cfg(feature = "net8_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net8_0")))]
+    LoadAssembly(load_assembly_fn),
+    /// See [`DelegateType::LoadAssemblyBytes`] and [`DelegateLoader::load_assembly_from_bytes`](crate::hostfxr::DelegateLoader::load_assembly_from_bytes).
+    #[cfg(feature = "net8_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net8_0")))]
+    LoadAssemblyBytes(load_assembly_bytes_fn),
+}
+
+impl RuntimeDelegate {
+    /// Wraps the given raw delegate of the given type.
+    ///
+    /// # Safety
+    /// `ptr` has to be a delegate of the given type, as returned by [`HostfxrContext::get_runtime_delegate`].
+    #[must_use]
+    pub unsafe fn from_raw(delegate_type: DelegateType, ptr: RawFunctionPtr) -> Self {
+        unsafe {
+            match delegate_type {
+                #[cfg(windows)]
+                DelegateType::ComActivation => Self::ComActivation(mem::transmute(ptr)),
+                #[cfg(windows)]
+                DelegateType::LoadInMemoryAssembly => {
+                    Self::LoadInMemoryAssembly(mem::transmute(ptr))
+                }
+                #[cfg(windows)]
+                DelegateType::WinrtActivation => Self::WinrtActivation(ptr),
+                #[cfg(windows)]
+                DelegateType::ComRegister => Self::ComRegister(mem::transmute(ptr)),
+                #[cfg(windows)]
+                DelegateType::ComUnregister => Self::ComUnregister(mem::transmute(ptr)),
+                DelegateType::LoadAssemblyAndGetFunctionPointer => {
+                    Self::LoadAssemblyAndGetFunctionPointer(mem::transmute(ptr))
+                }
+                #[cfg(feature = "net5_0")]
+                DelegateType::GetFunctionPointer => Self::GetFunctionPointer(mem::transmute(ptr)),
+                #[cfg(feature = "net8_0")]
+                DelegateType::LoadAssembly => Self::LoadAssembly(mem::transmute(ptr)),
+                #[cfg(feature = "net8_0")]
+                DelegateType::LoadAssemblyBytes => Self::LoadAssemblyBytes(mem::transmute(ptr)),
+            }
+        }
+    }
+
+    /// Returns the type of this delegate.
+    #[must_use]
+    pub const fn delegate_type(&self) -> DelegateType {
+        match self {
+            #[cfg(windows)]
+            Self::ComActivation(_) => DelegateType::ComActivation,
+            #[cfg(windows)]
+            Self::LoadInMemoryAssembly(_) => DelegateType::LoadInMemoryAssembly,
+            #[cfg(windows)]
+            Self::WinrtActivation(_) => DelegateType::WinrtActivation,
+            #[cfg(windows)]
+            Self::ComRegister(_) => DelegateType::ComRegister,
+            #[cfg(windows)]
+            Self::ComUnregister(_) => DelegateType::ComUnregister,
+            Self::LoadAssemblyAndGetFunctionPointer(_) => {
+                DelegateType::LoadAssemblyAndGetFunctionPointer
+            }
+            #[cfg(feature = "net5_0")]
+            Self::GetFunctionPointer(_) => DelegateType::GetFunctionPointer,
+            #[cfg(feature = "net8_0")]
+            Self::LoadAssembly(_) => DelegateType::LoadAssembly,
+            #[cfg(feature = "net8_0")]
+            Self::LoadAssemblyBytes(_) => DelegateType::LoadAssemblyBytes,
+        }
+    }
+
+    /// Returns the untyped pointer to this delegate.
+    #[must_use]
+    pub fn as_raw(&self) -> RawFunctionPtr {
+        match *self {
+            #[cfg(windows)]
+            Self::ComActivation(f) | Self::ComRegister(f) | Self::ComUnregister(f) => f as _,
+            #[cfg(windows)]
+            Self::LoadInMemoryAssembly(f) => f as _,
+            #[cfg(windows)]
+            Self::WinrtActivation(ptr) => ptr,
+            Self::LoadAssemblyAndGetFunctionPointer(f) => f as _,
+            #[cfg(feature = "net5_0")]
+            Self::GetFunctionPointer(f) => f as _,
+            #[cfg(feature = "net8_0")]
+            Self::LoadAssembly(f) => f as _,
+            #[cfg(feature = "net8_0")]
+            Self::LoadAssemblyBytes(f) => f as _,
+        }
+    }
+}
+
+impl<I> HostfxrContext<I> {
+    /// Gets a typed delegate for a runtime functionality.
+    /// This is a typed version of [`get_runtime_delegate`](HostfxrContext::get_runtime_delegate),
+    /// see there for the delegate types supported by each kind of context.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use netcorehost::hostfxr::{DelegateType, HostfxrContext, InitializedForRuntimeConfig, RuntimeDelegate};
+    /// # fn test(context: HostfxrContext<InitializedForRuntimeConfig>) {
+    /// let delegate = context
+    ///     .get_typed_runtime_delegate(DelegateType::LoadAssemblyAndGetFunctionPointer)
+    ///     .unwrap();
+    /// if let RuntimeDelegate::LoadAssemblyAndGetFunctionPointer(_load_assembly_and_get_function_pointer) = delegate {
+    ///     // call the delegate directly
+    /// }
+    /// # }
+    /// ```
+    pub fn get_typed_runtime_delegate(
+        &self,
+        delegate_type: DelegateType,
+    ) -> Result<RuntimeDelegate, HostingError> {
+        let ptr = self.get_runtime_delegate(delegate_type.into())?;
+        Ok(unsafe { RuntimeDelegate::from_raw(delegate_type, ptr) })
+    }
+}
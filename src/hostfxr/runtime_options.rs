@@ -0,0 +1,174 @@
+use thiserror::Error;
+
+use crate::{
+    env::HostEnvironment, error::HostingError, hostfxr::HostfxrContext, pdcstring::PdCStr,
+};
+
+/// Settings of the JIT and tiered compilation, which trade startup time against steady-state performance.
+///
+/// Settings that are not set keep the value from the `.runtimeconfig.json` or the runtime default.
+/// All settings except [`ready_to_run`](RuntimeOptions::ready_to_run) are applied as runtime properties
+/// using [`HostfxrContext::apply_runtime_options`], which has to happen before the runtime is loaded.
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{hostfxr::RuntimeOptions, nethost, pdcstr};
+/// let options = RuntimeOptions::new()
+///     .tiered_compilation(true)
+///     .quick_jit_for_loops(false);
+///
+/// let hostfxr = nethost::load_hostfxr().unwrap();
+/// let mut context = hostfxr
+///     .initialize_for_runtime_config(pdcstr!("Test.runtimeconfig.json"))
+///     .unwrap();
+/// context.apply_runtime_options(&options).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub struct RuntimeOptions {
+    tiered_compilation: Option<bool>,
+    quick_jit: Option<bool>,
+    quick_jit_for_loops: Option<bool>,
+    tiered_pgo: Option<bool>,
+    ready_to_run: Option<bool>,
+}
+
+impl RuntimeOptions {
+    /// Creates a new set of options that does not change any setting.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables or disables tiered compilation (`System.Runtime.TieredCompilation`).
+    ///
+    /// If disabled, every method is compiled once with full optimizations, which slows down startup.
+    #[must_use]
+    pub const fn tiered_compilation(mut self, enabled: bool) -> Self {
+        self.tiered_compilation = Some(enabled);
+        self
+    }
+
+    /// Enables or disables quick JIT (`System.Runtime.TieredCompilation.QuickJit`),
+    /// which compiles methods without optimizations in the first tier.
+    #[must_use]
+    pub const fn quick_jit(mut self, enabled: bool) -> Self {
+        self.quick_jit = Some(enabled);
+        self
+    }
+
+    /// Enables or disables quick JIT for methods containing loops
+    /// (`System.Runtime.TieredCompilation.QuickJitForLoops`).
+    #[must_use]
+    pub const fn quick_jit_for_loops(mut self, enabled: bool) -> Self {
+        self.quick_jit_for_loops = Some(enabled);
+        self
+    }
+
+    /// Enables or disables dynamic profile-guided optimization (`System.Runtime.TieredPGO`).
+    #[must_use]
+    pub const fn tiered_pgo(mut self, enabled: bool) -> Self {
+        self.tiered_pgo = Some(enabled);
+        self
+    }
+
+    /// Enables or disables the use of precompiled `ReadyToRun` code.
+    ///
+    /// The runtime only reads this setting from the `DOTNET_ReadyToRun` environment variable,
+    /// so it is not applied by [`HostfxrContext::apply_runtime_options`].
+    /// Use [`environment`](RuntimeOptions::environment) and keep the returned configuration applied until the runtime is loaded.
+    #[must_use]
+    pub const fn ready_to_run(mut self, enabled: bool) -> Self {
+        self.ready_to_run = Some(enabled);
+        self
+    }
+
+    /// Returns the runtime properties corresponding to these options.
+    pub fn properties(&self) -> impl Iterator<Item = (&'static PdCStr, &'static PdCStr)> {
+        [
+            (
+                crate::pdcstr!("System.Runtime.TieredCompilation"),
+                self.tiered_compilation,
+            ),
+            (
+                crate::pdcstr!("System.Runtime.TieredCompilation.QuickJit"),
+                self.quick_jit,
+            ),
+            (
+                crate::pdcstr!("System.Runtime.TieredCompilation.QuickJitForLoops"),
+                self.quick_jit_for_loops,
+            ),
+            (crate::pdcstr!("System.Runtime.TieredPGO"), self.tiered_pgo),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|value| (name, bool_value(value))))
+    }
+
+    /// Returns the environment variables corresponding to the options that can only be set through the environment.
+    #[must_use]
+    pub fn environment(&self) -> HostEnvironment {
+        let mut environment = HostEnvironment::new();
+        if let Some(ready_to_run) = self.ready_to_run {
+            environment =
+                environment.var("DOTNET_ReadyToRun", if ready_to_run { "1" } else { "0" });
+        }
+        environment
+    }
+}
+
+fn bool_value(value: bool) -> &'static PdCStr {
+    if value {
+        crate::pdcstr!("true")
+    } else {
+        crate::pdcstr!("false")
+    }
+}
+
+impl<I> HostfxrContext<I> {
+    /// Applies the given [`RuntimeOptions`] as runtime properties of this context and reads them back to verify
+    /// that they were applied.
+    ///
+    /// This has to be called before the runtime is loaded, as the runtime only reads these properties on startup.
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+    pub fn apply_runtime_options(
+        &mut self,
+        options: &RuntimeOptions,
+    ) -> Result<(), ApplyRuntimeOptionsError> {
+        for (name, value) in options.properties() {
+            self.set_runtime_property_value(name, value)?;
+        }
+
+        for (name, expected) in options.properties() {
+            let actual = self.get_runtime_property_value(name)?;
+            if actual != expected {
+                return Err(ApplyRuntimeOptionsError::NotApplied {
+                    name: name.to_string_lossy(),
+                    expected: expected.to_string_lossy(),
+                    actual: actual.to_string_lossy(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Enum for errors that can occur while applying [`RuntimeOptions`] to a context.
+#[derive(Debug, Error, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+#[non_exhaustive]
+pub enum ApplyRuntimeOptionsError {
+    /// An error occured inside the hosting components.
+    #[error(transparent)]
+    Hosting(#[from] HostingError),
+    /// A property did not have the expected value after it was set.
+    #[error("Runtime property {name} has value {actual:?} instead of {expected:?}.")]
+    NotApplied {
+        /// The name of the property.
+        name: String,
+        /// The value that was set.
+        expected: String,
+        /// The value that was read back.
+        actual: String,
+    },
+}
@@ -0,0 +1,110 @@
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::{
+    error::HostingError,
+    hostfxr::{trusted_platform_assemblies::split_path_list, AppendPathListError, HostfxrContext},
+    pdcstring::{PdCStr, PdCString},
+};
+
+/// The paths of all assemblies the default load context can resolve by name, see
+/// [`HostfxrContext::trusted_platform_assemblies`].
+pub const TRUSTED_PLATFORM_ASSEMBLIES: &str = "TRUSTED_PLATFORM_ASSEMBLIES";
+/// The directory of the application, see [`HostfxrContext::app_base_directory`].
+pub const APP_CONTEXT_BASE_DIRECTORY: &str = "APP_CONTEXT_BASE_DIRECTORY";
+/// The `.deps.json` files of the application and its frameworks.
+pub const APP_CONTEXT_DEPS_FILES: &str = "APP_CONTEXT_DEPS_FILES";
+/// The directories searched for native libraries, see [`HostfxrContext::native_dll_search_directories`].
+pub const NATIVE_DLL_SEARCH_DIRECTORIES: &str = "NATIVE_DLL_SEARCH_DIRECTORIES";
+/// Additional directories probed for assemblies, see [`HostfxrContext::app_paths`].
+pub const APP_PATHS: &str = "APP_PATHS";
+/// The directories searched for satellite resource assemblies, see [`HostfxrContext::platform_resource_roots`].
+pub const PLATFORM_RESOURCE_ROOTS: &str = "PLATFORM_RESOURCE_ROOTS";
+/// The assemblies whose `StartupHook.Initialize` method is run before the entry point,
+/// see [`HostfxrContext::startup_hooks`].
+pub const STARTUP_HOOKS: &str = "STARTUP_HOOKS";
+/// The additional directories probed for dependencies (`--additionalprobingpath`).
+pub const PROBING_DIRECTORIES: &str = "PROBING_DIRECTORIES";
+/// The `.deps.json` of the root framework.
+pub const FX_DEPS_FILE: &str = "FX_DEPS_FILE";
+/// The version of the root framework (like `8.0.0`).
+pub const FX_PRODUCT_VERSION: &str = "FX_PRODUCT_VERSION";
+/// The runtime identifier of the current platform (like `linux-x64`).
+pub const RUNTIME_IDENTIFIER: &str = "RUNTIME_IDENTIFIER";
+
+impl<I> HostfxrContext<I> {
+    /// Gets the directory of the application ([`APP_CONTEXT_BASE_DIRECTORY`]).
+    pub fn app_base_directory(&self) -> Result<PathBuf, HostingError> {
+        let value = self.get_runtime_property_value(property_name(APP_CONTEXT_BASE_DIRECTORY))?;
+        Ok(PathBuf::from(value.to_os_string()))
+    }
+
+    /// Gets the directories searched for native libraries ([`NATIVE_DLL_SEARCH_DIRECTORIES`]).
+    pub fn native_dll_search_directories(&self) -> Result<Vec<PathBuf>, HostingError> {
+        self.get_path_list_property(NATIVE_DLL_SEARCH_DIRECTORIES)
+    }
+
+    /// Gets the additional directories probed for assemblies ([`APP_PATHS`]).
+    /// The property is usually not set, in which case an empty list is returned.
+    pub fn app_paths(&self) -> Result<Vec<PathBuf>, HostingError> {
+        self.get_optional_path_list_property(APP_PATHS)
+    }
+
+    /// Gets the directories searched for satellite resource assemblies ([`PLATFORM_RESOURCE_ROOTS`]).
+    pub fn platform_resource_roots(&self) -> Result<Vec<PathBuf>, HostingError> {
+        self.get_optional_path_list_property(PLATFORM_RESOURCE_ROOTS)
+    }
+
+    /// Gets the startup hooks ([`STARTUP_HOOKS`]), which are assembly paths or names.
+    /// The property is only set if startup hooks are configured, otherwise an empty list is returned.
+    pub fn startup_hooks(&self) -> Result<Vec<PathBuf>, HostingError> {
+        self.get_optional_path_list_property(STARTUP_HOOKS)
+    }
+
+    /// Adds a startup hook to the [`STARTUP_HOOKS`] runtime property, so that its `StartupHook.Initialize` method is run
+    /// before the entry point of the application, without having to set the `DOTNET_STARTUP_HOOKS` environment variable.
+    ///
+    /// The hook is either an absolute path to an assembly or the simple name of an assembly the application can resolve.
+    /// Hooks that are already configured are skipped. Like
+    /// [`set_runtime_property_value`](HostfxrContext::set_runtime_property_value), this only has an effect before the
+    /// runtime is loaded.
+    pub fn add_startup_hook(
+        &mut self,
+        hook: impl AsRef<PdCStr>,
+    ) -> Result<(), AppendPathListError> {
+        let hook = PathBuf::from(hook.as_ref().to_os_string());
+        if !is_valid_startup_hook(&hook) {
+            return Err(AppendPathListError::InvalidStartupHook(hook));
+        }
+        self.append_path_list_property(&property_name(STARTUP_HOOKS), [hook])
+    }
+
+    fn get_path_list_property(&self, name: &str) -> Result<Vec<PathBuf>, HostingError> {
+        let value = self.get_runtime_property_value(property_name(name))?;
+        Ok(split_path_list(value).collect())
+    }
+
+    fn get_optional_path_list_property(&self, name: &str) -> Result<Vec<PathBuf>, HostingError> {
+        match self.get_path_list_property(name) {
+            Err(HostingError::HostPropertyNotFound) => Ok(Vec::new()),
+            result => result,
+        }
+    }
+}
+
+fn is_valid_startup_hook(hook: &Path) -> bool {
+    // mirrors the validation of `System.StartupHookProvider`.
+    if hook.components().count() > 1 {
+        return hook.is_absolute();
+    }
+    let Some(name) = hook.to_str() else {
+        return false;
+    };
+    !name.is_empty() && !name.to_ascii_lowercase().ends_with(".dll") && !name.contains([' ', ','])
+}
+
+fn property_name(name: &str) -> PdCString {
+    PdCString::from_str(name).expect("runtime property names do not contain nul characters")
+}
@@ -1,11 +1,38 @@
 use std::{collections::HashMap, mem::MaybeUninit, ptr};
 
 use crate::{
+    bindings::hostfxr::hostfxr_handle,
     error::{HostingError, HostingResult},
-    pdcstring::PdCStr,
+    pdcstring::{PdCStr, PdCString},
 };
 
-use super::HostfxrContext;
+use super::{strict_checks, Hostfxr, HostfxrContext, SharedHostfxrLibrary};
+
+impl Hostfxr {
+    /// Gets the runtime property value for the given key of the active host context.
+    /// The active host context is the one which loaded the runtime in the current process,
+    /// which may have been created by a different host like the `dotnet` muxer or the apphost.
+    ///
+    /// Returns [`HostingError::HostInvalidState`] if the runtime has not been loaded yet.
+    pub fn get_active_runtime_property_value(
+        &self,
+        name: impl AsRef<PdCStr>,
+    ) -> Result<PdCString, HostingError> {
+        let mut value = MaybeUninit::uninit();
+
+        let result = unsafe {
+            self.lib.hostfxr_get_runtime_property_value(
+                ptr::null(),
+                name.as_ref().as_ptr(),
+                value.as_mut_ptr(),
+            )
+        }
+        .unwrap();
+        HostingResult::from(result).into_result()?;
+
+        Ok(unsafe { PdCStr::from_str_ptr(value.assume_init()) }.to_owned())
+    }
+}
 
 impl<I> HostfxrContext<I> {
     /// Gets the runtime property value for the given key of this host context.
@@ -34,6 +61,11 @@ impl<I> HostfxrContext<I> {
         name: impl AsRef<PdCStr>,
         value: impl AsRef<PdCStr>,
     ) -> Result<(), HostingError> {
+        strict_checks::check_open(self.handle(), "modifying a runtime property");
+        strict_checks::check(
+            !self.has_loaded_runtime(),
+            "runtime properties cannot be modified after the runtime has been loaded",
+        );
         let result = unsafe {
             self.library().hostfxr_set_runtime_property_value(
                 self.handle().as_raw(),
@@ -45,11 +77,29 @@ impl<I> HostfxrContext<I> {
         HostingResult::from(result).into_result().map(|_| ())
     }
 
+    /// Sets the values of multiple runtime properties for this host context.
+    ///
+    /// The properties are set in order, stopping at the first error. Properties set before the error remain set.
+    pub fn set_runtime_properties<K: AsRef<PdCStr>, V: AsRef<PdCStr>>(
+        &mut self,
+        properties: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<(), HostingError> {
+        for (name, value) in properties {
+            self.set_runtime_property_value(name, value)?;
+        }
+        Ok(())
+    }
+
     /// Remove a runtime property for this host context.
     pub fn remove_runtime_property_value(
         &mut self,
         name: impl AsRef<PdCStr>,
     ) -> Result<(), HostingError> {
+        strict_checks::check_open(self.handle(), "modifying a runtime property");
+        strict_checks::check(
+            !self.has_loaded_runtime(),
+            "runtime properties cannot be modified after the runtime has been loaded",
+        );
         let result = unsafe {
             self.library().hostfxr_set_runtime_property_value(
                 self.handle().as_raw(),
@@ -63,48 +113,116 @@ impl<I> HostfxrContext<I> {
 
     /// Get all runtime properties for this host context.
     pub fn runtime_properties(&self) -> Result<HashMap<&'_ PdCStr, &'_ PdCStr>, HostingError> {
-        // get count
-        let mut count = MaybeUninit::uninit();
-        let mut result = unsafe {
-            self.library().hostfxr_get_runtime_properties(
-                self.handle().as_raw(),
-                count.as_mut_ptr(),
-                ptr::null_mut(),
-                ptr::null_mut(),
-            )
-        }
-        .unwrap();
+        get_runtime_properties(self.library(), self.handle().as_raw())
+    }
 
-        // ignore buffer too small error as the first call is only to get the required buffer size.
-        match HostingResult::from(result).into_result() {
-            Ok(_) | Err(HostingError::HostApiBufferTooSmall) => {}
-            Err(e) => return Err(e),
-        };
-
-        // get values / fill buffer
-        let mut count = unsafe { count.assume_init() };
-        let mut keys = Vec::with_capacity(count);
-        let mut values = Vec::with_capacity(count);
-        result = unsafe {
-            self.library().hostfxr_get_runtime_properties(
-                self.handle().as_raw(),
-                &mut count,
-                keys.as_mut_ptr(),
-                values.as_mut_ptr(),
-            )
-        }
-        .unwrap();
-        HostingResult::from(result).into_result()?;
+    /// Get all runtime properties for this host context whose name starts with the given prefix.
+    pub fn properties_with_prefix(
+        &self,
+        prefix: &str,
+    ) -> Result<HashMap<&'_ PdCStr, &'_ PdCStr>, HostingError> {
+        self.properties_matching(|name, _| name.starts_with(prefix))
+    }
 
-        unsafe { keys.set_len(count) };
-        unsafe { values.set_len(count) };
+    /// Get all runtime properties for this host context for which the given predicate returns `true`.
+    /// The properties are fetched once and then filtered.
+    pub fn properties_matching(
+        &self,
+        mut predicate: impl FnMut(&PdCStr, &PdCStr) -> bool,
+    ) -> Result<HashMap<&'_ PdCStr, &'_ PdCStr>, HostingError> {
+        let mut properties = self.runtime_properties()?;
+        properties.retain(|name, value| predicate(name, value));
+        Ok(properties)
+    }
 
-        let keys = keys.into_iter().map(|e| unsafe { PdCStr::from_str_ptr(e) });
-        let values = values
+    /// Compares the runtime properties requested by this context with the properties of the active runtime and
+    /// returns the ones that differ, sorted by name.
+    ///
+    /// This helps to diagnose why the initialization of a secondary context returned
+    /// [`HostingSuccess::DifferentRuntimeProperties`]. Properties of the active runtime that were not requested by this
+    /// context are not conflicts. For a primary context, the result is always empty.
+    ///
+    /// [`HostingSuccess::DifferentRuntimeProperties`]: crate::error::HostingSuccess::DifferentRuntimeProperties
+    pub fn property_conflicts(&self) -> Result<Vec<PropertyConflict>, HostingError> {
+        if self.is_primary() {
+            return Ok(Vec::new());
+        }
+
+        let requested = self.runtime_properties()?;
+        let active = get_runtime_properties(self.library(), ptr::null())?;
+        let mut conflicts = requested
             .into_iter()
-            .map(|e| unsafe { PdCStr::from_str_ptr(e) });
+            .filter_map(|(name, value)| {
+                let active_value = active.get(name).copied();
+                (active_value != Some(value)).then(|| PropertyConflict {
+                    name: name.to_owned(),
+                    requested: value.to_owned(),
+                    active: active_value.map(|value| value.to_owned()),
+                })
+            })
+            .collect::<Vec<_>>();
+        conflicts.sort_by(|a, b| a.name.as_slice().cmp(b.name.as_slice()));
+        Ok(conflicts)
+    }
+}
 
-        let map = keys.zip(values).collect();
-        Ok(map)
+/// A runtime property whose value requested by a secondary context differs from the value of the active runtime,
+/// see [`HostfxrContext::property_conflicts`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PropertyConflict {
+    /// The name of the property.
+    pub name: PdCString,
+    /// The value requested by the secondary context.
+    pub requested: PdCString,
+    /// The value of the active runtime or [`None`] if the property is not set.
+    pub active: Option<PdCString>,
+}
+
+fn get_runtime_properties(
+    library: &SharedHostfxrLibrary,
+    handle: hostfxr_handle,
+) -> Result<HashMap<&'_ PdCStr, &'_ PdCStr>, HostingError> {
+    // get count
+    let mut count = MaybeUninit::uninit();
+    let mut result = unsafe {
+        library.hostfxr_get_runtime_properties(
+            handle,
+            count.as_mut_ptr(),
+            ptr::null_mut(),
+            ptr::null_mut(),
+        )
     }
+    .unwrap();
+
+    // ignore buffer too small error as the first call is only to get the required buffer size.
+    match HostingResult::from(result).into_result() {
+        Ok(_) | Err(HostingError::HostApiBufferTooSmall) => {}
+        Err(e) => return Err(e),
+    };
+
+    // get values / fill buffer
+    let mut count = unsafe { count.assume_init() };
+    let mut keys = Vec::with_capacity(count);
+    let mut values = Vec::with_capacity(count);
+    result = unsafe {
+        library.hostfxr_get_runtime_properties(
+            handle,
+            &mut count,
+            keys.as_mut_ptr(),
+            values.as_mut_ptr(),
+        )
+    }
+    .unwrap();
+    HostingResult::from(result).into_result()?;
+
+    unsafe { keys.set_len(count) };
+    unsafe { values.set_len(count) };
+
+    let keys = keys.into_iter().map(|e| unsafe { PdCStr::from_str_ptr(e) });
+    let values = values
+        .into_iter()
+        .map(|e| unsafe { PdCStr::from_str_ptr(e) });
+
+    let map = keys.zip(values).collect();
+    Ok(map)
 }
@@ -0,0 +1,65 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::hostfxr::AppOrHostingResult;
+#[cfg(feature = "netcore3_0")]
+use crate::{error::HostingError, hostfxr::Hostfxr};
+
+static RUNTIME_STARTED: AtomicBool = AtomicBool::new(false);
+
+/// Returns whether a `CoreCLR` runtime has been started in the current process through this crate.
+///
+/// The runtime is considered started as soon as a runtime delegate has been retrieved from a context
+/// or an application run through this crate has returned. While such an application is still running, this returns
+/// `false`, use [`Hostfxr::is_runtime_started`] instead. As the runtime cannot be unloaded, this never changes back
+/// to `false`.
+///
+/// This does not detect runtimes that were started by other means (for example if the current library
+/// was loaded by a .NET application), use [`Hostfxr::is_runtime_started`] to also probe the hosting components.
+#[must_use]
+pub fn runtime_started() -> bool {
+    RUNTIME_STARTED.load(Ordering::Acquire)
+}
+
+pub(crate) fn mark_runtime_started() {
+    RUNTIME_STARTED.store(true, Ordering::Release);
+    super::exit_hook::runtime_loaded();
+}
+
+/// Marks the runtime as started after an application run through the hosting components returned, unless the
+/// hosting components failed before the application was run.
+pub(crate) fn mark_runtime_started_by_app(result: AppOrHostingResult) {
+    let failed = matches!(
+        result.as_hosting_exit_code().into_result(),
+        Err(error) if error.is_known()
+    );
+    if !failed {
+        mark_runtime_started();
+    }
+}
+
+#[cfg(feature = "netcore3_0")]
+impl Hostfxr {
+    /// Returns whether a `CoreCLR` runtime is running in the current process.
+    ///
+    /// In addition to the state tracked by [`runtime_started`], this asks hostfxr whether there is an
+    /// active host context, which is the case once the runtime has been loaded by any host using the same
+    /// hostfxr library, including the `dotnet` muxer and the apphost.
+    /// If a runtime is running, a new context can only be initialized as secondary context.
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+    #[must_use]
+    pub fn is_runtime_started(&self) -> bool {
+        if runtime_started() {
+            return true;
+        }
+
+        // The active host context only exists once the runtime is loaded.
+        let started = matches!(
+            self.get_active_runtime_property_value(crate::pdcstr!("FX_PRODUCT_VERSION")),
+            Ok(_) | Err(HostingError::HostPropertyNotFound)
+        );
+        if started {
+            mark_runtime_started();
+        }
+        started
+    }
+}
@@ -0,0 +1,289 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use thiserror::Error;
+
+use crate::{error::HostingError, hostfxr::HostfxrContext};
+
+/// A version of a framework or runtime in the format `major.minor.patch[-prerelease]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Version {
+    pub(crate) major: u64,
+    pub(crate) minor: u64,
+    pub(crate) patch: u64,
+    pub(crate) prerelease: bool,
+}
+
+impl Version {
+    /// Parses a version, missing minor and patch components default to `0`.
+    pub(crate) fn parse(version: &str) -> Option<Self> {
+        let (release, prerelease) = match version.trim().split_once('-') {
+            Some((release, _)) => (release, true),
+            None => (version.trim(), false),
+        };
+        let mut parts = release.split('.').map(str::parse::<u64>);
+        let major = parts.next()?.ok()?;
+        let minor = parts.next().unwrap_or(Ok(0)).ok()?;
+        let patch = parts.next().unwrap_or(Ok(0)).ok()?;
+        Some(Self {
+            major,
+            minor,
+            patch,
+            prerelease,
+        })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            // prereleases come before the release with the same version.
+            .then_with(|| other.prerelease.cmp(&self.prerelease))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Op {
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+    Tilde,
+    Caret,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Comparator {
+    op: Op,
+    major: u64,
+    minor: Option<u64>,
+    patch: Option<u64>,
+}
+
+impl Comparator {
+    fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let (op, version) = [
+            (">=", Op::GreaterEq),
+            ("<=", Op::LessEq),
+            (">", Op::Greater),
+            ("<", Op::Less),
+            ("=", Op::Exact),
+            ("~", Op::Tilde),
+            ("^", Op::Caret),
+        ]
+        .into_iter()
+        .find_map(|(prefix, op)| s.strip_prefix(prefix).map(|version| (op, version)))
+        .unwrap_or((Op::Caret, s));
+
+        let mut parts = version.trim().split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next().map(str::parse).transpose().ok()?;
+        let patch = parts.next().map(str::parse).transpose().ok()?;
+        if parts.next().is_some() || (minor.is_none() && patch.is_some()) {
+            return None;
+        }
+        Some(Self {
+            op,
+            major,
+            minor,
+            patch,
+        })
+    }
+
+    fn matches(&self, version: &Version) -> bool {
+        let lower = (self.major, self.minor.unwrap_or(0), self.patch.unwrap_or(0));
+        let actual = (version.major, version.minor, version.patch);
+        // compares only the components present in the comparator.
+        let prefix_cmp = match (self.minor, self.patch) {
+            (None, _) => version.major.cmp(&self.major),
+            (Some(minor), None) => (version.major, version.minor).cmp(&(self.major, minor)),
+            (Some(minor), Some(patch)) => actual.cmp(&(self.major, minor, patch)),
+        };
+
+        match self.op {
+            Op::Exact => prefix_cmp == Ordering::Equal,
+            Op::Greater => prefix_cmp == Ordering::Greater,
+            Op::GreaterEq => actual >= lower,
+            Op::Less => actual < lower,
+            Op::LessEq => prefix_cmp != Ordering::Greater,
+            Op::Tilde => {
+                actual >= lower
+                    && match self.minor {
+                        Some(minor) => (version.major, version.minor) == (self.major, minor),
+                        None => version.major == self.major,
+                    }
+            }
+            Op::Caret => {
+                actual >= lower
+                    && match (self.major, self.minor, self.patch) {
+                        (0, Some(0), Some(patch)) => actual == (0, 0, patch),
+                        (0, Some(minor), _) => (version.major, version.minor) == (0, minor),
+                        (major, _, _) => version.major == major,
+                    }
+            }
+        }
+    }
+}
+
+impl Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = match self.op {
+            Op::Exact => "=",
+            Op::Greater => ">",
+            Op::GreaterEq => ">=",
+            Op::Less => "<",
+            Op::LessEq => "<=",
+            Op::Tilde => "~",
+            Op::Caret => "^",
+        };
+        write!(f, "{op}{}", self.major)?;
+        if let Some(minor) = self.minor {
+            write!(f, ".{minor}")?;
+        }
+        if let Some(patch) = self.patch {
+            write!(f, ".{patch}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A requirement on the version of the runtime, using the syntax of semantic versioning requirements
+/// like `^8.0`, `~8.0.4`, `=8.0.4` or `>=8.0.4, <8.0.10`.
+///
+/// Versions without an operator are interpreted like with a `^` (same major version and at least the given version).
+///
+/// # Example
+/// ```rust
+/// # use netcorehost::hostfxr::RuntimeVersionReq;
+/// let requirement = "~8.0.4".parse::<RuntimeVersionReq>().unwrap();
+/// assert!(requirement.matches("8.0.10"));
+/// assert!(!requirement.matches("8.1.0"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub struct RuntimeVersionReq {
+    comparators: Vec<Comparator>,
+}
+
+impl RuntimeVersionReq {
+    /// Returns whether the given version satisfies this requirement.
+    /// Returns `false` if the version cannot be parsed.
+    #[must_use]
+    pub fn matches(&self, version: &str) -> bool {
+        Version::parse(version).is_some_and(|version| {
+            self.comparators
+                .iter()
+                .all(|comparator| comparator.matches(&version))
+        })
+    }
+}
+
+impl FromStr for RuntimeVersionReq {
+    type Err = ParseRuntimeVersionReqError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let comparators = if s.trim() == "*" {
+            Vec::new()
+        } else {
+            s.split(',')
+                .map(|comparator| {
+                    Comparator::parse(comparator)
+                        .ok_or_else(|| ParseRuntimeVersionReqError(s.to_string()))
+                })
+                .collect::<Result<_, _>>()?
+        };
+        Ok(Self { comparators })
+    }
+}
+
+impl Display for RuntimeVersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.comparators.is_empty() {
+            return write!(f, "*");
+        }
+        for (i, comparator) in self.comparators.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{comparator}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Error returned when parsing an invalid [`RuntimeVersionReq`].
+#[derive(Debug, Error, Clone, PartialEq, Eq, Hash)]
+#[error("Invalid runtime version requirement {0:?}.")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub struct ParseRuntimeVersionReqError(String);
+
+impl<I> HostfxrContext<I> {
+    /// Returns the version of the `Microsoft.NETCore.App` framework that was resolved for this context.
+    pub fn resolved_runtime_version(&self) -> Result<String, HostingError> {
+        self.get_runtime_property_value(crate::pdcstr!("FX_PRODUCT_VERSION"))
+            .map(|version| version.to_string_lossy())
+    }
+
+    /// Checks that the resolved `Microsoft.NETCore.App` version satisfies the given requirement,
+    /// protecting components that are tightly coupled to a runtime version from silently rolling forward.
+    ///
+    /// If the requirement is not satisfied, the context is closed before the runtime is loaded and
+    /// [`RequireRuntimeError::RuntimeVersionMismatch`] is returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use netcorehost::{nethost, pdcstr};
+    /// let hostfxr = nethost::load_hostfxr().unwrap();
+    /// let context = hostfxr
+    ///     .initialize_for_runtime_config(pdcstr!("Plugin.runtimeconfig.json"))
+    ///     .unwrap()
+    ///     .require_runtime(&"~8.0.4".parse().unwrap())
+    ///     .unwrap();
+    /// ```
+    pub fn require_runtime(
+        self,
+        requirement: &RuntimeVersionReq,
+    ) -> Result<Self, RequireRuntimeError> {
+        let resolved = self.resolved_runtime_version()?;
+        if requirement.matches(&resolved) {
+            Ok(self)
+        } else {
+            Err(RequireRuntimeError::RuntimeVersionMismatch {
+                required: requirement.to_string(),
+                resolved,
+            })
+        }
+    }
+}
+
+/// Enum for errors that can occur while checking the runtime version using [`HostfxrContext::require_runtime`].
+#[derive(Debug, Error, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+#[non_exhaustive]
+pub enum RequireRuntimeError {
+    /// An error occured inside the hosting components.
+    #[error(transparent)]
+    Hosting(#[from] HostingError),
+    /// The resolved runtime version does not satisfy the requirement.
+    #[error(
+        "The resolved runtime version {resolved} does not satisfy the requirement {required}."
+    )]
+    RuntimeVersionMismatch {
+        /// The required version.
+        required: String,
+        /// The version of `Microsoft.NETCore.App` that was resolved.
+        resolved: String,
+    },
+}
@@ -0,0 +1,185 @@
+//! Runtime detection of API misuse, enabled by the `strict-checks` feature.
+//!
+//! Detected misuse panics in debug builds and is reported as a warning on stderr in release builds.
+//! Contexts and loaders keep the hostfxr library loaded, so the library can only be unloaded or mixed up while a
+//! context is still open if the context is passed around as a raw [`HostfxrHandle`], which is detected here as well.
+//! Without the feature all checks compile to nothing.
+
+use crate::hostfxr::HostfxrHandle;
+
+#[cfg(feature = "strict-checks")]
+mod imp {
+    use std::{
+        collections::{HashMap, HashSet},
+        path::{Path, PathBuf},
+        sync::{Arc, Mutex, PoisonError, Weak},
+    };
+
+    use crate::hostfxr::{Hostfxr, HostfxrHandle, HostfxrLibrary};
+
+    // the library an open context was initialized with.
+    struct OpenedWith {
+        // may be unloaded once all references to it are gone, for example if a context only survives as a raw handle.
+        library: Weak<HostfxrLibrary>,
+        // loading the same library again returns another instance of it, so libraries are compared by path.
+        path: PathBuf,
+    }
+
+    #[derive(Default)]
+    struct Registry {
+        closed: HashSet<usize>,
+        libraries: HashMap<usize, OpenedWith>,
+    }
+
+    static REGISTRY: Mutex<Option<Registry>> = Mutex::new(None);
+
+    fn with_registry<R>(f: impl FnOnce(&mut Registry) -> R) -> R {
+        let mut registry = REGISTRY.lock().unwrap_or_else(PoisonError::into_inner);
+        f(registry.get_or_insert_with(Registry::default))
+    }
+
+    fn key(handle: HostfxrHandle) -> usize {
+        handle.as_raw() as usize
+    }
+
+    #[track_caller]
+    pub(crate) fn report_misuse(message: &str) {
+        if cfg!(debug_assertions) {
+            panic!("netcorehost API misuse: {message}");
+        } else {
+            eprintln!("warning: netcorehost API misuse: {message}");
+        }
+    }
+
+    fn same_library(a: &Path, b: &Path) -> bool {
+        a == b || matches!((a.canonicalize(), b.canonicalize()), (Ok(a), Ok(b)) if a == b)
+    }
+
+    pub(crate) fn handle_opened(handle: HostfxrHandle, hostfxr: &Hostfxr) {
+        with_registry(|registry| {
+            // hostfxr may reuse the address of a closed context for a new one.
+            registry.closed.remove(&key(handle));
+            registry.libraries.insert(
+                key(handle),
+                OpenedWith {
+                    library: Arc::downgrade(&hostfxr.lib),
+                    path: hostfxr.library_path.clone(),
+                },
+            );
+        });
+    }
+
+    pub(crate) fn handle_closed(handle: HostfxrHandle) {
+        with_registry(|registry| {
+            registry.closed.insert(key(handle));
+            registry.libraries.remove(&key(handle));
+        });
+    }
+
+    #[track_caller]
+    pub(crate) fn check_open(handle: HostfxrHandle, operation: &str) {
+        let (closed, unloaded) = with_registry(|registry| {
+            (
+                registry.closed.contains(&key(handle)),
+                registry
+                    .libraries
+                    .get(&key(handle))
+                    .is_some_and(|opened_with| opened_with.library.strong_count() == 0),
+            )
+        });
+        if closed {
+            report_misuse(&format!(
+                "{operation} on context {:?}, which has already been closed",
+                handle.as_raw()
+            ));
+        } else if unloaded {
+            report_misuse(&format!(
+                "{operation} on context {:?}, whose hostfxr library has already been unloaded",
+                handle.as_raw()
+            ));
+        }
+    }
+
+    #[track_caller]
+    pub(crate) fn check_library(handle: HostfxrHandle, hostfxr: &Hostfxr, operation: &str) {
+        let mismatch = with_registry(|registry| {
+            let Some(opened_with) = registry.libraries.get_mut(&key(handle)) else {
+                return false;
+            };
+            if !same_library(&opened_with.path, &hostfxr.library_path) {
+                return true;
+            }
+            // the context now keeps this instance of the library loaded.
+            opened_with.library = Arc::downgrade(&hostfxr.lib);
+            false
+        });
+        if mismatch {
+            report_misuse(&format!(
+                "{operation} on context {:?} with a hostfxr library other than the one it was initialized with",
+                handle.as_raw()
+            ));
+        }
+    }
+
+    #[track_caller]
+    pub(crate) fn check(condition: bool, message: &str) {
+        if !condition {
+            report_misuse(message);
+        }
+    }
+}
+
+#[cfg(not(feature = "strict-checks"))]
+mod imp {
+    use crate::hostfxr::{Hostfxr, HostfxrHandle};
+
+    #[inline(always)]
+    pub(crate) fn handle_opened(_handle: HostfxrHandle, _hostfxr: &Hostfxr) {}
+
+    #[inline(always)]
+    pub(crate) fn handle_closed(_handle: HostfxrHandle) {}
+
+    #[inline(always)]
+    pub(crate) fn check_open(_handle: HostfxrHandle, _operation: &str) {}
+
+    #[inline(always)]
+    pub(crate) fn check_library(_handle: HostfxrHandle, _hostfxr: &Hostfxr, _operation: &str) {}
+
+    #[inline(always)]
+    pub(crate) fn check(_condition: bool, _message: &str) {}
+}
+
+pub(crate) use imp::{check, check_library, check_open, handle_closed, handle_opened};
+
+/// Handle of the context a [`DelegateLoader`](crate::hostfxr::DelegateLoader) was created from,
+/// only tracked if strict checks are enabled.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct LoaderOrigin {
+    #[cfg(feature = "strict-checks")]
+    handle: HostfxrHandle,
+}
+
+// the handle is only used to look up whether the context has been closed and never dereferenced.
+#[cfg(feature = "strict-checks")]
+unsafe impl Send for LoaderOrigin {}
+#[cfg(feature = "strict-checks")]
+unsafe impl Sync for LoaderOrigin {}
+
+impl LoaderOrigin {
+    #[allow(unused_variables)]
+    pub(crate) const fn new(handle: HostfxrHandle) -> Self {
+        Self {
+            #[cfg(feature = "strict-checks")]
+            handle,
+        }
+    }
+
+    #[track_caller]
+    pub(crate) fn check_open(&self) {
+        #[cfg(feature = "strict-checks")]
+        check_open(
+            self.handle,
+            "loading a function pointer using a delegate loader",
+        );
+    }
+}
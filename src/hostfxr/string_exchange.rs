@@ -0,0 +1,203 @@
+use std::{cell::RefCell, str::Utf8Error};
+
+use thiserror::Error;
+
+/// Returns the pointer and length to pass a string to a managed method taking `(IntPtr ptr, int length)`.
+///
+/// The managed side can read the string using `NativeStrings.FromUtf8` from [`MANAGED_STRING_HELPERS`]
+/// or `Marshal.PtrToStringUTF8(ptr, length)`. The string is not null terminated.
+///
+/// # Panics
+/// Panics if the string is longer than [`i32::MAX`] bytes.
+///
+/// [`MANAGED_STRING_HELPERS`]: super::MANAGED_STRING_HELPERS
+#[must_use]
+pub fn utf8_parts(value: &str) -> Utf8Parts<'_> {
+    let length =
+        i32::try_from(value.len()).expect("string is too long to be passed to managed code");
+    Utf8Parts { value, length }
+}
+
+/// The pointer and length of a string passed to a managed method, created using [`utf8_parts`].
+///
+/// The parts borrow the string, so it cannot be dropped while they are still in use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Utf8Parts<'a> {
+    value: &'a str,
+    length: i32,
+}
+
+impl<'a> Utf8Parts<'a> {
+    /// Returns the pointer to the UTF-8 encoded bytes of the string.
+    #[must_use]
+    pub fn as_ptr(&self) -> *const u8 {
+        self.value.as_ptr()
+    }
+
+    /// Returns the length of the string in bytes.
+    #[must_use]
+    pub fn len(&self) -> i32 {
+        self.length
+    }
+
+    /// Returns whether the string is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the borrowed string.
+    #[must_use]
+    pub fn as_str(&self) -> &'a str {
+        self.value
+    }
+}
+
+/// Reads a string passed by managed code as `(IntPtr ptr, int length)`, the reverse of [`utf8_parts`].
+///
+/// This is useful for [`callback!`](crate::callback)s taking strings from managed code. The managed side can pass a
+/// string from a `byte*` pointing to the UTF-8 encoded bytes (e.g. from a `fixed` statement over
+/// `Encoding.UTF8.GetBytes(value)`) and its length.
+///
+/// # Safety
+/// `ptr` has to point to `length` bytes that stay valid and unmodified for `'a`. It may be null if `length` is zero.
+///
+/// # Panics
+/// Panics if `length` is negative.
+pub unsafe fn utf8_from_parts<'a>(ptr: *const u8, length: i32) -> Result<&'a str, Utf8Error> {
+    let length = usize::try_from(length).expect("string length must not be negative");
+    if length == 0 {
+        return Ok("");
+    }
+    let bytes = unsafe { std::slice::from_raw_parts(ptr, length) };
+    std::str::from_utf8(bytes)
+}
+
+const MAX_ATTEMPTS: usize = 4;
+
+/// A reusable buffer for receiving UTF-8 strings from managed code.
+///
+/// The managed method is called with a pointer to the buffer and its capacity in bytes and returns the length of the
+/// string in bytes, writing it only if it fits (see `NativeStrings.ToUtf8Buffer` in [`MANAGED_STRING_HELPERS`]).
+/// If the string did not fit, the buffer is grown and the method is called again.
+/// Negative return values are treated as errors reported by the managed method.
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{hostfxr::{utf8_parts, AssemblyDelegateLoader, StringExchangeBuffer}, pdcstr};
+/// # fn test(fn_loader: AssemblyDelegateLoader) {
+/// // [UnmanagedCallersOnly]
+/// // public static int Greet(IntPtr name, int nameLength, IntPtr buffer, int capacity) =>
+/// //     NativeStrings.ToUtf8Buffer($"Hello {NativeStrings.FromUtf8(name, nameLength)}!", buffer, capacity);
+/// let greet = fn_loader
+///     .get_function_with_unmanaged_callers_only::<fn(*const u8, i32, *mut u8, i32) -> i32>(
+///         pdcstr!("Library.Greeter, Library"),
+///         pdcstr!("Greet"),
+///     )
+///     .unwrap();
+///
+/// let name = utf8_parts("World");
+/// let greeting = StringExchangeBuffer::receive_with_thread_buffer(|buffer, capacity| {
+///     greet(name.as_ptr(), name.len(), buffer, capacity)
+/// })
+/// .unwrap();
+/// assert_eq!(greeting, "Hello World!");
+/// # }
+/// ```
+///
+/// [`MANAGED_STRING_HELPERS`]: super::MANAGED_STRING_HELPERS
+#[derive(Debug, Clone, Default)]
+pub struct StringExchangeBuffer {
+    buffer: Vec<u8>,
+}
+
+thread_local! {
+    static THREAD_BUFFER: RefCell<StringExchangeBuffer> = RefCell::new(StringExchangeBuffer::with_capacity(256));
+}
+
+impl StringExchangeBuffer {
+    /// Creates a new empty buffer, which is allocated on first use.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { buffer: Vec::new() }
+    }
+
+    /// Creates a new buffer with the given initial capacity in bytes.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: vec![0; capacity],
+        }
+    }
+
+    /// Returns the current capacity of the buffer in bytes.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Calls `call` with a pointer to the buffer and its capacity until the returned length fits into the buffer,
+    /// and returns the received string, which borrows the buffer.
+    pub fn receive(
+        &mut self,
+        mut call: impl FnMut(*mut u8, i32) -> i32,
+    ) -> Result<&str, StringExchangeError> {
+        for _ in 0..MAX_ATTEMPTS {
+            let capacity = i32::try_from(self.buffer.len()).unwrap_or(i32::MAX);
+            let length = call(self.buffer.as_mut_ptr(), capacity);
+            let Ok(length) = usize::try_from(length) else {
+                return Err(StringExchangeError::Managed(length));
+            };
+            if length <= self.buffer.len() {
+                return Ok(std::str::from_utf8(&self.buffer[..length])?);
+            }
+            self.buffer.resize(length, 0);
+        }
+        Err(StringExchangeError::LengthUnstable)
+    }
+
+    /// Like [`receive`](StringExchangeBuffer::receive), but returns an owned string.
+    pub fn receive_string(
+        &mut self,
+        call: impl FnMut(*mut u8, i32) -> i32,
+    ) -> Result<String, StringExchangeError> {
+        self.receive(call).map(str::to_owned)
+    }
+
+    /// Like [`receive_string`](StringExchangeBuffer::receive_string), but uses a buffer that is reused by all calls on
+    /// the current thread.
+    ///
+    /// If the thread buffer is already in use, because `call` itself receives a string, a temporary buffer is used.
+    pub fn receive_with_thread_buffer(
+        call: impl FnMut(*mut u8, i32) -> i32,
+    ) -> Result<String, StringExchangeError> {
+        let mut call = Some(call);
+        let result = THREAD_BUFFER.with(|buffer| {
+            buffer
+                .try_borrow_mut()
+                .ok()
+                .map(|mut buffer| buffer.receive_string(call.take().unwrap()))
+        });
+        match (result, call) {
+            (Some(result), _) => result,
+            (None, Some(call)) => Self::new().receive_string(call),
+            (None, None) => unreachable!(),
+        }
+    }
+}
+
+/// Enum for errors that can occur while receiving a string from managed code.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+#[non_exhaustive]
+pub enum StringExchangeError {
+    /// The managed method returned the given negative value to indicate an error.
+    #[error("The managed method reported an error ({0}).")]
+    Managed(i32),
+    /// The received bytes are not valid UTF-8.
+    #[error(transparent)]
+    InvalidUtf8(#[from] Utf8Error),
+    /// The managed method kept requesting a larger buffer.
+    #[error("The managed method kept requesting a larger buffer.")]
+    LengthUnstable,
+}
@@ -0,0 +1,42 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    PoisonError, RwLock,
+};
+
+type ThreadNameHook = Box<dyn Fn(&str) -> String + Send + Sync>;
+
+static HOOK: RwLock<Option<ThreadNameHook>> = RwLock::new(None);
+static NEXT_WORKER_ID: AtomicUsize = AtomicUsize::new(1);
+
+/// Sets a hook that determines the names of the threads spawned by this crate to call into the runtime.
+///
+/// The hook receives the default name, like `netcorehost-app` for [`run_app_on_thread`] or `netcorehost-worker-1`
+/// for [`ManagedCallScope`], and returns the name to use. Descriptive names make it easier to tell which native
+/// threads drive managed work in mixed-mode profiler and debugger sessions.
+/// Names set explicitly using [`ManagedCallScope::name`] are not passed to the hook.
+///
+/// [`run_app_on_thread`]: crate::hostfxr::HostfxrContext::run_app_on_thread
+/// [`ManagedCallScope`]: crate::hostfxr::ManagedCallScope
+/// [`ManagedCallScope::name`]: crate::hostfxr::ManagedCallScope::name
+pub fn set_thread_name_hook(hook: impl Fn(&str) -> String + Send + Sync + 'static) {
+    *HOOK.write().unwrap_or_else(PoisonError::into_inner) = Some(Box::new(hook));
+}
+
+/// Removes the hook set using [`set_thread_name_hook`], so that the default names are used again.
+pub fn clear_thread_name_hook() {
+    *HOOK.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Returns the name for a new thread with the given default name.
+pub(crate) fn thread_name(default: &str) -> String {
+    match &*HOOK.read().unwrap_or_else(PoisonError::into_inner) {
+        Some(hook) => hook(default),
+        None => default.to_string(),
+    }
+}
+
+/// Returns the name for a new worker thread, which are numbered consecutively.
+pub(crate) fn worker_thread_name() -> String {
+    let id = NEXT_WORKER_ID.fetch_add(1, Ordering::Relaxed);
+    thread_name(&format!("netcorehost-worker-{id}"))
+}
@@ -0,0 +1,205 @@
+use std::{
+    ffi::OsString,
+    iter::FusedIterator,
+    path::{Path, PathBuf},
+    slice,
+};
+
+use thiserror::Error;
+
+use crate::{
+    error::HostingError,
+    pdcstring::{PdCStr, PdCString, PdUChar},
+};
+
+use super::HostfxrContext;
+
+#[cfg(windows)]
+const PATH_LIST_SEPARATOR: PdUChar = b';' as PdUChar;
+#[cfg(not(windows))]
+const PATH_LIST_SEPARATOR: PdUChar = b':';
+
+impl<I> HostfxrContext<I> {
+    /// Gets the list of trusted platform assemblies (the `TRUSTED_PLATFORM_ASSEMBLIES` runtime property),
+    /// which contains the paths of all assemblies the default load context can resolve by name,
+    /// including the framework and the application dependencies listed in the `.deps.json`.
+    pub fn trusted_platform_assemblies(
+        &self,
+    ) -> Result<TrustedPlatformAssemblies<'_>, HostingError> {
+        let value =
+            self.get_runtime_property_value(crate::pdcstr!("TRUSTED_PLATFORM_ASSEMBLIES"))?;
+        Ok(TrustedPlatformAssemblies { value })
+    }
+
+    /// Appends the given assembly paths to the `TRUSTED_PLATFORM_ASSEMBLIES` runtime property.
+    ///
+    /// Paths that are already part of the list are skipped. Nothing is modified if any of the paths is invalid.
+    /// Like [`set_runtime_property_value`](HostfxrContext::set_runtime_property_value), this only has an effect
+    /// before the runtime is loaded.
+    pub fn append_trusted_platform_assemblies<P: AsRef<Path>>(
+        &mut self,
+        paths: impl IntoIterator<Item = P>,
+    ) -> Result<(), AppendPathListError> {
+        self.append_path_list_property(crate::pdcstr!("TRUSTED_PLATFORM_ASSEMBLIES"), paths)
+    }
+
+    /// Appends the given directories to the `NATIVE_DLL_SEARCH_DIRECTORIES` runtime property,
+    /// which lists the directories searched for native libraries.
+    ///
+    /// Directories that are already part of the list are skipped. Nothing is modified if any of the paths is invalid.
+    /// Like [`set_runtime_property_value`](HostfxrContext::set_runtime_property_value), this only has an effect
+    /// before the runtime is loaded.
+    pub fn append_native_search_directories<P: AsRef<Path>>(
+        &mut self,
+        paths: impl IntoIterator<Item = P>,
+    ) -> Result<(), AppendPathListError> {
+        self.append_path_list_property(crate::pdcstr!("NATIVE_DLL_SEARCH_DIRECTORIES"), paths)
+    }
+
+    pub(crate) fn append_path_list_property<P: AsRef<Path>>(
+        &mut self,
+        name: &PdCStr,
+        paths: impl IntoIterator<Item = P>,
+    ) -> Result<(), AppendPathListError> {
+        let mut value = match self.get_runtime_property_value(name) {
+            Ok(value) => value.as_slice().to_vec(),
+            Err(HostingError::HostPropertyNotFound) => Vec::new(),
+            Err(err) => return Err(err.into()),
+        };
+        let original_len = value.len();
+
+        for path in paths {
+            let path = path.as_ref();
+            let encoded = PdCString::from_os_str(path)
+                .ok()
+                .filter(|encoded| !encoded.as_slice().iter().any(is_separator))
+                .ok_or_else(|| AppendPathListError::InvalidPath(path.to_path_buf()))?;
+            let encoded = encoded.as_slice();
+            if value.split(is_separator).any(|segment| segment == encoded) {
+                continue;
+            }
+            if value.last().is_some_and(|c| !is_separator(c)) {
+                value.push(PATH_LIST_SEPARATOR);
+            }
+            value.extend_from_slice(encoded);
+            value.push(PATH_LIST_SEPARATOR);
+        }
+
+        if value.len() == original_len {
+            return Ok(());
+        }
+        let value = PdCString::from_vec(value).expect("path list does not contain nul characters");
+        self.set_runtime_property_value(name, value)?;
+        Ok(())
+    }
+}
+
+/// Enum for errors that can occur while appending paths to a path list runtime property,
+/// see [`HostfxrContext::append_trusted_platform_assemblies`].
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+#[non_exhaustive]
+pub enum AppendPathListError {
+    /// The runtime property could not be read or written.
+    #[error(transparent)]
+    Hosting(#[from] HostingError),
+    /// The path contains a nul character or the platform specific path list separator.
+    #[error("The path {0:?} cannot be part of a path list.")]
+    InvalidPath(PathBuf),
+    /// The startup hook is neither an absolute path to an assembly nor a valid assembly name.
+    #[error("The startup hook {0:?} is neither an absolute assembly path nor an assembly name.")]
+    InvalidStartupHook(PathBuf),
+}
+
+/// The value of the `TRUSTED_PLATFORM_ASSEMBLIES` runtime property of a [`HostfxrContext`].
+///
+/// The value is only split into paths while iterating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TrustedPlatformAssemblies<'a> {
+    value: &'a PdCStr,
+}
+
+impl<'a> TrustedPlatformAssemblies<'a> {
+    /// Returns the raw property value.
+    #[must_use]
+    pub const fn as_pdcstr(&self) -> &'a PdCStr {
+        self.value
+    }
+
+    /// Returns an iterator over the paths of the trusted platform assemblies.
+    #[must_use]
+    pub fn iter(&self) -> TrustedPlatformAssembliesIter<'a> {
+        split_path_list(self.value)
+    }
+
+    /// Returns whether an assembly with the given simple name (like `System.Text.Json`) is part of the list.
+    /// The name is compared case-insensitively.
+    #[must_use]
+    pub fn contains_assembly(&self, simple_name: &str) -> bool {
+        self.iter().any(|path| {
+            Path::new(&path)
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| stem.eq_ignore_ascii_case(simple_name))
+        })
+    }
+}
+
+impl<'a> IntoIterator for TrustedPlatformAssemblies<'a> {
+    type Item = PathBuf;
+    type IntoIter = TrustedPlatformAssembliesIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &TrustedPlatformAssemblies<'a> {
+    type Item = PathBuf;
+    type IntoIter = TrustedPlatformAssembliesIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Splits a runtime property value containing a list of paths separated by the platform specific separator.
+pub(crate) fn split_path_list(value: &PdCStr) -> TrustedPlatformAssembliesIter<'_> {
+    TrustedPlatformAssembliesIter {
+        inner: value.as_slice().split(is_separator),
+    }
+}
+
+fn is_separator(c: &PdUChar) -> bool {
+    *c == PATH_LIST_SEPARATOR
+}
+
+/// An iterator over the paths of [`TrustedPlatformAssemblies`].
+#[derive(Debug, Clone)]
+pub struct TrustedPlatformAssembliesIter<'a> {
+    inner: slice::Split<'a, PdUChar, fn(&PdUChar) -> bool>,
+}
+
+impl Iterator for TrustedPlatformAssembliesIter<'_> {
+    type Item = PathBuf;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // the list usually ends with a separator.
+        self.inner
+            .by_ref()
+            .find(|segment| !segment.is_empty())
+            .map(|segment| PathBuf::from(slice_to_os_string(segment)))
+    }
+}
+
+impl FusedIterator for TrustedPlatformAssembliesIter<'_> {}
+
+#[cfg(windows)]
+fn slice_to_os_string(slice: &[PdUChar]) -> OsString {
+    <OsString as std::os::windows::ffi::OsStringExt>::from_wide(slice)
+}
+
+#[cfg(not(windows))]
+fn slice_to_os_string(slice: &[PdUChar]) -> OsString {
+    <std::ffi::OsStr as std::os::unix::ffi::OsStrExt>::from_bytes(slice).to_owned()
+}
@@ -0,0 +1,67 @@
+use crate::{
+    bindings::char_t,
+    hostfxr::{AssemblyDelegateLoader, GetManagedFunctionError},
+    pdcstr,
+    pdcstring::PdCStr,
+};
+
+impl AssemblyDelegateLoader {
+    /// Runs the static constructor of the type with the given assembly qualified name if it has not run yet,
+    /// so that expensive initialization happens at a controlled moment instead of on the first call into the type.
+    ///
+    /// The constructor is run by the `NativeInitializer` class with the given assembly qualified type name using
+    /// `RuntimeHelpers.RunClassConstructor`, see [`MANAGED_INITIALIZER_HELPERS`].
+    /// If the static constructor throws, the `HRESULT` of the resulting `TypeInitializationException` is reported as
+    /// [`GetManagedFunctionError::Other`].
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use netcorehost::{hostfxr::AssemblyDelegateLoader, pdcstr};
+    /// # fn test(loader: AssemblyDelegateLoader) {
+    /// loader
+    ///     .ensure_initialized(
+    ///         pdcstr!("NativeInitializer, Game"),
+    ///         pdcstr!("Game.Assets, Game"),
+    ///     )
+    ///     .unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`MANAGED_INITIALIZER_HELPERS`]: super::MANAGED_INITIALIZER_HELPERS
+    pub fn ensure_initialized(
+        &self,
+        helper_type_name: &PdCStr,
+        type_name: &PdCStr,
+    ) -> Result<(), GetManagedFunctionError> {
+        self.run_initializer(helper_type_name, pdcstr!("RunClassConstructor"), type_name)
+    }
+
+    /// Runs the module initializer of the module defining the type with the given assembly qualified name if it has
+    /// not run yet, using `RuntimeHelpers.RunModuleConstructor`.
+    ///
+    /// Like [`ensure_initialized`](AssemblyDelegateLoader::ensure_initialized), this requires the `NativeInitializer`
+    /// class from [`MANAGED_INITIALIZER_HELPERS`]. Note that the module initializer of the module defining the helper
+    /// class itself always runs before the helper is called.
+    ///
+    /// [`MANAGED_INITIALIZER_HELPERS`]: super::MANAGED_INITIALIZER_HELPERS
+    pub fn ensure_module_initialized(
+        &self,
+        helper_type_name: &PdCStr,
+        type_name: &PdCStr,
+    ) -> Result<(), GetManagedFunctionError> {
+        self.run_initializer(helper_type_name, pdcstr!("RunModuleConstructor"), type_name)
+    }
+
+    fn run_initializer(
+        &self,
+        helper_type_name: &PdCStr,
+        helper_method_name: &PdCStr,
+        type_name: &PdCStr,
+    ) -> Result<(), GetManagedFunctionError> {
+        let run = self.get_function_with_unmanaged_callers_only::<fn(*const char_t) -> i32>(
+            helper_type_name,
+            helper_method_name,
+        )?;
+        GetManagedFunctionError::from_status_code(run(type_name.as_ptr())).map(|_| ())
+    }
+}
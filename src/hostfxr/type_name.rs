@@ -0,0 +1,125 @@
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::pdcstring::{ContainsNul, PdCString};
+
+/// A builder for assembly qualified type names, as expected by the [`DelegateLoader`](crate::hostfxr::DelegateLoader).
+///
+/// # Example
+/// ```rust
+/// # use netcorehost::hostfxr::TypeName;
+/// let name = TypeName::new("MyApp.Handlers.Handler")
+///     .nested("Inner")
+///     .assembly("MyApp");
+/// assert_eq!(name.to_string(), "MyApp.Handlers.Handler+Inner, MyApp");
+///
+/// let name = TypeName::new("System.Collections.Generic.List")
+///     .generic_arg(TypeName::new("System.Int32").assembly("System.Private.CoreLib"))
+///     .assembly("System.Private.CoreLib");
+/// assert_eq!(
+///     name.to_string(),
+///     "System.Collections.Generic.List`1[[System.Int32, System.Private.CoreLib]], System.Private.CoreLib"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub struct TypeName {
+    full_name: String,
+    nested: Vec<String>,
+    generic_args: Vec<TypeName>,
+    assembly: Option<String>,
+}
+
+impl TypeName {
+    /// Creates a new type name from the full name (including the namespace) of a top-level type.
+    #[must_use]
+    pub fn new(full_name: impl Into<String>) -> Self {
+        Self {
+            full_name: full_name.into(),
+            nested: Vec::new(),
+            generic_args: Vec::new(),
+            assembly: None,
+        }
+    }
+
+    /// Refers to the nested type with the given name inside of the current type.
+    #[must_use]
+    pub fn nested(mut self, name: impl Into<String>) -> Self {
+        self.nested.push(name.into());
+        self
+    }
+
+    /// Adds a generic argument to the innermost type.
+    /// The generic arity suffix (like `` `1 ``) is added automatically.
+    #[must_use]
+    pub fn generic_arg(mut self, arg: TypeName) -> Self {
+        self.generic_args.push(arg);
+        self
+    }
+
+    /// Adds multiple generic arguments to the innermost type.
+    #[must_use]
+    pub fn generic_args(mut self, args: impl IntoIterator<Item = TypeName>) -> Self {
+        self.generic_args.extend(args);
+        self
+    }
+
+    /// Sets the name of the assembly containing the type.
+    #[must_use]
+    pub fn assembly(mut self, assembly: impl Into<String>) -> Self {
+        self.assembly = Some(assembly.into());
+        self
+    }
+
+    /// Returns whether the type name is assembly qualified.
+    #[must_use]
+    pub fn is_assembly_qualified(&self) -> bool {
+        self.assembly.is_some()
+    }
+
+    /// Renders the type name as a [`PdCString`].
+    pub fn to_pdcstring(&self) -> Result<PdCString, ContainsNul> {
+        PdCString::from_str(&self.to_string())
+    }
+}
+
+impl Display for TypeName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.full_name)?;
+        for nested in &self.nested {
+            write!(f, "+{nested}")?;
+        }
+
+        if !self.generic_args.is_empty() {
+            write!(f, "`{}[", self.generic_args.len())?;
+            for (i, arg) in self.generic_args.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(",")?;
+                }
+                // assembly qualified arguments have to be enclosed in brackets to separate the assembly name from the next argument.
+                if arg.is_assembly_qualified() {
+                    write!(f, "[{arg}]")?;
+                } else {
+                    write!(f, "{arg}")?;
+                }
+            }
+            f.write_str("]")?;
+        }
+
+        if let Some(assembly) = &self.assembly {
+            write!(f, ", {assembly}")?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<&TypeName> for PdCString {
+    type Error = ContainsNul;
+
+    fn try_from(name: &TypeName) -> Result<Self, Self::Error> {
+        name.to_pdcstring()
+    }
+}
@@ -0,0 +1,98 @@
+use std::error::Error;
+
+use crate::{
+    hostfxr::{
+        AssemblyDelegateLoader, DelegateSignature, GetManagedFunctionError, HostfxrContext,
+        InitializedForRuntimeConfig, ManagedFunction,
+    },
+    pdcstring::PdCStr,
+};
+
+/// A static managed method annotated with [`UnmanagedCallersOnly`](https://docs.microsoft.com/en-us/dotnet/api/system.runtime.interopservices.unmanagedcallersonlyattribute),
+/// identified both by its type and method name and by the name it is exported under.
+///
+/// Which of the names is used depends on the [`UnmanagedFunctionLoader`]: the hosting components look up the method by
+/// its type and method name, while a `NativeAOT` compiled library only contains the export named by the `EntryPoint`
+/// property of the attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+pub struct UnmanagedExport<'a> {
+    /// Assembly qualified name of the type containing the method.
+    pub type_name: &'a PdCStr,
+    /// Name of the method.
+    pub method_name: &'a PdCStr,
+    /// Name of the native export, as specified by `UnmanagedCallersOnly.EntryPoint`.
+    pub entry_point: &'a str,
+}
+
+impl<'a> UnmanagedExport<'a> {
+    /// Creates a new export from the given type name, method name and entry point.
+    #[must_use]
+    pub const fn new(type_name: &'a PdCStr, method_name: &'a PdCStr, entry_point: &'a str) -> Self {
+        Self {
+            type_name,
+            method_name,
+            entry_point,
+        }
+    }
+}
+
+/// A source of [`UnmanagedCallersOnly`](https://docs.microsoft.com/en-us/dotnet/api/system.runtime.interopservices.unmanagedcallersonlyattribute)
+/// function pointers, allowing hosts to support components loaded through the hosting components and `NativeAOT`
+/// compiled libraries using the same code.
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+pub trait UnmanagedFunctionLoader {
+    /// The error returned if a function could not be loaded.
+    type Error: Error;
+
+    /// Returns a function pointer to the given export.
+    /// `F` has to match the signature of the method.
+    fn get_unmanaged_function<F: DelegateSignature>(
+        &self,
+        export: &UnmanagedExport<'_>,
+    ) -> Result<ManagedFunction<F::Managed>, Self::Error>;
+}
+
+impl UnmanagedFunctionLoader for AssemblyDelegateLoader {
+    type Error = GetManagedFunctionError;
+
+    fn get_unmanaged_function<F: DelegateSignature>(
+        &self,
+        export: &UnmanagedExport<'_>,
+    ) -> Result<ManagedFunction<F::Managed>, Self::Error> {
+        self.get_function_with_unmanaged_callers_only::<F>(export.type_name, export.method_name)
+    }
+}
+
+/// A managed library loaded through one of the supported backends, allowing frameworks like plugin systems
+/// to be generic over how the managed code is executed.
+///
+/// Function pointers are loaded using the [`UnmanagedFunctionLoader`] supertrait.
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+pub trait ManagedLibrary: UnmanagedFunctionLoader + Sized {
+    /// The host required to open a library, like an initialized [`HostfxrContext`].
+    type Host: ?Sized;
+
+    /// Opens the library with the given path using the given host.
+    fn open(host: &Self::Host, path: &PdCStr) -> Result<Self, Self::Error>;
+
+    /// Closes the library.
+    /// Function pointers loaded from it must not be used afterwards.
+    ///
+    /// The default implementation drops the library.
+    fn close(self) -> Result<(), Self::Error> {
+        drop(self);
+        Ok(())
+    }
+}
+
+impl ManagedLibrary for AssemblyDelegateLoader {
+    type Host = HostfxrContext<InitializedForRuntimeConfig>;
+
+    /// Creates a loader for the assembly with the given path, which is loaded lazily when the first function pointer is loaded.
+    ///
+    /// Closing the loader does not unload the assembly, as the hosting components do not support unloading.
+    fn open(host: &Self::Host, path: &PdCStr) -> Result<Self, Self::Error> {
+        Ok(host.get_delegate_loader_for_assembly(path.to_owned())?)
+    }
+}
@@ -0,0 +1,256 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+    hostfxr::{AssemblyDelegateLoader, DelegateTypeSpec, GetManagedFunctionError},
+    pdcstring::{PdCStr, PdCString},
+};
+
+#[cfg(feature = "net5_0")]
+use crate::{bindings::char_t, pdcstr};
+
+/// A managed method to resolve using [`AssemblyDelegateLoader::warmup`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub struct WarmupMethod<'a> {
+    type_name: &'a PdCStr,
+    method_name: &'a PdCStr,
+    delegate_type: DelegateTypeSpec<'a>,
+}
+
+impl<'a> WarmupMethod<'a> {
+    /// Creates a new method with the given assembly qualified type name and method name and the default signature.
+    #[must_use]
+    pub const fn new(type_name: &'a PdCStr, method_name: &'a PdCStr) -> Self {
+        Self {
+            type_name,
+            method_name,
+            delegate_type: DelegateTypeSpec::Default,
+        }
+    }
+
+    /// Sets the signature of the method, see [`DelegateTypeSpec`].
+    #[must_use]
+    pub const fn delegate_type(mut self, delegate_type: DelegateTypeSpec<'a>) -> Self {
+        self.delegate_type = delegate_type;
+        self
+    }
+}
+
+impl<'a> From<(&'a PdCStr, &'a PdCStr)> for WarmupMethod<'a> {
+    fn from((type_name, method_name): (&'a PdCStr, &'a PdCStr)) -> Self {
+        Self::new(type_name, method_name)
+    }
+}
+
+/// The result of resolving a single [`WarmupMethod`].
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub struct WarmupEntry {
+    type_name: PdCString,
+    method_name: PdCString,
+    duration: Duration,
+    prepare_duration: Option<Duration>,
+    error: Option<GetManagedFunctionError>,
+}
+
+impl WarmupEntry {
+    /// Returns the assembly qualified name of the type containing the method.
+    #[must_use]
+    pub fn type_name(&self) -> &PdCStr {
+        &self.type_name
+    }
+
+    /// Returns the name of the method.
+    #[must_use]
+    pub fn method_name(&self) -> &PdCStr {
+        &self.method_name
+    }
+
+    /// Returns the time it took to resolve the method, including the time it took to compile it if it was prepared.
+    #[must_use]
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the time it took to compile the method if it was prepared using
+    /// [`AssemblyDelegateLoader::warmup_and_prepare`].
+    #[must_use]
+    pub const fn prepare_duration(&self) -> Option<Duration> {
+        self.prepare_duration
+    }
+
+    /// Returns the error that occured while resolving the method, if any.
+    #[must_use]
+    pub const fn error(&self) -> Option<&GetManagedFunctionError> {
+        self.error.as_ref()
+    }
+
+    /// Returns whether the method was resolved successfully.
+    #[must_use]
+    pub const fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// A report of the methods resolved by [`AssemblyDelegateLoader::warmup`], in the order they were given.
+#[derive(Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub struct WarmupReport {
+    entries: Vec<WarmupEntry>,
+}
+
+impl WarmupReport {
+    /// Returns the entries of all resolved methods.
+    #[must_use]
+    pub fn entries(&self) -> &[WarmupEntry] {
+        &self.entries
+    }
+
+    /// Returns an iterator over the entries of all methods that could not be resolved.
+    pub fn failures(&self) -> impl Iterator<Item = &WarmupEntry> {
+        self.entries.iter().filter(|entry| !entry.is_ok())
+    }
+
+    /// Returns whether all methods were resolved successfully.
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.entries.iter().all(WarmupEntry::is_ok)
+    }
+
+    /// Returns the total time spent resolving methods.
+    #[must_use]
+    pub fn total_duration(&self) -> Duration {
+        self.entries.iter().map(WarmupEntry::duration).sum()
+    }
+}
+
+impl AssemblyDelegateLoader {
+    /// Resolves the given methods ahead of time and reports how long each one took.
+    ///
+    /// The first lookup of a method pays for loading its assembly and type and for creating the native entry point,
+    /// which latency sensitive hosts may want to move to load time. Resolving continues after a method fails,
+    /// the error is recorded in the corresponding [`WarmupEntry`].
+    ///
+    /// # Note
+    /// The methods are not invoked, so methods that were not precompiled (for example using `ReadyToRun`)
+    /// are still compiled by the JIT on their first call. Use
+    /// [`warmup_and_prepare`](AssemblyDelegateLoader::warmup_and_prepare) to compile them as well.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use netcorehost::{hostfxr::AssemblyDelegateLoader, pdcstr};
+    /// # fn test(loader: AssemblyDelegateLoader) {
+    /// let report = loader.warmup([
+    ///     (pdcstr!("Game.Audio, Game"), pdcstr!("Mix")),
+    ///     (pdcstr!("Game.Physics, Game"), pdcstr!("Step")),
+    /// ]);
+    /// for entry in report.entries() {
+    ///     println!("{}: {:?}", entry.method_name().display(), entry.duration());
+    /// }
+    /// # }
+    /// ```
+    pub fn warmup<'a>(
+        &self,
+        methods: impl IntoIterator<Item = impl Into<WarmupMethod<'a>>>,
+    ) -> WarmupReport {
+        warmup_with(methods, |type_name, method_name, delegate_type| {
+            // the function is never called, so the signature does not matter.
+            self.get_function_with_delegate_type::<fn()>(type_name, method_name, delegate_type)
+                .map(|_| ())
+        })
+    }
+
+    /// Like [`warmup`](AssemblyDelegateLoader::warmup), but also compiles each method that was resolved using
+    /// `RuntimeHelpers.PrepareMethod`, so that its first call does not have to wait for the JIT.
+    ///
+    /// The methods are compiled by the `NativeWarmup` class with the given assembly qualified type name, see
+    /// [`MANAGED_WARMUP_HELPERS`]. All static methods with the name of a [`WarmupMethod`] are compiled, regardless
+    /// of its delegate type. The time it took is reported by [`WarmupEntry::prepare_duration`].
+    ///
+    /// Returns an error if the `PrepareMethod` helper could not be resolved.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use netcorehost::{hostfxr::AssemblyDelegateLoader, pdcstr};
+    /// # fn test(loader: AssemblyDelegateLoader) {
+    /// let report = loader
+    ///     .warmup_and_prepare(
+    ///         pdcstr!("NativeWarmup, Game"),
+    ///         [(pdcstr!("Game.Audio, Game"), pdcstr!("Mix"))],
+    ///     )
+    ///     .unwrap();
+    /// for entry in report.entries() {
+    ///     println!("{}: {:?}", entry.method_name().display(), entry.prepare_duration());
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`MANAGED_WARMUP_HELPERS`]: super::MANAGED_WARMUP_HELPERS
+    #[cfg(feature = "net5_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+    pub fn warmup_and_prepare<'a>(
+        &self,
+        helper_type_name: &PdCStr,
+        methods: impl IntoIterator<Item = impl Into<WarmupMethod<'a>>>,
+    ) -> Result<WarmupReport, GetManagedFunctionError> {
+        let prepare_method = self
+            .get_function_with_unmanaged_callers_only::<fn(*const char_t, *const char_t) -> i32>(
+                helper_type_name,
+                pdcstr!("PrepareMethod"),
+            )?;
+        Ok(warmup_entries(methods, |method| {
+            // the function is never called, so the signature does not matter.
+            self.get_function_with_delegate_type::<fn()>(
+                method.type_name,
+                method.method_name,
+                method.delegate_type,
+            )?;
+            let start = Instant::now();
+            let result = prepare_method(method.type_name.as_ptr(), method.method_name.as_ptr());
+            let duration = start.elapsed();
+            GetManagedFunctionError::from_status_code(result)?;
+            Ok(Some(duration))
+        }))
+    }
+}
+
+pub(crate) fn warmup_with<'a>(
+    methods: impl IntoIterator<Item = impl Into<WarmupMethod<'a>>>,
+    mut resolve: impl FnMut(
+        &PdCStr,
+        &PdCStr,
+        DelegateTypeSpec<'_>,
+    ) -> Result<(), GetManagedFunctionError>,
+) -> WarmupReport {
+    warmup_entries(methods, |method| {
+        resolve(method.type_name, method.method_name, method.delegate_type).map(|()| None)
+    })
+}
+
+// `warm` returns the time it took to prepare the method, if it was prepared.
+fn warmup_entries<'a>(
+    methods: impl IntoIterator<Item = impl Into<WarmupMethod<'a>>>,
+    mut warm: impl FnMut(&WarmupMethod<'a>) -> Result<Option<Duration>, GetManagedFunctionError>,
+) -> WarmupReport {
+    let entries = methods
+        .into_iter()
+        .map(Into::into)
+        .map(|method| {
+            let start = Instant::now();
+            let result = warm(&method);
+            let duration = start.elapsed();
+            let (prepare_duration, error) = match result {
+                Ok(prepare_duration) => (prepare_duration, None),
+                Err(error) => (None, Some(error)),
+            };
+            WarmupEntry {
+                type_name: method.type_name.to_owned(),
+                method_name: method.method_name.to_owned(),
+                duration,
+                prepare_duration,
+                error,
+            }
+        })
+        .collect();
+    WarmupReport { entries }
+}
@@ -1,6 +1,12 @@
 #![cfg_attr(
     feature = "nightly",
-    feature(try_trait_v2, maybe_uninit_uninit_array, maybe_uninit_slice)
+    feature(
+        try_trait_v2,
+        maybe_uninit_uninit_array,
+        maybe_uninit_slice,
+        fn_traits,
+        unboxed_closures
+    )
 )]
 #![cfg_attr(feature = "doc-cfg", feature(doc_cfg))]
 #![warn(clippy::pedantic, clippy::cargo, unsafe_op_in_unsafe_fn, missing_docs)]
@@ -163,6 +169,12 @@
 //! # Features
 //! - `nethost` - Links against nethost and allows for automatic detection of the hostfxr library.
 //! - `download-nethost` - Automatically downloads the latest nethost binary from [NuGet](https://www.nuget.org/packages/Microsoft.NETCore.DotNetHost/).
+//! - `testing` - Enables the [`testing`](crate::testing) module for building managed test fixtures from inline C# sources (requires the .NET SDK).
+//! - `unstable` - Enables the [`unstable`](crate::unstable) module containing experimental subsystems, which may change in minor releases.
+//! - `build-helpers` - Enables the [`build_helpers`](crate::build_helpers) module for compiling C# projects from build scripts (requires the .NET SDK).
+//! - `strict-checks` - Detects misuse of host contexts and delegate loaders at runtime (like using a context after it was closed). Misuse panics in debug builds and prints a warning in release builds.
+//! - `serde` - Enables serializing a [`DiagnosticsSnapshot`](crate::diagnostics::DiagnosticsSnapshot) to JSON.
+//! - `serde-bridge` - Enables calling managed methods with requests and responses exchanged as JSON using [`AssemblyDelegateLoader::get_json_function`](crate::hostfxr::AssemblyDelegateLoader::get_json_function).
 //!
 //! [`UnmanagedCallersOnly`]: <https://docs.microsoft.com/en-us/dotnet/api/system.runtime.interopservices.unmanagedcallersonlyattribute>
 //! [`AssemblyDelegateLoader`]: crate::hostfxr::AssemblyDelegateLoader
@@ -180,6 +192,40 @@ pub mod hostfxr;
 #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "nethost")))]
 pub mod nethost;
 
+/// Module for collecting diagnostic information about the hosting environment.
+#[cfg(feature = "nethost")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "nethost")))]
+pub mod diagnostics;
+
+/// Module for checking whether .NET can be hosted without starting a runtime.
+#[cfg(all(feature = "nethost", feature = "net6_0"))]
+#[cfg_attr(
+    feature = "doc-cfg",
+    doc(cfg(all(feature = "nethost", feature = "net6_0")))
+)]
+pub mod preflight;
+
+/// Module for loading the runtime and calling into managed code with a single builder.
+#[cfg(all(feature = "nethost", feature = "netcore3_0"))]
+#[cfg_attr(
+    feature = "doc-cfg",
+    doc(cfg(all(feature = "nethost", feature = "netcore3_0")))
+)]
+pub mod host;
+
+/// Module for implementing a custom apphost, the native executable launching a .NET application.
+#[cfg(all(feature = "nethost", feature = "netcore2_1"))]
+#[cfg_attr(
+    feature = "doc-cfg",
+    doc(cfg(all(feature = "nethost", feature = "netcore2_1")))
+)]
+pub mod apphost;
+
+/// Module for loading managed libraries compiled with `NativeAOT`.
+#[cfg(feature = "net5_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "net5_0")))]
+pub mod nativeaot;
+
 /// Module for a platform dependent c-like string type.
 #[allow(missing_docs)]
 pub mod pdcstring;
@@ -187,5 +233,45 @@ pub mod pdcstring;
 /// Module containing error enums.
 pub mod error;
 
+/// Module for scoped modifications of the environment variables read by the hosting components.
+pub mod env;
+
+/// Module for sharing native resources with managed code through revocable handles instead of raw pointers.
+pub mod handle;
+
+/// Module for temporary directories holding generated files.
+pub mod scratch;
+
+/// Module for managing the directories single-file bundles extract their files to.
+pub mod bundle;
+
+/// Module for locating and running the dotnet executable.
+pub mod dotnet_cli;
+
+mod platform;
+
+/// Module for passing strings between Rust and managed code as UTF-16.
+pub mod marshal;
+
+/// Module for building managed test fixtures from inline C# sources.
+#[cfg(feature = "testing")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "testing")))]
+pub mod testing;
+
+/// Module for compiling managed projects from build scripts.
+#[cfg(feature = "build-helpers")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "build-helpers")))]
+pub mod build_helpers;
+
+/// Module re-exporting the subset of the API covered by semver guarantees.
+pub mod stable;
+
+/// Module for experimental subsystems.
+#[cfg(feature = "unstable")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "unstable")))]
+pub mod unstable;
+
 #[doc(hidden)]
 pub use hostfxr_sys::dlopen2;
+
+pub use hostfxr::runtime_started;
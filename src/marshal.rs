@@ -0,0 +1,274 @@
+use std::{ffi::c_void, fmt, ptr::NonNull, slice, string::FromUtf16Error};
+
+/// A null terminated UTF-16 string that can be passed to managed methods taking a `char*` or a `string` marshalled
+/// as `LPWStr`.
+///
+/// The string stays owned by Rust, so the managed method must not free it or keep the pointer beyond the call.
+/// Interior null characters are kept, but end the string for managed code reading it up to the terminator
+/// (like `Marshal.PtrToStringUni(ptr)`). Use [`len`](Utf16String::len) to pass the length explicitly in that case.
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{hostfxr::AssemblyDelegateLoader, marshal::Utf16String, pdcstr};
+/// # fn test(fn_loader: AssemblyDelegateLoader) {
+/// // [UnmanagedCallersOnly]
+/// // public static int CountWords(char* text) => new string(text).Split(' ').Length;
+/// let count_words = fn_loader
+///     .get_function_with_unmanaged_callers_only::<fn(*const u16) -> i32>(
+///         pdcstr!("Plugin.Text, Plugin"),
+///         pdcstr!("CountWords"),
+///     )
+///     .unwrap();
+/// let text = Utf16String::new("hello from rust");
+/// assert_eq!(count_words(text.as_ptr()), 3);
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Utf16String {
+    // always ends with a null terminator.
+    buffer: Vec<u16>,
+}
+
+impl Utf16String {
+    /// Encodes the given string as UTF-16.
+    #[must_use]
+    pub fn new(value: &str) -> Self {
+        Self {
+            buffer: value.encode_utf16().chain([0]).collect(),
+        }
+    }
+
+    /// Returns a pointer to the null terminated string.
+    #[must_use]
+    pub fn as_ptr(&self) -> *const u16 {
+        self.buffer.as_ptr()
+    }
+
+    /// Returns the code units of the string without the null terminator.
+    #[must_use]
+    pub fn as_slice(&self) -> &[u16] {
+        &self.buffer[..self.len()]
+    }
+
+    /// Returns the code units of the string including the null terminator.
+    #[must_use]
+    pub fn as_slice_with_nul(&self) -> &[u16] {
+        &self.buffer
+    }
+
+    /// Returns the length of the string in UTF-16 code units, without the null terminator.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.buffer.len() - 1
+    }
+
+    /// Returns whether the string is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for Utf16String {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl From<&str> for Utf16String {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl fmt::Debug for Utf16String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&String::from_utf16_lossy(self.as_slice()), f)
+    }
+}
+
+/// Reads a null terminated UTF-16 string, like a `char*` or a `string` marshalled as `LPWStr` by managed code.
+///
+/// # Safety
+/// `ptr` has to point to a valid null terminated UTF-16 string.
+pub unsafe fn utf16_from_ptr(ptr: *const u16) -> Result<String, FromUtf16Error> {
+    String::from_utf16(unsafe { utf16_slice(ptr) })
+}
+
+/// Reads a UTF-16 string passed by managed code as a pointer and its length in code units, like the result of
+/// `fixed (char* ptr = value)` and `value.Length`.
+///
+/// # Safety
+/// `ptr` has to point to `length` code units that stay valid and unmodified during the call. It may be null if
+/// `length` is zero.
+///
+/// # Panics
+/// Panics if `length` is negative.
+pub unsafe fn utf16_from_parts(ptr: *const u16, length: i32) -> Result<String, FromUtf16Error> {
+    let length = usize::try_from(length).expect("string length must not be negative");
+    if length == 0 {
+        return Ok(String::new());
+    }
+    String::from_utf16(unsafe { slice::from_raw_parts(ptr, length) })
+}
+
+unsafe fn utf16_slice<'a>(ptr: *const u16) -> &'a [u16] {
+    let mut length = 0;
+    while unsafe { *ptr.add(length) } != 0 {
+        length += 1;
+    }
+    unsafe { slice::from_raw_parts(ptr, length) }
+}
+
+/// The allocator managed code used to allocate memory passed to native code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ManagedAllocator {
+    /// Memory allocated using `Marshal.AllocHGlobal` or `Marshal.StringToHGlobalUni`.
+    ///
+    /// This is `LocalAlloc` on Windows and `malloc` on other platforms.
+    HGlobal,
+    /// Memory allocated using `Marshal.AllocCoTaskMem` or `Marshal.StringToCoTaskMemUni`, which is also used for
+    /// strings returned from delegates with `[return: MarshalAs(UnmanagedType.LPWStr)]`.
+    ///
+    /// This is `CoTaskMemAlloc` on Windows and `malloc` on other platforms.
+    CoTaskMem,
+}
+
+impl ManagedAllocator {
+    /// Frees memory allocated by managed code using this allocator, like `Marshal.FreeHGlobal` and
+    /// `Marshal.FreeCoTaskMem`. Null pointers are ignored.
+    ///
+    /// # Safety
+    /// `ptr` has to be null or allocated using this allocator and must not be used afterwards.
+    pub unsafe fn free(self, ptr: *mut c_void) {
+        if ptr.is_null() {
+            return;
+        }
+        #[cfg(windows)]
+        match self {
+            Self::HGlobal => drop(unsafe { sys::LocalFree(ptr) }),
+            Self::CoTaskMem => unsafe { sys::CoTaskMemFree(ptr) },
+        }
+        #[cfg(not(windows))]
+        unsafe {
+            sys::free(ptr);
+        }
+    }
+}
+
+#[cfg(windows)]
+mod sys {
+    use std::ffi::c_void;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        pub fn LocalFree(mem: *mut c_void) -> *mut c_void;
+    }
+
+    #[link(name = "ole32")]
+    extern "system" {
+        pub fn CoTaskMemFree(ptr: *mut c_void);
+    }
+}
+
+#[cfg(not(windows))]
+mod sys {
+    use std::ffi::c_void;
+
+    extern "C" {
+        pub fn free(ptr: *mut c_void);
+    }
+}
+
+/// A null terminated UTF-16 string allocated by managed code, which is freed using the matching allocator on drop.
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{hostfxr::AssemblyDelegateLoader, marshal::{ManagedAllocator, ManagedUtf16String}, pdcstr};
+/// # fn test(fn_loader: AssemblyDelegateLoader) {
+/// // [UnmanagedCallersOnly]
+/// // public static IntPtr GetVersion() => Marshal.StringToCoTaskMemUni(Plugin.Version);
+/// let get_version = fn_loader
+///     .get_function_with_unmanaged_callers_only::<fn() -> *mut u16>(
+///         pdcstr!("Plugin.Info, Plugin"),
+///         pdcstr!("GetVersion"),
+///     )
+///     .unwrap();
+/// let version = unsafe { ManagedUtf16String::from_raw(get_version(), ManagedAllocator::CoTaskMem) }.unwrap();
+/// println!("{}", version.to_string_lossy());
+/// # }
+/// ```
+pub struct ManagedUtf16String {
+    ptr: NonNull<u16>,
+    length: usize,
+    allocator: ManagedAllocator,
+}
+
+// the string is not shared with managed code.
+unsafe impl Send for ManagedUtf16String {}
+unsafe impl Sync for ManagedUtf16String {}
+
+impl ManagedUtf16String {
+    /// Takes ownership of a null terminated UTF-16 string allocated by managed code using the given allocator.
+    /// Returns [`None`] if `ptr` is null.
+    ///
+    /// # Safety
+    /// `ptr` has to be null or point to a valid null terminated UTF-16 string allocated using `allocator`, which is
+    /// not used or freed by anything else afterwards.
+    #[must_use]
+    pub unsafe fn from_raw(ptr: *mut u16, allocator: ManagedAllocator) -> Option<Self> {
+        let ptr = NonNull::new(ptr)?;
+        let length = unsafe { utf16_slice(ptr.as_ptr()) }.len();
+        Some(Self {
+            ptr,
+            length,
+            allocator,
+        })
+    }
+
+    /// Returns the code units of the string without the null terminator.
+    #[must_use]
+    pub fn as_slice(&self) -> &[u16] {
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.length) }
+    }
+
+    /// Returns the allocator the string is freed with.
+    #[must_use]
+    pub fn allocator(&self) -> ManagedAllocator {
+        self.allocator
+    }
+
+    /// Decodes the string, replacing invalid code units with [`char::REPLACEMENT_CHARACTER`].
+    #[must_use]
+    pub fn to_string_lossy(&self) -> String {
+        String::from_utf16_lossy(self.as_slice())
+    }
+
+    /// Decodes the string and frees it.
+    pub fn into_string(self) -> Result<String, FromUtf16Error> {
+        String::from_utf16(self.as_slice())
+    }
+
+    /// Releases the ownership of the string without freeing it and returns the pointer.
+    #[must_use]
+    pub fn into_raw(self) -> *mut u16 {
+        let ptr = self.ptr.as_ptr();
+        std::mem::forget(self);
+        ptr
+    }
+}
+
+impl Drop for ManagedUtf16String {
+    fn drop(&mut self) {
+        unsafe { self.allocator.free(self.ptr.as_ptr().cast()) };
+    }
+}
+
+impl fmt::Debug for ManagedUtf16String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ManagedUtf16String")
+            .field("value", &self.to_string_lossy())
+            .field("allocator", &self.allocator)
+            .finish()
+    }
+}
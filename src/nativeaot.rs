@@ -0,0 +1,83 @@
+use std::{
+    fmt::{self, Debug},
+    path::Path,
+};
+
+use crate::{
+    dlopen2::raw::Library,
+    hostfxr::{
+        DelegateSignature, ManagedFunction, ManagedLibrary, RawFunctionPtr, UnmanagedExport,
+        UnmanagedFunctionLoader,
+    },
+    pdcstring::PdCStr,
+};
+
+/// A managed library compiled with `NativeAOT`, which exports its [`UnmanagedCallersOnly`] methods as plain native symbols.
+///
+/// These libraries contain their own runtime, so they are loaded like any other native library without
+/// initializing the hosting components.
+///
+/// Like the runtime loaded by hostfxr, the library is never unloaded, as the runtime it contains cannot be shut down.
+/// Its handle is leaked, so functions loaded from it stay valid until the process exits.
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::{hostfxr::{UnmanagedExport, UnmanagedFunctionLoader}, nativeaot::NativeAotLibrary, pdcstr};
+/// let library = NativeAotLibrary::load("MyPlugin.so").unwrap();
+/// let export = UnmanagedExport::new(pdcstr!("MyPlugin.Exports, MyPlugin"), pdcstr!("Add"), "add");
+/// let add = library
+///     .get_unmanaged_function::<fn(i32, i32) -> i32>(&export)
+///     .unwrap();
+/// assert_eq!(add(1, 2), 3);
+/// ```
+///
+/// [`UnmanagedCallersOnly`]: https://docs.microsoft.com/en-us/dotnet/api/system.runtime.interopservices.unmanagedcallersonlyattribute
+#[derive(Clone, Copy)]
+pub struct NativeAotLibrary {
+    lib: &'static Library,
+}
+
+impl Debug for NativeAotLibrary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NativeAotLibrary").finish_non_exhaustive()
+    }
+}
+
+impl NativeAotLibrary {
+    /// Loads the `NativeAOT` compiled library from the given path.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, crate::dlopen2::Error> {
+        let lib = Library::open(path.as_ref())?;
+        Ok(Self {
+            lib: Box::leak(Box::new(lib)),
+        })
+    }
+
+    /// Returns a function pointer to the native export with the given name.
+    /// `F` has to match the signature of the exported method.
+    pub fn get_function<F: DelegateSignature>(
+        &self,
+        entry_point: &str,
+    ) -> Result<ManagedFunction<F::Managed>, crate::dlopen2::Error> {
+        let function = unsafe { self.lib.symbol::<RawFunctionPtr>(entry_point) }?;
+        Ok(ManagedFunction(unsafe { F::Managed::from_ptr(function) }))
+    }
+}
+
+impl UnmanagedFunctionLoader for NativeAotLibrary {
+    type Error = crate::dlopen2::Error;
+
+    fn get_unmanaged_function<F: DelegateSignature>(
+        &self,
+        export: &UnmanagedExport<'_>,
+    ) -> Result<ManagedFunction<F::Managed>, Self::Error> {
+        self.get_function::<F>(export.entry_point)
+    }
+}
+
+impl ManagedLibrary for NativeAotLibrary {
+    type Host = ();
+
+    fn open(_host: &Self::Host, path: &PdCStr) -> Result<Self, Self::Error> {
+        Self::load(path.to_os_string())
+    }
+}
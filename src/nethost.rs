@@ -1,7 +1,7 @@
 use crate::{
     bindings::{nethost::get_hostfxr_parameters, MAX_PATH},
     error::{HostingError, HostingResult, HostingSuccess},
-    hostfxr::Hostfxr,
+    hostfxr::{Hostfxr, HostfxrLoadOptions},
     pdcstring::{self, PdCStr, PdUChar},
 };
 use std::{ffi::OsString, mem::MaybeUninit, ptr};
@@ -79,6 +79,15 @@ pub fn load_hostfxr() -> Result<Hostfxr, LoadHostfxrError> {
     Ok(hostfxr)
 }
 
+/// Retrieves the path to the hostfxr library and loads it using the given options.
+pub fn load_hostfxr_with_options(
+    options: &HostfxrLoadOptions,
+) -> Result<Hostfxr, LoadHostfxrError> {
+    let hostfxr_path = options.apply_library_name(get_hostfxr_path()?);
+    let hostfxr = Hostfxr::load_from_path_with_options(hostfxr_path, options)?;
+    Ok(hostfxr)
+}
+
 /// Retrieves the path to the hostfxr library and loads it.
 /// Hostfxr is located as if the `assembly_path` is the apphost.
 pub fn load_hostfxr_with_assembly_path<P: AsRef<PdCStr>>(
@@ -89,6 +98,18 @@ pub fn load_hostfxr_with_assembly_path<P: AsRef<PdCStr>>(
     Ok(hostfxr)
 }
 
+/// Retrieves the path to the hostfxr library and loads it using the given options.
+/// Hostfxr is located as if the `assembly_path` is the apphost.
+pub fn load_hostfxr_with_assembly_path_with_options<P: AsRef<PdCStr>>(
+    assembly_path: P,
+    options: &HostfxrLoadOptions,
+) -> Result<Hostfxr, LoadHostfxrError> {
+    let hostfxr_path =
+        options.apply_library_name(get_hostfxr_path_with_assembly_path(assembly_path)?);
+    let hostfxr = Hostfxr::load_from_path_with_options(hostfxr_path, options)?;
+    Ok(hostfxr)
+}
+
 /// Retrieves the path to the hostfxr library and loads it.
 /// Hostfxr is located as if an application is started using `dotnet app.dll`, which means it will be
 /// searched for under the `dotnet_root` path.
@@ -100,8 +121,23 @@ pub fn load_hostfxr_with_dotnet_root<P: AsRef<PdCStr>>(
     Ok(hostfxr)
 }
 
+/// Retrieves the path to the hostfxr library and loads it using the given options.
+/// Hostfxr is located as if an application is started using `dotnet app.dll`, which means it will be
+/// searched for under the `dotnet_root` path.
+pub fn load_hostfxr_with_dotnet_root_with_options<P: AsRef<PdCStr>>(
+    dotnet_root: P,
+    options: &HostfxrLoadOptions,
+) -> Result<Hostfxr, LoadHostfxrError> {
+    let hostfxr_path = options.apply_library_name(get_hostfxr_path_with_dotnet_root(dotnet_root)?);
+    let hostfxr = Hostfxr::load_from_path_with_options(hostfxr_path, options)?;
+    Ok(hostfxr)
+}
+
 /// Enum for errors that can occur while locating and loading the hostfxr library.
+///
+/// New variants may be added in minor releases, so matches on this enum have to include a wildcard arm.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum LoadHostfxrError {
     /// An error occured inside the hosting components.
     #[error(transparent)]
@@ -111,6 +147,17 @@ pub enum LoadHostfxrError {
     DlOpen(#[from] crate::dlopen2::Error),
 }
 
+impl LoadHostfxrError {
+    /// Returns the error from the hosting components if this is a [`LoadHostfxrError::Hosting`] error.
+    #[must_use]
+    pub const fn hosting_error(&self) -> Option<HostingError> {
+        match self {
+            Self::Hosting(error) => Some(*error),
+            Self::DlOpen(_) => None,
+        }
+    }
+}
+
 const unsafe fn maybe_uninit_slice_assume_init_ref<T>(slice: &[MaybeUninit<T>]) -> &[T] {
     #[cfg(feature = "nightly")]
     unsafe {
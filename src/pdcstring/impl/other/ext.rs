@@ -1,13 +1,27 @@
-use std::ffi::{CStr, CString};
+use std::{
+    ffi::{CStr, CString, OsString},
+    os::unix::ffi::OsStringExt,
+};
 
-use crate::pdcstring::{PdCStr, PdCString};
+use crate::pdcstring::{ContainsNul, PdCStr, PdCString};
 
+/// Conversions between [`PdCString`] and the owned string types of the standard library.
+///
+/// All conversions take ownership and reuse the existing buffer, so they do not copy the string data.
 pub trait PdCStringExt
 where
     Self: Sized,
 {
+    /// Converts a [`CString`] into a [`PdCString`]. This never allocates.
     fn from_c_string(s: CString) -> Self;
+    /// Converts this string into a [`CString`]. This never allocates.
     fn into_c_string(self) -> CString;
+    /// Converts an [`OsString`] into a [`PdCString`], checking for interior nul values.
+    ///
+    /// The buffer of the [`OsString`] is reused, it is only reallocated if it has no spare capacity for the nul terminator.
+    fn from_os_string(s: OsString) -> Result<Self, ContainsNul>;
+    /// Converts this string into an [`OsString`] without the nul terminator. This never allocates.
+    fn into_os_string(self) -> OsString;
 }
 
 impl PdCStringExt for PdCString {
@@ -18,10 +32,21 @@ impl PdCStringExt for PdCString {
     fn into_c_string(self) -> CString {
         self.into_inner()
     }
+
+    fn from_os_string(s: OsString) -> Result<Self, ContainsNul> {
+        Self::from_vec(s.into_vec())
+    }
+
+    fn into_os_string(self) -> OsString {
+        OsString::from_vec(self.into_vec())
+    }
 }
 
+/// Conversions between [`PdCStr`] and [`CStr`].
 pub trait PdCStrExt {
+    /// Converts a [`CStr`] into a [`PdCStr`] without copying.
     fn from_c_str(s: &CStr) -> &Self;
+    /// Returns this string as a [`CStr`] without copying.
     fn as_c_str(&self) -> &CStr;
 }
 
@@ -1,6 +1,9 @@
 use std::{
+    borrow::Cow,
     ffi::{CStr, OsStr, OsString},
+    fmt::{self, Formatter, Write},
     os::unix::prelude::OsStrExt,
+    str,
 };
 
 use crate::pdcstring::{MissingNulTerminator, PdCStrInner, PdChar, PdUChar, ToStringError};
@@ -35,6 +38,14 @@ impl PdCStrInner for CStr {
         OsStr::from_bytes(CStr::to_bytes(self)).to_owned()
     }
 
+    fn to_os_str_cow(&self) -> Cow<'_, OsStr> {
+        Cow::Borrowed(OsStr::from_bytes(CStr::to_bytes(self)))
+    }
+
+    fn from_os_str_with_nul(s: &OsStr) -> Option<&Self> {
+        CStr::from_bytes_with_nul(s.as_bytes()).ok()
+    }
+
     fn from_slice_with_nul(slice: &[PdUChar]) -> Result<&Self, MissingNulTerminator> {
         CStr::from_bytes_with_nul(slice).map_err(MissingNulTerminator)
     }
@@ -61,7 +72,41 @@ impl PdCStrInner for CStr {
             .map_err(ToStringError)
     }
 
+    fn to_string_cow(&self) -> Result<Cow<'_, str>, ToStringError> {
+        CStr::to_str(self).map(Cow::Borrowed).map_err(ToStringError)
+    }
+
     fn to_string_lossy(&self) -> String {
         CStr::to_string_lossy(self).to_string()
     }
+
+    fn fmt_lossy(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let bytes = CStr::to_bytes(self);
+        if let Ok(s) = str::from_utf8(bytes) {
+            return f.pad(s);
+        }
+        for chunk in bytes.utf8_chunks() {
+            f.write_str(chunk.valid())?;
+            if !chunk.invalid().is_empty() {
+                f.write_char(char::REPLACEMENT_CHARACTER)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn eq_str(&self, other: &str) -> bool {
+        CStr::to_bytes(self) == other.as_bytes()
+    }
+
+    fn eq_os_str(&self, other: &OsStr) -> bool {
+        CStr::to_bytes(self) == other.as_bytes()
+    }
+
+    fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        CStr::to_bytes(self).eq_ignore_ascii_case(other.as_bytes())
+    }
+
+    fn starts_with_str(&self, prefix: &str) -> bool {
+        CStr::to_bytes(self).starts_with(prefix.as_bytes())
+    }
 }
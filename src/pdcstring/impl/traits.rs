@@ -1,7 +1,8 @@
 use std::{
+    borrow::Cow,
     error::Error,
     ffi::{OsStr, OsString},
-    fmt::{Debug, Display},
+    fmt::{self, Debug, Display, Formatter},
 };
 
 use crate::pdcstring::{ContainsNul, MissingNulTerminator, PdChar, PdUChar, ToStringError};
@@ -23,13 +24,21 @@ pub(crate) trait PdCStrInner {
     unsafe fn from_str_ptr<'a>(ptr: *const PdChar) -> &'a Self;
     unsafe fn from_slice_with_nul_unchecked(slice: &[PdUChar]) -> &Self;
     fn to_os_string(&self) -> OsString;
+    fn to_os_str_cow(&self) -> Cow<'_, OsStr>;
+    fn from_os_str_with_nul(s: &OsStr) -> Option<&Self>;
     fn from_slice_with_nul(slice: &[PdUChar]) -> Result<&Self, MissingNulTerminator>;
     fn as_slice(&self) -> &[PdUChar];
     fn as_slice_with_nul(&self) -> &[PdUChar];
     fn is_empty(&self) -> bool;
     fn len(&self) -> usize;
     fn to_string(&self) -> Result<String, ToStringError>;
+    fn to_string_cow(&self) -> Result<Cow<'_, str>, ToStringError>;
     fn to_string_lossy(&self) -> String;
+    fn fmt_lossy(&self, f: &mut Formatter<'_>) -> fmt::Result;
+    fn eq_str(&self, other: &str) -> bool;
+    fn eq_os_str(&self, other: &OsStr) -> bool;
+    fn eq_ignore_ascii_case(&self, other: &str) -> bool;
+    fn starts_with_str(&self, prefix: &str) -> bool;
 }
 
 pub(crate) trait ToStringErrorInner: Debug + Display + Error + Clone {
@@ -1,13 +1,24 @@
-use widestring::{U16CStr, U16CString};
+use widestring::{U16CStr, U16CString, U16String};
 
-use crate::pdcstring::{PdCStr, PdCString};
+use crate::pdcstring::{ContainsNul, PdCStr, PdCString};
 
+/// Conversions between [`PdCString`] and the owned string types of [`widestring`].
+///
+/// All conversions take ownership and reuse the existing buffer, so they do not copy the string data.
 pub trait PdCStringExt
 where
     Self: Sized,
 {
+    /// Converts a [`U16CString`] into a [`PdCString`]. This never allocates.
     fn from_u16_c_string(s: U16CString) -> Self;
+    /// Converts this string into a [`U16CString`]. This never allocates.
     fn into_u16_c_string(self) -> U16CString;
+    /// Converts a [`U16String`] into a [`PdCString`], checking for interior nul values.
+    ///
+    /// The buffer of the [`U16String`] is reused, it is only reallocated if it has no spare capacity for the nul terminator.
+    fn from_u16_string(s: U16String) -> Result<Self, ContainsNul>;
+    /// Converts this string into a [`U16String`] without the nul terminator. This never allocates.
+    fn into_u16_string(self) -> U16String;
 }
 
 impl PdCStringExt for PdCString {
@@ -18,10 +29,21 @@ impl PdCStringExt for PdCString {
     fn into_u16_c_string(self) -> U16CString {
         self.into_inner()
     }
+
+    fn from_u16_string(s: U16String) -> Result<Self, ContainsNul> {
+        Self::from_vec(s.into_vec())
+    }
+
+    fn into_u16_string(self) -> U16String {
+        U16String::from_vec(self.into_vec())
+    }
 }
 
+/// Conversions between [`PdCStr`] and [`U16CStr`].
 pub trait PdCStrExt {
+    /// Converts a [`U16CStr`] into a [`PdCStr`] without copying.
     fn from_u16_c_str(s: &U16CStr) -> &Self;
+    /// Returns this string as a [`U16CStr`] without copying.
     fn as_u16_c_str(&self) -> &U16CStr;
 }
 
@@ -1,4 +1,9 @@
-use std::ffi::OsString;
+use std::{
+    borrow::Cow,
+    ffi::{OsStr, OsString},
+    fmt::{self, Formatter, Write},
+    os::windows::ffi::OsStrExt,
+};
 
 use widestring::U16CStr;
 
@@ -31,6 +36,15 @@ impl PdCStrInner for U16CStr {
         U16CStr::to_os_string(self)
     }
 
+    fn to_os_str_cow(&self) -> Cow<'_, OsStr> {
+        Cow::Owned(U16CStr::to_os_string(self))
+    }
+
+    fn from_os_str_with_nul(_s: &OsStr) -> Option<&Self> {
+        // OsStr is not stored as UTF-16, so it can never be borrowed.
+        None
+    }
+
     fn from_slice_with_nul(slice: &[PdChar]) -> Result<&Self, MissingNulTerminator> {
         U16CStr::from_slice_truncate(slice).map_err(MissingNulTerminator)
     }
@@ -55,7 +69,61 @@ impl PdCStrInner for U16CStr {
         U16CStr::to_string(self).map_err(ToStringError)
     }
 
+    fn to_string_cow(&self) -> Result<Cow<'_, str>, ToStringError> {
+        // UTF-16 data can never be borrowed as a str.
+        U16CStr::to_string(self)
+            .map(Cow::Owned)
+            .map_err(ToStringError)
+    }
+
     fn to_string_lossy(&self) -> String {
         U16CStr::to_string_lossy(self)
     }
+
+    fn fmt_lossy(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Ok(s) = U16CStr::to_string(self) {
+            return f.pad(&s);
+        }
+        for c in char::decode_utf16(U16CStr::as_slice(self).iter().copied()) {
+            f.write_char(c.unwrap_or(char::REPLACEMENT_CHARACTER))?;
+        }
+        Ok(())
+    }
+
+    fn eq_str(&self, other: &str) -> bool {
+        U16CStr::as_slice(self)
+            .iter()
+            .copied()
+            .eq(other.encode_utf16())
+    }
+
+    fn eq_os_str(&self, other: &OsStr) -> bool {
+        U16CStr::as_slice(self)
+            .iter()
+            .copied()
+            .eq(other.encode_wide())
+    }
+
+    fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        fn to_ascii_lowercase(c: u16) -> u16 {
+            if (u16::from(b'A')..=u16::from(b'Z')).contains(&c) {
+                c + u16::from(b'a' - b'A')
+            } else {
+                c
+            }
+        }
+
+        U16CStr::as_slice(self)
+            .iter()
+            .copied()
+            .map(to_ascii_lowercase)
+            .eq(other.encode_utf16().map(to_ascii_lowercase))
+    }
+
+    fn starts_with_str(&self, prefix: &str) -> bool {
+        let mut chars = U16CStr::as_slice(self).iter().copied();
+        prefix
+            .encode_utf16()
+            .all(|expected| chars.next() == Some(expected))
+    }
 }
@@ -0,0 +1,77 @@
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{Arc, Mutex, OnceLock, PoisonError},
+};
+
+use crate::pdcstring::{ContainsNul, PdCString};
+
+/// A pool of interned [`PdCString`]s, for identifiers (like type and method names) that are repeatedly converted
+/// from [`str`].
+///
+/// Each distinct string is only converted once, later calls with the same string return the shared instance without
+/// allocating. Interned strings are kept until the interner is dropped or [`clear`](PdCStringInterner::clear)ed.
+/// For string literals, prefer [`pdcstr!`](crate::pdcstr), which performs the conversion at compile time.
+///
+/// # Example
+/// ```rust
+/// # use netcorehost::pdcstring::PdCStringInterner;
+/// let interner = PdCStringInterner::new();
+/// let first = interner.intern("MyPlugin.Exports, MyPlugin").unwrap();
+/// let second = interner.intern("MyPlugin.Exports, MyPlugin").unwrap();
+/// assert!(std::sync::Arc::ptr_eq(&first, &second));
+/// ```
+#[derive(Debug, Default)]
+pub struct PdCStringInterner {
+    strings: Mutex<HashMap<Box<str>, Arc<PdCString>>>,
+}
+
+impl PdCStringInterner {
+    /// Creates a new empty interner.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the process-wide interner.
+    #[must_use]
+    pub fn global() -> &'static Self {
+        static GLOBAL: OnceLock<PdCStringInterner> = OnceLock::new();
+        GLOBAL.get_or_init(Self::new)
+    }
+
+    /// Returns the interned [`PdCString`] for the given string, converting it if it was not interned before.
+    pub fn intern(&self, s: &str) -> Result<Arc<PdCString>, ContainsNul> {
+        let mut strings = self.strings.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(interned) = strings.get(s) {
+            return Ok(Arc::clone(interned));
+        }
+
+        let interned = Arc::new(PdCString::from_str(s)?);
+        strings.insert(s.into(), Arc::clone(&interned));
+        Ok(interned)
+    }
+
+    /// Returns the number of interned strings.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.strings
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Returns whether no strings are interned.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all interned strings. Previously returned strings stay valid.
+    pub fn clear(&self) {
+        self.strings
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
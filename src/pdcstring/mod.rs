@@ -15,3 +15,6 @@ pub use r#impl::*;
 
 mod shared;
 pub use shared::*;
+
+mod interner;
+pub use interner::*;
@@ -1,5 +1,5 @@
 use std::{
-    borrow::Borrow,
+    borrow::{Borrow, Cow},
     convert::TryFrom,
     ffi::{OsStr, OsString},
     fmt::{self, Debug, Display, Formatter},
@@ -32,6 +32,20 @@ impl PdCString {
     pub fn from_os_str(s: impl AsRef<OsStr>) -> Result<Self, ContainsNul> {
         PdCStringInner::from_os_str(s).map(Self::from_inner)
     }
+    /// Converts an [`OsStr`] to a [`PdCStr`], borrowing the input if it is already a valid nul-terminated
+    /// platform-dependent string and copying it otherwise.
+    ///
+    /// On Unix the input is only borrowed if it ends with its only nul value, which is rarely the case for strings
+    /// obtained from the standard library, so most inputs are copied like by [`PdCString::from_os_str`].
+    /// On Windows the input always has to be reencoded, so this always allocates and a trailing nul is
+    /// rejected like any other nul value.
+    #[inline]
+    pub fn from_os_str_cow(s: &OsStr) -> Result<Cow<'_, PdCStr>, ContainsNul> {
+        match <PdCStrInnerImpl as PdCStrInner>::from_os_str_with_nul(s) {
+            Some(inner) => Ok(Cow::Borrowed(PdCStr::from_inner(inner))),
+            None => Self::from_os_str(s).map(Cow::Owned),
+        }
+    }
     /// Constructs a new [`PdCString`] copied from a nul-terminated string pointer.
     #[inline]
     #[must_use]
@@ -105,6 +119,14 @@ impl PdCStr {
     pub fn to_os_string(&self) -> OsString {
         PdCStrInner::to_os_string(self.as_inner())
     }
+    /// Converts the string to an [`OsStr`], borrowing the string data if possible.
+    ///
+    /// This never allocates on Unix and always allocates on Windows.
+    #[inline]
+    #[must_use]
+    pub fn to_os_str_cow(&self) -> Cow<'_, OsStr> {
+        PdCStrInner::to_os_str_cow(self.as_inner())
+    }
     /// Converts this string to a slice of the underlying elements.
     /// The slice will **not** include the nul terminator.
     #[inline]
@@ -135,6 +157,13 @@ impl PdCStr {
     pub fn to_string(&self) -> Result<String, ToStringError> {
         PdCStrInner::to_string(self.as_inner())
     }
+    /// Converts the string to a [`str`] if it contains valid encoded data, borrowing the string data if possible.
+    ///
+    /// This never allocates on Unix and always allocates on Windows.
+    #[inline]
+    pub fn to_string_cow(&self) -> Result<Cow<'_, str>, ToStringError> {
+        PdCStrInner::to_string_cow(self.as_inner())
+    }
     /// Decodes the string to a [`String`] even if it contains invalid data.
     /// Any invalid sequences are replaced with U+FFFD REPLACEMENT CHARACTER, which looks like this: �. It will *not have a nul terminator.
     #[inline]
@@ -142,6 +171,40 @@ impl PdCStr {
     pub fn to_string_lossy(&self) -> String {
         PdCStrInner::to_string_lossy(self.as_inner())
     }
+    /// Returns an object that implements [`Display`] for printing the string, like [`Path::display`](std::path::Path::display).
+    /// The string is only converted while formatting, replacing invalid sequences with U+FFFD REPLACEMENT CHARACTER,
+    /// so unlike [`to_string`](PdCStr::to_string) this never fails and unlike [`to_string_lossy`](PdCStr::to_string_lossy)
+    /// no intermediate [`String`] has to be created.
+    #[inline]
+    #[must_use]
+    pub fn display(&self) -> PdCStrDisplay<'_> {
+        PdCStrDisplay(self)
+    }
+    /// Decodes the string to an UTF-8 [`String`] like [`to_string_lossy`](PdCStr::to_string_lossy), but additionally reports
+    /// whether any invalid data had to be replaced with U+FFFD REPLACEMENT CHARACTER.
+    /// Replacement characters that were already present in the original string are not reported.
+    #[inline]
+    #[must_use]
+    pub fn to_string_utf8_lossy_with_report(&self) -> (String, bool) {
+        match self.to_string_cow() {
+            Ok(s) => (s.into_owned(), false),
+            Err(_) => (self.to_string_lossy(), true),
+        }
+    }
+    /// Checks that this string is an ASCII case-insensitive match for the given [`str`].
+    /// The comparison is performed without allocating.
+    #[inline]
+    #[must_use]
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        PdCStrInner::eq_ignore_ascii_case(self.as_inner(), other)
+    }
+    /// Returns whether the given [`str`] is a prefix of this string.
+    /// The comparison is performed without allocating.
+    #[inline]
+    #[must_use]
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        PdCStrInner::starts_with_str(self.as_inner(), prefix)
+    }
 }
 
 impl Borrow<PdCStr> for PdCString {
@@ -166,7 +229,23 @@ impl Deref for PdCString {
 
 impl Display for PdCStr {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
+        Display::fmt(&self.display(), f)
+    }
+}
+
+/// Helper struct for printing a [`PdCStr`] with [`format!`] and `{}`, created by [`PdCStr::display`].
+#[derive(Clone, Copy)]
+pub struct PdCStrDisplay<'a>(&'a PdCStr);
+
+impl Debug for PdCStrDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.0, f)
+    }
+}
+
+impl Display for PdCStrDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        PdCStrInner::fmt_lossy(self.0.as_inner(), f)
     }
 }
 
@@ -220,6 +299,48 @@ impl From<PdCString> for Vec<PdUChar> {
     }
 }
 
+impl PartialEq<str> for PdCStr {
+    fn eq(&self, other: &str) -> bool {
+        PdCStrInner::eq_str(self.as_inner(), other)
+    }
+}
+
+impl PartialEq<PdCStr> for str {
+    fn eq(&self, other: &PdCStr) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<OsStr> for PdCStr {
+    fn eq(&self, other: &OsStr) -> bool {
+        PdCStrInner::eq_os_str(self.as_inner(), other)
+    }
+}
+
+impl PartialEq<PdCStr> for OsStr {
+    fn eq(&self, other: &PdCStr) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<str> for PdCString {
+    fn eq(&self, other: &str) -> bool {
+        **self == *other
+    }
+}
+
+impl PartialEq<&str> for PdCString {
+    fn eq(&self, other: &&str) -> bool {
+        **self == **other
+    }
+}
+
+impl PartialEq<OsStr> for PdCString {
+    fn eq(&self, other: &OsStr) -> bool {
+        **self == *other
+    }
+}
+
 impl AsRef<PdCStr> for PdCStr {
     fn as_ref(&self) -> &Self {
         self
@@ -0,0 +1,63 @@
+/// Returns the architecture name used by the hosting components in runtime identifiers, environment variables and
+/// install locations (like `x64`), or [`None`] if .NET does not support the current architecture.
+pub(crate) const fn architecture() -> Option<&'static str> {
+    if cfg!(target_arch = "x86_64") {
+        Some("x64")
+    } else if cfg!(target_arch = "x86") {
+        Some("x86")
+    } else if cfg!(target_arch = "aarch64") {
+        Some("arm64")
+    } else if cfg!(target_arch = "arm") {
+        Some("arm")
+    } else if cfg!(target_arch = "s390x") {
+        Some("s390x")
+    } else if cfg!(all(target_arch = "powerpc64", target_endian = "little")) {
+        Some("ppc64le")
+    } else if cfg!(target_arch = "loongarch64") {
+        Some("loongarch64")
+    } else if cfg!(target_arch = "riscv64") {
+        Some("riscv64")
+    } else if cfg!(target_arch = "wasm32") {
+        Some("wasm")
+    } else {
+        None
+    }
+}
+
+/// Returns the name of the current operating system used in runtime identifiers (like `linux-musl`), or [`None`] if
+/// .NET does not support the current operating system.
+const fn operating_system() -> Option<&'static str> {
+    if cfg!(windows) {
+        Some("win")
+    } else if cfg!(target_os = "macos") {
+        Some("osx")
+    } else if cfg!(target_os = "ios") {
+        Some("ios")
+    } else if cfg!(target_os = "tvos") {
+        Some("tvos")
+    } else if cfg!(target_os = "android") {
+        Some("android")
+    } else if cfg!(all(target_os = "linux", target_env = "musl")) {
+        Some("linux-musl")
+    } else if cfg!(target_os = "linux") {
+        Some("linux")
+    } else if cfg!(target_os = "freebsd") {
+        Some("freebsd")
+    } else if cfg!(target_os = "illumos") {
+        Some("illumos")
+    } else if cfg!(target_os = "solaris") {
+        Some("solaris")
+    } else if cfg!(target_os = "wasi") {
+        Some("wasi")
+    } else if cfg!(all(target_arch = "wasm32", target_os = "unknown")) {
+        Some("browser")
+    } else {
+        None
+    }
+}
+
+/// Returns the runtime identifier of the current process (like `linux-x64`), or [`None`] if .NET does not support
+/// the current platform.
+pub(crate) fn runtime_identifier() -> Option<String> {
+    Some(format!("{}-{}", operating_system()?, architecture()?))
+}
@@ -0,0 +1,347 @@
+use std::{
+    env::consts::ARCH,
+    fmt::{self, Display},
+    path::PathBuf,
+};
+
+use crate::{
+    hostfxr::{EnvironmentInfo, Hostfxr, Version},
+    nethost,
+};
+
+/// Name of the shared framework containing the base class library.
+pub const NETCORE_APP_FRAMEWORK: &str = "Microsoft.NETCore.App";
+
+/// The requirements checked by [`check`].
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::preflight::{self, Requirements};
+/// let report = preflight::check(
+///     &Requirements::new()
+///         .target_framework("net8.0")
+///         .framework("Microsoft.AspNetCore.App", "8.0.0")
+///         .assembly("MyApp.dll"),
+/// );
+/// for check in report.failures() {
+///     eprintln!("{check}");
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Requirements {
+    frameworks: Vec<(String, String)>,
+    target_frameworks: Vec<String>,
+    assemblies: Vec<PathBuf>,
+}
+
+impl Requirements {
+    /// Creates a new set of requirements, which only requires hostfxr to be loadable.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires a framework with the given name and at least the given version with the same major version to be installed.
+    #[must_use]
+    pub fn framework(mut self, name: impl Into<String>, min_version: impl Into<String>) -> Self {
+        self.frameworks.push((name.into(), min_version.into()));
+        self
+    }
+
+    /// Requires a runtime to be installed that can run assemblies targeting the given target framework moniker
+    /// (like `net8.0` or `netcoreapp3.1`).
+    #[must_use]
+    pub fn target_framework(mut self, tfm: impl Into<String>) -> Self {
+        self.target_frameworks.push(tfm.into());
+        self
+    }
+
+    /// Requires the assembly at the given path to exist.
+    #[must_use]
+    pub fn assembly(mut self, path: impl Into<PathBuf>) -> Self {
+        self.assemblies.push(path.into());
+        self
+    }
+}
+
+/// The kind of a [`PreflightCheck`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PreflightCheckKind {
+    /// hostfxr could be located using nethost.
+    HostfxrLocated,
+    /// hostfxr could be loaded into the current process, which fails if its architecture does not match.
+    HostfxrLoaded,
+    /// The installed SDKs and frameworks could be enumerated.
+    EnvironmentInfo,
+    /// A required framework is installed.
+    Framework {
+        /// The name of the framework.
+        name: String,
+        /// The minimum required version.
+        version: String,
+    },
+    /// The target framework moniker is supported by an installed runtime.
+    TargetFramework(String),
+    /// An assembly exists.
+    Assembly(PathBuf),
+}
+
+impl Display for PreflightCheckKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HostfxrLocated => write!(f, "hostfxr is locatable"),
+            Self::HostfxrLoaded => write!(f, "hostfxr is loadable for {ARCH}"),
+            Self::EnvironmentInfo => write!(f, "installed frameworks can be enumerated"),
+            Self::Framework { name, version } => {
+                write!(f, "framework {name} {version} is installed")
+            }
+            Self::TargetFramework(tfm) => write!(f, "target framework {tfm} is supported"),
+            Self::Assembly(path) => write!(f, "assembly {} exists", path.display()),
+        }
+    }
+}
+
+/// The outcome of a [`PreflightCheck`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PreflightOutcome {
+    /// The requirement is met, with details like the path or version that satisfied it.
+    Passed(String),
+    /// The requirement is not met, with a description of the problem.
+    Failed(String),
+    /// The requirement could not be checked because an earlier check failed.
+    Skipped,
+}
+
+/// A single check of a [`PreflightReport`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PreflightCheck {
+    /// What was checked.
+    pub kind: PreflightCheckKind,
+    /// The outcome of the check.
+    pub outcome: PreflightOutcome,
+}
+
+impl PreflightCheck {
+    /// Returns whether the check passed.
+    #[must_use]
+    pub const fn passed(&self) -> bool {
+        matches!(self.outcome, PreflightOutcome::Passed(_))
+    }
+}
+
+impl Display for PreflightCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.outcome {
+            PreflightOutcome::Passed(details) => write!(f, "[ok] {} ({details})", self.kind),
+            PreflightOutcome::Failed(reason) => write!(f, "[failed] {}: {reason}", self.kind),
+            PreflightOutcome::Skipped => write!(f, "[skipped] {}", self.kind),
+        }
+    }
+}
+
+/// The result of [`check`], containing one [`PreflightCheck`] per requirement in the order they were checked.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PreflightReport {
+    checks: Vec<PreflightCheck>,
+}
+
+impl PreflightReport {
+    /// Returns all performed checks.
+    #[must_use]
+    pub fn checks(&self) -> &[PreflightCheck] {
+        &self.checks
+    }
+
+    /// Returns an iterator over all checks that did not pass, including skipped ones.
+    pub fn failures(&self) -> impl Iterator<Item = &PreflightCheck> {
+        self.checks.iter().filter(|check| !check.passed())
+    }
+
+    /// Returns whether all checks passed, which means that hosting is expected to succeed.
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.checks.iter().all(PreflightCheck::passed)
+    }
+
+    fn push(&mut self, kind: PreflightCheckKind, outcome: PreflightOutcome) {
+        self.checks.push(PreflightCheck { kind, outcome });
+    }
+}
+
+/// Checks whether the given requirements for hosting .NET are met, without starting a runtime.
+///
+/// This locates and loads hostfxr using nethost and enumerates the installed frameworks,
+/// which is the same information `dotnet --info` reports.
+#[must_use]
+pub fn check(requirements: &Requirements) -> PreflightReport {
+    let mut report = PreflightReport::default();
+
+    for path in &requirements.assemblies {
+        let outcome = if path.is_file() {
+            PreflightOutcome::Passed(path.display().to_string())
+        } else {
+            PreflightOutcome::Failed("file not found".to_string())
+        };
+        report.push(PreflightCheckKind::Assembly(path.clone()), outcome);
+    }
+
+    let environment = check_environment(&mut report);
+
+    for (name, version) in &requirements.frameworks {
+        let outcome = environment
+            .as_ref()
+            .map_or(PreflightOutcome::Skipped, |environment| {
+                framework_outcome(environment, name, version)
+            });
+        report.push(
+            PreflightCheckKind::Framework {
+                name: name.clone(),
+                version: version.clone(),
+            },
+            outcome,
+        );
+    }
+
+    for tfm in &requirements.target_frameworks {
+        let outcome = match (environment.as_ref(), framework_version_of_tfm(tfm)) {
+            (_, None) => PreflightOutcome::Failed(format!(
+                "{tfm} is not a target framework moniker of .NET Core or .NET 5+"
+            )),
+            (None, Some(_)) => PreflightOutcome::Skipped,
+            (Some(environment), Some(version)) => {
+                framework_outcome(environment, NETCORE_APP_FRAMEWORK, &version)
+            }
+        };
+        report.push(PreflightCheckKind::TargetFramework(tfm.clone()), outcome);
+    }
+
+    report
+}
+
+fn check_environment(report: &mut PreflightReport) -> Option<EnvironmentInfo> {
+    let hostfxr_path = match nethost::get_hostfxr_path() {
+        Ok(path) => {
+            report.push(
+                PreflightCheckKind::HostfxrLocated,
+                PreflightOutcome::Passed(PathBuf::from(&path).display().to_string()),
+            );
+            path
+        }
+        Err(err) => {
+            report.push(
+                PreflightCheckKind::HostfxrLocated,
+                PreflightOutcome::Failed(err.to_string()),
+            );
+            report.push(PreflightCheckKind::HostfxrLoaded, PreflightOutcome::Skipped);
+            report.push(
+                PreflightCheckKind::EnvironmentInfo,
+                PreflightOutcome::Skipped,
+            );
+            return None;
+        }
+    };
+
+    let hostfxr = match Hostfxr::load_from_path(&hostfxr_path) {
+        Ok(hostfxr) => {
+            report.push(
+                PreflightCheckKind::HostfxrLoaded,
+                PreflightOutcome::Passed(ARCH.to_string()),
+            );
+            hostfxr
+        }
+        Err(err) => {
+            report.push(
+                PreflightCheckKind::HostfxrLoaded,
+                PreflightOutcome::Failed(err.to_string()),
+            );
+            report.push(
+                PreflightCheckKind::EnvironmentInfo,
+                PreflightOutcome::Skipped,
+            );
+            return None;
+        }
+    };
+
+    match hostfxr.get_dotnet_environment_info() {
+        Ok(environment) => {
+            report.push(
+                PreflightCheckKind::EnvironmentInfo,
+                PreflightOutcome::Passed(format!("hostfxr {}", environment.hostfxr_version)),
+            );
+            Some(environment)
+        }
+        Err(err) => {
+            report.push(
+                PreflightCheckKind::EnvironmentInfo,
+                PreflightOutcome::Failed(err.to_string()),
+            );
+            None
+        }
+    }
+}
+
+fn framework_outcome(
+    environment: &EnvironmentInfo,
+    name: &str,
+    min_version: &str,
+) -> PreflightOutcome {
+    let Some(required) = Version::parse(min_version) else {
+        return PreflightOutcome::Failed(format!("{min_version} is not a valid version"));
+    };
+
+    // the default roll forward policy allows newer minor and patch versions of the same major version.
+    let best = environment
+        .frameworks
+        .iter()
+        .filter(|framework| framework.name.eq_ignore_ascii_case(name))
+        .filter_map(|framework| {
+            Version::parse(&framework.version).map(|version| (version, framework))
+        })
+        .filter(|(version, _)| version.major == required.major && *version >= required)
+        .max_by(|(a, _), (b, _)| a.cmp(b));
+
+    match best {
+        Some((_, framework)) => PreflightOutcome::Passed(format!(
+            "{} at {}",
+            framework.version,
+            framework.path.display()
+        )),
+        None => {
+            let installed = environment
+                .frameworks
+                .iter()
+                .filter(|framework| framework.name.eq_ignore_ascii_case(name))
+                .map(|framework| framework.version.as_str())
+                .collect::<Vec<_>>();
+            if installed.is_empty() {
+                PreflightOutcome::Failed("not installed".to_string())
+            } else {
+                PreflightOutcome::Failed(format!(
+                    "no compatible version installed, found {}",
+                    installed.join(", ")
+                ))
+            }
+        }
+    }
+}
+
+/// Returns the minimum version of `Microsoft.NETCore.App` required by the given target framework moniker.
+fn framework_version_of_tfm(tfm: &str) -> Option<String> {
+    let tfm = tfm.to_ascii_lowercase();
+    // platform specific monikers like net8.0-windows use the same runtime.
+    let tfm = tfm.split('-').next()?;
+    let version = tfm.strip_prefix("netcoreapp").or_else(|| {
+        tfm.strip_prefix("net")
+            .filter(|version| version.contains('.'))
+    })?;
+    let (major, minor) = version.split_once('.')?;
+    let major = major.parse::<u64>().ok()?;
+    let minor = minor.parse::<u64>().ok()?;
+    // monikers like net4.8 refer to the .NET Framework, which cannot be hosted using hostfxr.
+    if tfm.starts_with("netcoreapp") || major >= 5 {
+        Some(format!("{major}.{minor}.0"))
+    } else {
+        None
+    }
+}
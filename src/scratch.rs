@@ -0,0 +1,176 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, SystemTime},
+};
+
+/// Scratch directories of other processes that have not been modified for this long are assumed to be
+/// left over from a crashed process and are removed, if it cannot be determined whether the process is still running.
+pub const ORPHAN_TIMEOUT: Duration = Duration::from_secs(24 * 60 * 60);
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A uniquely named temporary directory for generated files, which is removed on drop.
+///
+/// Directories are named after the current process id, so directories left over by processes that exited
+/// abnormally can be identified. These are removed when a new scratch directory is created in the same root
+/// and the process that created them is no longer running. On platforms where this cannot be determined, they are
+/// removed once they have not been modified for [`ORPHAN_TIMEOUT`].
+#[derive(Debug)]
+pub struct ScratchDir {
+    path: PathBuf,
+    keep: bool,
+}
+
+impl ScratchDir {
+    /// Returns the default root for scratch directories, which is `netcorehost` inside the temp directory.
+    #[must_use]
+    pub fn default_root() -> PathBuf {
+        env::temp_dir().join("netcorehost")
+    }
+
+    /// Creates a new scratch directory inside [`default_root`](Self::default_root).
+    pub fn new() -> io::Result<Self> {
+        Self::new_in(Self::default_root())
+    }
+
+    /// Creates a new scratch directory inside the given root directory.
+    pub fn new_in(root: impl AsRef<Path>) -> io::Result<Self> {
+        let root = root.as_ref();
+        fs::create_dir_all(root)?;
+        sweep_orphans(root);
+
+        loop {
+            let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+            let path = root.join(format!("{}-{id}", process::id()));
+            match fs::create_dir(&path) {
+                Ok(()) => return Ok(Self { path, keep: false }),
+                // left over from a previous process with the same id.
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {}
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Returns the path to the directory.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the path to the file with the given name inside the directory.
+    #[must_use]
+    pub fn join(&self, file_name: impl AsRef<Path>) -> PathBuf {
+        self.path.join(file_name)
+    }
+
+    /// Consumes the scratch directory without removing it and returns its path.
+    #[must_use]
+    pub fn keep(mut self) -> PathBuf {
+        self.keep = true;
+        self.path.clone()
+    }
+}
+
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        if !self.keep {
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+}
+
+fn sweep_orphans(root: &Path) {
+    let Ok(entries) = fs::read_dir(root) else {
+        return;
+    };
+    let current_pid = process::id();
+
+    for entry in entries.flatten() {
+        let file_name = entry.file_name();
+        let Some(pid) = file_name
+            .to_str()
+            .and_then(|name| name.split_once('-'))
+            .and_then(|(pid, _)| pid.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        if pid == current_pid {
+            continue;
+        }
+
+        let is_orphaned = match sys::is_process_running(pid) {
+            Some(running) => !running,
+            None => entry
+                .metadata()
+                .and_then(|metadata| metadata.modified())
+                .ok()
+                .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+                .is_some_and(|age| age > ORPHAN_TIMEOUT),
+        };
+        if is_orphaned {
+            let _ = fs::remove_dir_all(entry.path());
+        }
+    }
+}
+
+#[cfg(unix)]
+mod sys {
+    use std::{ffi::c_int, io};
+
+    const ESRCH: c_int = 3;
+
+    extern "C" {
+        fn kill(pid: c_int, signal: c_int) -> c_int;
+    }
+
+    pub fn is_process_running(pid: u32) -> Option<bool> {
+        let pid = c_int::try_from(pid).ok()?;
+        // signal 0 only checks whether the process exists, failing with EPERM if it belongs to another user.
+        if unsafe { kill(pid, 0) } == 0 {
+            return Some(true);
+        }
+        Some(io::Error::last_os_error().raw_os_error() != Some(ESRCH))
+    }
+}
+
+#[cfg(windows)]
+mod sys {
+    use std::{ffi::c_void, io};
+
+    type Handle = *mut c_void;
+
+    const PROCESS_QUERY_LIMITED_INFORMATION: u32 = 0x1000;
+    const ERROR_INVALID_PARAMETER: i32 = 87;
+    const STILL_ACTIVE: u32 = 259;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn OpenProcess(desired_access: u32, inherit_handle: i32, process_id: u32) -> Handle;
+        fn GetExitCodeProcess(process: Handle, exit_code: *mut u32) -> i32;
+        fn CloseHandle(handle: Handle) -> i32;
+    }
+
+    pub fn is_process_running(pid: u32) -> Option<bool> {
+        let process = unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid) };
+        if process.is_null() {
+            // opening a running process of another user fails with ERROR_ACCESS_DENIED.
+            return Some(
+                io::Error::last_os_error().raw_os_error() != Some(ERROR_INVALID_PARAMETER),
+            );
+        }
+        let mut exit_code = 0;
+        let result = unsafe { GetExitCodeProcess(process, &mut exit_code) };
+        unsafe { CloseHandle(process) };
+        (result != 0).then_some(exit_code == STILL_ACTIVE)
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod sys {
+    pub fn is_process_running(_pid: u32) -> Option<bool> {
+        None
+    }
+}
@@ -0,0 +1,41 @@
+//! The items in this module are covered by the semver guarantees of the crate: they are only changed or removed
+//! in major releases. Everything else, especially the items in [`unstable`](crate::unstable), may change in minor releases.
+
+/// Stable abstractions of the hostfxr library.
+pub mod hostfxr {
+    pub use crate::hostfxr::{AppOrHostingResult, Hostfxr};
+
+    #[cfg(feature = "netcore3_0")]
+    #[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+    pub use crate::hostfxr::{
+        AssemblyDelegateLoader, DelegateLoader, FunctionPtr, GetManagedFunctionError,
+        HostfxrContext, HostfxrHandle, InitializedForCommandLine, InitializedForRuntimeConfig,
+        ManagedFunction, ManagedFunctionPtr, ManagedFunctionWithDefaultSignature,
+        ManagedFunctionWithUnknownSignature, RawFunctionPtr,
+    };
+}
+
+/// Stable abstractions of the nethost library.
+#[cfg(feature = "nethost")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "nethost")))]
+pub mod nethost {
+    pub use crate::nethost::{
+        get_hostfxr_path, get_hostfxr_path_with_assembly_path, get_hostfxr_path_with_dotnet_root,
+        load_hostfxr, load_hostfxr_with_assembly_path, load_hostfxr_with_dotnet_root,
+        LoadHostfxrError,
+    };
+}
+
+/// Stable platform dependent c-like string types.
+pub mod pdcstring {
+    pub use crate::pdcstring::{
+        ContainsNul, MissingNulTerminator, PdCStr, PdCStrDisplay, PdCString, ToStringError,
+    };
+}
+
+/// Stable error types.
+pub mod error {
+    pub use crate::error::{Error, HostingError, HostingResult, HostingSuccess};
+}
+
+pub use crate::pdcstr;
@@ -0,0 +1,333 @@
+use std::{
+    env,
+    fs::{self, OpenOptions},
+    io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::pdcstring::PdCString;
+
+/// The target framework used if none is specified.
+pub const DEFAULT_TARGET_FRAMEWORK: &str = "net8.0";
+
+/// Environment variable which can be used to override the default target framework.
+pub const TARGET_FRAMEWORK_ENV_VAR: &str = "NETCOREHOST_TEST_NETCORE_VERSION";
+
+/// Version of the generated project layout, part of the cache key so that changes to the layout invalidate old builds.
+const PROJECT_LAYOUT_VERSION: u32 = 1;
+
+/// Lock files older than this are assumed to belong to a crashed build and are removed.
+const STALE_LOCK_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// Name of the file marking a fixture build as complete.
+const COMPLETE_MARKER: &str = ".complete";
+
+/// A managed test fixture built from inline C# sources.
+///
+/// Builds are cached on disk keyed by a hash of the sources and the target framework, so a fixture is
+/// only built once even if it is used by many tests. Concurrent builds of the same fixture from multiple
+/// threads or processes (like tests run with `rusty-fork`) are serialized using a lock file.
+///
+/// By default, the cache is placed in `netcorehost-fixtures` inside the cargo target directory.
+///
+/// # Example
+/// ```rust,no_run
+/// # use netcorehost::testing::InlineFixture;
+/// let fixture = InlineFixture::new("Greeter")
+///     .source(
+///         "Greeter.cs",
+///         r#"
+///             namespace Greeter;
+///             public static class Program {
+///                 [System.Runtime.InteropServices.UnmanagedCallersOnly]
+///                 public static int Answer() => 42;
+///             }
+///         "#,
+///     )
+///     .build()
+///     .unwrap();
+/// let runtime_config_path = fixture.runtime_config_path_pd();
+/// let assembly_path = fixture.assembly_path_pd();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InlineFixture {
+    name: String,
+    sources: Vec<(String, String)>,
+    target_framework: Option<String>,
+    cache_dir: Option<PathBuf>,
+}
+
+impl InlineFixture {
+    /// Creates a new fixture producing an assembly with the given name.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            sources: Vec::new(),
+            target_framework: None,
+            cache_dir: None,
+        }
+    }
+
+    /// Adds a C# source file with the given file name and contents.
+    #[must_use]
+    pub fn source(mut self, file_name: impl Into<String>, contents: impl Into<String>) -> Self {
+        self.sources.push((file_name.into(), contents.into()));
+        self
+    }
+
+    /// Sets the target framework moniker (like `net8.0`) to build for.
+    ///
+    /// Defaults to the value of the `NETCOREHOST_TEST_NETCORE_VERSION` environment variable or [`DEFAULT_TARGET_FRAMEWORK`].
+    #[must_use]
+    pub fn target_framework(mut self, tfm: impl Into<String>) -> Self {
+        self.target_framework = Some(tfm.into());
+        self
+    }
+
+    /// Sets the directory in which builds are cached.
+    #[must_use]
+    pub fn cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(dir.into());
+        self
+    }
+
+    fn resolved_target_framework(&self) -> String {
+        self.target_framework
+            .clone()
+            .or_else(|| env::var(TARGET_FRAMEWORK_ENV_VAR).ok())
+            .unwrap_or_else(|| DEFAULT_TARGET_FRAMEWORK.to_string())
+    }
+
+    fn resolved_cache_dir(&self) -> PathBuf {
+        if let Some(dir) = &self.cache_dir {
+            return dir.clone();
+        }
+        let target_dir = env::var_os("CARGO_TARGET_DIR").map_or_else(
+            || {
+                env::var_os("CARGO_MANIFEST_DIR")
+                    .map_or_else(|| PathBuf::from("."), PathBuf::from)
+                    .join("target")
+            },
+            PathBuf::from,
+        );
+        target_dir.join("netcorehost-fixtures")
+    }
+
+    /// Returns the cache key of this fixture for the given target framework.
+    fn cache_key(&self, target_framework: &str) -> String {
+        let mut hasher = Fnv1a::new();
+        hasher.write_u32(PROJECT_LAYOUT_VERSION);
+        hasher.write_str(&self.name);
+        hasher.write_str(target_framework);
+        for (file_name, contents) in &self.sources {
+            hasher.write_str(file_name);
+            hasher.write_str(contents);
+        }
+        format!(
+            "{}-{}-{:016x}",
+            self.name,
+            target_framework,
+            hasher.finish()
+        )
+    }
+
+    /// Builds the fixture or returns the cached build if it exists.
+    pub fn build(&self) -> io::Result<BuiltFixture> {
+        let target_framework = self.resolved_target_framework();
+        let cache_dir = self.resolved_cache_dir();
+        let cache_key = self.cache_key(&target_framework);
+        let fixture_dir = cache_dir.join(&cache_key);
+        let fixture = BuiltFixture {
+            output_dir: fixture_dir.join("bin"),
+            name: self.name.clone(),
+        };
+
+        if fixture_dir.join(COMPLETE_MARKER).exists() {
+            return Ok(fixture);
+        }
+
+        fs::create_dir_all(&cache_dir)?;
+        let _lock = FileLock::acquire(cache_dir.join(format!("{cache_key}.lock")))?;
+
+        // another process may have completed the build while we were waiting for the lock.
+        if fixture_dir.join(COMPLETE_MARKER).exists() {
+            return Ok(fixture);
+        }
+
+        if fixture_dir.exists() {
+            fs::remove_dir_all(&fixture_dir)?;
+        }
+        fs::create_dir_all(&fixture_dir)?;
+
+        self.write_project(&fixture_dir, &target_framework)?;
+        run_dotnet_build(&fixture_dir, &fixture.output_dir)?;
+
+        fs::write(fixture_dir.join(COMPLETE_MARKER), [])?;
+        Ok(fixture)
+    }
+
+    fn write_project(&self, dir: &Path, target_framework: &str) -> io::Result<()> {
+        let project = format!(
+            r#"<Project Sdk="Microsoft.NET.Sdk">
+  <PropertyGroup>
+    <TargetFramework>{target_framework}</TargetFramework>
+    <AssemblyName>{name}</AssemblyName>
+    <OutputType>Library</OutputType>
+    <GenerateRuntimeConfigurationFiles>true</GenerateRuntimeConfigurationFiles>
+    <EnableDynamicLoading>true</EnableDynamicLoading>
+    <AllowUnsafeBlocks>true</AllowUnsafeBlocks>
+    <ImplicitUsings>enable</ImplicitUsings>
+  </PropertyGroup>
+</Project>
+"#,
+            name = self.name
+        );
+        fs::write(dir.join(format!("{}.csproj", self.name)), project)?;
+
+        for (file_name, contents) in &self.sources {
+            let path = dir.join(file_name);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, contents)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn run_dotnet_build(project_dir: &Path, output_dir: &Path) -> io::Result<()> {
+    let output = crate::dotnet_cli::command()
+        .arg("build")
+        .arg("--configuration")
+        .arg("Debug")
+        .arg("--output")
+        .arg(output_dir)
+        .current_dir(project_dir)
+        .output()?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!(
+            "dotnet build failed with {}:\n{}{}",
+            output.status,
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        )))
+    }
+}
+
+/// A built [`InlineFixture`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BuiltFixture {
+    output_dir: PathBuf,
+    name: String,
+}
+
+impl BuiltFixture {
+    /// Returns the directory containing the build output.
+    #[must_use]
+    pub fn output_dir(&self) -> &Path {
+        &self.output_dir
+    }
+
+    /// Returns the path to the built assembly.
+    #[must_use]
+    pub fn assembly_path(&self) -> PathBuf {
+        self.output_dir.join(format!("{}.dll", self.name))
+    }
+
+    /// Returns the path to the generated `.runtimeconfig.json`.
+    #[must_use]
+    pub fn runtime_config_path(&self) -> PathBuf {
+        self.output_dir
+            .join(format!("{}.runtimeconfig.json", self.name))
+    }
+
+    /// Returns the path to the built assembly as a [`PdCString`].
+    #[must_use]
+    pub fn assembly_path_pd(&self) -> PdCString {
+        PdCString::from_os_str(self.assembly_path()).unwrap()
+    }
+
+    /// Returns the path to the generated `.runtimeconfig.json` as a [`PdCString`].
+    #[must_use]
+    pub fn runtime_config_path_pd(&self) -> PdCString {
+        PdCString::from_os_str(self.runtime_config_path()).unwrap()
+    }
+}
+
+/// A cross-process lock based on the atomic creation of a lock file.
+struct FileLock {
+    path: PathBuf,
+}
+
+impl FileLock {
+    fn acquire(path: PathBuf) -> io::Result<Self> {
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Ok(Self { path }),
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+                    if Self::is_stale(&path) {
+                        let _ = fs::remove_file(&path);
+                    } else {
+                        thread::sleep(Duration::from_millis(100));
+                    }
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    fn is_stale(path: &Path) -> bool {
+        fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+            .is_some_and(|age| age > STALE_LOCK_TIMEOUT)
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// 64-bit FNV-1a hasher, used instead of [`DefaultHasher`](std::collections::hash_map::DefaultHasher)
+/// as its output has to be stable across compiler versions.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_str(&mut self, value: &str) {
+        // length prefix to avoid collisions between e.g. ("ab", "c") and ("a", "bc").
+        self.write(&(value.len() as u64).to_le_bytes());
+        self.write(value.as_bytes());
+    }
+
+    const fn finish(&self) -> u64 {
+        self.0
+    }
+}
@@ -0,0 +1,10 @@
+//! Experimental subsystems which may change or be removed in minor releases.
+//! They are only available with the `unstable` feature.
+
+pub use crate::hostfxr::resolution_trace::{
+    CandidateStatus, FrameworkCandidate, ResolutionReport, TraceCapture,
+};
+
+#[cfg(feature = "netcore3_0")]
+#[cfg_attr(feature = "doc-cfg", doc(cfg(feature = "netcore3_0")))]
+pub use crate::hostfxr::resolution_trace::TracedHostingError;
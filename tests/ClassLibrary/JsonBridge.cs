@@ -0,0 +1,34 @@
+using System;
+using System.Runtime.InteropServices;
+using System.Text;
+using System.Text.Json;
+
+internal static unsafe class JsonBridge {
+    private static readonly JsonSerializerOptions Options = new JsonSerializerOptions(JsonSerializerDefaults.Web);
+
+    [StructLayout(LayoutKind.Sequential)]
+    private struct Args {
+        public byte* Request;
+        public int RequestLength;
+        public IntPtr Response;
+        public delegate* unmanaged<IntPtr, byte*, int, void> Respond;
+    }
+
+    public static int Invoke<TRequest, TResponse>(IntPtr args, int sizeBytes, Func<TRequest, TResponse> handler) {
+        Args* bridge = (Args*)args;
+        byte[] response;
+        int result;
+        try {
+            var request = JsonSerializer.Deserialize<TRequest>(new ReadOnlySpan<byte>(bridge->Request, bridge->RequestLength), Options);
+            response = JsonSerializer.SerializeToUtf8Bytes(handler(request), Options);
+            result = 0;
+        } catch (Exception e) {
+            response = Encoding.UTF8.GetBytes(e.Message);
+            result = -1;
+        }
+        fixed (byte* ptr = response) {
+            bridge->Respond(bridge->Response, ptr, response.Length);
+        }
+        return result;
+    }
+}
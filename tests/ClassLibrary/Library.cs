@@ -1,5 +1,8 @@
 ﻿using System;
+using System.Linq;
+using System.Reflection;
 using System.Runtime.InteropServices;
+using System.Threading;
 
 namespace ClassLibrary {
     public class Library {
@@ -8,5 +11,153 @@ namespace ClassLibrary {
             Console.WriteLine("Hello from Library!");
             return 42;
         }
+
+        public static int ComponentEntryPoint(IntPtr arg, int argLength) {
+            return argLength;
+        }
+
+        public static int SumBytes(IntPtr arg, int argLength) {
+            int sum = 0;
+            for (int i = 0; i < argLength; i++) {
+                sum += Marshal.ReadByte(arg, i);
+            }
+            return sum;
+        }
+
+        [UnmanagedCallersOnly]
+        public static unsafe IntPtr ReverseUtf16(char* value, int useCoTaskMem) {
+            char[] chars = new string(value).ToCharArray();
+            Array.Reverse(chars);
+            string reversed = new string(chars);
+            return useCoTaskMem != 0 ? Marshal.StringToCoTaskMemUni(reversed) : Marshal.StringToHGlobalUni(reversed);
+        }
+
+        public class JsonPoint {
+            public int X { get; set; }
+            public int Y { get; set; }
+        }
+
+        public static int AddJsonPoints(IntPtr args, int sizeBytes) =>
+            JsonBridge.Invoke(args, sizeBytes, (JsonPoint[] points) =>
+                new JsonPoint { X = points.Sum(p => p.X), Y = points.Sum(p => p.Y) });
+
+        public static int FailJson(IntPtr args, int sizeBytes) =>
+            JsonBridge.Invoke<JsonPoint, JsonPoint>(args, sizeBytes, _ =>
+                throw new InvalidOperationException("invalid point"));
+
+        public delegate int AddDelegate(int a, int b);
+        public static int Add(int a, int b) {
+            return a + b;
+        }
+
+        [UnmanagedCallersOnly(EntryPoint = "add_unmanaged")]
+        public static int AddUnmanaged(int a, int b) {
+            return a + b;
+        }
+
+        public delegate int TryDivideDelegate(int a, int b, out int result);
+        public static int TryDivide(int a, int b, out int result) {
+            if (b == 0) {
+                result = 0;
+                return 0;
+            }
+            result = a / b;
+            return 1;
+        }
+
+        public delegate void IncrementDelegate(ref int value);
+        public static void Increment(ref int value) {
+            value += 1;
+        }
+
+        [UnmanagedCallersOnly]
+        public static unsafe void IncrementUnmanaged(int* value) {
+            *value += 1;
+        }
+
+        [UnmanagedCallersOnly]
+        public static int Greet(IntPtr name, int nameLength, IntPtr buffer, int capacity) {
+            return NativeStrings.ToUtf8Buffer($"Hello {NativeStrings.FromUtf8(name, nameLength)}!", buffer, capacity);
+        }
+
+        [UnmanagedCallersOnly]
+        public static unsafe int Apply(IntPtr callback, int a, int b) {
+            return ((delegate* unmanaged<int, int, int>)callback)(a, b);
+        }
+
+        [UnmanagedCallersOnly]
+        public static unsafe int SendGreeting(IntPtr callback) {
+            byte[] bytes = System.Text.Encoding.UTF8.GetBytes("Hello from .NET");
+            fixed (byte* ptr = bytes) {
+                return ((delegate* unmanaged<byte*, int, int>)callback)(ptr, bytes.Length);
+            }
+        }
+
+        [UnmanagedCallersOnly]
+        public static unsafe int ApplyWithContext(IntPtr callback, IntPtr context, int a, int b) {
+            return ((delegate* unmanaged<IntPtr, int, int, int>)callback)(context, a, b);
+        }
+
+        [UnmanagedCallersOnly]
+        public static unsafe void ReleaseContext(IntPtr release, IntPtr context) {
+            ((delegate* unmanaged<IntPtr, void>)release)(context);
+        }
+
+        public static T Max<T>(T a, T b) where T : IComparable<T> {
+            return a.CompareTo(b) >= 0 ? a : b;
+        }
+
+        public static int CountTypeArguments<T1, T2>() {
+            return 2;
+        }
+
+        public static int Scale(int value) {
+            return value * 2;
+        }
+
+        public static double Scale(double value) {
+            return value / 2;
+        }
+
+        [UnmanagedCallersOnly]
+        public static void DelayAssemblyResolution(int milliseconds) {
+            AppDomain.CurrentDomain.AssemblyResolve += (sender, args) => {
+                if (new AssemblyName(args.Name).Name == "SlowAssembly") {
+                    Thread.Sleep(milliseconds);
+                }
+                return null;
+            };
+        }
+
+        private static ulong storedHandle;
+
+        [UnmanagedCallersOnly]
+        public static void StoreHandle(ulong handle) {
+            storedHandle = handle;
+        }
+
+        [UnmanagedCallersOnly]
+        public static ulong LoadHandle() {
+            return storedHandle;
+        }
+
+        public static int StaticConstructorRuns;
+
+        [UnmanagedCallersOnly]
+        public static int GetStaticConstructorRuns() {
+            return StaticConstructorRuns;
+        }
+    }
+
+    public static class LazyInitialized {
+        static LazyInitialized() {
+            Library.StaticConstructorRuns++;
+        }
+    }
+
+    public static class FailingInitializer {
+        static FailingInitializer() {
+            throw new InvalidOperationException("initialization failed");
+        }
     }
 }
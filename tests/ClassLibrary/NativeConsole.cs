@@ -0,0 +1,88 @@
+using System;
+using System.IO;
+using System.Runtime.InteropServices;
+using System.Text;
+using System.Threading;
+
+public static unsafe class NativeConsole {
+    private static readonly object Gate = new object();
+    private static TextWriter originalOut;
+    private static TextWriter originalError;
+    private static CallbackWriter outWriter;
+    private static CallbackWriter errorWriter;
+
+    [UnmanagedCallersOnly]
+    public static int Install(IntPtr callback, IntPtr state) {
+        lock (Gate) {
+            if (outWriter != null) {
+                return -1;
+            }
+            originalOut = Console.Out;
+            originalError = Console.Error;
+            outWriter = new CallbackWriter(callback, state, 1);
+            errorWriter = new CallbackWriter(callback, state, 2);
+            Console.SetOut(outWriter);
+            Console.SetError(errorWriter);
+            return 0;
+        }
+    }
+
+    [UnmanagedCallersOnly]
+    public static void Uninstall() {
+        lock (Gate) {
+            if (outWriter == null) {
+                return;
+            }
+            Console.SetOut(originalOut);
+            Console.SetError(originalError);
+            outWriter.Flush();
+            errorWriter.Flush();
+            outWriter = null;
+            errorWriter = null;
+        }
+    }
+
+    private sealed class CallbackWriter : TextWriter {
+        private readonly IntPtr callback;
+        private readonly IntPtr state;
+        private readonly int stream;
+        private readonly ThreadLocal<StringBuilder> lines = new ThreadLocal<StringBuilder>(() => new StringBuilder());
+
+        public CallbackWriter(IntPtr callback, IntPtr state, int stream) {
+            this.callback = callback;
+            this.state = state;
+            this.stream = stream;
+        }
+
+        public override Encoding Encoding => Encoding.UTF8;
+
+        public override void Write(char value) {
+            if (value == '\n') {
+                Emit();
+            } else if (value != '\r') {
+                lines.Value.Append(value);
+            }
+        }
+
+        public override void Flush() {
+            if (lines.Value.Length > 0) {
+                Emit();
+            }
+        }
+
+        private void Emit() {
+            StringBuilder line = lines.Value;
+            byte[] bytes = Encoding.UTF8.GetBytes(line.ToString());
+            line.Clear();
+            lock (Gate) {
+                if (outWriter != this && errorWriter != this) {
+                    return;
+                }
+                fixed (byte* ptr = bytes) {
+                    ((delegate* unmanaged<IntPtr, int, int, byte*, int, void>)callback)(
+                        state, stream, Environment.CurrentManagedThreadId, ptr, bytes.Length);
+                }
+            }
+        }
+    }
+}
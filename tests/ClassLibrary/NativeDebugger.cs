@@ -0,0 +1,9 @@
+using System.Diagnostics;
+using System.Runtime.InteropServices;
+
+public static class NativeDebugger {
+    [UnmanagedCallersOnly]
+    public static int IsAttached() {
+        return Debugger.IsAttached ? 1 : 0;
+    }
+}
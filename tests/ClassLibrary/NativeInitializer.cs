@@ -0,0 +1,27 @@
+using System;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+
+public static class NativeInitializer {
+    [UnmanagedCallersOnly]
+    public static int RunClassConstructor(IntPtr typeName) {
+        try {
+            Type type = Type.GetType(Marshal.PtrToStringAuto(typeName), throwOnError: true);
+            RuntimeHelpers.RunClassConstructor(type.TypeHandle);
+            return 0;
+        } catch (Exception e) {
+            return e.HResult;
+        }
+    }
+
+    [UnmanagedCallersOnly]
+    public static int RunModuleConstructor(IntPtr typeName) {
+        try {
+            Type type = Type.GetType(Marshal.PtrToStringAuto(typeName), throwOnError: true);
+            RuntimeHelpers.RunModuleConstructor(type.Module.ModuleHandle);
+            return 0;
+        } catch (Exception e) {
+            return e.HResult;
+        }
+    }
+}
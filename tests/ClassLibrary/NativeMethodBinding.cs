@@ -0,0 +1,87 @@
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Reflection;
+using System.Reflection.Emit;
+using System.Runtime.InteropServices;
+
+public static class NativeMethodBinding {
+    private static readonly ModuleBuilder DelegateTypes = AssemblyBuilder
+        .DefineDynamicAssembly(new AssemblyName("NativeMethodBinding.DelegateTypes"), AssemblyBuilderAccess.Run)
+        .DefineDynamicModule("NativeMethodBinding.DelegateTypes");
+    // the delegates have to be kept alive as long as their function pointers may be called.
+    private static readonly List<Delegate> Delegates = new List<Delegate>();
+
+    [UnmanagedCallersOnly]
+    public static int BindGenericMethod(IntPtr typeName, IntPtr methodName, IntPtr typeArguments, int typeArgumentCount, IntPtr result) {
+        try {
+            Type type = ResolveType(typeName);
+            string name = Marshal.PtrToStringAuto(methodName);
+            Type[] arguments = ResolveTypes(typeArguments, typeArgumentCount);
+            MethodInfo method = type
+                .GetMethods(BindingFlags.Public | BindingFlags.NonPublic | BindingFlags.Static)
+                .SingleOrDefault(m => m.Name == name && m.IsGenericMethodDefinition && m.GetGenericArguments().Length == arguments.Length)
+                ?? throw new MissingMethodException(type.FullName, name);
+            Marshal.WriteIntPtr(result, Bind(method.MakeGenericMethod(arguments)));
+            return 0;
+        } catch (Exception e) {
+            return e.HResult;
+        }
+    }
+
+    [UnmanagedCallersOnly]
+    public static int BindMethod(IntPtr typeName, IntPtr methodName, IntPtr parameterTypes, int parameterTypeCount, IntPtr result) {
+        try {
+            Type type = ResolveType(typeName);
+            string name = Marshal.PtrToStringAuto(methodName);
+            Type[] parameters = ResolveTypes(parameterTypes, parameterTypeCount);
+            MethodInfo method = type.GetMethod(name, BindingFlags.Public | BindingFlags.NonPublic | BindingFlags.Static, null, parameters, null)
+                ?? throw new MissingMethodException(type.FullName, name);
+            Marshal.WriteIntPtr(result, Bind(method));
+            return 0;
+        } catch (Exception e) {
+            return e.HResult;
+        }
+    }
+
+    private static Type ResolveType(IntPtr name) {
+        return Type.GetType(Marshal.PtrToStringAuto(name), throwOnError: true);
+    }
+
+    private static Type[] ResolveTypes(IntPtr names, int count) {
+        Type[] types = new Type[count];
+        for (int i = 0; i < count; i++) {
+            types[i] = ResolveType(Marshal.ReadIntPtr(names, i * IntPtr.Size));
+        }
+        return types;
+    }
+
+    private static IntPtr Bind(MethodInfo method) {
+        Type[] parameterTypes = method.GetParameters().Select(p => p.ParameterType).ToArray();
+        Delegate function;
+        lock (Delegates) {
+            Type delegateType = CreateDelegateType("NativeDelegate" + Delegates.Count, method.ReturnType, parameterTypes);
+            function = Delegate.CreateDelegate(delegateType, method);
+            Delegates.Add(function);
+        }
+        return Marshal.GetFunctionPointerForDelegate(function);
+    }
+
+    private static Type CreateDelegateType(string name, Type returnType, Type[] parameterTypes) {
+        TypeBuilder builder = DelegateTypes.DefineType(name, TypeAttributes.Public | TypeAttributes.Sealed, typeof(MulticastDelegate));
+        builder
+            .DefineConstructor(
+                MethodAttributes.Public | MethodAttributes.HideBySig | MethodAttributes.SpecialName | MethodAttributes.RTSpecialName,
+                CallingConventions.Standard,
+                new[] { typeof(object), typeof(IntPtr) })
+            .SetImplementationFlags(MethodImplAttributes.Runtime | MethodImplAttributes.Managed);
+        builder
+            .DefineMethod(
+                "Invoke",
+                MethodAttributes.Public | MethodAttributes.HideBySig | MethodAttributes.NewSlot | MethodAttributes.Virtual,
+                returnType,
+                parameterTypes)
+            .SetImplementationFlags(MethodImplAttributes.Runtime | MethodImplAttributes.Managed);
+        return builder.CreateType();
+    }
+}
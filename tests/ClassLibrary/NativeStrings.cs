@@ -0,0 +1,17 @@
+using System;
+using System.Runtime.InteropServices;
+using System.Text;
+
+internal static class NativeStrings {
+    public static string FromUtf8(IntPtr ptr, int length) {
+        return length == 0 ? string.Empty : Marshal.PtrToStringUTF8(ptr, length);
+    }
+
+    public static int ToUtf8Buffer(string value, IntPtr buffer, int capacity) {
+        byte[] bytes = Encoding.UTF8.GetBytes(value);
+        if (bytes.Length <= capacity) {
+            Marshal.Copy(bytes, 0, buffer, bytes.Length);
+        }
+        return bytes.Length;
+    }
+}
@@ -0,0 +1,25 @@
+using System;
+using System.Reflection;
+using System.Runtime.CompilerServices;
+using System.Runtime.InteropServices;
+
+public static class NativeWarmup {
+    [UnmanagedCallersOnly]
+    public static int PrepareMethod(IntPtr typeName, IntPtr methodName) {
+        try {
+            Type type = Type.GetType(Marshal.PtrToStringAuto(typeName), throwOnError: true);
+            string name = Marshal.PtrToStringAuto(methodName);
+            BindingFlags flags = BindingFlags.Public | BindingFlags.NonPublic | BindingFlags.Static;
+            bool found = false;
+            foreach (MethodInfo method in type.GetMethods(flags)) {
+                if (method.Name == name && !method.ContainsGenericParameters) {
+                    RuntimeHelpers.PrepareMethod(method.MethodHandle);
+                    found = true;
+                }
+            }
+            return found ? 0 : new MissingMethodException(type.FullName, name).HResult;
+        } catch (Exception e) {
+            return e.HResult;
+        }
+    }
+}
@@ -0,0 +1,15 @@
+using System.Runtime.InteropServices;
+
+namespace NativeAotLibrary {
+    public class Exports {
+        [UnmanagedCallersOnly(EntryPoint = "add_unmanaged")]
+        public static int AddUnmanaged(int a, int b) {
+            return a + b;
+        }
+
+        [UnmanagedCallersOnly(EntryPoint = "get_answer")]
+        public static int GetAnswer() {
+            return 42;
+        }
+    }
+}
@@ -0,0 +1,43 @@
+#![cfg(all(feature = "nethost", feature = "netcore2_1"))]
+
+use netcorehost::apphost::{self, ApphostError, Config, DotnetRootPolicy};
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn run_app() {
+        common::setup();
+
+        let config = Config {
+            app_dll: PathBuf::from(common::test_dll_path().to_os_string()),
+            dotnet_root_policy: DotnetRootPolicy::Default,
+            args_passthrough: false,
+        };
+        assert_eq!(apphost::try_run(&config).unwrap(), 42);
+    }
+
+    #[test]
+    fn app_not_found() {
+        let config = Config::new("AppThatDoesNotExist.dll");
+        let err = apphost::try_run(&config).unwrap_err();
+        assert!(matches!(err, ApphostError::AppNotFound(_)));
+        assert_eq!(apphost::run_with_raw_exit_code(&config), err.exit_code());
+    }
+
+    #[test]
+    fn app_local_without_hostfxr() {
+        common::setup();
+
+        let config = Config {
+            app_dll: PathBuf::from(common::test_dll_path().to_os_string()),
+            dotnet_root_policy: DotnetRootPolicy::AppLocal,
+            args_passthrough: false,
+        };
+        let err = apphost::try_run(&config).unwrap_err();
+        assert!(err.is_missing_runtime());
+    }
+}
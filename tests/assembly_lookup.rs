@@ -0,0 +1,35 @@
+use netcorehost::hostfxr::{find_assembly_in_dir, FindAssemblyError};
+use std::fs;
+
+#[test]
+fn find_assembly_ignoring_case() {
+    let dir = std::env::temp_dir().join(format!(
+        "netcorehost-assembly-lookup-{}",
+        std::process::id()
+    ));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("ClassLibrary.dll"), []).unwrap();
+
+    assert_eq!(
+        find_assembly_in_dir(&dir, "ClassLibrary").unwrap(),
+        dir.join("ClassLibrary.dll")
+    );
+    assert_eq!(
+        find_assembly_in_dir(&dir, "classlibrary")
+            .unwrap()
+            .file_name(),
+        dir.join("ClassLibrary.dll").file_name()
+    );
+    assert_eq!(
+        find_assembly_in_dir(&dir, "CLASSLIBRARY.DLL")
+            .unwrap()
+            .file_name(),
+        dir.join("ClassLibrary.dll").file_name()
+    );
+    assert!(matches!(
+        find_assembly_in_dir(&dir, "Missing"),
+        Err(FindAssemblyError::NotFound { .. })
+    ));
+
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "netcore3_0")]
+
+use netcorehost::{error::HostingError, hostfxr::AttachToRuntimeError, nethost, pdcstr};
+use rusty_fork::rusty_fork_test;
+use std::ptr;
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn attach_to_running_runtime() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        context.get_delegate_loader().unwrap();
+
+        let attached = hostfxr.attach_to_current_runtime().unwrap();
+        assert!(!attached.is_primary());
+
+        let fn_loader = attached
+            .get_delegate_loader_for_assembly(common::test_dll_path())
+            .unwrap();
+        let hello = fn_loader
+            .get_function_with_default_signature(pdcstr!("Test.Program, Test"), pdcstr!("Hello"))
+            .unwrap();
+        let result = unsafe { hello(ptr::null(), 0) };
+        assert_eq!(result, 42);
+    }
+
+    #[test]
+    fn attach_without_runtime() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let result = hostfxr.attach_to_current_runtime();
+        assert!(matches!(
+            result,
+            Err(AttachToRuntimeError::Hosting(HostingError::HostInvalidState))
+        ));
+    }
+}
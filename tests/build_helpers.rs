@@ -0,0 +1,39 @@
+#![cfg(feature = "build-helpers")]
+
+use netcorehost::{build_helpers, env::ScopedEnv};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn compile_project() {
+        let out_dir = std::env::temp_dir().join(format!("netcorehost-build-helpers-{}", std::process::id()));
+        let mut env = ScopedEnv::new();
+        env.set("OUT_DIR", &out_dir);
+
+        let project = build_helpers::compile_project(
+            "tests/ClassLibrary/ClassLibrary.csproj",
+            &common::test_netcore_version(),
+        )
+        .unwrap();
+        assert!(project.output_dir().starts_with(&out_dir));
+        assert!(project.assembly_path().exists());
+        assert!(project.symbols_path().exists());
+
+        let _ = std::fs::remove_dir_all(&out_dir);
+    }
+
+    #[test]
+    fn compile_project_requires_out_dir() {
+        let mut env = ScopedEnv::new();
+        env.remove("OUT_DIR");
+
+        let result = build_helpers::compile_project(
+            "tests/ClassLibrary/ClassLibrary.csproj",
+            &common::test_netcore_version(),
+        );
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,46 @@
+use netcorehost::{bundle, env::HostEnvironment, scratch::ScratchDir};
+use std::{fs, time::Duration};
+
+#[test]
+fn extraction_base_dir_from_env() {
+    let dir = std::env::temp_dir().join("netcorehost-bundle-base");
+    let _env = HostEnvironment::new().bundle_extract_base_dir(&dir).apply();
+    assert_eq!(bundle::extraction_base_dir(), dir);
+}
+
+#[test]
+fn list_and_remove_extractions() {
+    let base = ScratchDir::new().unwrap();
+    assert!(bundle::extractions(&base.join("missing"))
+        .unwrap()
+        .is_empty());
+
+    let old = bundle::extraction_dir(base.path(), "MyApp", "old-id");
+    let new = bundle::extraction_dir(base.path(), "MyApp", "new-id");
+    let other = bundle::extraction_dir(base.path(), "OtherApp", "id");
+    for dir in [&old, &new, &other] {
+        fs::create_dir_all(dir).unwrap();
+    }
+    fs::write(base.join("file.txt"), "not an app").unwrap();
+
+    let mut extractions = bundle::extractions(base.path()).unwrap();
+    extractions.sort_by(|a, b| a.path.cmp(&b.path));
+    assert_eq!(
+        extractions.iter().map(|e| &e.path).collect::<Vec<_>>(),
+        [&new, &old, &other]
+    );
+    assert_eq!(
+        bundle::app_extractions(base.path(), "MyApp").unwrap().len(),
+        2
+    );
+
+    let removed =
+        bundle::remove_extractions_older_than(base.path(), Duration::from_secs(3600)).unwrap();
+    assert!(removed.is_empty());
+
+    std::thread::sleep(Duration::from_millis(50));
+    let removed = bundle::remove_extractions_older_than(base.path(), Duration::ZERO).unwrap();
+    assert_eq!(removed.len(), 3);
+    assert!(bundle::extractions(base.path()).unwrap().is_empty());
+    assert!(base.join("file.txt").is_file());
+}
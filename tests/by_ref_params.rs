@@ -0,0 +1,93 @@
+#![cfg(feature = "netcore3_0")]
+
+use netcorehost::{
+    hostfxr::{Out, OutParam, RefParam},
+    nethost, pdcstr,
+};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn out_param() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+        let try_divide = fn_loader
+            .get_function::<fn(i32, i32, OutParam<i32>) -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("TryDivide"),
+                pdcstr!("ClassLibrary.Library+TryDivideDelegate, ClassLibrary"),
+            )
+            .unwrap();
+
+        let mut result = Out::new();
+        assert_eq!(try_divide(84, 2, result.as_param()), 1);
+        assert_eq!(unsafe { result.assume_init() }, 42);
+
+        let mut result = Out::new();
+        assert_eq!(try_divide(1, 0, result.as_param()), 0);
+        assert_eq!(unsafe { result.assume_init() }, 0);
+    }
+
+    #[test]
+    fn ref_param() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+        let increment = fn_loader
+            .get_function::<fn(RefParam<i32>)>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("Increment"),
+                pdcstr!("ClassLibrary.Library+IncrementDelegate, ClassLibrary"),
+            )
+            .unwrap();
+
+        let mut value = 41;
+        increment(RefParam::new(&mut value));
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    #[cfg(feature = "net5_0")]
+    fn ref_param_unmanaged_callers_only() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+        let increment = fn_loader
+            .get_function_with_unmanaged_callers_only::<fn(RefParam<i32>)>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("IncrementUnmanaged"),
+            )
+            .unwrap();
+
+        let mut value = 0;
+        let param = RefParam::from(&mut value);
+        increment(param);
+        increment(param);
+        assert_eq!(value, 2);
+    }
+}
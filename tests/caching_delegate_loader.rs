@@ -0,0 +1,52 @@
+#![cfg(feature = "net5_0")]
+
+use netcorehost::{hostfxr::CachingDelegateLoader, nethost, pdcstr};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn caches_resolved_functions() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let loader = CachingDelegateLoader::new(context.get_delegate_loader().unwrap());
+        assert!(loader.is_empty());
+
+        let library_path = common::library_dll_path();
+        let first = loader
+            .load_assembly_and_get_function_with_unmanaged_callers_only::<fn(i32, i32) -> i32>(
+                &library_path,
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("AddUnmanaged"),
+            )
+            .unwrap();
+        let second = loader
+            .clone()
+            .load_assembly_and_get_function_with_unmanaged_callers_only::<fn(i32, i32) -> i32>(
+                &library_path,
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("AddUnmanaged"),
+            )
+            .unwrap();
+        assert_eq!(loader.len(), 1);
+        assert_eq!(*first as usize, *second as usize);
+        assert_eq!(second(1, 2), 3);
+
+        let missing = loader.load_assembly_and_get_function_with_unmanaged_callers_only::<fn()>(
+            &library_path,
+            pdcstr!("ClassLibrary.Library, ClassLibrary"),
+            pdcstr!("DoesNotExist"),
+        );
+        assert!(missing.is_err());
+        assert_eq!(loader.len(), 1);
+
+        loader.clear();
+        assert!(loader.is_empty());
+    }
+}
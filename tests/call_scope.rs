@@ -0,0 +1,64 @@
+#![cfg(feature = "netcore3_0")]
+
+use netcorehost::{
+    hostfxr::{self, ManagedCallScope},
+    nethost, pdcstr,
+};
+use rusty_fork::rusty_fork_test;
+use std::{ptr, thread};
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn call_on_thread_with_stack_size() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::test_dll_path())
+            .unwrap();
+        let hello = fn_loader
+            .get_function_with_default_signature(pdcstr!("Test.Program, Test"), pdcstr!("Hello"))
+            .unwrap();
+
+        let result = ManagedCallScope::with_stack_size(16 * 1024 * 1024)
+            .run(|| unsafe { hello(ptr::null(), 0) })
+            .unwrap();
+        assert_eq!(result, 42);
+    }
+
+    #[test]
+    fn thread_name_hook() {
+        hostfxr::set_thread_name_hook(|name| format!("my-host/{name}"));
+        let name = ManagedCallScope::new()
+            .run(|| thread::current().name().unwrap().to_string())
+            .unwrap();
+        assert!(name.starts_with("my-host/netcorehost-worker-"));
+
+        let name = ManagedCallScope::new()
+            .name("explicit")
+            .run(|| thread::current().name().unwrap().to_string())
+            .unwrap();
+        assert_eq!(name, "explicit");
+
+        hostfxr::clear_thread_name_hook();
+        let name = ManagedCallScope::new()
+            .run(|| thread::current().name().unwrap().to_string())
+            .unwrap();
+        assert!(name.starts_with("netcorehost-worker-"));
+    }
+}
+
+#[test]
+#[should_panic(expected = "panic in scope")]
+fn propagates_panics() {
+    ManagedCallScope::new()
+        .run(|| panic!("panic in scope"))
+        .unwrap();
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "net5_0")]
+
+use std::sync::Mutex;
+
+use netcorehost::{
+    callback,
+    hostfxr::{abort_on_panic, catch_panic_to_error_code, utf8_from_parts, RawFunctionPtr},
+    nethost, pdcstr,
+};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+callback! {
+    fn add(a: i32, b: i32) -> i32 {
+        a + b
+    }
+}
+
+callback! {
+    fn divide(a: i32, b: i32) -> i32 {
+        a / b
+    } on_panic {
+        -1
+    }
+}
+
+callback! {
+    fn reset() {}
+}
+
+static GREETING: Mutex<String> = Mutex::new(String::new());
+
+callback! {
+    fn receive_greeting(greeting: *const u8, length: i32) -> i32 {
+        let greeting = unsafe { utf8_from_parts(greeting, length) }.unwrap();
+        *GREETING.lock().unwrap() = greeting.to_string();
+        length
+    }
+}
+
+#[test]
+fn generates_csharp_signatures() {
+    assert_eq!(add.csharp_signature(), "delegate* unmanaged<int, int, int>");
+    assert_eq!(reset.csharp_signature(), "delegate* unmanaged<void>");
+    assert_eq!(
+        receive_greeting.csharp_signature(),
+        "delegate* unmanaged<nint, int, int>"
+    );
+}
+
+#[test]
+fn catches_panics() {
+    assert_eq!(divide(6, 3), 2);
+    assert_eq!(divide(1, 0), -1);
+}
+
+#[test]
+fn reads_utf8_from_parts() {
+    assert_eq!(unsafe { utf8_from_parts(std::ptr::null(), 0) }.unwrap(), "");
+    let value = "Hällo";
+    assert_eq!(
+        unsafe { utf8_from_parts(value.as_ptr(), value.len() as i32) }.unwrap(),
+        value
+    );
+    assert!(unsafe { utf8_from_parts([0xFF].as_ptr(), 1) }.is_err());
+}
+
+rusty_fork_test! {
+    #[test]
+    fn called_from_managed_code() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+
+        let apply = fn_loader
+            .get_function_with_unmanaged_callers_only::<fn(RawFunctionPtr, i32, i32) -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("Apply"),
+            )
+            .unwrap();
+        assert_eq!(apply(add.as_ptr(), 3, 4), 7);
+        assert_eq!(apply(divide.as_ptr(), 1, 0), -1);
+
+        let send_greeting = fn_loader
+            .get_function_with_unmanaged_callers_only::<fn(RawFunctionPtr) -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("SendGreeting"),
+            )
+            .unwrap();
+        assert_eq!(send_greeting(receive_greeting.as_ptr()), 15);
+        assert_eq!(*GREETING.lock().unwrap(), "Hello from .NET");
+    }
+}
+
+#[test]
+fn converts_panics_to_error_codes() {
+    assert_eq!(catch_panic_to_error_code(-1, || 1), 1);
+    assert_eq!(catch_panic_to_error_code(-1, || panic!("failed")), -1);
+    assert_eq!(abort_on_panic(|| 2), 2);
+}
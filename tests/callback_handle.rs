@@ -0,0 +1,115 @@
+#![cfg(feature = "net5_0")]
+
+use std::{
+    ffi::c_void,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use netcorehost::{
+    hostfxr::{kept_alive_callbacks, release_callback, CallbackHandle},
+    nethost, pdcstr,
+};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+struct DropCounter(Arc<AtomicUsize>);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+rusty_fork_test! {
+    #[test]
+    fn calls_closure_through_trampoline() {
+        let offset = 10;
+        let handle = CallbackHandle::new(move |a: i32, b: i32| a + b + offset);
+        assert_eq!(unsafe { (handle.trampoline())(handle.context(), 1, 2) }, 13);
+
+        let unit = CallbackHandle::new(|| ());
+        let other_unit = CallbackHandle::new(|| ());
+        assert_ne!(unit.context(), other_unit.context());
+    }
+
+    #[test]
+    fn drops_closure_with_handle() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let counter = DropCounter(Arc::clone(&drops));
+        let handle = CallbackHandle::new(move || {
+            let _ = &counter;
+        });
+        assert_eq!(drops.load(Ordering::SeqCst), 0);
+        drop(handle);
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn keeps_closure_alive_until_released() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let counter = DropCounter(Arc::clone(&drops));
+        let handle = CallbackHandle::new(move |value: i32| {
+            let _ = &counter;
+            value * 2
+        });
+        let trampoline = handle.trampoline();
+        let context = handle.keep_alive();
+        assert_eq!(kept_alive_callbacks(), 1);
+        assert_eq!(unsafe { trampoline(context, 21) }, 42);
+        assert_eq!(drops.load(Ordering::SeqCst), 0);
+
+        release_callback(context);
+        assert_eq!(kept_alive_callbacks(), 0);
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+
+        // releasing again or releasing unknown contexts is ignored.
+        release_callback(context);
+        release_callback(std::ptr::null_mut());
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn called_and_released_from_managed_code() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+
+        let apply = fn_loader
+            .get_function_with_unmanaged_callers_only::<fn(
+                unsafe extern "system" fn(*mut c_void, i32, i32) -> i32,
+                *mut c_void,
+                i32,
+                i32,
+            ) -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("ApplyWithContext"),
+            )
+            .unwrap();
+        let release = fn_loader
+            .get_function_with_unmanaged_callers_only::<fn(extern "system" fn(*mut c_void), *mut c_void)>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("ReleaseContext"),
+            )
+            .unwrap();
+
+        let factor = 3;
+        let handle = CallbackHandle::new(move |a: i32, b: i32| (a + b) * factor);
+        let trampoline = handle.trampoline();
+        let callback_context = handle.keep_alive();
+        assert_eq!(apply(trampoline, callback_context, 1, 2), 9);
+
+        release(release_callback, callback_context);
+        assert_eq!(kept_alive_callbacks(), 0);
+    }
+}
@@ -0,0 +1,46 @@
+#![cfg(all(windows, feature = "netcore3_0"))]
+
+use netcorehost::{hostfxr::Guid, nethost, pdcstr};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+const IID_ICLASS_FACTORY: Guid = Guid::from_u128(0x00000001_0000_0000_c000_000000000046);
+
+#[test]
+fn guid_roundtrips() {
+    assert_eq!(IID_ICLASS_FACTORY.data1, 0x00000001);
+    assert_eq!(IID_ICLASS_FACTORY.data4, [0xc0, 0, 0, 0, 0, 0, 0, 0x46]);
+    assert_eq!(
+        IID_ICLASS_FACTORY.to_u128(),
+        0x00000001_0000_0000_c000_000000000046
+    );
+    assert_eq!(
+        IID_ICLASS_FACTORY.to_string(),
+        "00000001-0000-0000-c000-000000000046"
+    );
+}
+
+rusty_fork_test! {
+    #[test]
+    fn com_activation_of_missing_type_fails() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let delegate = context.get_com_activation_delegate().unwrap();
+
+        let result = delegate.get_class_factory(
+            &Guid::from_u128(0x0b1f_8a3c_5d2e_4f60_9a7b_c8d9e0f1a2b3),
+            &IID_ICLASS_FACTORY,
+            &common::test_dll_path(),
+            pdcstr!("Test"),
+            pdcstr!("Test.DoesNotExist, Test"),
+        );
+        let error = result.unwrap_err();
+        assert!(error.hresult() < 0);
+    }
+}
@@ -1,11 +1,10 @@
 #![allow(unused)]
 
-use netcorehost::pdcstring::PdCString;
+use netcorehost::{dotnet_cli, pdcstring::PdCString};
 use path_absolutize::Absolutize;
 use std::{
     env,
     path::{Path, PathBuf},
-    process::Command,
     str::FromStr,
 };
 
@@ -69,6 +68,16 @@ pub fn library_symbols_path() -> PdCString {
     .unwrap()
 }
 
+pub fn nativeaot_library_path() -> PathBuf {
+    PathBuf::from(format!(
+        "tests/NativeAotLibrary/bin/publish/NativeAotLibrary{}",
+        env::consts::DLL_SUFFIX
+    ))
+    .absolutize()
+    .unwrap()
+    .into_owned()
+}
+
 pub fn setup() {
     build_test_project();
     build_library_project();
@@ -79,7 +88,7 @@ pub fn build_test_project() {
         return;
     }
 
-    Command::new("dotnet")
+    dotnet_cli::command()
         .arg("build")
         .arg("Test.sln")
         .arg("--framework")
@@ -96,7 +105,7 @@ pub fn build_library_project() {
         return;
     }
 
-    Command::new("dotnet")
+    dotnet_cli::command()
         .arg("build")
         .arg("ClassLibrary.sln")
         .arg("--framework")
@@ -107,3 +116,22 @@ pub fn build_library_project() {
         .wait()
         .expect("dotnet build failed");
 }
+
+pub fn build_nativeaot_library() {
+    if nativeaot_library_path().exists() {
+        return;
+    }
+
+    dotnet_cli::command()
+        .arg("publish")
+        .arg("NativeAotLibrary.csproj")
+        .arg("--configuration")
+        .arg("Release")
+        .arg("--output")
+        .arg("bin/publish")
+        .current_dir("tests/NativeAotLibrary")
+        .spawn()
+        .expect("dotnet publish failed")
+        .wait()
+        .expect("dotnet publish failed");
+}
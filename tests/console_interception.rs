@@ -0,0 +1,68 @@
+#![cfg(feature = "net5_0")]
+
+use netcorehost::{
+    hostfxr::{ConsoleInterception, ConsoleInterceptionError, ConsoleStream},
+    nethost, pdcstr,
+};
+use rusty_fork::rusty_fork_test;
+use std::sync::{Arc, Mutex};
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn intercepts_console_lines() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+        let hello = fn_loader
+            .get_function_with_unmanaged_callers_only::<fn() -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("Hello"),
+            )
+            .unwrap();
+
+        let lines = Arc::new(Mutex::new(Vec::new()));
+        let interception = ConsoleInterception::install(
+            &fn_loader,
+            pdcstr!("NativeConsole, ClassLibrary"),
+            "library",
+            {
+                let lines = Arc::clone(&lines);
+                move |line| {
+                    lines.lock().unwrap().push((line.stream, line.source.to_string(), line.text.to_string()));
+                }
+            },
+        )
+        .unwrap();
+        assert_eq!(interception.source(), "library");
+
+        let second = ConsoleInterception::install(
+            &fn_loader,
+            pdcstr!("NativeConsole, ClassLibrary"),
+            "other",
+            |_| {},
+        );
+        assert!(matches!(second, Err(ConsoleInterceptionError::AlreadyInstalled)));
+
+        assert_eq!(hello(), 42);
+        drop(interception);
+        assert_eq!(hello(), 42);
+
+        assert_eq!(
+            *lines.lock().unwrap(),
+            [(
+                ConsoleStream::Out,
+                "library".to_string(),
+                "Hello from Library!".to_string()
+            )]
+        );
+    }
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "net5_0")]
+
+use netcorehost::{
+    hostfxr::ContextPool, nethost, pdcstr, pdcstring::PdCString, scratch::ScratchDir,
+};
+use rusty_fork::rusty_fork_test;
+use std::{fs, ptr};
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn reuses_pooled_context() {
+        common::setup();
+
+        let pool = ContextPool::new(nethost::load_hostfxr().unwrap());
+        assert!(pool.is_empty());
+
+        let first = pool.context(common::test_runtime_config_path(), |context| context.handle()).unwrap();
+        let second = pool.context(common::test_runtime_config_path(), |context| context.handle()).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(pool.len(), 1);
+        assert!(pool.contains(common::test_runtime_config_path()));
+
+        let loader = pool
+            .delegate_loader_for_assembly(common::test_runtime_config_path(), common::test_dll_path())
+            .unwrap();
+        let hello = loader
+            .get_function_with_default_signature(pdcstr!("Test.Program, Test"), pdcstr!("Hello"))
+            .unwrap();
+        let result = unsafe { hello(ptr::null(), 0) };
+        assert_eq!(result, 42);
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn evicts_least_recently_used_context() {
+        common::setup();
+
+        // a copy of the runtime config is a different pool entry, but compatible with the loaded runtime.
+        let dir = ScratchDir::new().unwrap();
+        let copied_config_path = dir.path().join("Copy.runtimeconfig.json");
+        fs::copy(common::test_runtime_config_path().to_os_string(), &copied_config_path).unwrap();
+        let copied_config_path = PdCString::from_os_str(copied_config_path).unwrap();
+
+        let pool = ContextPool::new(nethost::load_hostfxr().unwrap()).capacity(1);
+        pool.delegate_loader(common::test_runtime_config_path()).unwrap();
+        pool.delegate_loader(&copied_config_path).unwrap();
+
+        assert_eq!(pool.len(), 1);
+        assert!(!pool.contains(common::test_runtime_config_path()));
+        assert!(pool.contains(&copied_config_path));
+
+        assert!(pool.remove(&copied_config_path).unwrap().is_ok());
+        assert!(pool.remove(&copied_config_path).is_none());
+        assert!(pool.is_empty());
+    }
+}
@@ -1,6 +1,6 @@
 #![cfg(feature = "netcore3_0")]
 
-use netcorehost::{nethost, pdcstr};
+use netcorehost::{hostfxr::DelegateTypeSpec, nethost, pdcstr};
 use rusty_fork::rusty_fork_test;
 
 #[path = "common.rs"]
@@ -28,4 +28,45 @@ rusty_fork_test! {
 
         hello();
     }
+
+    #[test]
+    fn delegate_type_spec() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::test_dll_path())
+            .unwrap();
+
+        let custom_hello = fn_loader
+            .get_function_with_delegate_type::<fn()>(
+                pdcstr!("Test.Program, Test"),
+                pdcstr!("CustomHello"),
+                DelegateTypeSpec::Custom(pdcstr!("Test.Program+CustomHelloFunc, Test")),
+            )
+            .unwrap();
+        custom_hello();
+
+        let hello = fn_loader
+            .get_function_with_delegate_type::<unsafe fn(*const u8, i32) -> i32>(
+                pdcstr!("Test.Program, Test"),
+                pdcstr!("Hello"),
+                DelegateTypeSpec::Default,
+            )
+            .unwrap();
+        assert_eq!(unsafe { hello(std::ptr::null(), 0) }, 42);
+
+        let unmanaged_hello = fn_loader
+            .get_function_with_delegate_type::<fn() -> i32>(
+                pdcstr!("Test.Program, Test"),
+                pdcstr!("UnmanagedHello"),
+                DelegateTypeSpec::UnmanagedCallersOnly,
+            )
+            .unwrap();
+        assert_eq!(unmanaged_hello(), 42);
+    }
 }
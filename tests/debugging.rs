@@ -0,0 +1,40 @@
+#![cfg(feature = "net5_0")]
+
+use netcorehost::{
+    hostfxr::{self, DebugOptions},
+    nethost, pdcstr,
+};
+use rusty_fork::rusty_fork_test;
+use std::time::{Duration, Instant};
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn wait_for_debugger_times_out() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+        let type_name = pdcstr!("NativeDebugger, ClassLibrary");
+
+        assert!(!hostfxr::is_managed_debugger_attached(&fn_loader, type_name).unwrap());
+
+        let timeout = Duration::from_millis(300);
+        let start = Instant::now();
+        let attached = DebugOptions::new()
+            .wait_for_managed_debugger(timeout)
+            .poll_interval(Duration::from_millis(50))
+            .print_hint(false)
+            .wait(&fn_loader, type_name)
+            .unwrap();
+        assert!(!attached);
+        assert!(start.elapsed() >= timeout);
+    }
+}
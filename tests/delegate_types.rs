@@ -0,0 +1,227 @@
+#![cfg(feature = "net5_0")]
+
+use netcorehost::{
+    hostfxr::{DelegateTypeSpec, FfiSafe},
+    nethost, pdcstr,
+};
+use rusty_fork::rusty_fork_test;
+use std::ptr;
+
+#[path = "common.rs"]
+mod common;
+
+#[repr(C)]
+struct Pair {
+    a: u16,
+    b: u16,
+}
+
+unsafe impl FfiSafe for Pair {}
+
+rusty_fork_test! {
+    #[test]
+    fn component_entry_point_call_helpers() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+
+        let entry_point = fn_loader
+            .get_function_with_default_signature(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("ComponentEntryPoint"),
+            )
+            .unwrap();
+        assert_eq!(unsafe { entry_point.call_with_bytes(&[1, 2, 3]) }, 3);
+        assert_eq!(unsafe { entry_point.call_with_bytes(&[]) }, 0);
+        assert_eq!(unsafe { entry_point.call_with_struct(&Pair { a: 1, b: 2 }) }, 4);
+        assert_eq!(unsafe { entry_point.call_with_struct(&7u64) }, 8);
+        assert_eq!(unsafe { entry_point.call_without_args() }, 0);
+
+        let sum_bytes = fn_loader
+            .get_function_with_default_signature(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("SumBytes"),
+            )
+            .unwrap();
+        assert_eq!(unsafe { sum_bytes.call_with_bytes(&[1, 2, 3]) }, 6);
+        assert_eq!(
+            unsafe { sum_bytes.call_with_struct(&Pair { a: 0x0201, b: 0x0403 }) },
+            10
+        );
+    }
+
+    #[test]
+    fn component_entry_point() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+
+        let entry_point = fn_loader
+            .get_function_with_default_signature(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("ComponentEntryPoint"),
+            )
+            .unwrap();
+        assert_eq!(unsafe { entry_point(ptr::null(), 7) }, 7);
+    }
+
+    #[test]
+    fn unmanaged_callers_only() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+
+        let add = fn_loader
+            .get_function_with_unmanaged_callers_only::<fn(i32, i32) -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("AddUnmanaged"),
+            )
+            .unwrap();
+        assert_eq!(add(1, 2), 3);
+    }
+
+    #[test]
+    fn custom_delegate() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+
+        let add = fn_loader
+            .get_function::<fn(i32, i32) -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("Add"),
+                pdcstr!("ClassLibrary.Library+AddDelegate, ClassLibrary"),
+            )
+            .unwrap();
+        assert_eq!(add(2, 3), 5);
+    }
+
+    #[test]
+    fn explicit_delegate_type() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+
+        let add = fn_loader
+            .get_function_with_delegate_type::<fn(i32, i32) -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("Add"),
+                DelegateTypeSpec::Custom(pdcstr!("ClassLibrary.Library+AddDelegate, ClassLibrary")),
+            )
+            .unwrap();
+        assert_eq!(add(10, 20), 30);
+
+        let add_unmanaged = fn_loader
+            .get_function_with_delegate_type::<fn(i32, i32) -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("AddUnmanaged"),
+                DelegateTypeSpec::UnmanagedCallersOnly,
+            )
+            .unwrap();
+        assert_eq!(add_unmanaged(10, 20), 30);
+
+        let missing = fn_loader.get_function_with_delegate_type::<fn(i32, i32) -> i32>(
+            pdcstr!("ClassLibrary.Library, ClassLibrary"),
+            pdcstr!("DoesNotExist"),
+            DelegateTypeSpec::UnmanagedCallersOnly,
+        );
+        assert!(missing.is_err());
+    }
+}
+
+#[cfg(feature = "net8_0")]
+rusty_fork_test! {
+    #[test]
+    fn load_assembly() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        context
+            .load_assembly_from_path(common::library_dll_path())
+            .unwrap();
+
+        let fn_loader = context.get_delegate_loader().unwrap();
+        let entry_point = fn_loader
+            .get_function_with_default_signature(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("ComponentEntryPoint"),
+            )
+            .unwrap();
+        assert_eq!(unsafe { entry_point(ptr::null(), 11) }, 11);
+
+        let add = fn_loader
+            .get_function::<fn(i32, i32) -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("Add"),
+                pdcstr!("ClassLibrary.Library+AddDelegate, ClassLibrary"),
+            )
+            .unwrap();
+        assert_eq!(add(4, 5), 9);
+
+        let add_unmanaged = fn_loader
+            .get_function_with_unmanaged_callers_only::<fn(i32, i32) -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("AddUnmanaged"),
+            )
+            .unwrap();
+        assert_eq!(add_unmanaged(6, 7), 13);
+    }
+
+    #[test]
+    fn load_assembly_bytes() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+
+        let assembly_bytes = std::fs::read(common::library_dll_path().to_os_string()).unwrap();
+        let symbol_bytes = std::fs::read(common::library_symbols_path().to_os_string()).unwrap();
+        context
+            .load_assembly_from_bytes(assembly_bytes, symbol_bytes)
+            .unwrap();
+
+        let fn_loader = context.get_delegate_loader().unwrap();
+        let add_unmanaged = fn_loader
+            .get_function_with_unmanaged_callers_only::<fn(i32, i32) -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("AddUnmanaged"),
+            )
+            .unwrap();
+        assert_eq!(add_unmanaged(20, 22), 42);
+    }
+}
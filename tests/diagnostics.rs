@@ -0,0 +1,40 @@
+#![cfg(feature = "nethost")]
+
+use netcorehost::diagnostics;
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn snapshot_locates_hostfxr() {
+        common::setup();
+
+        let snapshot = diagnostics::snapshot();
+        assert!(snapshot.hostfxr_error.is_none(), "{:?}", snapshot.hostfxr_error);
+        assert!(snapshot.hostfxr_path.is_some());
+        assert!(snapshot.dotnet_root.is_some());
+        assert!(!snapshot.runtime_started);
+        assert!(snapshot.runtime_identifier.is_some());
+
+        #[cfg(feature = "net6_0")]
+        {
+            assert!(snapshot.hostfxr_version.is_some());
+            assert!(snapshot
+                .frameworks
+                .iter()
+                .any(|framework| framework.name == "Microsoft.NETCore.App"));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn snapshot_to_json() {
+        common::setup();
+
+        let json = diagnostics::snapshot().to_json().unwrap();
+        assert!(json.contains("\"crate_version\""));
+        assert!(json.contains("\"frameworks\""));
+    }
+}
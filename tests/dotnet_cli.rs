@@ -0,0 +1,234 @@
+use std::{ffi::OsStr, fs, path::Path};
+
+use netcorehost::{
+    dotnet_cli::{self, DotnetCli, DotnetInfo, DotnetLocator, InstalledRuntime, InstalledSdk},
+    env::HostEnvironment,
+    scratch::ScratchDir,
+};
+use rusty_fork::rusty_fork_test;
+
+#[test]
+fn locate_installed_dotnet() {
+    let dotnet = dotnet_cli::locate().unwrap();
+    assert!(dotnet.is_file());
+    assert_eq!(dotnet.file_name().unwrap(), dotnet_cli::executable_name());
+}
+
+#[test]
+fn custom_dirs_are_checked_first() {
+    let root = ScratchDir::new().unwrap();
+    let locator = DotnetLocator::new().dir(root.path());
+    assert_ne!(locator.locate_root().as_deref(), Some(root.path()));
+
+    fs::write(root.path().join(dotnet_cli::executable_name()), "").unwrap();
+    assert_eq!(locator.locate_root().as_deref(), Some(root.path()));
+}
+
+#[test]
+fn parse_list_output() {
+    let sdks = dotnet_cli::parse_list_sdks(
+        "6.0.420 [/usr/share/dotnet/sdk]\n8.0.100-rc.2.23502.2 [/usr/share/dotnet/sdk]\n",
+    )
+    .unwrap();
+    assert_eq!(
+        sdks,
+        [
+            InstalledSdk {
+                version: "6.0.420".to_string(),
+                path: Path::new("/usr/share/dotnet/sdk/6.0.420").to_path_buf(),
+            },
+            InstalledSdk {
+                version: "8.0.100-rc.2.23502.2".to_string(),
+                path: Path::new("/usr/share/dotnet/sdk/8.0.100-rc.2.23502.2").to_path_buf(),
+            },
+        ]
+    );
+
+    let runtimes = dotnet_cli::parse_list_runtimes(
+        "Microsoft.NETCore.App 8.0.0 [C:\\Program Files\\dotnet\\shared\\Microsoft.NETCore.App]\r\n",
+    )
+    .unwrap();
+    assert_eq!(
+        runtimes,
+        [InstalledRuntime {
+            name: "Microsoft.NETCore.App".to_string(),
+            version: "8.0.0".to_string(),
+            path: Path::new(r"C:\Program Files\dotnet\shared\Microsoft.NETCore.App").to_path_buf(),
+        }]
+    );
+
+    assert!(dotnet_cli::parse_list_sdks("8.0.100").is_err());
+    assert!(dotnet_cli::parse_list_runtimes("8.0.0 [/usr/share/dotnet]").is_err());
+}
+
+#[test]
+fn parse_info_output() {
+    let output = "\
+.NET SDK:
+ Version:           8.0.100
+ Commit:            57efcf1350
+ Workload version:  8.0.100-manifests.6c33ef20
+
+Runtime Environment:
+ OS Name:     ubuntu
+ RID:         linux-x64
+ Base Path:   /usr/share/dotnet/sdk/8.0.100/
+
+Host:
+  Version:      8.0.0
+  Architecture: x64
+  Commit:       5535e31a71
+
+.NET SDKs installed:
+  8.0.100 [/usr/share/dotnet/sdk]
+
+.NET runtimes installed:
+  Microsoft.AspNetCore.App 8.0.0 [/usr/share/dotnet/shared/Microsoft.AspNetCore.App]
+  Microsoft.NETCore.App 8.0.0 [/usr/share/dotnet/shared/Microsoft.NETCore.App]
+
+Other architectures found:
+  None
+
+Environment variables:
+  Not set
+";
+    let info = dotnet_cli::parse_info(output).unwrap();
+    assert_eq!(info.host_version, "8.0.0");
+    assert_eq!(info.host_commit, "5535e31a71");
+    assert_eq!(info.host_architecture.as_deref(), Some("x64"));
+    assert_eq!(info.sdk_version.as_deref(), Some("8.0.100"));
+    assert_eq!(info.sdks.len(), 1);
+    assert_eq!(info.runtimes.len(), 2);
+    assert_eq!(info.runtimes[1].name, "Microsoft.NETCore.App");
+
+    let output = "\
+Host (useful for support):
+  Version: 3.1.32
+  Commit:  ce3153d8bb
+
+.NET Core SDKs installed:
+  No SDKs were found.
+
+.NET Core runtimes installed:
+  Microsoft.NETCore.App 3.1.32 [/usr/share/dotnet/shared/Microsoft.NETCore.App]
+";
+    let info = dotnet_cli::parse_info(output).unwrap();
+    assert_eq!(info.host_version, "3.1.32");
+    assert_eq!(info.host_architecture, None);
+    assert_eq!(info.sdk_version, None);
+    assert!(info.sdks.is_empty());
+    assert_eq!(info.runtimes.len(), 1);
+
+    assert!(dotnet_cli::parse_info("Runtime Environment:\n OS Name: ubuntu\n").is_err());
+}
+
+#[test]
+fn list_commands_match_info() {
+    let cli = DotnetCli::new().native_fallback(false);
+    let info = cli.info().unwrap();
+
+    assert_eq!(cli.list_sdks().unwrap(), info.sdks);
+    assert_eq!(cli.list_runtimes().unwrap(), info.runtimes);
+    assert!(info.sdks.iter().all(|sdk| sdk.path.ends_with(&sdk.version)));
+    assert!(info
+        .runtimes
+        .iter()
+        .any(|runtime| runtime.name == "Microsoft.NETCore.App"));
+}
+
+#[test]
+#[cfg(all(feature = "net6_0", feature = "nethost"))]
+fn cli_matches_native_environment_info() {
+    use netcorehost::{nethost, pdcstring::PdCString};
+
+    let dotnet_root = DotnetLocator::new().locate_root().unwrap();
+    let cli_info = DotnetCli::with_executable(dotnet_root.join(dotnet_cli::executable_name()))
+        .native_fallback(false)
+        .info()
+        .unwrap();
+
+    let hostfxr =
+        nethost::load_hostfxr_with_dotnet_root(PdCString::from_os_str(&dotnet_root).unwrap())
+            .unwrap();
+    let native_info = DotnetInfo::from(hostfxr.get_dotnet_environment_info().unwrap());
+
+    assert_eq!(cli_info.host_version, native_info.host_version);
+    assert_eq!(cli_info.sdks, native_info.sdks);
+    assert_eq!(cli_info.runtimes, native_info.runtimes);
+}
+
+rusty_fork_test! {
+    #[test]
+    fn dotnet_root_takes_precedence_over_path() {
+        let root = ScratchDir::new().unwrap();
+        fs::write(root.path().join(dotnet_cli::executable_name()), "").unwrap();
+
+        let _env = HostEnvironment::new()
+            .remove_var("DOTNET_ROOT_X64")
+            .remove_var("DOTNET_ROOT_X86")
+            .remove_var("DOTNET_ROOT_ARM64")
+            .remove_var("DOTNET_ROOT(x86)")
+            .dotnet_root(root.path())
+            .apply();
+        assert_eq!(DotnetLocator::new().locate_root().as_deref(), Some(root.path()));
+        assert_ne!(
+            DotnetLocator::new().environment(false).locate_root().as_deref(),
+            Some(root.path())
+        );
+    }
+
+    #[test]
+    fn path_takes_precedence_for_cli() {
+        let dotnet_root = ScratchDir::new().unwrap();
+        fs::write(dotnet_root.path().join(dotnet_cli::executable_name()), "").unwrap();
+        let path_root = ScratchDir::new().unwrap();
+        fs::write(path_root.path().join(dotnet_cli::executable_name()), "").unwrap();
+
+        let _env = HostEnvironment::new()
+            .remove_var("DOTNET_ROOT_X64")
+            .remove_var("DOTNET_ROOT_X86")
+            .remove_var("DOTNET_ROOT_ARM64")
+            .remove_var("DOTNET_ROOT(x86)")
+            .dotnet_root(dotnet_root.path())
+            .var("PATH", path_root.path())
+            .apply();
+        assert_eq!(DotnetLocator::for_cli().locate_root().as_deref(), Some(path_root.path()));
+        assert_eq!(
+            dotnet_cli::locate(),
+            Some(path_root.path().join(dotnet_cli::executable_name()))
+        );
+        assert_eq!(DotnetLocator::new().locate_root().as_deref(), Some(dotnet_root.path()));
+        assert_eq!(
+            DotnetLocator::for_cli().search_path(false).locate_root().as_deref(),
+            Some(dotnet_root.path())
+        );
+    }
+}
+
+#[test]
+fn commands_opt_out_of_telemetry() {
+    fn env_value<'a>(command: &'a std::process::Command, key: &str) -> Option<&'a OsStr> {
+        command
+            .get_envs()
+            .find(|(name, _)| *name == key)
+            .and_then(|(_, value)| value)
+    }
+
+    let command = DotnetCli::with_executable("dotnet").command();
+    assert_eq!(
+        env_value(&command, "DOTNET_CLI_TELEMETRY_OPTOUT"),
+        Some(OsStr::new("1"))
+    );
+    assert_eq!(env_value(&command, "DOTNET_NOLOGO"), Some(OsStr::new("1")));
+
+    let command = dotnet_cli::command();
+    assert_eq!(
+        env_value(&command, "DOTNET_CLI_TELEMETRY_OPTOUT"),
+        Some(OsStr::new("1"))
+    );
+
+    let command = DotnetCli::with_executable("dotnet")
+        .telemetry(true)
+        .command();
+    assert_eq!(env_value(&command, "DOTNET_CLI_TELEMETRY_OPTOUT"), None);
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "net8_0")]
+
+use netcorehost::{hostfxr::EmbeddedAssembly, nethost, pdcstr};
+use rusty_fork::rusty_fork_test;
+use std::fs;
+
+#[path = "common.rs"]
+mod common;
+
+fn leak_file(path: netcorehost::pdcstring::PdCString) -> &'static [u8] {
+    fs::read(path.to_os_string()).unwrap().leak()
+}
+
+rusty_fork_test! {
+    #[test]
+    fn load_embedded() {
+        common::setup();
+
+        let assembly = EmbeddedAssembly::new(
+            "ClassLibrary.dll",
+            leak_file(common::library_dll_path()),
+            Some(leak_file(common::library_symbols_path())),
+        );
+        assert_eq!(assembly.name(), "ClassLibrary");
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        context.load_embedded_assembly(&assembly).unwrap();
+
+        let fn_loader = context.get_delegate_loader().unwrap();
+        let hello = fn_loader
+            .get_function_with_unmanaged_callers_only::<fn() -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("Hello"),
+            )
+            .unwrap();
+        assert_eq!(hello(), 42);
+    }
+
+    #[test]
+    fn load_embedded_without_symbols() {
+        common::setup();
+
+        let assembly =
+            EmbeddedAssembly::new("ClassLibrary.dll", leak_file(common::library_dll_path()), None);
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        context.load_embedded_assembly(&assembly).unwrap();
+    }
+}
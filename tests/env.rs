@@ -0,0 +1,112 @@
+use netcorehost::env::{
+    EnvLock, HostEnvironment, MultilevelLookup, MultilevelLookupWarning, ScopedEnv,
+};
+use std::{env, thread};
+
+#[test]
+fn scoped_env_restores_variables() {
+    let _lock = EnvLock::acquire();
+    env::set_var("NETCOREHOST_TEST_EXISTING", "original");
+    env::remove_var("NETCOREHOST_TEST_MISSING");
+
+    {
+        let mut scope = ScopedEnv::new();
+        scope
+            .set("NETCOREHOST_TEST_EXISTING", "changed")
+            .set("NETCOREHOST_TEST_MISSING", "added")
+            .set("NETCOREHOST_TEST_EXISTING", "changed again");
+        assert_eq!(
+            env::var("NETCOREHOST_TEST_EXISTING").unwrap(),
+            "changed again"
+        );
+        assert_eq!(env::var("NETCOREHOST_TEST_MISSING").unwrap(), "added");
+    }
+
+    assert_eq!(env::var("NETCOREHOST_TEST_EXISTING").unwrap(), "original");
+    assert!(env::var_os("NETCOREHOST_TEST_MISSING").is_none());
+}
+
+#[test]
+fn host_environment_applies_variables() {
+    let original = {
+        let _lock = EnvLock::acquire();
+        env::var_os("DOTNET_ROLL_FORWARD")
+    };
+    {
+        let _env = HostEnvironment::new()
+            .roll_forward("LatestMajor")
+            .trace(true)
+            .remove_var("COREHOST_TRACEFILE")
+            .apply();
+        assert_eq!(env::var("DOTNET_ROLL_FORWARD").unwrap(), "LatestMajor");
+        assert_eq!(env::var("COREHOST_TRACE").unwrap(), "1");
+        assert!(env::var_os("COREHOST_TRACEFILE").is_none());
+    }
+    let _lock = EnvLock::acquire();
+    assert_eq!(env::var_os("DOTNET_ROLL_FORWARD"), original);
+}
+
+#[test]
+fn host_environment_disables_telemetry() {
+    let _env = HostEnvironment::new().disable_dotnet_telemetry().apply();
+    assert_eq!(env::var("DOTNET_CLI_TELEMETRY_OPTOUT").unwrap(), "1");
+    assert_eq!(env::var("DOTNET_NOLOGO").unwrap(), "1");
+}
+
+#[test]
+fn env_lock_serializes_threads() {
+    let threads = (0..8)
+        .map(|i| {
+            thread::spawn(move || {
+                let mut scope = ScopedEnv::new();
+                scope.set("NETCOREHOST_TEST_SHARED", i.to_string());
+                thread::yield_now();
+                assert_eq!(env::var("NETCOREHOST_TEST_SHARED").unwrap(), i.to_string());
+            })
+        })
+        .collect::<Vec<_>>();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+}
+
+#[test]
+fn multilevel_lookup_setting() {
+    let environment = HostEnvironment::new().multilevel_lookup(MultilevelLookup::Disable);
+    assert_eq!(
+        environment.get_multilevel_lookup(),
+        MultilevelLookup::Disable
+    );
+    assert_eq!(
+        environment
+            .multilevel_lookup(MultilevelLookup::Auto)
+            .get_multilevel_lookup(),
+        MultilevelLookup::Auto
+    );
+
+    {
+        let _env = HostEnvironment::new()
+            .multilevel_lookup(MultilevelLookup::Force)
+            .apply();
+        assert_eq!(MultilevelLookup::from_env(), MultilevelLookup::Force);
+    }
+}
+
+#[test]
+fn multilevel_lookup_warnings() {
+    assert_eq!(MultilevelLookup::Auto.check("8.0.0"), None);
+    if cfg!(windows) {
+        assert_eq!(MultilevelLookup::Force.check("6.0.36"), None);
+        assert_eq!(
+            MultilevelLookup::Disable.check("7.0.0"),
+            Some(MultilevelLookupWarning::IgnoredByHostfxr {
+                version: "7.0.0".to_string()
+            })
+        );
+    } else {
+        assert_eq!(
+            MultilevelLookup::Force.check("6.0.36"),
+            Some(MultilevelLookupWarning::UnsupportedPlatform)
+        );
+    }
+}
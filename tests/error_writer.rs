@@ -0,0 +1,91 @@
+#![cfg(feature = "netcore3_0")]
+
+use netcorehost::{
+    hostfxr::{last_error_messages, HostfxrLoadOptions},
+    nethost, pdcstr,
+};
+use rusty_fork::rusty_fork_test;
+use std::{cell::RefCell, rc::Rc};
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn captures_errors_until_dropped() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let guard = hostfxr.set_error_writer({
+            let messages = Rc::clone(&messages);
+            move |message| messages.borrow_mut().push(message.to_string_lossy())
+        });
+
+        let result =
+            hostfxr.initialize_for_runtime_config(pdcstr!("PathThatDoesNotExist.runtimeconfig.json"));
+        assert!(result.is_err());
+        let captured = messages.borrow().len();
+        assert!(captured > 0);
+        assert!(messages
+            .borrow()
+            .iter()
+            .any(|message| message.contains("PathThatDoesNotExist")));
+
+        drop(guard);
+        let result =
+            hostfxr.initialize_for_runtime_config(pdcstr!("PathThatDoesNotExist.runtimeconfig.json"));
+        assert!(result.is_err());
+        assert_eq!(messages.borrow().len(), captured);
+    }
+
+    #[test]
+    fn nested_writers_restore_previous() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let outer = Rc::new(RefCell::new(0));
+        let inner = Rc::new(RefCell::new(0));
+
+        let _outer_guard = hostfxr.set_error_writer({
+            let outer = Rc::clone(&outer);
+            move |_| *outer.borrow_mut() += 1
+        });
+        {
+            let _inner_guard = hostfxr.set_error_writer({
+                let inner = Rc::clone(&inner);
+                move |_| *inner.borrow_mut() += 1
+            });
+            let _ = hostfxr
+                .initialize_for_runtime_config(pdcstr!("PathThatDoesNotExist.runtimeconfig.json"));
+        }
+        assert!(*inner.borrow() > 0);
+        assert_eq!(*outer.borrow(), 0);
+
+        let _ =
+            hostfxr.initialize_for_runtime_config(pdcstr!("PathThatDoesNotExist.runtimeconfig.json"));
+        assert!(*outer.borrow() > 0);
+    }
+
+    #[test]
+    fn captures_messages_of_failed_calls() {
+        common::setup();
+
+        let options = HostfxrLoadOptions::new().capture_error_messages(true);
+        let hostfxr = nethost::load_hostfxr_with_options(&options).unwrap();
+
+        let result =
+            hostfxr.initialize_for_runtime_config(pdcstr!("PathThatDoesNotExist.runtimeconfig.json"));
+        assert!(result.is_err());
+        let messages = last_error_messages();
+        assert!(messages
+            .iter()
+            .any(|message| message.contains("PathThatDoesNotExist")));
+
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        assert!(last_error_messages().is_empty());
+        context.close().unwrap();
+    }
+}
@@ -1,6 +1,10 @@
 #![cfg(feature = "netcore3_0")]
 
-use netcorehost::{hostfxr::GetManagedFunctionError, nethost, pdcstr};
+use netcorehost::{
+    error::{Error, ErrorKind, HostingError},
+    hostfxr::{GetManagedFunctionError, InvalidNameReason},
+    nethost, pdcstr,
+};
 use rusty_fork::rusty_fork_test;
 
 #[path = "common.rs"]
@@ -126,4 +130,244 @@ rusty_fork_test! {
 
         context.close().unwrap();
     }
+
+    #[test]
+    fn preload_assembly() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(pdcstr!("PathThatDoesNotExist.dll"))
+            .unwrap();
+        assert_eq!(
+            fn_loader.preload().unwrap_err(),
+            GetManagedFunctionError::AssemblyNotFound
+        );
+        assert!(!fn_loader.is_loaded());
+
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::test_dll_path())
+            .unwrap();
+        assert_eq!(fn_loader.assembly_path(), &*common::test_dll_path());
+        assert!(!fn_loader.is_loaded());
+        fn_loader.preload().unwrap();
+        assert!(fn_loader.is_loaded());
+        assert!(fn_loader.clone().is_loaded());
+        fn_loader
+            .get_function_with_default_signature(pdcstr!("Test.Program, Test"), pdcstr!("Hello"))
+            .unwrap();
+
+        context.close().unwrap();
+    }
+
+    #[test]
+    fn invalid_names() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::test_dll_path())
+            .unwrap();
+
+        let missing_assembly_name = fn_loader
+            .get_function_with_default_signature(pdcstr!("Test.Program"), pdcstr!("Hello"));
+        assert_eq!(
+            missing_assembly_name.err().unwrap(),
+            GetManagedFunctionError::InvalidTypeName {
+                input: "Test.Program".to_string(),
+                reason: InvalidNameReason::MissingAssemblyName
+            }
+        );
+
+        let surrounding_whitespace = fn_loader
+            .get_function_with_default_signature(pdcstr!("Test.Program, Test "), pdcstr!("Hello"));
+        assert!(matches!(
+            surrounding_whitespace.err().unwrap(),
+            GetManagedFunctionError::InvalidTypeName {
+                reason: InvalidNameReason::SurroundingWhitespace,
+                ..
+            }
+        ));
+
+        let generic_syntax = fn_loader.get_function_with_default_signature(
+            pdcstr!("System.Collections.Generic.List<int>, System.Private.CoreLib"),
+            pdcstr!("Hello"),
+        );
+        assert!(matches!(
+            generic_syntax.err().unwrap(),
+            GetManagedFunctionError::InvalidTypeName {
+                reason: InvalidNameReason::CSharpGenericSyntax,
+                ..
+            }
+        ));
+
+        let parameter_list = fn_loader
+            .get_function_with_default_signature(pdcstr!("Test.Program, Test"), pdcstr!("Hello()"));
+        assert!(matches!(
+            parameter_list.err().unwrap(),
+            GetManagedFunctionError::InvalidMethodName {
+                reason: InvalidNameReason::ContainsParameterList,
+                ..
+            }
+        ));
+
+        // generic arguments are separated by commas, which must not be mistaken for the assembly name.
+        let generic_type = fn_loader.get_function_with_default_signature(
+            pdcstr!("System.Collections.Generic.Dictionary`2[[System.Int32, System.Private.CoreLib],[System.Int32, System.Private.CoreLib]]"),
+            pdcstr!("Hello"),
+        );
+        assert!(matches!(
+            generic_type.err().unwrap(),
+            GetManagedFunctionError::InvalidTypeName {
+                reason: InvalidNameReason::MissingAssemblyName,
+                ..
+            }
+        ));
+
+        context.close().unwrap();
+    }
+
+    #[test]
+    fn sdk_command_line() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+
+        for verb in [pdcstr!("test"), pdcstr!("run"), pdcstr!("build")] {
+            assert_eq!(
+                hostfxr.initialize_for_dotnet_command_line(verb).err(),
+                Some(HostingError::AppArgNotRunnable)
+            );
+        }
+    }
+}
+
+#[test]
+fn error_accessors() {
+    let hosting_error = HostingError::HostApiBufferTooSmall;
+    let error = Error::from(hosting_error);
+    assert_eq!(error.kind(), ErrorKind::Hosting);
+    assert_eq!(error.hosting_error(), Some(hosting_error));
+    assert_eq!(error.code(), Some(hosting_error.value()));
+
+    let error = Error::from(GetManagedFunctionError::Hosting(hosting_error));
+    assert_eq!(error.kind(), ErrorKind::GetFunctionPointer);
+    assert_eq!(error.hosting_error(), Some(hosting_error));
+    assert_eq!(error.code(), Some(hosting_error.value()));
+
+    let error = Error::from(GetManagedFunctionError::MissingMethod);
+    assert_eq!(error.hosting_error(), None);
+    assert_eq!(error.code(), Some(0x8013_1513));
+    assert!(GetManagedFunctionError::MissingMethod.is_not_found());
+    assert_eq!(GetManagedFunctionError::Other(0x1234).code(), 0x1234);
+}
+
+#[test]
+fn managed_function_error_codes_round_trip() {
+    for error in [
+        GetManagedFunctionError::TypeNotFound,
+        GetManagedFunctionError::MissingMethod,
+        GetManagedFunctionError::AssemblyNotFound,
+        GetManagedFunctionError::MethodNotUnmanagedCallersOnly,
+        GetManagedFunctionError::Hosting(HostingError::HostApiBufferTooSmall),
+        GetManagedFunctionError::Other(0x1234),
+    ] {
+        assert_eq!(
+            GetManagedFunctionError::from_status_code(error.code() as i32).unwrap_err(),
+            error
+        );
+    }
+}
+
+#[test]
+fn invalid_name_error_codes_do_not_round_trip() {
+    for error in [
+        GetManagedFunctionError::InvalidTypeName {
+            input: "Type".to_string(),
+            reason: InvalidNameReason::MissingAssemblyName,
+        },
+        GetManagedFunctionError::InvalidMethodName {
+            input: String::new(),
+            reason: InvalidNameReason::Empty,
+        },
+    ] {
+        assert_eq!(error.code(), 0x8007_0057);
+        assert_eq!(
+            GetManagedFunctionError::from_status_code(error.code() as i32).unwrap_err(),
+            GetManagedFunctionError::MissingMethod
+        );
+    }
+}
+
+#[test]
+fn error_hints() {
+    let hint = HostingError::FrameworkMissingFailure.hint().unwrap();
+    let url = hint.url.as_deref().unwrap();
+    assert!(url.starts_with("https://aka.ms/dotnet-core-applaunch?"));
+    assert!(url.contains("&rid="));
+    assert!(hint.to_string().starts_with(hint.message));
+    assert!(hint.to_string().ends_with(&format!("({url})")));
+
+    let hint = HostingError::AppArgNotRunnable.hint().unwrap();
+    assert!(hint.message.contains(".dll"));
+    assert_eq!(hint.url, None);
+    assert_eq!(hint.to_string(), hint.message);
+
+    assert!(HostingError::CoreHostCurHostFindFailure
+        .hint()
+        .unwrap()
+        .message
+        .contains("DOTNET_ROOT"));
+    assert_eq!(HostingError::HostApiBufferTooSmall.hint(), None);
+    assert_eq!(HostingError::Unknown(0x1234).hint(), None);
+
+    let error = Error::from(HostingError::FrameworkMissingFailure);
+    assert_eq!(error.hint(), HostingError::FrameworkMissingFailure.hint());
+}
+
+#[test]
+fn hosting_status_names() {
+    use netcorehost::error::HostingSuccess;
+
+    assert_eq!(
+        "FrameworkMissingFailure".parse::<HostingError>().unwrap(),
+        HostingError::FrameworkMissingFailure
+    );
+    assert_eq!(
+        "0x80008096".parse::<HostingError>().unwrap(),
+        HostingError::FrameworkMissingFailure
+    );
+    assert_eq!(
+        "0x8000FFFF".parse::<HostingError>().unwrap(),
+        HostingError::Unknown(0x8000_FFFF)
+    );
+    assert!("Success".parse::<HostingError>().is_err());
+    assert!("0".parse::<HostingError>().is_err());
+    assert!("NotAStatus".parse::<HostingError>().is_err());
+
+    assert_eq!(
+        "Success".parse::<HostingSuccess>().unwrap(),
+        HostingSuccess::Success
+    );
+    assert_eq!(
+        "0".parse::<HostingSuccess>().unwrap(),
+        HostingSuccess::Success
+    );
+
+    for error in HostingError::all_variants() {
+        assert!(error.is_known());
+        assert_eq!(error.name().parse::<HostingError>().unwrap(), *error);
+    }
+    for success in HostingSuccess::all_variants() {
+        assert_eq!(success.name().parse::<HostingSuccess>().unwrap(), *success);
+    }
 }
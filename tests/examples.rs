@@ -0,0 +1,74 @@
+#![cfg(feature = "nethost")]
+
+use netcorehost::dotnet_cli;
+use std::{env, path::PathBuf, process::Command};
+
+// builds the managed project of the example and runs the example, which cargo builds together with the tests.
+fn run_example(name: &str) -> String {
+    let status = dotnet_cli::command()
+        .arg("build")
+        .arg("ExampleProject.sln")
+        .current_dir(format!("examples/{name}/ExampleProject"))
+        .status()
+        .expect("dotnet build failed");
+    assert!(status.success(), "dotnet build failed with {status}");
+
+    // tests are built into target/<profile>/deps and examples into target/<profile>/examples.
+    let mut executable = PathBuf::from(env::current_exe().unwrap().parent().unwrap());
+    executable.pop();
+    executable.push("examples");
+    executable.push(format!("{name}{}", env::consts::EXE_SUFFIX));
+
+    let output = Command::new(&executable)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap_or_else(|err| panic!("failed to run {}: {err}", executable.display()));
+    assert!(
+        output.status.success(),
+        "example {name} failed with {}: {}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .replace("\r\n", "\n")
+}
+
+#[test]
+#[cfg(feature = "netcore3_0")]
+fn custom_delegate_type() {
+    assert_eq!(
+        run_example("custom-delegate-type"),
+        "1 + 2 = 3\nHello from C#!\nHello from C#!\n"
+    );
+}
+
+#[test]
+#[cfg(feature = "net5_0")]
+fn generic_method() {
+    assert_eq!(
+        run_example("generic-method"),
+        "Max(1, 2) = 2\nMax(1.5, 0.5) = 1.5\n"
+    );
+}
+
+#[test]
+#[cfg(feature = "net5_0")]
+fn get_function_pointer() {
+    assert_eq!(
+        run_example("get-function-pointer"),
+        "Hello from C#!\nHello from Main!\n"
+    );
+}
+
+#[test]
+#[cfg(feature = "net8_0")]
+fn load_assembly() {
+    assert_eq!(run_example("load-assembly"), "Hello from C#!\n");
+}
+
+#[test]
+#[cfg(feature = "net8_0")]
+fn load_assembly_bytes() {
+    assert_eq!(run_example("load-assembly-bytes"), "Hello from C#!\n");
+}
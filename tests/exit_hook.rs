@@ -0,0 +1,80 @@
+#![cfg(feature = "netcore3_0")]
+
+use netcorehost::{
+    hostfxr::{self, HostfxrContext, InitializedForRuntimeConfig},
+    nethost,
+};
+use std::{env, fs, path::PathBuf, process::Command, sync::Mutex};
+
+#[path = "common.rs"]
+mod common;
+
+const MARKER_ENV_VAR: &str = "NETCOREHOST_TEST_EXIT_HOOK_MARKER";
+
+static CONTEXT: Mutex<Option<HostfxrContext<InitializedForRuntimeConfig>>> = Mutex::new(None);
+
+// Run by `exit_hook_runs_on_exit` in a separate process, as the hook only runs when the process exits.
+#[test]
+#[ignore]
+fn exit_hook_child() {
+    let Some(marker) = env::var_os(MARKER_ENV_VAR).map(PathBuf::from) else {
+        return;
+    };
+    common::setup();
+
+    hostfxr::enable_exit_hook();
+    hostfxr::on_process_exit(move || {
+        let closed = CONTEXT
+            .lock()
+            .unwrap()
+            .take()
+            .map(|context| context.close());
+        let status = match closed {
+            Some(Ok(_)) => "closed",
+            Some(Err(_)) => "failed",
+            None => "missing",
+        };
+        fs::write(
+            marker,
+            format!("{status} {}", hostfxr::is_process_exiting()),
+        )
+        .unwrap();
+    });
+
+    let hostfxr = nethost::load_hostfxr().unwrap();
+    let context = hostfxr
+        .initialize_for_runtime_config(common::test_runtime_config_path())
+        .unwrap();
+    context.get_delegate_loader().unwrap();
+    *CONTEXT.lock().unwrap() = Some(context);
+    assert!(!hostfxr::is_process_exiting());
+}
+
+#[test]
+fn exit_hook_runs_on_exit() {
+    common::setup();
+
+    let marker = env::temp_dir().join(format!("netcorehost-exit-hook-{}", std::process::id()));
+    let _ = fs::remove_file(&marker);
+
+    let status = Command::new(env::current_exe().unwrap())
+        .args([
+            "exit_hook_child",
+            "--exact",
+            "--ignored",
+            "--test-threads=1",
+        ])
+        .env(MARKER_ENV_VAR, &marker)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let content = fs::read_to_string(&marker).unwrap();
+    let _ = fs::remove_file(&marker);
+    assert_eq!(content, "closed false");
+}
+
+#[test]
+fn not_exiting_by_default() {
+    assert!(!hostfxr::is_process_exiting());
+}
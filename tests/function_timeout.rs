@@ -0,0 +1,100 @@
+#![cfg(feature = "netcore3_0")]
+
+use std::{ptr, time::Duration};
+
+use netcorehost::{
+    hostfxr::{GetManagedFunctionError, GetManagedFunctionTimeoutError},
+    nethost, pdcstr,
+};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn get_function_with_timeout_resolves_function() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::test_dll_path())
+            .unwrap();
+
+        let hello = fn_loader
+            .get_function_with_default_signature_with_timeout(
+                pdcstr!("Test.Program, Test"),
+                pdcstr!("Hello"),
+                Duration::from_secs(60),
+            )
+            .unwrap();
+        let result = unsafe { hello(ptr::null(), 0) };
+        assert_eq!(result, 42);
+    }
+
+    #[test]
+    fn get_function_with_timeout_propagates_errors() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::test_dll_path())
+            .unwrap();
+
+        let error = fn_loader
+            .get_function_with_default_signature_with_timeout(
+                pdcstr!("Test.Program, Test"),
+                pdcstr!("DoesNotExist"),
+                Duration::from_secs(60),
+            )
+            .err()
+            .unwrap();
+        assert!(!error.is_timeout());
+        assert!(matches!(
+            error,
+            GetManagedFunctionTimeoutError::Failed(GetManagedFunctionError::MissingMethod)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "net5_0")]
+    fn get_function_with_timeout_times_out() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+        let delay_assembly_resolution = fn_loader
+            .get_function_with_unmanaged_callers_only::<fn(i32)>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("DelayAssemblyResolution"),
+            )
+            .unwrap();
+        delay_assembly_resolution(5000);
+
+        let error = fn_loader
+            .get_function_with_default_signature_with_timeout(
+                pdcstr!("SlowAssembly.Program, SlowAssembly"),
+                pdcstr!("Hello"),
+                Duration::from_millis(100),
+            )
+            .err()
+            .unwrap();
+        assert!(error.is_timeout());
+        assert!(matches!(
+            error,
+            GetManagedFunctionTimeoutError::TimedOut { timeout }
+                if timeout == Duration::from_millis(100)
+        ));
+    }
+}
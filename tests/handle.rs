@@ -0,0 +1,131 @@
+use netcorehost::handle::{HandleId, HandleTable};
+use std::sync::Arc;
+
+#[cfg(feature = "net5_0")]
+use netcorehost::{hostfxr::FfiSafe, nethost, pdcstr};
+#[cfg(feature = "net5_0")]
+use rusty_fork::rusty_fork_test;
+
+#[cfg(feature = "net5_0")]
+#[path = "common.rs"]
+mod common;
+
+#[test]
+fn insert_and_get() {
+    let table = HandleTable::new();
+    let first = table.insert(1);
+    let second = table.insert(2);
+
+    assert_ne!(first.id(), second.id());
+    assert!(!first.id().is_invalid());
+    assert_eq!(table.len(), 2);
+    assert_eq!(table.get(first.id()).as_deref(), Some(&1));
+    assert_eq!(*second.get(), 2);
+    assert_eq!(
+        table
+            .get(HandleId::from_raw(second.id().as_raw()))
+            .as_deref(),
+        Some(&2)
+    );
+}
+
+#[test]
+fn drop_revokes() {
+    let table = HandleTable::new();
+    let handle = table.insert("resource".to_string());
+    let id = handle.id();
+    assert!(table.contains(id));
+
+    drop(handle);
+    assert!(!table.contains(id));
+    assert!(table.get(id).is_none());
+    assert!(table.is_empty());
+}
+
+#[test]
+fn ids_are_not_reused() {
+    let table = HandleTable::new();
+    let id = table.insert(1).id();
+    let handle = table.insert(2);
+    assert_ne!(handle.id(), id);
+    assert!(table.get(id).is_none());
+}
+
+#[test]
+fn invalid_id_never_resolves() {
+    let table = HandleTable::new();
+    let _handle = table.insert(1);
+    assert!(table.get(HandleId::INVALID).is_none());
+    assert!(table.get(HandleId::default()).is_none());
+}
+
+#[test]
+fn revoke_returns_resource() {
+    let table = HandleTable::new();
+    let handle = table.insert(vec![1, 2, 3]);
+    let id = handle.id();
+    assert_eq!(handle.revoke(), Ok(vec![1, 2, 3]));
+    assert!(!table.contains(id));
+
+    let handle = table.insert(vec![4]);
+    let in_use = table.get(handle.id()).unwrap();
+    assert_eq!(handle.revoke(), Err(Arc::clone(&in_use)));
+    assert_eq!(*in_use, vec![4]);
+}
+
+#[test]
+fn static_table() {
+    static TABLE: HandleTable<u32> = HandleTable::new();
+
+    let handle = TABLE.insert(42);
+    let id = handle.id();
+    let lookup = std::thread::spawn(move || TABLE.get(id).map(|value| *value))
+        .join()
+        .unwrap();
+    assert_eq!(lookup, Some(42));
+}
+
+#[cfg(feature = "net5_0")]
+#[test]
+fn maps_to_ulong() {
+    assert_eq!(HandleId::csharp_type(), "ulong");
+}
+
+#[cfg(feature = "net5_0")]
+rusty_fork_test! {
+    #[test]
+    fn round_trip_through_managed_code() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+        let store_handle = fn_loader
+            .get_function_with_unmanaged_callers_only::<fn(HandleId)>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("StoreHandle"),
+            )
+            .unwrap();
+        let load_handle = fn_loader
+            .get_function_with_unmanaged_callers_only::<fn() -> HandleId>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("LoadHandle"),
+            )
+            .unwrap();
+
+        let table = HandleTable::new();
+        let _first = table.insert("first");
+        let second = table.insert("second");
+        store_handle(second.id());
+        let id = load_handle();
+        assert_eq!(id, second.id());
+        assert_eq!(table.get(id).as_deref(), Some(&"second"));
+
+        drop(second);
+        assert!(table.get(load_handle()).is_none());
+    }
+}
@@ -0,0 +1,51 @@
+#![cfg(all(feature = "nethost", feature = "net5_0"))]
+
+use netcorehost::{error::ErrorKind, host::RuntimeHostBuilder, hostfxr::RuntimeOptions, pdcstr};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn build_and_call() {
+        common::setup();
+
+        let host = RuntimeHostBuilder::new(common::test_runtime_config_path())
+            .property(pdcstr!("TEST_PROPERTY"), pdcstr!("TEST_VALUE"))
+            .runtime_options(RuntimeOptions::new().tiered_compilation(false))
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            host.context()
+                .get_runtime_property_value(pdcstr!("TEST_PROPERTY"))
+                .unwrap(),
+            pdcstr!("TEST_VALUE")
+        );
+        assert_eq!(
+            host.context()
+                .get_runtime_property_value(pdcstr!("System.Runtime.TieredCompilation"))
+                .unwrap(),
+            pdcstr!("false")
+        );
+
+        let hello = host
+            .delegate_loader_for_assembly(common::test_dll_path())
+            .unwrap()
+            .get_function_with_unmanaged_callers_only::<fn() -> i32>(
+                pdcstr!("Test.Program, Test"),
+                pdcstr!("UnmanagedHello"),
+            )
+            .unwrap();
+        assert_eq!(hello(), 42);
+    }
+
+    #[test]
+    fn missing_runtime_config() {
+        let error = RuntimeHostBuilder::new(pdcstr!("does-not-exist.runtimeconfig.json"))
+            .build()
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Hosting);
+    }
+}
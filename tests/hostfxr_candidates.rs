@@ -0,0 +1,97 @@
+#![cfg(feature = "netcore3_0")]
+
+use std::{
+    env::consts::{DLL_PREFIX, DLL_SUFFIX},
+    fs,
+};
+
+use netcorehost::{
+    dotnet_cli::DotnetLocator,
+    hostfxr::{HostfxrCandidates, LoadHostfxrVersionError, RuntimeVersionReq},
+    pdcstr,
+    scratch::ScratchDir,
+};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+fn req(s: &str) -> RuntimeVersionReq {
+    s.parse().unwrap()
+}
+
+#[test]
+fn enumerate_installed_versions() {
+    let root = ScratchDir::new().unwrap();
+    let fxr_dir = root.join("host").join("fxr");
+    for version in ["8.0.10", "6.0.0", "9.0.0-rc.1", "8.0.2", "not-a-version"] {
+        let dir = fxr_dir.join(version);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(format!("{DLL_PREFIX}hostfxr{DLL_SUFFIX}")), "").unwrap();
+    }
+    // versions without the library are skipped.
+    fs::create_dir_all(fxr_dir.join("7.0.0")).unwrap();
+
+    let candidates = HostfxrCandidates::enumerate(root.path()).unwrap();
+    assert_eq!(candidates.dotnet_root(), root.path());
+    let versions = candidates
+        .iter()
+        .map(|candidate| candidate.version())
+        .collect::<Vec<_>>();
+    assert_eq!(versions, ["6.0.0", "8.0.2", "8.0.10", "9.0.0-rc.1"]);
+    assert!(candidates
+        .iter()
+        .all(|candidate| candidate.path().starts_with(&fxr_dir)));
+
+    assert_eq!(candidates.latest().unwrap().version(), "9.0.0-rc.1");
+    assert_eq!(candidates.find(&req("8.0")).unwrap().version(), "8.0.10");
+    assert_eq!(candidates.find(&req("=6")).unwrap().version(), "6.0.0");
+    assert!(candidates.find(&req("^7.0")).is_none());
+
+    match candidates.load_version(&req("^7.0")) {
+        Err(LoadHostfxrVersionError::NoMatchingVersion {
+            required,
+            available,
+        }) => {
+            assert_eq!(required, "^7.0");
+            assert_eq!(available, ["6.0.0", "8.0.2", "8.0.10", "9.0.0-rc.1"]);
+        }
+        other => panic!("unexpected result {other:?}"),
+    }
+}
+
+#[test]
+fn enumerate_without_host_dir() {
+    let root = ScratchDir::new().unwrap();
+    let candidates = HostfxrCandidates::enumerate(root.path()).unwrap();
+    assert!(candidates.is_empty());
+    assert!(candidates.latest().is_none());
+}
+
+rusty_fork_test! {
+    #[test]
+    fn load_installed_version() {
+        common::setup();
+
+        let dotnet_root = DotnetLocator::new().locate_root().unwrap();
+        let candidates = HostfxrCandidates::enumerate(dotnet_root).unwrap();
+        assert!(!candidates.is_empty());
+
+        let hostfxr = candidates.load_version(&req("*")).unwrap();
+
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::test_dll_path())
+            .unwrap();
+        let hello = fn_loader
+            .get_function_with_default_signature(
+                pdcstr!("Test.Program, Test"),
+                pdcstr!("Hello"),
+            )
+            .unwrap();
+        let result = unsafe { hello(std::ptr::null(), 0) };
+        assert_eq!(result, 42);
+    }
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "netcore3_0")]
+
+use netcorehost::{
+    hostfxr::HostfxrParameters,
+    nethost, pdcstr,
+    pdcstring::{PdCStr, PdCString},
+};
+use rusty_fork::rusty_fork_test;
+use std::{iter, ptr};
+
+#[path = "common.rs"]
+mod common;
+
+fn parameters_for(hostfxr: &netcorehost::hostfxr::Hostfxr) -> HostfxrParameters {
+    let dotnet_root = PdCString::from_os_str(hostfxr.get_dotnet_root()).unwrap();
+    let host_path = PdCString::from_os_str(std::env::current_exe().unwrap()).unwrap();
+    HostfxrParameters::new()
+        .dotnet_root(dotnet_root)
+        .host_path(host_path)
+}
+
+rusty_fork_test! {
+    #[test]
+    fn runtime_config_with_params() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config_with_params(
+                common::test_runtime_config_path(),
+                &parameters_for(&hostfxr),
+            )
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::test_dll_path())
+            .unwrap();
+        let hello = fn_loader
+            .get_function_with_default_signature(pdcstr!("Test.Program, Test"), pdcstr!("Hello"))
+            .unwrap();
+        let result = unsafe { hello(ptr::null(), 0) };
+        assert_eq!(result, 42);
+    }
+
+    #[test]
+    fn command_line_with_params() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_dotnet_command_line_with_params(
+                common::test_dll_path(),
+                iter::empty::<&PdCStr>(),
+                &parameters_for(&hostfxr),
+            )
+            .unwrap();
+        assert_eq!(context.run_app().value(), 42);
+    }
+}
+
+#[test]
+fn parameters_builder() {
+    let parameters = HostfxrParameters::new();
+    assert_eq!(parameters.get_host_path(), None);
+    assert_eq!(parameters.get_dotnet_root(), None);
+
+    let parameters = parameters.dotnet_root(pdcstr!("/opt/dotnet"));
+    assert_eq!(parameters.get_dotnet_root(), Some(pdcstr!("/opt/dotnet")));
+    assert_eq!(parameters.get_host_path(), None);
+}
@@ -0,0 +1,54 @@
+#![cfg(all(feature = "serde-bridge", feature = "net5_0"))]
+
+use netcorehost::{hostfxr::JsonCallError, nethost, pdcstr};
+use rusty_fork::rusty_fork_test;
+use serde::{Deserialize, Serialize};
+
+#[path = "common.rs"]
+mod common;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+rusty_fork_test! {
+    #[test]
+    fn call_json_function() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+
+        let add_points = fn_loader
+            .get_json_function::<[Point], Point>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("AddJsonPoints"),
+            )
+            .unwrap();
+        let sum = add_points
+            .call(&[Point { x: 1, y: 2 }, Point { x: 3, y: 4 }])
+            .unwrap();
+        assert_eq!(sum, Point { x: 4, y: 6 });
+
+        let fail = fn_loader
+            .get_json_function::<Point, Point>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("FailJson"),
+            )
+            .unwrap();
+        match fail.call(&Point { x: 0, y: 0 }) {
+            Err(JsonCallError::Managed { code, message }) => {
+                assert_eq!(code, -1);
+                assert_eq!(message, "invalid point");
+            }
+            other => panic!("unexpected result {other:?}"),
+        }
+    }
+}
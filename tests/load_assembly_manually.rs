@@ -1,6 +1,6 @@
 #![cfg(feature = "net8_0")]
 
-use netcorehost::{nethost, pdcstr};
+use netcorehost::{error::HostingError, nethost, pdcstr};
 use rusty_fork::rusty_fork_test;
 use std::fs;
 
@@ -66,4 +66,87 @@ rusty_fork_test! {
         let result = hello();
         assert_eq!(result, 42);
     }
+
+    #[test]
+    fn load_from_path_with_delegate_loader() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let delegate_loader = context.get_delegate_loader().unwrap();
+
+        delegate_loader
+            .load_assembly(common::library_dll_path())
+            .unwrap();
+
+        let hello = delegate_loader
+            .get_function_with_unmanaged_callers_only::<fn() -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("Hello"),
+            )
+            .unwrap();
+
+        let result = hello();
+        assert_eq!(result, 42);
+    }
+
+    #[test]
+    fn load_from_bytes_with_delegate_loader() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let delegate_loader = context.get_delegate_loader().unwrap();
+
+        let assembly_bytes = fs::read(common::library_dll_path().to_os_string()).unwrap();
+
+        delegate_loader
+            .load_assembly_from_bytes(&assembly_bytes, None)
+            .unwrap();
+
+        let hello = delegate_loader
+            .get_function_with_unmanaged_callers_only::<fn() -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("Hello"),
+            )
+            .unwrap();
+
+        let result = hello();
+        assert_eq!(result, 42);
+    }
+
+    #[test]
+    #[cfg(not(feature = "strict-checks"))]
+    fn delegate_loader_resolves_load_assembly_lazily() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let delegate_loader = context.get_delegate_loader().unwrap();
+        let other_loader = delegate_loader.clone();
+
+        delegate_loader
+            .load_assembly(common::library_dll_path())
+            .unwrap();
+        context.close().unwrap();
+
+        // resolved before the context was closed, so still usable.
+        other_loader
+            .load_assembly(common::library_dll_path())
+            .unwrap();
+        let assembly_bytes = fs::read(common::library_dll_path().to_os_string()).unwrap();
+        assert_eq!(
+            other_loader.load_assembly_from_bytes(&assembly_bytes, None),
+            Err(HostingError::HostInvalidState)
+        );
+    }
 }
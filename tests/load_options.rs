@@ -0,0 +1,89 @@
+#![cfg(feature = "nethost")]
+
+use netcorehost::{
+    hostfxr::HostfxrLoadOptions,
+    nethost::{self, LoadHostfxrError},
+    pdcstring::PdCString,
+};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+#[test]
+fn default_options() {
+    let options = HostfxrLoadOptions::new();
+    assert_eq!(options, HostfxrLoadOptions::default());
+    assert!(!options.suppresses_error_dialogs());
+    assert!(!options.captures_error_messages());
+    assert_eq!(options.get_library_name(), None);
+
+    let options = options.suppress_error_dialogs(true);
+    assert!(options.suppresses_error_dialogs());
+}
+
+#[cfg(windows)]
+#[link(name = "kernel32")]
+extern "system" {
+    fn GetThreadErrorMode() -> u32;
+    fn SetThreadErrorMode(new_mode: u32, old_mode: *mut u32) -> i32;
+}
+
+rusty_fork_test! {
+    #[test]
+    fn custom_library_name() {
+        common::setup();
+
+        let options = HostfxrLoadOptions::new().library_name("hostfxr_does_not_exist");
+        let result = nethost::load_hostfxr_with_options(&options);
+        assert!(matches!(result, Err(LoadHostfxrError::DlOpen(_))));
+
+        let hostfxr_path = nethost::get_hostfxr_path().unwrap();
+        let file_name = std::path::Path::new(&hostfxr_path).file_name().unwrap();
+        let options = HostfxrLoadOptions::new().library_name(file_name);
+        assert_eq!(options.get_library_name(), Some(file_name));
+        nethost::load_hostfxr_with_options(&options).unwrap();
+    }
+
+    #[test]
+    fn options_are_used_by_all_load_functions() {
+        common::setup();
+
+        let options = HostfxrLoadOptions::new().suppress_error_dialogs(false);
+        let dotnet_root = nethost::load_hostfxr().unwrap().get_dotnet_root();
+        let dotnet_root = PdCString::from_os_str(dotnet_root).unwrap();
+        nethost::load_hostfxr_with_dotnet_root_with_options(&dotnet_root, &options).unwrap();
+        nethost::load_hostfxr_with_assembly_path_with_options(common::test_dll_path(), &options)
+            .unwrap();
+
+        let options = options.library_name("hostfxr_does_not_exist");
+        assert!(matches!(
+            nethost::load_hostfxr_with_dotnet_root_with_options(&dotnet_root, &options),
+            Err(LoadHostfxrError::DlOpen(_))
+        ));
+        assert!(matches!(
+            nethost::load_hostfxr_with_assembly_path_with_options(
+                common::test_dll_path(),
+                &options
+            ),
+            Err(LoadHostfxrError::DlOpen(_))
+        ));
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn restores_error_mode() {
+        const SEM_NOGPFAULTERRORBOX: u32 = 0x0002;
+        common::setup();
+
+        unsafe { SetThreadErrorMode(SEM_NOGPFAULTERRORBOX, std::ptr::null_mut()) };
+
+        let options = HostfxrLoadOptions::new().suppress_error_dialogs(true);
+        nethost::load_hostfxr_with_options(&options).unwrap();
+        assert_eq!(unsafe { GetThreadErrorMode() }, SEM_NOGPFAULTERRORBOX);
+
+        let options = options.library_name("hostfxr_does_not_exist");
+        assert!(nethost::load_hostfxr_with_options(&options).is_err());
+        assert_eq!(unsafe { GetThreadErrorMode() }, SEM_NOGPFAULTERRORBOX);
+    }
+}
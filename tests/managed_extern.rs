@@ -0,0 +1,44 @@
+#![cfg(feature = "net5_0")]
+
+use netcorehost::{hostfxr::GetManagedFunctionError, managed_extern, nethost};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+managed_extern! {
+    struct Library for "ClassLibrary.Library, ClassLibrary" {
+        fn hello() -> i32 = "Hello";
+        fn add_unmanaged(a: i32, b: i32) -> i32 = "AddUnmanaged";
+        fn add(a: i32, b: i32) -> i32 = "Add" as "ClassLibrary.Library+AddDelegate, ClassLibrary";
+        fn missing() = "Missing";
+    }
+}
+
+rusty_fork_test! {
+    #[test]
+    fn calls_declared_methods() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let library = Library::new(
+            context
+                .get_delegate_loader_for_assembly(common::library_dll_path())
+                .unwrap(),
+        );
+
+        assert_eq!(library.hello().unwrap(), 42);
+        assert_eq!(library.add_unmanaged(1, 2).unwrap(), 3);
+        assert_eq!(library.add(3, 4).unwrap(), 7);
+        assert_eq!(library.add(5, 6).unwrap(), 11);
+        assert!(matches!(
+            library.missing(),
+            Err(GetManagedFunctionError::MissingMethod)
+        ));
+        assert!(library.resolve_all().is_err());
+        assert!(library.loader().is_loaded());
+    }
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "netcore3_0")]
+
+use netcorehost::hostfxr;
+
+#[test]
+fn managed_helpers_are_up_to_date() {
+    let helpers = [
+        (
+            "tests/ClassLibrary/NativeStrings.cs",
+            include_str!("ClassLibrary/NativeStrings.cs"),
+            hostfxr::MANAGED_STRING_HELPERS,
+        ),
+        #[cfg(feature = "net5_0")]
+        (
+            "tests/ClassLibrary/NativeWarmup.cs",
+            include_str!("ClassLibrary/NativeWarmup.cs"),
+            hostfxr::MANAGED_WARMUP_HELPERS,
+        ),
+        #[cfg(feature = "net5_0")]
+        (
+            "tests/ClassLibrary/NativeInitializer.cs",
+            include_str!("ClassLibrary/NativeInitializer.cs"),
+            hostfxr::MANAGED_INITIALIZER_HELPERS,
+        ),
+        #[cfg(feature = "net5_0")]
+        (
+            "tests/ClassLibrary/NativeMethodBinding.cs",
+            include_str!("ClassLibrary/NativeMethodBinding.cs"),
+            hostfxr::MANAGED_METHOD_BINDING_HELPERS,
+        ),
+        #[cfg(feature = "net5_0")]
+        (
+            "examples/generic-method/ExampleProject/NativeMethodBinding.cs",
+            include_str!("../examples/generic-method/ExampleProject/NativeMethodBinding.cs"),
+            hostfxr::MANAGED_METHOD_BINDING_HELPERS,
+        ),
+        #[cfg(feature = "net5_0")]
+        (
+            "tests/ClassLibrary/NativeConsole.cs",
+            include_str!("ClassLibrary/NativeConsole.cs"),
+            hostfxr::MANAGED_CONSOLE_INTERCEPTOR,
+        ),
+        #[cfg(feature = "net5_0")]
+        (
+            "tests/ClassLibrary/NativeDebugger.cs",
+            include_str!("ClassLibrary/NativeDebugger.cs"),
+            hostfxr::MANAGED_DEBUGGER_HELPERS,
+        ),
+        #[cfg(all(feature = "serde-bridge", feature = "net5_0"))]
+        (
+            "tests/ClassLibrary/JsonBridge.cs",
+            include_str!("ClassLibrary/JsonBridge.cs"),
+            hostfxr::MANAGED_JSON_BRIDGE,
+        ),
+    ];
+
+    for (file, source, expected) in helpers {
+        assert_eq!(source, expected, "{file} is out of date");
+    }
+}
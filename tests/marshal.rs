@@ -0,0 +1,81 @@
+use netcorehost::marshal::{utf16_from_parts, utf16_from_ptr, Utf16String};
+
+#[cfg(feature = "net5_0")]
+use netcorehost::{
+    marshal::{ManagedAllocator, ManagedUtf16String},
+    nethost, pdcstr,
+};
+#[cfg(feature = "net5_0")]
+use rusty_fork::rusty_fork_test;
+
+#[cfg(feature = "net5_0")]
+#[path = "common.rs"]
+mod common;
+
+#[test]
+fn utf16_string_round_trip() {
+    let value = "häll😀";
+    let string = Utf16String::new(value);
+    assert_eq!(string.len(), value.encode_utf16().count());
+    assert_eq!(string.as_slice_with_nul().last(), Some(&0));
+    assert_eq!(unsafe { utf16_from_ptr(string.as_ptr()) }.unwrap(), value);
+    assert_eq!(
+        unsafe { utf16_from_parts(string.as_ptr(), string.len() as i32) }.unwrap(),
+        value
+    );
+    assert_eq!(format!("{string:?}"), format!("{value:?}"));
+}
+
+#[test]
+fn empty_utf16_string() {
+    let string = Utf16String::default();
+    assert!(string.is_empty());
+    assert_eq!(string.as_slice_with_nul(), [0]);
+    assert_eq!(unsafe { utf16_from_ptr(string.as_ptr()) }.unwrap(), "");
+    assert_eq!(
+        unsafe { utf16_from_parts(std::ptr::null(), 0) }.unwrap(),
+        ""
+    );
+}
+
+#[test]
+fn invalid_utf16() {
+    let unpaired_surrogate = [0xD800u16, 0];
+    assert!(unsafe { utf16_from_ptr(unpaired_surrogate.as_ptr()) }.is_err());
+}
+
+#[cfg(feature = "net5_0")]
+rusty_fork_test! {
+    #[test]
+    fn take_ownership_of_managed_strings() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+        let reverse = fn_loader
+            .get_function_with_unmanaged_callers_only::<fn(*const u16, i32) -> *mut u16>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("ReverseUtf16"),
+            )
+            .unwrap();
+
+        let value = Utf16String::new("hello");
+        for (allocator, use_co_task_mem) in [
+            (ManagedAllocator::HGlobal, 0),
+            (ManagedAllocator::CoTaskMem, 1),
+        ] {
+            let reversed = unsafe {
+                ManagedUtf16String::from_raw(reverse(value.as_ptr(), use_co_task_mem), allocator)
+            }
+            .unwrap();
+            assert_eq!(reversed.allocator(), allocator);
+            assert_eq!(reversed.to_string_lossy(), "olleh");
+            assert_eq!(reversed.into_string().unwrap(), "olleh");
+        }
+    }
+}
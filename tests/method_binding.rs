@@ -0,0 +1,121 @@
+#![cfg(feature = "net5_0")]
+
+use netcorehost::{hostfxr::GetManagedFunctionError, nethost, pdcstr};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn get_generic_function() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+        let helper = pdcstr!("NativeMethodBinding, ClassLibrary");
+        let library = pdcstr!("ClassLibrary.Library, ClassLibrary");
+
+        let max_int = fn_loader
+            .get_function_generic::<fn(i32, i32) -> i32>(
+                helper,
+                library,
+                pdcstr!("Max"),
+                &[pdcstr!("System.Int32")],
+            )
+            .unwrap();
+        assert_eq!(max_int(1, 2), 2);
+        let max_double = fn_loader
+            .get_function_generic::<fn(f64, f64) -> f64>(
+                helper,
+                library,
+                pdcstr!("Max"),
+                &[pdcstr!("System.Double")],
+            )
+            .unwrap();
+        assert_eq!(max_double(1.5, 0.5), 1.5);
+        let count_type_arguments = fn_loader
+            .get_function_generic::<fn() -> i32>(
+                helper,
+                library,
+                pdcstr!("CountTypeArguments"),
+                &[pdcstr!("System.Int32"), pdcstr!("System.String")],
+            )
+            .unwrap();
+        assert_eq!(count_type_arguments(), 2);
+
+        assert_eq!(
+            fn_loader
+                .get_function_generic::<fn() -> i32>(
+                    helper,
+                    library,
+                    pdcstr!("CountTypeArguments"),
+                    &[pdcstr!("System.Int32")],
+                )
+                .unwrap_err(),
+            GetManagedFunctionError::MissingMethod
+        );
+        assert_eq!(
+            fn_loader
+                .get_function_generic::<fn(i32, i32) -> i32>(
+                    helper,
+                    library,
+                    pdcstr!("Max"),
+                    &[pdcstr!("System.DoesNotExist")],
+                )
+                .unwrap_err(),
+            GetManagedFunctionError::TypeNotFound
+        );
+    }
+
+    #[test]
+    fn get_function_by_signature() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+        let helper = pdcstr!("NativeMethodBinding, ClassLibrary");
+        let library = pdcstr!("ClassLibrary.Library, ClassLibrary");
+
+        let scale_int = fn_loader
+            .get_function_by_signature::<fn(i32) -> i32>(
+                helper,
+                library,
+                pdcstr!("Scale"),
+                &[pdcstr!("System.Int32")],
+            )
+            .unwrap();
+        assert_eq!(scale_int(21), 42);
+        let scale_double = fn_loader
+            .get_function_by_signature::<fn(f64) -> f64>(
+                helper,
+                library,
+                pdcstr!("Scale"),
+                &[pdcstr!("System.Double")],
+            )
+            .unwrap();
+        assert_eq!(scale_double(3.0), 1.5);
+
+        assert_eq!(
+            fn_loader
+                .get_function_by_signature::<fn(i64) -> i64>(
+                    helper,
+                    library,
+                    pdcstr!("Scale"),
+                    &[pdcstr!("System.Int64")],
+                )
+                .unwrap_err(),
+            GetManagedFunctionError::MissingMethod
+        );
+    }
+}
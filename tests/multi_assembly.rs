@@ -0,0 +1,89 @@
+#![cfg(feature = "net5_0")]
+
+use netcorehost::{hostfxr::GetManagedFunctionError, nethost, pdcstr};
+use rusty_fork::rusty_fork_test;
+use std::ptr;
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn loads_functions_from_multiple_assemblies() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let loader = context.get_multi_assembly_delegate_loader().unwrap();
+
+        let hello = loader
+            .for_assembly(common::test_dll_path())
+            .get_function_with_default_signature(pdcstr!("Test.Program, Test"), pdcstr!("Hello"))
+            .unwrap();
+        let result = unsafe { hello(ptr::null(), 0) };
+        assert_eq!(result, 42);
+
+        let library_hello = loader
+            .for_assembly(common::library_dll_path())
+            .get_function_with_unmanaged_callers_only::<fn() -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("Hello"),
+            )
+            .unwrap();
+        assert_eq!(library_hello(), 42);
+
+        assert_eq!(loader.assemblies().len(), 2);
+    }
+
+    #[test]
+    fn caches_resolved_functions() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let loader = context.get_multi_assembly_delegate_loader().unwrap();
+
+        let first = loader
+            .for_assembly(common::test_dll_path())
+            .get_function_with_default_signature(pdcstr!("Test.Program, Test"), pdcstr!("Hello"))
+            .unwrap();
+        let second = loader
+            .for_assembly(common::test_dll_path())
+            .get_function_with_default_signature(pdcstr!("Test.Program, Test"), pdcstr!("Hello"))
+            .unwrap();
+        assert_eq!(*first as usize, *second as usize);
+    }
+
+    #[test]
+    fn preload_reports_per_assembly() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let loader = context.get_multi_assembly_delegate_loader().unwrap();
+
+        let reports = loader.preload([(
+            common::test_dll_path(),
+            [
+                (pdcstr!("Test.Program, Test"), pdcstr!("Hello")),
+                (pdcstr!("Test.Program, Test"), pdcstr!("DoesNotExist")),
+            ],
+        )]);
+
+        assert_eq!(reports.len(), 1);
+        let (assembly_path, report) = &reports[0];
+        assert_eq!(assembly_path, &common::test_dll_path());
+        assert_eq!(report.entries().len(), 2);
+        assert!(report.entries()[0].is_ok());
+        assert_eq!(
+            report.entries()[1].error(),
+            Some(&GetManagedFunctionError::MissingMethod)
+        );
+    }
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "net5_0")]
+
+use netcorehost::{
+    hostfxr::{AssemblyDelegateLoader, ManagedLibrary, UnmanagedExport, UnmanagedFunctionLoader},
+    nativeaot::NativeAotLibrary,
+    nethost, pdcstr,
+    pdcstring::{PdCStr, PdCString},
+};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+fn add_export() -> UnmanagedExport<'static> {
+    UnmanagedExport::new(
+        pdcstr!("ClassLibrary.Library, ClassLibrary"),
+        pdcstr!("AddUnmanaged"),
+        "add_unmanaged",
+    )
+}
+
+fn call_add(loader: &impl UnmanagedFunctionLoader) -> i32 {
+    let add = loader
+        .get_unmanaged_function::<fn(i32, i32) -> i32>(&add_export())
+        .map_err(|err| err.to_string())
+        .unwrap();
+    add(40, 2)
+}
+
+fn open_and_call_add<L: ManagedLibrary>(host: &L::Host, path: &PdCStr) -> i32 {
+    let library = L::open(host, path).unwrap();
+    let result = call_add(&library);
+    library.close().unwrap();
+    result
+}
+
+rusty_fork_test! {
+    #[test]
+    fn hostfxr_loader_resolves_export() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+
+        assert_eq!(call_add(&fn_loader), 42);
+    }
+
+    #[test]
+    fn hostfxr_backend_as_managed_library() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+
+        let result = open_and_call_add::<AssemblyDelegateLoader>(
+            &context,
+            &common::library_dll_path(),
+        );
+        assert_eq!(result, 42);
+    }
+
+    #[test]
+    fn nativeaot_library_calls_export() {
+        common::build_nativeaot_library();
+
+        let library = NativeAotLibrary::load(common::nativeaot_library_path()).unwrap();
+        let get_answer = library.get_function::<fn() -> i32>("get_answer").unwrap();
+        assert_eq!(get_answer(), 42);
+        assert_eq!(call_add(&library), 42);
+        assert!(library.get_function::<fn() -> i32>("missing_export").is_err());
+
+        // the library is not unloaded, so functions stay valid after closing it.
+        let add = library
+            .get_unmanaged_function::<fn(i32, i32) -> i32>(&add_export())
+            .unwrap();
+        library.close().unwrap();
+        assert_eq!(add(1, 2), 3);
+    }
+
+    #[test]
+    fn nativeaot_backend_as_managed_library() {
+        common::build_nativeaot_library();
+
+        let path = PdCString::from_os_str(common::nativeaot_library_path()).unwrap();
+        let result = open_and_call_add::<NativeAotLibrary>(&(), &path);
+        assert_eq!(result, 42);
+    }
+}
+
+#[test]
+fn nativeaot_load_missing_library() {
+    assert!(NativeAotLibrary::load("does/not/exist/NativeAotLibrary.so").is_err());
+    assert!(<NativeAotLibrary as ManagedLibrary>::open(
+        &(),
+        pdcstr!("does/not/exist/NativeAotLibrary.so")
+    )
+    .is_err());
+}
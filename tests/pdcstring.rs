@@ -0,0 +1,184 @@
+use std::{borrow::Cow, ffi::OsStr, str::FromStr, sync::Arc};
+
+use netcorehost::{
+    pdcstr,
+    pdcstring::{PdCString, PdCStringInterner},
+};
+
+#[test]
+fn to_string_cow() {
+    let s = pdcstr!("some ascii text");
+    let cow = s.to_string_cow().unwrap();
+    assert_eq!(cow, "some ascii text");
+    #[cfg(not(windows))]
+    assert!(matches!(cow, Cow::Borrowed(_)));
+}
+
+#[test]
+fn to_os_str_cow() {
+    let s = pdcstr!("some text");
+    let cow = s.to_os_str_cow();
+    assert_eq!(cow, OsStr::new("some text"));
+    #[cfg(not(windows))]
+    assert!(matches!(cow, Cow::Borrowed(_)));
+}
+
+#[test]
+fn from_os_str_cow() {
+    let owned = PdCString::from_os_str_cow(OsStr::new("some text")).unwrap();
+    assert!(matches!(owned, Cow::Owned(_)));
+    assert_eq!(owned.as_ref(), pdcstr!("some text"));
+
+    #[cfg(not(windows))]
+    {
+        let with_nul = PdCString::from_os_str_cow(OsStr::new("some text\0")).unwrap();
+        assert!(matches!(with_nul, Cow::Borrowed(_)));
+        assert_eq!(with_nul.to_string_lossy(), "some text");
+    }
+
+    assert!(PdCString::from_os_str_cow(OsStr::new("some\0text")).is_err());
+}
+
+#[test]
+fn eq_str_and_os_str() {
+    let s = pdcstr!("System.Runtime");
+    assert_eq!(*s, *"System.Runtime");
+    assert_eq!(*"System.Runtime", *s);
+    assert_ne!(*s, *"System.Runtim");
+    assert_ne!(*s, *"System.Runtime.Extra");
+    assert_eq!(*s, *OsStr::new("System.Runtime"));
+    assert_eq!(*OsStr::new("System.Runtime"), *s);
+
+    let owned = s.to_owned();
+    assert_eq!(owned, "System.Runtime");
+    assert_eq!(owned, *OsStr::new("System.Runtime"));
+}
+
+#[test]
+fn eq_non_ascii() {
+    let s = PdCString::from_str("Grüße, 世界").unwrap();
+    assert_eq!(*s, *"Grüße, 世界");
+    assert_ne!(*s, *"Grusse, 世界");
+}
+
+#[test]
+fn eq_ignore_ascii_case() {
+    let s = pdcstr!("APP_CONTEXT_BASE_DIRECTORY");
+    assert!(s.eq_ignore_ascii_case("app_context_base_directory"));
+    assert!(s.eq_ignore_ascii_case("App_Context_Base_Directory"));
+    assert!(!s.eq_ignore_ascii_case("app_context_base_dir"));
+
+    let s = PdCString::from_str("ÄPFEL").unwrap();
+    assert!(s.eq_ignore_ascii_case("ÄPFEL"));
+    assert!(s.eq_ignore_ascii_case("Äpfel"));
+    assert!(!s.eq_ignore_ascii_case("äpfel"));
+}
+
+#[test]
+fn starts_with() {
+    let s = pdcstr!("DOTNET_gcServer");
+    assert!(s.starts_with("DOTNET_"));
+    assert!(s.starts_with(""));
+    assert!(s.starts_with("DOTNET_gcServer"));
+    assert!(!s.starts_with("DOTNET_gcServer1"));
+    assert!(!s.starts_with("dotnet_"));
+}
+
+#[test]
+fn non_latin_round_trip() {
+    for text in [
+        "/opt/продукт/dotnet",
+        "C:\\Program Files\\应用程序\\dotnet",
+        "/home/ユーザー/.dotnet/shared/Microsoft.NETCore.App",
+        "/usr/share/مايكروسوفت/dotnet",
+        "emoji 🦀 path",
+    ] {
+        let s = PdCString::from_os_str(OsStr::new(text)).unwrap();
+        assert_eq!(s.to_os_string(), OsStr::new(text));
+        assert_eq!(s.to_string().unwrap(), text);
+        assert_eq!(s.to_string_lossy(), text);
+        assert_eq!(
+            s.to_string_utf8_lossy_with_report(),
+            (text.to_string(), false)
+        );
+        assert_eq!(*s, *text);
+
+        let s = PdCString::from_str(text).unwrap();
+        assert_eq!(s.to_os_string(), OsStr::new(text));
+    }
+}
+
+#[test]
+fn lossy_with_report() {
+    let s = PdCString::from_str("already contains \u{FFFD}").unwrap();
+    assert_eq!(
+        s.to_string_utf8_lossy_with_report(),
+        ("already contains \u{FFFD}".to_string(), false)
+    );
+
+    #[cfg(not(windows))]
+    let invalid = PdCString::from_vec(vec![b'a', 0xFF, b'b']).unwrap();
+    #[cfg(windows)]
+    let invalid = PdCString::from_vec(vec![u16::from(b'a'), 0xD800, u16::from(b'b')]).unwrap();
+    assert_eq!(
+        invalid.to_string_utf8_lossy_with_report(),
+        ("a\u{FFFD}b".to_string(), true)
+    );
+}
+
+#[test]
+fn display() {
+    let s = PdCString::from_str("/usr/share/dotnet").unwrap();
+    assert_eq!(s.display().to_string(), "/usr/share/dotnet");
+    assert_eq!(format!("{}", *s), "/usr/share/dotnet");
+    assert_eq!(format!("[{:>6}]", pdcstr!("abc").display()), "[   abc]");
+
+    #[cfg(not(windows))]
+    let invalid = PdCString::from_vec(vec![b'a', 0xFF, b'b']).unwrap();
+    #[cfg(windows)]
+    let invalid = PdCString::from_vec(vec![u16::from(b'a'), 0xD800, u16::from(b'b')]).unwrap();
+    assert_eq!(invalid.display().to_string(), "a\u{FFFD}b");
+    assert_eq!(format!("{}", *invalid), "a\u{FFFD}b");
+}
+
+#[test]
+#[cfg(not(windows))]
+fn os_string_round_trip() {
+    use netcorehost::pdcstring::other::PdCStringExt;
+    use std::ffi::OsString;
+
+    let s = PdCString::from_os_string(OsString::from("/usr/share/dotnet")).unwrap();
+    assert_eq!(&*s, pdcstr!("/usr/share/dotnet"));
+    let ptr = s.as_ptr();
+    let c_string = s.into_c_string();
+    assert_eq!(c_string.as_ptr(), ptr);
+    let s = PdCString::from_c_string(c_string);
+    assert_eq!(s.into_os_string(), OsString::from("/usr/share/dotnet"));
+
+    let err = PdCString::from_os_string(OsString::from("a\0b")).unwrap_err();
+    assert_eq!(err.nul_position(), 1);
+}
+
+#[test]
+fn interner_reuses_strings() {
+    let interner = PdCStringInterner::new();
+    let first = interner.intern("Test.Program, Test").unwrap();
+    let second = interner.intern("Test.Program, Test").unwrap();
+    let other = interner.intern("Hello").unwrap();
+
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(first.to_string_lossy(), "Test.Program, Test");
+    assert_eq!(other.to_string_lossy(), "Hello");
+    assert_eq!(interner.len(), 2);
+
+    interner.clear();
+    assert!(interner.is_empty());
+    assert_eq!(first.to_string_lossy(), "Test.Program, Test");
+}
+
+#[test]
+fn interner_rejects_interior_nul() {
+    let interner = PdCStringInterner::new();
+    assert!(interner.intern("a\0b").is_err());
+    assert!(interner.is_empty());
+}
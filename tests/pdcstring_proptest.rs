@@ -0,0 +1,154 @@
+use std::{ffi::OsStr, str::FromStr};
+
+use netcorehost::pdcstring::{PdCStr, PdCString, PdUChar};
+use proptest::prelude::*;
+
+#[cfg(windows)]
+fn to_units(s: &str) -> Vec<PdUChar> {
+    s.encode_utf16().collect()
+}
+
+#[cfg(not(windows))]
+fn to_units(s: &str) -> Vec<PdUChar> {
+    s.as_bytes().to_vec()
+}
+
+#[cfg(windows)]
+fn lossy(units: &[PdUChar]) -> String {
+    String::from_utf16_lossy(units)
+}
+
+#[cfg(not(windows))]
+fn lossy(units: &[PdUChar]) -> String {
+    String::from_utf8_lossy(units).into_owned()
+}
+
+fn without_nul() -> impl Strategy<Value = String> {
+    any::<String>().prop_map(|s| s.replace('\0', ""))
+}
+
+fn units_without_nul() -> impl Strategy<Value = Vec<PdUChar>> {
+    prop::collection::vec(1..=PdUChar::MAX, 0..64)
+}
+
+proptest! {
+    #[test]
+    fn str_round_trip(s in without_nul()) {
+        let pd = PdCString::from_str(&s).unwrap();
+        prop_assert_eq!(pd.to_string().unwrap(), s.as_str());
+        prop_assert_eq!(pd.to_string_cow().unwrap(), s.as_str());
+        prop_assert_eq!(pd.to_string_lossy(), s.as_str());
+        prop_assert_eq!(pd.to_string_utf8_lossy_with_report(), (s.clone(), false));
+        prop_assert_eq!(pd.display().to_string(), s.as_str());
+        prop_assert_eq!(pd.to_os_string(), OsStr::new(&s));
+        prop_assert!(pd.eq_ignore_ascii_case(&s.to_ascii_uppercase()));
+        prop_assert_eq!(&*pd, s.as_str());
+
+        let from_os = PdCString::from_os_str(OsStr::new(&s)).unwrap();
+        prop_assert_eq!(&from_os, &pd);
+        prop_assert_eq!(&*PdCString::from_os_str_cow(OsStr::new(&s)).unwrap(), &*pd);
+    }
+
+    #[test]
+    fn length_invariants(s in without_nul()) {
+        let pd = PdCString::from_str(&s).unwrap();
+        let units = to_units(&s);
+        prop_assert_eq!(pd.len(), units.len());
+        prop_assert_eq!(pd.is_empty(), units.is_empty());
+        prop_assert_eq!(pd.as_slice(), units.as_slice());
+
+        let with_nul = pd.as_slice_with_nul();
+        prop_assert_eq!(with_nul.len(), pd.len() + 1);
+        prop_assert_eq!(with_nul.last(), Some(&0));
+        prop_assert_eq!(&with_nul[..pd.len()], pd.as_slice());
+
+        prop_assert_eq!(pd.clone().into_vec(), units.clone());
+        let mut expected_with_nul = units;
+        expected_with_nul.push(0);
+        prop_assert_eq!(pd.into_vec_with_nul(), expected_with_nul);
+    }
+
+    #[test]
+    fn prefixes(s in without_nul(), split in any::<prop::sample::Index>()) {
+        let pd = PdCString::from_str(&s).unwrap();
+        let mut at = split.index(s.len() + 1);
+        while !s.is_char_boundary(at) {
+            at -= 1;
+        }
+        prop_assert!(pd.starts_with(&s[..at]));
+        prop_assert!(!pd.starts_with(&format!("{s}x")));
+    }
+
+    #[test]
+    fn interior_nul_is_rejected(prefix in without_nul(), suffix in any::<String>()) {
+        let s = format!("{prefix}\0{suffix}");
+        let err = PdCString::from_str(&s).unwrap_err();
+        prop_assert_eq!(err.nul_position(), to_units(&prefix).len());
+        prop_assert_eq!(err.into_vec(), to_units(&s));
+
+        prop_assert!(PdCString::from_os_str(OsStr::new(&s)).is_err());
+        prop_assert!(PdCString::from_vec(to_units(&s)).is_err());
+    }
+
+    #[test]
+    fn arbitrary_units(units in units_without_nul()) {
+        let pd = PdCString::from_vec(units.clone()).unwrap();
+        prop_assert_eq!(pd.as_slice(), units.as_slice());
+
+        let expected = lossy(&units);
+        prop_assert_eq!(pd.to_string_lossy(), expected.as_str());
+        prop_assert_eq!(pd.display().to_string(), expected.as_str());
+
+        let (reported, replaced) = pd.to_string_utf8_lossy_with_report();
+        prop_assert_eq!(reported, expected);
+        prop_assert_eq!(replaced, pd.to_string().is_err());
+
+        // the OsString representation preserves invalid data, including lone surrogates on Windows.
+        let round_trip = PdCString::from_os_str(pd.to_os_string()).unwrap();
+        prop_assert_eq!(round_trip.as_slice(), units.as_slice());
+    }
+
+    #[test]
+    fn slice_with_nul(units in units_without_nul()) {
+        let mut with_nul = units.clone();
+        with_nul.push(0);
+        let pd = PdCStr::from_slice_with_nul(&with_nul).unwrap();
+        prop_assert_eq!(pd.as_slice(), units.as_slice());
+        prop_assert_eq!(pd.as_slice_with_nul(), with_nul.as_slice());
+
+        if !units.is_empty() {
+            prop_assert!(PdCStr::from_slice_with_nul(&units).is_err());
+        }
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn lone_surrogates(
+        prefix in without_nul(),
+        surrogate in 0xD800u16..=0xDFFF,
+        suffix in without_nul(),
+    ) {
+        let mut units = to_units(&prefix);
+        units.push(surrogate);
+        units.extend(to_units(&suffix));
+        let pd = PdCString::from_vec(units).unwrap();
+
+        prop_assert!(pd.to_string().is_err());
+        prop_assert_eq!(
+            pd.to_string_lossy(),
+            format!("{prefix}\u{FFFD}{suffix}")
+        );
+        prop_assert!(pd.to_string_utf8_lossy_with_report().1);
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn os_string_conversions_move(bytes in prop::collection::vec(1..=u8::MAX, 0..64)) {
+        use netcorehost::pdcstring::other::PdCStringExt;
+        use std::{ffi::OsString, os::unix::ffi::OsStringExt};
+
+        let pd = PdCString::from_os_string(OsString::from_vec(bytes.clone())).unwrap();
+        prop_assert_eq!(pd.as_slice(), bytes.as_slice());
+        prop_assert_eq!(pd.into_os_string().into_vec(), bytes);
+    }
+}
@@ -0,0 +1,64 @@
+#![cfg(all(feature = "nethost", feature = "net6_0"))]
+
+use netcorehost::preflight::{self, PreflightCheckKind, PreflightOutcome, Requirements};
+use rusty_fork::rusty_fork_test;
+use std::path::PathBuf;
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn installed_runtime_passes() {
+        common::setup();
+
+        let report = preflight::check(
+            &Requirements::new()
+                .target_framework(common::test_netcore_version())
+                .assembly(common::test_dll_path().to_os_string()),
+        );
+        assert!(report.is_ok(), "{:#?}", report);
+        assert!(report
+            .checks()
+            .iter()
+            .any(|check| check.kind == PreflightCheckKind::HostfxrLoaded));
+    }
+
+    #[test]
+    fn unmet_requirements_fail() {
+        let report = preflight::check(
+            &Requirements::new()
+                .framework("Microsoft.NETCore.App", "999.0.0")
+                .target_framework("net48")
+                .assembly("does/not/exist/Missing.dll"),
+        );
+        assert!(!report.is_ok());
+
+        let outcome_of = |kind: &PreflightCheckKind| {
+            report
+                .checks()
+                .iter()
+                .find(|check| &check.kind == kind)
+                .map(|check| check.outcome.clone())
+                .unwrap()
+        };
+        assert!(matches!(
+            outcome_of(&PreflightCheckKind::Framework {
+                name: "Microsoft.NETCore.App".to_string(),
+                version: "999.0.0".to_string(),
+            }),
+            PreflightOutcome::Failed(_)
+        ));
+        assert!(matches!(
+            outcome_of(&PreflightCheckKind::TargetFramework("net48".to_string())),
+            PreflightOutcome::Failed(_)
+        ));
+        assert!(matches!(
+            outcome_of(&PreflightCheckKind::Assembly(PathBuf::from(
+                "does/not/exist/Missing.dll"
+            ))),
+            PreflightOutcome::Failed(_)
+        ));
+        assert_eq!(report.failures().count(), 3);
+    }
+}
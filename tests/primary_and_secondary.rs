@@ -1,6 +1,12 @@
 #![cfg(feature = "netcore3_0")]
 
-use netcorehost::nethost;
+use netcorehost::{
+    error::HostingSuccess,
+    hostfxr::{
+        HostfxrContext, InitializationKind, InitializedForUnknown, PropertyConflict, RuntimeConfig,
+    },
+    nethost, pdcstr,
+};
 use rusty_fork::rusty_fork_test;
 
 #[path = "common.rs"]
@@ -16,6 +22,11 @@ rusty_fork_test! {
             .initialize_for_runtime_config(common::test_runtime_config_path())
             .unwrap();
         assert!(context.is_primary());
+        assert_eq!(
+            context.initialization_success_detail(),
+            Some(HostingSuccess::Success)
+        );
+        assert_eq!(context.initialization_kind(), InitializationKind::Primary);
         context.close().unwrap();
     }
 
@@ -34,7 +45,89 @@ rusty_fork_test! {
             .initialize_for_runtime_config(common::test_runtime_config_path())
             .unwrap();
         assert!(!context2.is_primary());
+        let detail = context2.initialization_success_detail().unwrap();
+        assert!(detail.is_host_already_initialized());
+        assert!(!detail.is_different_runtime_properties());
+        assert_eq!(context2.initialization_kind(), InitializationKind::Secondary);
 
         context2.close().unwrap();
     }
+
+    #[test]
+    fn secondary_property_conflicts() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let mut context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        context
+            .set_runtime_properties([
+                (pdcstr!("TEST_SHARED"), pdcstr!("same")),
+                (pdcstr!("TEST_CONFLICT"), pdcstr!("primary")),
+            ])
+            .unwrap();
+        context.get_delegate_loader().unwrap();
+        assert!(context.property_conflicts().unwrap().is_empty());
+
+        let tfm = common::test_netcore_version();
+        let config = RuntimeConfig::new()
+            .tfm(&tfm)
+            .framework("Microsoft.NETCore.App", format!("{}.0", &tfm["net".len()..]))
+            .property("TEST_SHARED", "same")
+            .property("TEST_CONFLICT", "secondary")
+            .property("TEST_MISSING", "secondary");
+        let context2 = hostfxr.initialize_for_runtime_config_value(&config).unwrap();
+        assert!(!context2.is_primary());
+        assert_eq!(
+            context2.initialization_success_detail(),
+            Some(HostingSuccess::DifferentRuntimeProperties)
+        );
+        assert_eq!(
+            context2.initialization_kind(),
+            InitializationKind::SecondaryWithDifferentProperties
+        );
+
+        let conflicts = context2.property_conflicts().unwrap();
+        assert_eq!(
+            conflicts,
+            [
+                PropertyConflict {
+                    name: pdcstr!("TEST_CONFLICT").to_owned(),
+                    requested: pdcstr!("secondary").to_owned(),
+                    active: Some(pdcstr!("primary").to_owned()),
+                },
+                PropertyConflict {
+                    name: pdcstr!("TEST_MISSING").to_owned(),
+                    requested: pdcstr!("secondary").to_owned(),
+                    active: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn release_and_adopt_context() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let mut context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        context
+            .set_runtime_property_value(pdcstr!("TEST_PROPERTY"), pdcstr!("TEST_VALUE"))
+            .unwrap();
+        let handle = context.into_handle();
+
+        let context = unsafe {
+            HostfxrContext::<InitializedForUnknown>::from_handle(handle, hostfxr.clone(), true)
+        };
+        assert_eq!(context.handle(), handle);
+        assert_eq!(
+            context.get_runtime_property_value(pdcstr!("TEST_PROPERTY")).unwrap(),
+            pdcstr!("TEST_VALUE")
+        );
+        context.get_delegate_loader().unwrap();
+        context.close().unwrap();
+    }
 }
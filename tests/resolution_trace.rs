@@ -0,0 +1,70 @@
+#![cfg(feature = "unstable")]
+
+use netcorehost::unstable::{CandidateStatus, ResolutionReport};
+use std::path::PathBuf;
+
+#[cfg(feature = "netcore3_0")]
+use netcorehost::{nethost, pdcstr};
+#[cfg(feature = "netcore3_0")]
+use rusty_fork::rusty_fork_test;
+
+#[cfg(feature = "netcore3_0")]
+#[path = "common.rs"]
+mod common;
+
+const TRACE: &str = "\
+Tracing enabled @ Thu Jan  1 00:00:00 2026 GMT
+--- Invoked hostfxr_initialize_for_runtime_config [commit hash: abc]
+Reading fx resolver directory=[/usr/share/dotnet/host/fxr]
+Considering fxr version=[8.0.1]...
+Considering fxr version=[9.0.0]...
+Detected latest fxr version=[/usr/share/dotnet/host/fxr/9.0.0]...
+Resolved fxr [/usr/share/dotnet/host/fxr/9.0.0/libhostfxr.so]...
+Searching FX directory in [/usr/share/dotnet]
+Attempting FX roll forward starting from version='[8.0.0]'
+Found version [8.0.1]
+Ignoring FX version [8.0.2] without .deps.json
+Chose FX version [/usr/share/dotnet/shared/Microsoft.NETCore.App/8.0.1]
+The specified runtimeconfig.json [/app/missing.runtimeconfig.json] does not exist
+";
+
+#[test]
+fn parse_resolution_report() {
+    let report = ResolutionReport::parse(TRACE);
+
+    assert_eq!(report.lines().len(), TRACE.lines().count());
+    assert_eq!(
+        report.probed_paths(),
+        [
+            PathBuf::from("/usr/share/dotnet/host/fxr"),
+            PathBuf::from("/usr/share/dotnet")
+        ]
+    );
+    assert!(report
+        .candidates()
+        .iter()
+        .any(|candidate| candidate.version == "8.0.1"
+            && candidate.status == CandidateStatus::Considered));
+    assert!(report.candidates().iter().any(
+        |candidate| candidate.version == "9.0.0" && candidate.status == CandidateStatus::Chosen
+    ));
+    assert_eq!(
+        report.rejected_candidates().collect::<Vec<_>>(),
+        [("8.0.2", "without .deps.json")]
+    );
+    assert_eq!(report.errors().len(), 1);
+}
+
+#[cfg(feature = "netcore3_0")]
+rusty_fork_test! {
+    #[test]
+    fn traced_initialization_error() {
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let err = hostfxr
+            .initialize_for_runtime_config_traced(pdcstr!("PathThatDoesNotExist.runtimeconfig.json"))
+            .err()
+            .unwrap();
+        assert!(!err.report().lines().is_empty());
+        assert!(!err.report().errors().is_empty());
+    }
+}
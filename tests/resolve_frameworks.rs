@@ -0,0 +1,31 @@
+#![cfg(feature = "net9_0")]
+
+use netcorehost::{error::HostingError, nethost};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn resolves_frameworks_of_runtime_config() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let frameworks = match hostfxr.resolve_frameworks(common::test_runtime_config_path(), None) {
+            Ok(frameworks) => frameworks,
+            // hostfxr from before .NET 9 does not provide the API.
+            Err(HostingError::HostApiUnsupportedVersion) => return,
+            Err(err) => panic!("{err}"),
+        };
+
+        assert!(frameworks.is_satisfied());
+        let netcore_app = frameworks
+            .resolved
+            .iter()
+            .find(|framework| framework.name == "Microsoft.NETCore.App")
+            .unwrap();
+        assert!(netcore_app.resolved_version.is_some());
+        assert!(netcore_app.resolved_path.as_ref().unwrap().exists());
+    }
+}
@@ -1,6 +1,6 @@
 #![allow(deprecated)]
 
-use netcorehost::nethost;
+use netcorehost::{nethost, pdcstring::PdCString};
 use rusty_fork::rusty_fork_test;
 
 #[path = "common.rs"]
@@ -20,6 +20,34 @@ rusty_fork_test! {
         assert_eq!(result, 42);
     }
 
+    #[test]
+    #[cfg(feature = "netcore3_0")]
+    fn run_app_captured() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_dotnet_command_line(common::test_dll_path())
+            .unwrap();
+        let output = context.run_app_captured().unwrap();
+        assert_eq!(output.result.value(), 42);
+        assert_eq!(String::from_utf8_lossy(&output.stdout).trim(), "Hello from C#!");
+        assert!(output.stderr.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "netcore3_0")]
+    fn run_app_on_thread() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_dotnet_command_line(common::test_dll_path())
+            .unwrap();
+        let result = context.run_app_on_thread().unwrap().value();
+        assert_eq!(result, 42);
+    }
+
     #[test]
     #[cfg(feature = "netcore1_0")]
     fn run_app_direct() {
@@ -30,4 +58,35 @@ rusty_fork_test! {
         result.as_hosting_exit_code().unwrap();
         assert_eq!(result.value(), 42);
     }
+
+    #[test]
+    #[cfg(feature = "netcore1_0")]
+    fn run_main() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let dotnet_exe = PdCString::from_os_str(hostfxr.get_dotnet_exe()).unwrap();
+        let result = hostfxr.run_main(&[dotnet_exe, common::test_dll_path()]);
+        result.as_hosting_exit_code().unwrap();
+        assert_eq!(result.value(), 42);
+    }
+
+    #[test]
+    #[cfg(feature = "netcore2_1")]
+    fn run_main_with_startupinfo() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let dotnet_exe = PdCString::from_os_str(hostfxr.get_dotnet_exe()).unwrap();
+        let dotnet_root = PdCString::from_os_str(hostfxr.get_dotnet_root()).unwrap();
+        let app_path = common::test_dll_path();
+        let result = hostfxr.run_main_with_startupinfo(
+            &dotnet_exe,
+            &dotnet_root,
+            &app_path,
+            &[&*dotnet_exe, &*app_path],
+        );
+        result.as_hosting_exit_code().unwrap();
+        assert_eq!(result.value(), 42);
+    }
 }
@@ -0,0 +1,83 @@
+#![cfg(feature = "netcore3_0")]
+
+use netcorehost::{
+    hostfxr::{ConfigPropertyValue, RollForward, RuntimeConfig},
+    nethost, pdcstr,
+};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+#[test]
+fn empty_config() {
+    assert_eq!(RuntimeConfig::new().to_json(), r#"{"runtimeOptions":{}}"#);
+}
+
+#[test]
+fn single_framework() {
+    let config = RuntimeConfig::new()
+        .tfm("net8.0")
+        .framework("Microsoft.NETCore.App", "8.0.0")
+        .roll_forward(RollForward::LatestMinor);
+    assert_eq!(
+        config.to_json(),
+        r#"{"runtimeOptions":{"tfm":"net8.0","rollForward":"LatestMinor","framework":{"name":"Microsoft.NETCore.App","version":"8.0.0"}}}"#
+    );
+}
+
+#[test]
+fn multiple_frameworks_and_properties() {
+    let config = RuntimeConfig::new()
+        .framework("Microsoft.NETCore.App", "8.0.0")
+        .framework("Microsoft.AspNetCore.App", "8.0.0")
+        .property("System.GC.Server", true)
+        .property("System.GC.HeapCount", 4)
+        .property("Custom", "a \"quoted\"\\value\n")
+        .property("System.GC.Server", false);
+    assert_eq!(
+        config.to_json(),
+        concat!(
+            r#"{"runtimeOptions":{"frameworks":["#,
+            r#"{"name":"Microsoft.NETCore.App","version":"8.0.0"},"#,
+            r#"{"name":"Microsoft.AspNetCore.App","version":"8.0.0"}],"#,
+            r#""configProperties":{"System.GC.Server":false,"System.GC.HeapCount":4,"#,
+            r#""Custom":"a \"quoted\"\\value\n"}}}"#
+        )
+    );
+    assert_eq!(
+        config.get_property("System.GC.HeapCount"),
+        Some(&ConfigPropertyValue::Integer(4))
+    );
+    assert_eq!(config.get_frameworks().len(), 2);
+}
+
+#[test]
+fn parse_roll_forward() {
+    assert_eq!("latestmajor".parse(), Ok(RollForward::LatestMajor));
+    assert_eq!(RollForward::Disable.to_string(), "Disable");
+    assert!("Sideways".parse::<RollForward>().is_err());
+}
+
+rusty_fork_test! {
+    #[test]
+    fn initialize_with_generated_config() {
+        common::setup();
+
+        let tfm = common::test_netcore_version();
+        let config = RuntimeConfig::new()
+            .tfm(&tfm)
+            .framework("Microsoft.NETCore.App", format!("{}.0", &tfm["net".len()..]))
+            .roll_forward(RollForward::LatestPatch)
+            .property("TEST_PROPERTY", "TEST_VALUE");
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr.initialize_for_runtime_config_value(&config).unwrap();
+        assert_eq!(
+            context
+                .get_runtime_property_value(pdcstr!("TEST_PROPERTY"))
+                .unwrap(),
+            pdcstr!("TEST_VALUE")
+        );
+    }
+}
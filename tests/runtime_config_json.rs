@@ -0,0 +1,48 @@
+#![cfg(feature = "netcore3_0")]
+
+use netcorehost::{
+    error::HostingError, hostfxr::InitializeForRuntimeConfigJsonError, nethost, pdcstr,
+};
+use rusty_fork::rusty_fork_test;
+use std::{fs, ptr};
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn initialize_from_json() {
+        common::setup();
+
+        let runtime_config =
+            fs::read_to_string(common::test_runtime_config_path().to_os_string()).unwrap();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config_json(&runtime_config)
+            .unwrap();
+
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::test_dll_path())
+            .unwrap();
+        let hello = fn_loader
+            .get_function_with_default_signature(pdcstr!("Test.Program, Test"), pdcstr!("Hello"))
+            .unwrap();
+        let result = unsafe { hello(ptr::null(), 0) };
+        assert_eq!(result, 42);
+    }
+
+    #[test]
+    fn initialize_from_invalid_json() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let result = hostfxr.initialize_for_runtime_config_json("{ not json");
+        assert!(matches!(
+            result,
+            Err(InitializeForRuntimeConfigJsonError::Hosting(
+                HostingError::InvalidConfigFile
+            ))
+        ));
+    }
+}
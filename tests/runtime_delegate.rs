@@ -0,0 +1,72 @@
+#![cfg(feature = "netcore3_0")]
+
+use netcorehost::{
+    bindings::hostfxr::hostfxr_delegate_type,
+    hostfxr::{DelegateType, RuntimeDelegate},
+    nethost,
+};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn typed_runtime_delegate_matches_raw_delegate() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+
+        let delegate = context
+            .get_typed_runtime_delegate(DelegateType::LoadAssemblyAndGetFunctionPointer)
+            .unwrap();
+        assert!(matches!(
+            delegate,
+            RuntimeDelegate::LoadAssemblyAndGetFunctionPointer(_)
+        ));
+        assert_eq!(
+            delegate.delegate_type(),
+            DelegateType::LoadAssemblyAndGetFunctionPointer
+        );
+
+        let raw = context
+            .get_runtime_delegate(hostfxr_delegate_type::hdt_load_assembly_and_get_function_pointer)
+            .unwrap();
+        assert_eq!(delegate.as_raw(), raw);
+    }
+
+    #[test]
+    fn runtime_delegates_are_cached() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+
+        let r#type = hostfxr_delegate_type::hdt_load_assembly_and_get_function_pointer;
+        let first = context.get_runtime_delegate(r#type).unwrap();
+        context.get_delegate_loader().unwrap();
+        context.get_delegate_loader().unwrap();
+        assert_eq!(context.get_runtime_delegate(r#type).unwrap(), first);
+    }
+
+    #[test]
+    #[cfg(feature = "net5_0")]
+    fn typed_get_function_pointer_delegate() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+
+        let delegate = context
+            .get_typed_runtime_delegate(DelegateType::GetFunctionPointer)
+            .unwrap();
+        assert!(matches!(delegate, RuntimeDelegate::GetFunctionPointer(_)));
+    }
+}
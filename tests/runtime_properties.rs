@@ -1,11 +1,28 @@
 #![cfg(feature = "netcore3_0")]
 
-use netcorehost::{nethost, pdcstr};
+use netcorehost::{
+    hostfxr::{
+        is_sensitive_property_name, runtime_properties, AppendPathListError, RuntimeOptions,
+    },
+    nethost, pdcstr,
+    pdcstring::PdCString,
+};
 use rusty_fork::rusty_fork_test;
+use std::{path::PathBuf, str::FromStr};
 
 #[path = "common.rs"]
 mod common;
 
+#[test]
+fn sensitive_property_names() {
+    assert!(is_sensitive_property_name(pdcstr!("MY_API_TOKEN")));
+    assert!(is_sensitive_property_name(pdcstr!("db.Password")));
+    assert!(is_sensitive_property_name(pdcstr!("apikey")));
+    assert!(!is_sensitive_property_name(pdcstr!("MY_SETTING")));
+    assert!(!is_sensitive_property_name(pdcstr!("TOKE")));
+    assert!(!is_sensitive_property_name(pdcstr!("")));
+}
+
 rusty_fork_test! {
     #[test]
     fn runtime_properties() {
@@ -30,4 +47,272 @@ rusty_fork_test! {
         let property_value = properties.get(test_property_name).copied().unwrap();
         assert_eq!(test_property_value, property_value);
     }
+
+    #[test]
+    fn set_runtime_properties() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let mut context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+
+        let owned_name = PdCString::from_str("TEST_BULK_C").unwrap();
+        let owned_value = PdCString::from_str("C").unwrap();
+        context
+            .set_runtime_properties([
+                (pdcstr!("TEST_BULK_A"), pdcstr!("A")),
+                (pdcstr!("TEST_BULK_B"), pdcstr!("B")),
+                (&*owned_name, &*owned_value),
+            ])
+            .unwrap();
+
+        let properties = context.properties_with_prefix("TEST_BULK_").unwrap();
+        assert_eq!(properties.len(), 3);
+        assert_eq!(properties.get(pdcstr!("TEST_BULK_A")).copied(), Some(pdcstr!("A")));
+        assert_eq!(properties.get(pdcstr!("TEST_BULK_B")).copied(), Some(pdcstr!("B")));
+        assert_eq!(properties.get(pdcstr!("TEST_BULK_C")).copied(), Some(pdcstr!("C")));
+    }
+
+    #[test]
+    fn properties_with_prefix() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let mut context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+
+        context
+            .set_runtime_property_value(pdcstr!("TEST_PREFIX_A"), pdcstr!("A"))
+            .unwrap();
+        context
+            .set_runtime_property_value(pdcstr!("TEST_PREFIX_B"), pdcstr!("B"))
+            .unwrap();
+        context
+            .set_runtime_property_value(pdcstr!("OTHER_PROPERTY"), pdcstr!("C"))
+            .unwrap();
+
+        let properties = context.properties_with_prefix("TEST_PREFIX_").unwrap();
+        assert_eq!(properties.len(), 2);
+        assert_eq!(properties.get(pdcstr!("TEST_PREFIX_A")).copied(), Some(pdcstr!("A")));
+        assert_eq!(properties.get(pdcstr!("TEST_PREFIX_B")).copied(), Some(pdcstr!("B")));
+
+        let properties = context
+            .properties_matching(|_, value| *value == *"C")
+            .unwrap();
+        assert_eq!(properties.len(), 1);
+        assert!(properties.contains_key(pdcstr!("OTHER_PROPERTY")));
+    }
+
+    #[test]
+    fn diagnostics_redacts_sensitive_properties() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let mut context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+
+        context
+            .set_runtime_property_value(pdcstr!("MY_API_TOKEN"), pdcstr!("hunter2"))
+            .unwrap();
+        context
+            .set_runtime_property_value(pdcstr!("MY_SETTING"), pdcstr!("visible"))
+            .unwrap();
+
+        let output = format!("{context:?}");
+        assert!(output.contains("InitializedForRuntimeConfig"));
+        assert!(output.contains("runtime_version: Some("));
+        assert!(!output.contains("hunter2"));
+
+        let output = context.diagnostics().to_string();
+        assert!(output.contains("is_primary: true"));
+        assert!(output.contains("InitializedForRuntimeConfig"));
+        assert!(!output.contains("MY_SETTING"));
+
+        let output = context.diagnostics().with_properties(true).to_string();
+        assert!(output.contains("MY_API_TOKEN"));
+        assert!(!output.contains("hunter2"));
+        assert!(output.contains("visible"));
+
+        let output = context
+            .diagnostics()
+            .with_properties(true)
+            .redact_with(|_| false)
+            .to_string();
+        assert!(output.contains("hunter2"));
+    }
+
+    #[test]
+    fn non_ascii_property_round_trip() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let mut context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+
+        let name = PdCString::from_str("ПРОПЕРТИ_名前").unwrap();
+        let value = PdCString::from_str("/opt/продукт/アプリ/🦀").unwrap();
+        context.set_runtime_property_value(&name, &value).unwrap();
+
+        let property_value = context.get_runtime_property_value(&name).unwrap();
+        assert_eq!(property_value, value.as_ref());
+        assert_eq!(
+            property_value.to_string_utf8_lossy_with_report(),
+            ("/opt/продукт/アプリ/🦀".to_string(), false)
+        );
+    }
+
+    #[test]
+    fn trusted_platform_assemblies() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+
+        let tpa = context.trusted_platform_assemblies().unwrap();
+        assert!(tpa.iter().count() > 0);
+        assert!(tpa.iter().all(|path| path.is_absolute()));
+        assert!(tpa.contains_assembly("System.Private.CoreLib"));
+        assert!(tpa.contains_assembly("system.private.corelib"));
+        assert!(!tpa.contains_assembly("SomeAssemblyThatDoesNotExist"));
+    }
+
+    #[test]
+    fn append_path_list_properties() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let mut context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+
+        let library_path = std::env::current_dir()
+            .unwrap()
+            .join(common::library_dll_path().to_os_string());
+        let tpa_count = context.trusted_platform_assemblies().unwrap().iter().count();
+        context
+            .append_trusted_platform_assemblies([&library_path, &library_path])
+            .unwrap();
+        let tpa = context.trusted_platform_assemblies().unwrap();
+        assert_eq!(tpa.iter().count(), tpa_count + 1);
+        assert!(tpa.contains_assembly("ClassLibrary"));
+
+        let directory = library_path.parent().unwrap().to_path_buf();
+        context.append_native_search_directories([&directory]).unwrap();
+        assert!(context
+            .native_dll_search_directories()
+            .unwrap()
+            .contains(&directory));
+
+        let invalid = PathBuf::from(format!("a{}b", if cfg!(windows) { ';' } else { ':' }));
+        assert_eq!(
+            context.append_native_search_directories([&directory, &invalid]),
+            Err(AppendPathListError::InvalidPath(invalid))
+        );
+    }
+
+    #[test]
+    fn add_startup_hook() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let mut context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+
+        let library_path = std::env::current_dir()
+            .unwrap()
+            .join(common::library_dll_path().to_os_string());
+        let library_path = PdCString::from_os_str(library_path).unwrap();
+        context.add_startup_hook(&library_path).unwrap();
+        context.add_startup_hook(pdcstr!("StartupHookAssembly")).unwrap();
+        context.add_startup_hook(&library_path).unwrap();
+        assert_eq!(
+            context.startup_hooks().unwrap(),
+            [
+                PathBuf::from(library_path.to_os_string()),
+                PathBuf::from("StartupHookAssembly")
+            ]
+        );
+
+        for invalid in [pdcstr!("relative/Hook.dll"), pdcstr!("Hook.dll"), pdcstr!("Hook, Version=1.0.0.0")] {
+            assert!(matches!(
+                context.add_startup_hook(invalid),
+                Err(AppendPathListError::InvalidStartupHook(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn runtime_options() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let mut context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+
+        let options = RuntimeOptions::new()
+            .tiered_compilation(false)
+            .quick_jit_for_loops(true)
+            .ready_to_run(false);
+        context.apply_runtime_options(&options).unwrap();
+
+        assert_eq!(
+            context
+                .get_runtime_property_value(pdcstr!("System.Runtime.TieredCompilation"))
+                .unwrap(),
+            "false"
+        );
+        assert_eq!(
+            context
+                .get_runtime_property_value(pdcstr!(
+                    "System.Runtime.TieredCompilation.QuickJitForLoops"
+                ))
+                .unwrap(),
+            "true"
+        );
+        // ready to run is only configurable through the environment.
+        assert_eq!(options.properties().count(), 2);
+        assert_eq!(
+            options.environment(),
+            netcorehost::env::HostEnvironment::new().var("DOTNET_ReadyToRun", "0")
+        );
+    }
+
+    #[test]
+    fn well_known_properties() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+
+        let runtime_config_path = PathBuf::from(common::test_runtime_config_path().to_os_string());
+        let base_directory = context.app_base_directory().unwrap();
+        assert_eq!(
+            base_directory.components().collect::<Vec<_>>(),
+            runtime_config_path.parent().unwrap().components().collect::<Vec<_>>()
+        );
+
+        let tpa = context.trusted_platform_assemblies().unwrap();
+        let tpa_property = context
+            .get_runtime_property_value(PdCString::from_str(runtime_properties::TRUSTED_PLATFORM_ASSEMBLIES).unwrap())
+            .unwrap();
+        assert_eq!(tpa.as_pdcstr(), tpa_property);
+
+        let native_dll_search_directories = context.native_dll_search_directories().unwrap();
+        assert!(!native_dll_search_directories.is_empty());
+        assert!(native_dll_search_directories.iter().all(|dir| !dir.as_os_str().is_empty()));
+
+        assert!(context.startup_hooks().unwrap().is_empty());
+        context.app_paths().unwrap();
+        context.platform_resource_roots().unwrap();
+    }
 }
@@ -0,0 +1,68 @@
+#![cfg(feature = "netcore3_0")]
+
+use netcorehost::{nethost, pdcstr};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn runtime_started() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        assert!(!netcorehost::runtime_started());
+        assert!(!hostfxr.is_runtime_started());
+
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        assert!(!netcorehost::runtime_started());
+
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::test_dll_path())
+            .unwrap();
+        fn_loader
+            .get_function_with_default_signature(pdcstr!("Test.Program, Test"), pdcstr!("Hello"))
+            .unwrap();
+        assert!(netcorehost::runtime_started());
+        assert!(hostfxr.is_runtime_started());
+    }
+
+    #[test]
+    fn runtime_started_probe() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_dotnet_command_line(common::test_dll_path())
+            .unwrap();
+        context.run_app().as_hosting_exit_code().unwrap();
+
+        assert!(hostfxr.is_runtime_started());
+    }
+
+    #[test]
+    fn runtime_started_by_app() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_dotnet_command_line(common::test_dll_path())
+            .unwrap();
+        assert!(!netcorehost::runtime_started());
+        context.run_app().as_hosting_exit_code().unwrap();
+        assert!(netcorehost::runtime_started());
+    }
+
+    #[test]
+    fn runtime_not_started_by_failed_app() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let result = hostfxr.run_app(pdcstr!("DoesNotExist.dll"));
+        assert!(result.as_hosting_exit_code().is_err());
+        assert!(!netcorehost::runtime_started());
+    }
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "netcore3_0")]
+
+use netcorehost::{
+    hostfxr::{RequireRuntimeError, RuntimeVersionReq},
+    nethost,
+};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+fn req(s: &str) -> RuntimeVersionReq {
+    s.parse().unwrap()
+}
+
+#[test]
+fn version_requirements() {
+    assert!(req("8.0").matches("8.0.4"));
+    assert!(req("8.0").matches("8.5.0"));
+    assert!(!req("8.0").matches("9.0.0"));
+
+    assert!(req("~8.0.4").matches("8.0.10"));
+    assert!(!req("~8.0.4").matches("8.0.3"));
+    assert!(!req("~8.0.4").matches("8.1.0"));
+
+    assert!(req("=8.0").matches("8.0.7"));
+    assert!(!req("=8.0.4").matches("8.0.5"));
+
+    assert!(req(">=8.0.4, <8.0.10").matches("8.0.9"));
+    assert!(!req(">=8.0.4, <8.0.10").matches("8.0.10"));
+    assert!(req("<=8.0").matches("8.0.99"));
+    assert!(!req(">8.0").matches("8.0.99"));
+    assert!(req(">8.0").matches("8.1.0"));
+
+    assert!(req("*").matches("6.0.0"));
+    assert!(!req("*").matches("not a version"));
+
+    assert!("8.x".parse::<RuntimeVersionReq>().is_err());
+    assert!(">=".parse::<RuntimeVersionReq>().is_err());
+    assert_eq!(req(">=8.0.4,<9").to_string(), ">=8.0.4, <9");
+}
+
+rusty_fork_test! {
+    #[test]
+    fn require_runtime() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let resolved = context.resolved_runtime_version().unwrap();
+
+        let context = context
+            .require_runtime(&req(&format!("={resolved}")))
+            .unwrap();
+
+        let result = context.require_runtime(&req("<1.0"));
+        assert!(matches!(
+            result.err().unwrap(),
+            RequireRuntimeError::RuntimeVersionMismatch { resolved: ref version, .. } if *version == resolved
+        ));
+    }
+
+    #[test]
+    fn debug_info() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let info = context.debug_info().unwrap();
+
+        assert_eq!(info.runtime_version(), context.resolved_runtime_version().unwrap());
+        assert_eq!(info.process_id(), std::process::id());
+        assert!(info.coreclr_path().is_file());
+        assert!(info.dac_path().is_file());
+        assert!(info.runtime_dir().ends_with(info.runtime_version()));
+    }
+}
@@ -0,0 +1,87 @@
+use netcorehost::scratch::{ScratchDir, ORPHAN_TIMEOUT};
+use std::{
+    fs,
+    process::{Command, Stdio},
+    time::SystemTime,
+};
+
+#[test]
+fn scratch_dir_is_removed_on_drop() {
+    let root = std::env::temp_dir().join("netcorehost-scratch-test-drop");
+
+    let dir = ScratchDir::new_in(&root).unwrap();
+    let path = dir.path().to_path_buf();
+    assert!(path.is_dir());
+    fs::write(dir.join("file.txt"), "content").unwrap();
+
+    drop(dir);
+    assert!(!path.exists());
+
+    let _ = fs::remove_dir_all(root);
+}
+
+#[test]
+fn scratch_dirs_are_unique() {
+    let root = std::env::temp_dir().join("netcorehost-scratch-test-unique");
+
+    let first = ScratchDir::new_in(&root).unwrap();
+    let second = ScratchDir::new_in(&root).unwrap();
+    assert_ne!(first.path(), second.path());
+
+    drop((first, second));
+    let _ = fs::remove_dir_all(root);
+}
+
+#[test]
+fn scratch_dir_can_be_kept() {
+    let root = std::env::temp_dir().join("netcorehost-scratch-test-keep");
+
+    let path = ScratchDir::new_in(&root).unwrap().keep();
+    assert!(path.is_dir());
+
+    let _ = fs::remove_dir_all(root);
+}
+
+#[test]
+fn directories_of_exited_processes_are_removed() {
+    let root = std::env::temp_dir().join("netcorehost-scratch-test-exited");
+    fs::create_dir_all(&root).unwrap();
+
+    let mut child = Command::new(std::env::current_exe().unwrap())
+        .arg("--list")
+        .stdout(Stdio::null())
+        .spawn()
+        .unwrap();
+    let pid = child.id();
+    child.wait().unwrap();
+    let orphan = root.join(format!("{pid}-0"));
+    fs::create_dir_all(&orphan).unwrap();
+
+    let dir = ScratchDir::new_in(&root).unwrap();
+    assert!(!orphan.exists());
+
+    drop(dir);
+    let _ = fs::remove_dir_all(root);
+}
+
+#[test]
+#[cfg(unix)]
+fn directories_of_running_processes_are_kept() {
+    let root = std::env::temp_dir().join("netcorehost-scratch-test-running");
+    fs::create_dir_all(&root).unwrap();
+
+    let pid = std::os::unix::process::parent_id();
+    let other = root.join(format!("{pid}-0"));
+    fs::create_dir_all(&other).unwrap();
+    // the directory is kept even if it is older than the orphan timeout.
+    fs::File::open(&other)
+        .unwrap()
+        .set_modified(SystemTime::now() - ORPHAN_TIMEOUT * 2)
+        .unwrap();
+
+    let dir = ScratchDir::new_in(&root).unwrap();
+    assert!(other.is_dir());
+
+    drop(dir);
+    let _ = fs::remove_dir_all(root);
+}
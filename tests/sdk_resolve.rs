@@ -8,7 +8,7 @@ use std::{
 mod common;
 
 #[test]
-#[cfg(feature = "netcore3_0")]
+#[cfg(feature = "netcore2_1")]
 fn resolve_sdk() {
     let hostfxr = nethost::load_hostfxr().unwrap();
 
@@ -33,7 +33,30 @@ fn resolve_sdk() {
 }
 
 #[test]
-#[cfg(feature = "netcore3_0")]
+#[cfg(feature = "netcore2_0")]
+#[allow(deprecated)]
+fn resolve_sdk_legacy() {
+    let hostfxr = nethost::load_hostfxr().unwrap();
+
+    let actual_sdks = get_sdks();
+    let sdks_dir = actual_sdks
+        .first()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap();
+
+    let sdk = hostfxr
+        .resolve_sdk_legacy(&PdCString::from_os_str(sdks_dir).unwrap(), pdcstr!("."))
+        .unwrap()
+        .unwrap();
+
+    assert!(actual_sdks.contains(&sdk));
+}
+
+#[test]
+#[cfg(feature = "netcore2_1")]
 fn list_sdks() {
     let hostfxr = nethost::load_hostfxr().unwrap();
 
@@ -59,9 +82,13 @@ fn get_native_search_directories() {
     common::setup();
 
     let hostfxr = nethost::load_hostfxr().unwrap();
-    hostfxr
+    let directories = hostfxr
         .get_native_search_directories(&common::test_dll_path())
         .unwrap();
+    assert!(directories.len() > 1);
+    assert!(directories
+        .iter()
+        .all(|directory| !directory.as_os_str().is_empty()));
 }
 
 fn get_sdks() -> Vec<PathBuf> {
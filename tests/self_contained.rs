@@ -0,0 +1,84 @@
+#![cfg(feature = "netcore3_0")]
+
+use netcorehost::{hostfxr::Hostfxr, pdcstr, pdcstring::PdCString};
+use path_absolutize::Absolutize;
+use rusty_fork::rusty_fork_test;
+use std::{
+    iter,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+#[path = "common.rs"]
+mod common;
+
+fn runtime_identifier() -> String {
+    let os = if cfg!(windows) {
+        "win"
+    } else if cfg!(target_os = "macos") {
+        "osx"
+    } else {
+        "linux"
+    };
+    let arch = if cfg!(target_arch = "x86_64") {
+        "x64"
+    } else if cfg!(target_arch = "aarch64") {
+        "arm64"
+    } else if cfg!(target_arch = "x86") {
+        "x86"
+    } else {
+        "arm"
+    };
+    format!("{os}-{arch}")
+}
+
+fn publish_self_contained_test_project() -> PathBuf {
+    let output_dir = Path::new("tests/Test/bin/SelfContained")
+        .join(common::test_netcore_version())
+        .absolutize()
+        .unwrap()
+        .into_owned();
+    if output_dir.join("Test.dll").exists() {
+        return output_dir;
+    }
+
+    let status = Command::new("dotnet")
+        .arg("publish")
+        .arg("Test.csproj")
+        .arg("--framework")
+        .arg(common::test_netcore_version())
+        .arg("--runtime")
+        .arg(runtime_identifier())
+        .arg("--self-contained")
+        .arg("--output")
+        .arg(&output_dir)
+        .current_dir("tests/Test")
+        .status()
+        .expect("dotnet publish failed");
+    assert!(status.success(), "dotnet publish failed");
+    output_dir
+}
+
+rusty_fork_test! {
+    #[test]
+    fn run_self_contained_app() {
+        let app_dir = publish_self_contained_test_project();
+
+        let hostfxr = Hostfxr::load_self_contained(&app_dir).unwrap();
+        assert_eq!(hostfxr.get_dotnet_root(), app_dir);
+
+        let app_path = PdCString::from_os_str(app_dir.join("Test.dll")).unwrap();
+        let context = hostfxr
+            .initialize_for_self_contained_command_line(&app_path, iter::empty::<&netcorehost::pdcstring::PdCStr>())
+            .unwrap();
+        assert_eq!(
+            context
+                .get_runtime_property_value(pdcstr!("APP_CONTEXT_BASE_DIRECTORY"))
+                .unwrap()
+                .to_string_lossy()
+                .trim_end_matches(['/', '\\']),
+            app_dir.to_string_lossy()
+        );
+        assert_eq!(context.run_app().value(), 42);
+    }
+}
@@ -0,0 +1,125 @@
+#![cfg(all(feature = "netcore3_0", feature = "strict-checks", debug_assertions))]
+
+use std::panic::{self, AssertUnwindSafe};
+
+use netcorehost::{
+    hostfxr::{HostfxrContext, InitializedForCommandLine, InitializedForRuntimeConfig},
+    nethost, pdcstr,
+};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+fn assert_misuse<R>(expected: &str, f: impl FnOnce() -> R) {
+    let payload = match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(_) => panic!("misuse was not detected"),
+        Err(payload) => payload,
+    };
+    let message = payload
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| payload.downcast_ref::<&str>().copied())
+        .unwrap();
+    assert!(
+        message.starts_with("netcorehost API misuse") && message.contains(expected),
+        "unexpected panic message: {message}"
+    );
+}
+
+rusty_fork_test! {
+    #[test]
+    fn loader_used_after_close() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::test_dll_path())
+            .unwrap();
+        context.close().unwrap();
+
+        assert_misuse("already been closed", || {
+            fn_loader
+                .get_function_with_default_signature(pdcstr!("Test.Program, Test"), pdcstr!("Hello"))
+                .is_ok()
+        });
+    }
+
+    #[test]
+    fn property_set_after_runtime_loaded() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let mut context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let _fn_loader = context.get_delegate_loader().unwrap();
+
+        assert_misuse("after the runtime has been loaded", || {
+            context.set_runtime_property_value(pdcstr!("TEST_PROPERTY"), pdcstr!("TEST_VALUE"))
+        });
+        assert_misuse("after the runtime has been loaded", || {
+            context.remove_runtime_property_value(pdcstr!("TEST_PROPERTY"))
+        });
+    }
+
+    #[test]
+    fn raw_handle_reused_after_run_app() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_dotnet_command_line(common::test_dll_path())
+            .unwrap();
+        let handle = context.handle();
+        assert_eq!(context.run_app().value(), 42);
+
+        assert_misuse("already been closed", || unsafe {
+            HostfxrContext::<InitializedForCommandLine>::from_handle(handle, hostfxr.clone(), true)
+                .run_app()
+        });
+    }
+
+    #[test]
+    fn raw_handle_adopted_with_reloaded_library() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let handle = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap()
+            .into_handle();
+        // loading the same library again is not reported as a different library.
+        let other_hostfxr = nethost::load_hostfxr().unwrap();
+        let context = unsafe {
+            HostfxrContext::<InitializedForRuntimeConfig>::from_handle(handle, other_hostfxr, true)
+        };
+        drop(hostfxr);
+        context.get_delegate_loader().unwrap();
+        context.close().unwrap();
+    }
+
+    #[test]
+    fn valid_usage_is_not_reported() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let mut context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        context
+            .set_runtime_property_value(pdcstr!("TEST_PROPERTY"), pdcstr!("TEST_VALUE"))
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::test_dll_path())
+            .unwrap();
+        let hello = fn_loader
+            .get_function_with_default_signature(pdcstr!("Test.Program, Test"), pdcstr!("Hello"))
+            .unwrap();
+        assert_eq!(unsafe { hello(std::ptr::null(), 0) }, 42);
+        context.close().unwrap();
+    }
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "netcore3_0")]
+
+use netcorehost::{
+    hostfxr::{utf8_parts, StringExchangeBuffer, StringExchangeError},
+    nethost, pdcstr,
+};
+use rusty_fork::rusty_fork_test;
+use std::{ptr, slice};
+
+#[path = "common.rs"]
+mod common;
+
+fn write_if_fits(value: &str, buffer: *mut u8, capacity: i32) -> i32 {
+    if value.len() <= capacity as usize {
+        unsafe { ptr::copy_nonoverlapping(value.as_ptr(), buffer, value.len()) };
+    }
+    value.len() as i32
+}
+
+#[test]
+fn utf8_parts_of_str() {
+    let value = "häll😀";
+    let parts = utf8_parts(value);
+    assert_eq!(parts.len() as usize, value.len());
+    assert!(!parts.is_empty());
+    assert_eq!(parts.as_str(), value);
+    assert_eq!(
+        unsafe { slice::from_raw_parts(parts.as_ptr(), parts.len() as usize) },
+        value.as_bytes()
+    );
+    assert!(utf8_parts("").is_empty());
+}
+
+#[test]
+fn buffer_grows_to_required_length() {
+    let mut buffer = StringExchangeBuffer::with_capacity(4);
+    let mut calls = 0;
+    let received = buffer
+        .receive_string(|ptr, capacity| {
+            calls += 1;
+            write_if_fits("a longer string", ptr, capacity)
+        })
+        .unwrap();
+    assert_eq!(received, "a longer string");
+    assert_eq!(calls, 2);
+    assert!(buffer.capacity() >= received.len());
+
+    let mut calls = 0;
+    let received = buffer
+        .receive(|ptr, capacity| {
+            calls += 1;
+            write_if_fits("short", ptr, capacity)
+        })
+        .unwrap();
+    assert_eq!(received, "short");
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn empty_buffer() {
+    let received = StringExchangeBuffer::new()
+        .receive_string(|ptr, capacity| write_if_fits("", ptr, capacity))
+        .unwrap();
+    assert_eq!(received, "");
+}
+
+#[test]
+fn errors() {
+    let mut buffer = StringExchangeBuffer::new();
+    assert_eq!(
+        buffer.receive(|_, _| -1),
+        Err(StringExchangeError::Managed(-1))
+    );
+    assert_eq!(
+        buffer.receive(|_, capacity| capacity + 1),
+        Err(StringExchangeError::LengthUnstable)
+    );
+    assert!(matches!(
+        buffer.receive(|ptr, capacity| {
+            if capacity >= 1 {
+                unsafe { *ptr = 0xff };
+            }
+            1
+        }),
+        Err(StringExchangeError::InvalidUtf8(_))
+    ));
+}
+
+#[test]
+fn nested_thread_buffer() {
+    let outer = StringExchangeBuffer::receive_with_thread_buffer(|ptr, capacity| {
+        let inner = StringExchangeBuffer::receive_with_thread_buffer(|ptr, capacity| {
+            write_if_fits("inner", ptr, capacity)
+        })
+        .unwrap();
+        write_if_fits(&format!("outer {inner}"), ptr, capacity)
+    })
+    .unwrap();
+    assert_eq!(outer, "outer inner");
+}
+
+rusty_fork_test! {
+    #[test]
+    #[cfg(feature = "net5_0")]
+    fn exchange_with_managed_code() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+        let greet = fn_loader
+            .get_function_with_unmanaged_callers_only::<fn(*const u8, i32, *mut u8, i32) -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("Greet"),
+            )
+            .unwrap();
+
+        let name = "Wörld".repeat(100);
+        let parts = utf8_parts(&name);
+        let greeting = StringExchangeBuffer::receive_with_thread_buffer(|buffer, capacity| {
+            greet(parts.as_ptr(), parts.len(), buffer, capacity)
+        })
+        .unwrap();
+        assert_eq!(greeting, format!("Hello {name}!"));
+    }
+}
@@ -0,0 +1,65 @@
+#![cfg(all(feature = "testing", feature = "net5_0"))]
+
+use netcorehost::{nethost, pdcstr, testing::InlineFixture};
+use rusty_fork::rusty_fork_test;
+
+const SOURCE: &str = r#"
+namespace InlineFixtureTest;
+
+public static class Program {
+    [System.Runtime.InteropServices.UnmanagedCallersOnly]
+    public static int Answer() => 42;
+}
+"#;
+
+rusty_fork_test! {
+    #[test]
+    fn inline_fixture() {
+        let fixture = InlineFixture::new("InlineFixtureTest")
+            .source("Program.cs", SOURCE)
+            .build()
+            .unwrap();
+        assert!(fixture.assembly_path().exists());
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(fixture.runtime_config_path_pd())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(fixture.assembly_path_pd())
+            .unwrap();
+        let answer = fn_loader
+            .get_function_with_unmanaged_callers_only::<fn() -> i32>(
+                pdcstr!("InlineFixtureTest.Program, InlineFixtureTest"),
+                pdcstr!("Answer"),
+            )
+            .unwrap();
+        assert_eq!(answer(), 42);
+    }
+}
+
+#[test]
+fn inline_fixture_is_cached() {
+    let fixture = InlineFixture::new("InlineFixtureCacheTest").source("Program.cs", SOURCE);
+    let first = fixture.build().unwrap();
+    let modified = std::fs::metadata(first.assembly_path())
+        .unwrap()
+        .modified()
+        .unwrap();
+
+    let second = fixture.build().unwrap();
+    assert_eq!(first, second);
+    assert_eq!(
+        std::fs::metadata(second.assembly_path())
+            .unwrap()
+            .modified()
+            .unwrap(),
+        modified
+    );
+
+    let changed = InlineFixture::new("InlineFixtureCacheTest")
+        .source("Program.cs", SOURCE.replace("42", "43"))
+        .build()
+        .unwrap();
+    assert_ne!(first.output_dir(), changed.output_dir());
+}
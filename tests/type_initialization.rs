@@ -0,0 +1,50 @@
+#![cfg(feature = "net5_0")]
+
+use netcorehost::{hostfxr::GetManagedFunctionError, nethost, pdcstr};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn run_static_constructors() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+        let helper = pdcstr!("NativeInitializer, ClassLibrary");
+        let lazy_initialized = pdcstr!("ClassLibrary.LazyInitialized, ClassLibrary");
+        let failing_initializer = pdcstr!("ClassLibrary.FailingInitializer, ClassLibrary");
+        let static_constructor_runs = fn_loader
+            .get_function_with_unmanaged_callers_only::<fn() -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("GetStaticConstructorRuns"),
+            )
+            .unwrap();
+
+        assert_eq!(static_constructor_runs(), 0);
+        fn_loader.ensure_initialized(helper, lazy_initialized).unwrap();
+        assert_eq!(static_constructor_runs(), 1);
+        fn_loader.ensure_initialized(helper, lazy_initialized).unwrap();
+        assert_eq!(static_constructor_runs(), 1);
+        fn_loader.ensure_module_initialized(helper, lazy_initialized).unwrap();
+
+        // COR_E_TYPEINITIALIZATION
+        assert_eq!(
+            fn_loader.ensure_initialized(helper, failing_initializer).unwrap_err(),
+            GetManagedFunctionError::Other(0x8013_1534)
+        );
+        assert_eq!(
+            fn_loader
+                .ensure_initialized(helper, pdcstr!("ClassLibrary.DoesNotExist, ClassLibrary"))
+                .unwrap_err(),
+            GetManagedFunctionError::TypeNotFound
+        );
+    }
+}
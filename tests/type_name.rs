@@ -0,0 +1,58 @@
+#![cfg(feature = "netcore3_0")]
+
+use netcorehost::{hostfxr::TypeName, nethost, pdcstr};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+#[test]
+fn render_type_names() {
+    assert_eq!(TypeName::new("Test.Program").to_string(), "Test.Program");
+    assert_eq!(
+        TypeName::new("Test.Program")
+            .nested("Inner")
+            .nested("Innermost")
+            .assembly("Test")
+            .to_string(),
+        "Test.Program+Inner+Innermost, Test"
+    );
+    assert_eq!(
+        TypeName::new("System.Collections.Generic.Dictionary")
+            .generic_args([
+                TypeName::new("System.String").assembly("System.Private.CoreLib"),
+                TypeName::new("System.Int32"),
+            ])
+            .assembly("System.Private.CoreLib")
+            .to_string(),
+        "System.Collections.Generic.Dictionary`2[[System.String, System.Private.CoreLib],System.Int32], System.Private.CoreLib"
+    );
+}
+
+rusty_fork_test! {
+    #[test]
+    fn load_function_with_type_name() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+
+        let library = TypeName::new("ClassLibrary.Library").assembly("ClassLibrary");
+        let add_delegate = TypeName::new("ClassLibrary.Library")
+            .nested("AddDelegate")
+            .assembly("ClassLibrary");
+        let add = fn_loader
+            .get_function::<fn(i32, i32) -> i32>(
+                &library.to_pdcstring().unwrap(),
+                pdcstr!("Add"),
+                &add_delegate.to_pdcstring().unwrap(),
+            )
+            .unwrap();
+        assert_eq!(add(1, 2), 3);
+    }
+}
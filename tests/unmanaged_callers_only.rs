@@ -28,4 +28,38 @@ rusty_fork_test! {
         let result = hello();
         assert_eq!(result, 42);
     }
+
+    #[test]
+    #[cfg(feature = "net5_0")]
+    fn managed_function_is_callable() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+        let add = fn_loader
+            .get_function_with_unmanaged_callers_only::<fn(i32, i32) -> i32>(
+                pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                pdcstr!("AddUnmanaged"),
+            )
+            .unwrap();
+        context.close().unwrap();
+
+        // the function outlives the context and can be sent to other threads.
+        let result = std::thread::spawn(move || add(20, 22)).join().unwrap();
+        assert_eq!(result, 42);
+
+        #[cfg(feature = "nightly")]
+        {
+            fn apply(f: impl Fn(i32, i32) -> i32) -> i32 {
+                f(1, 2)
+            }
+            assert_eq!(apply(add), 3);
+        }
+    }
 }
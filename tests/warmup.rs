@@ -0,0 +1,104 @@
+#![cfg(feature = "netcore3_0")]
+
+use netcorehost::{
+    hostfxr::{GetManagedFunctionError, WarmupMethod},
+    nethost, pdcstr,
+};
+use rusty_fork::rusty_fork_test;
+
+#[path = "common.rs"]
+mod common;
+
+rusty_fork_test! {
+    #[test]
+    fn warmup_reports_each_method() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::test_dll_path())
+            .unwrap();
+
+        let report = fn_loader.warmup([
+            WarmupMethod::new(pdcstr!("Test.Program, Test"), pdcstr!("Hello")),
+            WarmupMethod::new(pdcstr!("Test.Program, Test"), pdcstr!("DoesNotExist")),
+        ]);
+
+        assert_eq!(report.entries().len(), 2);
+        assert!(!report.is_ok());
+
+        let hello = &report.entries()[0];
+        assert!(hello.is_ok());
+        assert_eq!(hello.method_name(), pdcstr!("Hello"));
+
+        let failures = report.failures().collect::<Vec<_>>();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].method_name(), pdcstr!("DoesNotExist"));
+        assert_eq!(
+            failures[0].error(),
+            Some(&GetManagedFunctionError::MissingMethod)
+        );
+        assert!(report.total_duration() >= hello.duration());
+    }
+
+    #[test]
+    fn warmup_accepts_tuples() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::test_dll_path())
+            .unwrap();
+
+        let report = fn_loader.warmup([(pdcstr!("Test.Program, Test"), pdcstr!("Hello"))]);
+        assert!(report.is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "net5_0")]
+    fn warmup_prepares_methods() {
+        common::setup();
+
+        let hostfxr = nethost::load_hostfxr().unwrap();
+        let context = hostfxr
+            .initialize_for_runtime_config(common::test_runtime_config_path())
+            .unwrap();
+        let fn_loader = context
+            .get_delegate_loader_for_assembly(common::library_dll_path())
+            .unwrap();
+
+        let report = fn_loader
+            .warmup_and_prepare(
+                pdcstr!("NativeWarmup, ClassLibrary"),
+                [
+                    (pdcstr!("ClassLibrary.Library, ClassLibrary"), pdcstr!("SumBytes")),
+                    (pdcstr!("ClassLibrary.Library, ClassLibrary"), pdcstr!("DoesNotExist")),
+                ],
+            )
+            .unwrap();
+
+        let sum_bytes = &report.entries()[0];
+        assert!(sum_bytes.is_ok());
+        assert!(sum_bytes.prepare_duration().unwrap() <= sum_bytes.duration());
+
+        let missing = &report.entries()[1];
+        assert_eq!(missing.error(), Some(&GetManagedFunctionError::MissingMethod));
+        assert_eq!(missing.prepare_duration(), None);
+
+        assert_eq!(
+            fn_loader
+                .warmup_and_prepare(
+                    pdcstr!("ClassLibrary.Library, ClassLibrary"),
+                    [(pdcstr!("ClassLibrary.Library, ClassLibrary"), pdcstr!("SumBytes"))],
+                )
+                .unwrap_err(),
+            GetManagedFunctionError::MissingMethod
+        );
+    }
+}